@@ -0,0 +1,276 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A bump allocator for short-lived contract executions.
+//!
+//! Allocations are served from a fixed size arena by bumping an offset and
+//! deallocations are ignored. Since every contract execution runs in a fresh
+//! Wasm instance that only lives for a single call, the memory it allocates
+//! never needs to be reused and can be released all at once via [`reset`].
+//!
+//! Once the arena is exhausted the allocator falls back to growing the Wasm
+//! memory as provided by the host.
+//!
+//! The allocator keeps track of the current usage and of the highest usage
+//! reached so far, which is retained across resets, to help developers tune
+//! their buffer sizes and discover hidden allocations.
+
+use core::{
+    alloc::{
+        GlobalAlloc,
+        Layout,
+    },
+    cell::{
+        Cell,
+        UnsafeCell,
+    },
+    ptr,
+};
+
+/// The size of the arena of the bump allocator in bytes.
+pub const ARENA_SIZE: usize = 64 * 1024;
+
+/// The state shared by all uses of the bump allocator.
+static STATE: State = State::new();
+
+/// A bump allocator serving allocations from a fixed size arena.
+///
+/// Deallocations are no-ops. Use [`reset`] to release all memory at once.
+pub struct BumpAllocator;
+
+unsafe impl GlobalAlloc for BumpAllocator {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        STATE.alloc(layout)
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
+
+/// The arena and the bookkeeping of the bump allocator.
+struct State {
+    /// The memory that allocations are served from.
+    arena: UnsafeCell<[u8; ARENA_SIZE]>,
+    /// The address of the first free byte of the current region.
+    ///
+    /// Is zero as long as the arena has not been used.
+    next: Cell<usize>,
+    /// The address one past the last byte of the current region.
+    end: Cell<usize>,
+    /// The number of bytes that have been allocated.
+    usage: Cell<usize>,
+    /// The highest number of bytes that have been allocated at once.
+    peak: Cell<usize>,
+}
+
+// Wasm smart contracts are executed single threaded so there can be no
+// concurrent accesses to the state of the allocator.
+unsafe impl Sync for State {}
+
+impl State {
+    /// Creates the state of an allocator that has not served any allocation.
+    const fn new() -> Self {
+        Self {
+            arena: UnsafeCell::new([0x00; ARENA_SIZE]),
+            next: Cell::new(0),
+            end: Cell::new(0),
+            usage: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    }
+
+    /// Allocates memory for the layout or returns a null pointer if neither
+    /// the current region nor newly grown memory pages can serve it.
+    fn alloc(&self, layout: Layout) -> *mut u8 {
+        if self.next.get() == 0 {
+            let start = self.arena.get() as usize;
+            self.set_region(start, start + ARENA_SIZE);
+        }
+        if let Some(ptr) = self.bump(layout) {
+            return ptr
+        }
+        match grow_memory(layout) {
+            Some((start, end)) => {
+                self.set_region(start, end);
+                self.bump(layout).unwrap_or_else(ptr::null_mut)
+            }
+            None => ptr::null_mut(),
+        }
+    }
+
+    /// Releases all memory so that the arena serves allocations from its start again.
+    ///
+    /// The peak usage is retained.
+    fn reset(&self) {
+        self.next.set(0);
+        self.end.set(0);
+        self.usage.set(0);
+    }
+
+    /// Makes the given memory region the one to serve allocations from.
+    ///
+    /// The rest of the previous region is counted as used since it is
+    /// never going to serve allocations again.
+    fn set_region(&self, start: usize, end: usize) {
+        if self.next.get() != 0 {
+            self.add_usage(self.end.get() - self.next.get());
+        }
+        self.next.set(start);
+        self.end.set(end);
+    }
+
+    /// Allocates memory for the layout from the current region if it fits.
+    fn bump(&self, layout: Layout) -> Option<*mut u8> {
+        let align_mask = layout.align() - 1;
+        let aligned = self.next.get().checked_add(align_mask)? & !align_mask;
+        let next = aligned.checked_add(layout.size())?;
+        if next > self.end.get() {
+            return None
+        }
+        self.add_usage(next - self.next.get());
+        self.next.set(next);
+        Some(aligned as *mut u8)
+    }
+
+    /// Adds the number of bytes to the usage and updates the peak usage.
+    fn add_usage(&self, bytes: usize) {
+        let usage = self.usage.get() + bytes;
+        self.usage.set(usage);
+        if usage > self.peak.get() {
+            self.peak.set(usage);
+        }
+    }
+}
+
+/// The size of a Wasm memory page in bytes.
+#[cfg(target_arch = "wasm32")]
+const PAGE_SIZE: usize = 64 * 1024;
+
+/// Grows the Wasm memory so that it can serve an allocation for the layout.
+///
+/// Returns the start and the end of the newly grown memory region or `None`
+/// if the host refused to grow the memory.
+///
+/// # Note
+///
+/// By default the memory is grown by a single page at a time which fails for
+/// allocations that do not fit into a single page. With the `multi-page-growth`
+/// crate feature enabled the memory is grown by as many pages as required.
+#[cfg(target_arch = "wasm32")]
+fn grow_memory(layout: Layout) -> Option<(usize, usize)> {
+    let required = layout.size().checked_add(layout.align() - 1)?;
+    let pages = if cfg!(feature = "multi-page-growth") {
+        required.checked_add(PAGE_SIZE - 1)? / PAGE_SIZE
+    } else {
+        1
+    };
+    let previous = core::arch::wasm32::memory_grow(0, pages);
+    if previous == usize::max_value() {
+        return None
+    }
+    let start = previous.checked_mul(PAGE_SIZE)?;
+    Some((start, start.checked_add(pages.checked_mul(PAGE_SIZE)?)?))
+}
+
+/// Memory can only be grown for Wasm compilations.
+#[cfg(not(target_arch = "wasm32"))]
+fn grow_memory(_layout: Layout) -> Option<(usize, usize)> {
+    None
+}
+
+/// Releases all memory that has been allocated so far.
+///
+/// The peak usage is retained across resets. Memory pages that have been
+/// grown after the arena was exhausted are not going to be reused.
+///
+/// # Safety
+///
+/// All memory that has been allocated before is freed. The caller must
+/// ensure that none of it is accessed afterwards, e.g. by only resetting
+/// at the very beginning of a contract execution.
+pub unsafe fn reset() {
+    STATE.reset()
+}
+
+/// Returns the number of bytes that are currently allocated.
+///
+/// This includes the padding required to align the allocations as well as
+/// the unused rest of exhausted memory regions.
+pub fn usage() -> usize {
+    STATE.usage.get()
+}
+
+/// Returns the highest number of bytes that have been allocated at once.
+pub fn peak_usage() -> usize {
+    STATE.peak.get()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(size: usize, align: usize) -> Layout {
+        Layout::from_size_align(size, align).unwrap()
+    }
+
+    #[test]
+    fn alloc_aligns_allocations() {
+        let state = State::new();
+        let first = state.alloc(layout(1, 1)) as usize;
+        assert_eq!(first, state.arena.get() as usize);
+        for &align in &[2, 4, 8, 16] {
+            let ptr = state.alloc(layout(3, align)) as usize;
+            assert_eq!(ptr % align, 0);
+            assert!(ptr > first);
+        }
+    }
+
+    #[test]
+    fn usage_accounts_for_padding() {
+        let state = State::new();
+        let first = state.alloc(layout(1, 1)) as usize;
+        assert_eq!(state.usage.get(), 1);
+        let second = state.alloc(layout(8, 8)) as usize;
+        assert_eq!(state.usage.get(), second + 8 - first);
+        assert_eq!(state.peak.get(), state.usage.get());
+    }
+
+    #[test]
+    fn reset_releases_memory_and_retains_peak() {
+        let state = State::new();
+        let first = state.alloc(layout(100, 1));
+        state.alloc(layout(50, 1));
+        assert_eq!(state.usage.get(), 150);
+        state.reset();
+        assert_eq!(state.usage.get(), 0);
+        assert_eq!(state.peak.get(), 150);
+        assert_eq!(state.alloc(layout(10, 1)), first);
+        assert_eq!(state.usage.get(), 10);
+        assert_eq!(state.peak.get(), 150);
+    }
+
+    #[test]
+    fn exhausted_arena_returns_null() {
+        let state = State::new();
+        assert!(!state.alloc(layout(ARENA_SIZE, 1)).is_null());
+        assert_eq!(state.usage.get(), ARENA_SIZE);
+        // Memory cannot be grown for non-Wasm compilations.
+        assert!(state.alloc(layout(1, 1)).is_null());
+        assert_eq!(state.usage.get(), ARENA_SIZE);
+        let state = State::new();
+        assert!(state.alloc(layout(ARENA_SIZE + 1, 1)).is_null());
+        assert_eq!(state.usage.get(), 0);
+    }
+}
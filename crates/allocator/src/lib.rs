@@ -16,15 +16,36 @@
 //!
 //! The Wee allocator is an allocator specifically designed to have a low footprint albeith
 //! being less efficient for allocation and deallocation operations.
+//!
+//! With the `bump` crate feature enabled a bump allocator is used instead which
+//! serves allocations from a fixed size arena and never frees memory on its own.
+//! This fits the short-lived contract executions well and allows to report the
+//! peak heap usage of an execution.
+//!
+//! If an allocation cannot be served the allocation error handler of `ink_env`
+//! prints the requested size and the current heap usage to the environmental log
+//! before the contract execution traps.
 
 #![cfg_attr(not(feature = "std"), no_std)]
-#![cfg_attr(not(feature = "std"), feature(alloc_error_handler, core_intrinsics))]
 
 // We use `wee_alloc` as the global allocator since it is optimized for binary file size
 // so that contracts compiled with it as allocator do not grow too much in size.
-#[cfg(not(feature = "std"))]
+#[cfg(all(not(feature = "std"), not(feature = "bump")))]
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
-#[cfg(not(feature = "std"))]
-mod handlers;
+#[cfg(all(not(feature = "std"), feature = "bump"))]
+#[global_allocator]
+static ALLOC: bump::BumpAllocator = bump::BumpAllocator;
+
+#[cfg(feature = "bump")]
+mod bump;
+
+#[cfg(feature = "bump")]
+pub use self::bump::{
+    peak_usage,
+    reset,
+    usage,
+    BumpAllocator,
+    ARENA_SIZE,
+};
@@ -21,9 +21,14 @@ use crate::{
         TypedEnvBackend,
     },
     call::{
+        build_call,
         utils::ReturnType,
         CallParams,
         CreateParams,
+        ExecutionInput,
+        LangError,
+        RevertEnvelope,
+        Selector,
     },
     engine::{
         EnvInstance,
@@ -35,8 +40,11 @@ use crate::{
     },
     topics::Topics,
     Environment,
+    Error,
+    EventError,
     Result,
 };
+use ink_prelude::vec::Vec;
 use ink_primitives::Key;
 
 /// Returns the address of the caller of the executed contract.
@@ -198,6 +206,11 @@ where
 }
 
 /// Emits an event with the given event data.
+///
+/// # Panics
+///
+/// If the encoding of the event topics or data exceeds the environmental buffer.
+/// Use [`try_emit_event`] in order to handle this case.
 pub fn emit_event<T, Event>(event: Event)
 where
     T: Environment,
@@ -208,6 +221,28 @@ where
     })
 }
 
+/// Emits an event with the given event data if its encoding fits into the
+/// environmental buffer.
+///
+/// The sizes of the encoded event topics and data are checked before they are
+/// written so that no event is emitted in case of an error.
+///
+/// # Errors
+///
+/// - [`EventError::TopicTooLarge`] if the encoding of one of the event topics
+///   does not fit into the environmental buffer.
+/// - [`EventError::DataTooLarge`] if the encoding of the event data does not
+///   fit into the environmental buffer.
+pub fn try_emit_event<T, Event>(event: Event) -> core::result::Result<(), EventError>
+where
+    T: Environment,
+    Event: Topics + scale::Encode,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::try_emit_event::<T, Event>(instance, event)
+    })
+}
+
 /// Sets the rent allowance of the executed contract to the new value.
 pub fn set_rent_allowance<T>(new_value: T::Balance)
 where
@@ -246,6 +281,26 @@ where
     })
 }
 
+/// Returns the encoded values stored under the given keys in the contract's storage.
+///
+/// The returned vector has one entry per key in the same order as `keys`.
+/// Entries of keys that do not store a value are `None`.
+///
+/// # Note
+///
+/// The contracts pallet provides no host function to load multiple storage
+/// entries at once, so every entry is loaded by its own host function call.
+/// This costs the same as loading the entries one by one.
+///
+/// # Errors
+///
+/// - If loading one of the entries failed
+pub fn get_contract_storage_batch(keys: &[Key]) -> Result<Vec<Option<Vec<u8>>>> {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::get_contract_storage_batch(instance, keys)
+    })
+}
+
 /// Clears the contract's storage key entry.
 pub fn clear_contract_storage(key: &Key) {
     <EnvInstance as OnInstance>::on_instance(|instance| {
@@ -334,6 +389,170 @@ where
     })
 }
 
+/// Invokes a contract message without panicking if the called contract reverts.
+///
+/// Returns `Ok(Err(lang_error))` if the called contract reverted with a
+/// language level error, e.g. because it does not know the called selector.
+///
+/// # Note
+///
+/// This is a low level way to invoke another smart contract.
+/// Prefer to use the ink! guided and type safe approach to using this.
+///
+/// # Errors
+///
+/// - If the called account does not exist.
+/// - If the called account is not a contract.
+/// - If the called contract is a tombstone.
+/// - If the called contract execution has trapped.
+/// - If the called contract ran out of gas upon execution.
+/// - If the called contract reverted without a language level error.
+pub fn try_invoke_contract<T, Args>(
+    params: &CallParams<T, Args, ()>,
+) -> Result<core::result::Result<(), LangError>>
+where
+    T: Environment,
+    Args: scale::Encode,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::try_invoke_contract::<T, Args>(instance, params)
+    })
+}
+
+/// Evaluates a contract message without panicking if the called contract reverts.
+///
+/// Returns `Ok(Err(lang_error))` if the called contract reverted with a
+/// language level error, e.g. because it does not know the called selector.
+///
+/// # Note
+///
+/// This is a low level way to evaluate another smart contract.
+/// Prefer to use the ink! guided and type safe approach to using this.
+///
+/// # Errors
+///
+/// - If the called account does not exist.
+/// - If the called account is not a contract.
+/// - If the called contract is a tombstone.
+/// - If the called contract execution has trapped.
+/// - If the called contract ran out of gas upon execution.
+/// - If the called contract reverted without a language level error.
+/// - If the returned value failed to decode properly.
+pub fn try_eval_contract<T, Args, R>(
+    params: &CallParams<T, Args, ReturnType<R>>,
+) -> Result<core::result::Result<R, LangError>>
+where
+    T: Environment,
+    Args: scale::Encode,
+    R: scale::Decode,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::try_eval_contract::<T, Args, R>(instance, params)
+    })
+}
+
+/// Calls the message with the given selector of the executed contract itself.
+///
+/// Unlike a plain method call the message is dispatched through the host as a
+/// separate contract execution. Therefore all storage changes of the called
+/// message are rolled back if it fails which allows to implement "try this,
+/// roll back on failure" logic.
+///
+/// The `args` are encoded directly after the selector. Use a tuple to pass
+/// multiple arguments and `()` to pass none.
+///
+/// # Note
+///
+/// The called message operates on the contract storage as it was written at
+/// the end of the last contract execution since ink! only writes back storage
+/// changes at the end of a message. Likewise a calling message that mutates
+/// the storage would overwrite the storage changes of the called message upon
+/// returning. For this reason the ink! dispatcher denies self-calls from
+/// `&mut self` messages and self-calls can only be issued from `&self` messages.
+///
+/// Self-calls of `#[ink(message, non_reentrant)]` messages from within another
+/// non-reentrant message fail since the reentrancy guard of the calling message
+/// is still held.
+///
+/// # Errors
+///
+/// - If the executed ink! message is a `&mut self` message.
+/// - If the called message execution has trapped or reverted.
+/// - If the called message ran out of gas upon execution.
+/// - If the returned value failed to decode properly.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ink_env::{call::Selector, DefaultEnvironment};
+/// let applied: bool = ink_env::call_self::<DefaultEnvironment, _, _>(
+///     Selector::new([0xDE, 0xAD, 0xBE, 0xEF]),
+///     (42u32, true),
+/// )
+/// .unwrap_or(false);
+/// ```
+pub fn call_self<T, Args, R>(selector: Selector, args: Args) -> Result<R>
+where
+    T: Environment,
+    Args: scale::Encode,
+    R: scale::Decode,
+{
+    let denied = <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::self_calls_denied(instance)
+    });
+    if denied {
+        return Err(Error::SelfCallDenied)
+    }
+    build_call::<T>()
+        .callee(account_id::<T>()?)
+        .exec_input(ExecutionInput::new(selector).push_arg(args))
+        .returns::<ReturnType<R>>()
+        .fire()
+}
+
+/// Sets whether self-calls via [`call_self`] are denied.
+///
+/// # Note
+///
+/// Used by the ink! dispatcher to deny self-calls while executing ink! messages
+/// that mutate the contract storage.
+#[doc(hidden)]
+pub fn set_self_calls_denied(denied: bool) {
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::set_self_calls_denied(instance, denied)
+    })
+}
+
+/// Instantiates another contract without panicking if its constructor reverts.
+///
+/// Returns `Ok(Err(lang_error))` if the instantiated contract reverted with a
+/// language level error, e.g. because it does not know the called selector.
+///
+/// # Note
+///
+/// This is a low level way to instantiate another smart contract.
+/// Prefer to use the ink! guided and type safe approach to using this.
+///
+/// # Errors
+///
+/// - If the code hash is invalid.
+/// - If the instantiation process traps.
+/// - If the instantiation process runs out of gas.
+/// - If given insufficient endowment.
+/// - If the instantiated contract reverted without a language level error.
+/// - If the returned account ID failed to decode properly.
+pub fn try_instantiate_contract<T, Args, C>(
+    params: &CreateParams<T, Args, C>,
+) -> Result<core::result::Result<T::AccountId, LangError>>
+where
+    T: Environment,
+    Args: scale::Encode,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::try_instantiate_contract::<T, Args, C>(instance, params)
+    })
+}
+
 /// Restores a smart contract in tombstone state.
 ///
 /// # Params
@@ -457,6 +676,23 @@ where
     })
 }
 
+/// Hands the event to the chain extension function of the runtime receiving it.
+///
+/// This allows runtimes to mirror contract events into their pallets.
+/// In contrast to [`emit_event`] the event is not deposited as contract event.
+///
+/// # Errors
+///
+/// - If the chain extension function of the event does not exist in the runtime.
+/// - If the runtime cannot decode the event.
+#[cfg(feature = "ink-unstable-chain-extensions")]
+pub fn deposit_runtime_event<E>(event: &E) -> Result<()>
+where
+    E: crate::RuntimeEvent,
+{
+    call_chain_extension::<E, ()>(<E as crate::RuntimeEvent>::FUNC_ID, event)
+}
+
 /// Returns the execution input to the executed contract and decodes it as `T`.
 ///
 /// # Note
@@ -489,6 +725,28 @@ where
     })
 }
 
+/// Reads the input of the executed contract and decodes it unless it is longer
+/// than `max_len` bytes.
+///
+/// # Note
+///
+/// Used by the ink! dispatch to reject large inputs before decoding them.
+/// Inputs that exceed the 16kB environmental buffer trap the execution.
+///
+/// # Errors
+///
+/// - [`Error::InputTooLarge`](`crate::Error::InputTooLarge`) if the input is
+///   longer than `max_len` bytes.
+/// - If the given `T` cannot be properly decoded from the expected input.
+pub fn decode_input_bounded<T>(max_len: usize) -> Result<T>
+where
+    T: scale::Decode,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        EnvBackend::decode_input_bounded::<T>(instance, max_len)
+    })
+}
+
 /// Returns the value back to the caller of the executed contract.
 ///
 /// # Note
@@ -503,6 +761,49 @@ where
     })
 }
 
+/// Reverts the execution of the contract with the given raw data.
+///
+/// Unlike [`return_value`] the data is returned as is without being encoded.
+/// This allows to transparently forward the data that a called contract
+/// has reverted with, see [`Error::revert_data`](`crate::Error::revert_data`).
+///
+/// # Note
+///
+/// This function stops the execution of the contract immediately.
+pub fn revert_with_data(data: &[u8]) -> ! {
+    /// Encodes the wrapped bytes as they are without a length prefix.
+    struct RawBytes<'a>(&'a [u8]);
+
+    impl scale::Encode for RawBytes<'_> {
+        fn size_hint(&self) -> usize {
+            self.0.len()
+        }
+
+        fn encode_to<O: scale::Output>(&self, dest: &mut O) {
+            dest.write(self.0)
+        }
+    }
+
+    return_value(ReturnFlags::default().set_reverted(true), &RawBytes(data))
+}
+
+/// Reverts the execution of the contract with the given error code and payload.
+///
+/// The code and the SCALE encoded payload are returned within a
+/// [`RevertEnvelope`] that callers can decode via
+/// [`Error::revert_envelope`](`crate::Error::revert_envelope`).
+///
+/// # Note
+///
+/// This function stops the execution of the contract immediately.
+pub fn revert_with_code<D>(code: u32, data: &D) -> !
+where
+    D: scale::Encode,
+{
+    let envelope = RevertEnvelope::new(code, scale::Encode::encode(data));
+    return_value(ReturnFlags::default().set_reverted(true), &envelope)
+}
+
 /// Returns a random hash seed.
 ///
 /// # Note
@@ -529,6 +830,27 @@ pub fn debug_println(content: &str) {
     })
 }
 
+/// Prints the current and the peak heap usage to the environmental log.
+///
+/// # Note
+///
+/// This is called automatically whenever a contract execution returns
+/// if the `heap-instrumentation` crate feature is enabled. The report is
+/// formatted on the stack so that it does not alter the heap usage itself.
+#[cfg(feature = "heap-instrumentation")]
+pub fn report_heap_usage() {
+    let mut report = ink_prelude::tinyfmt::Buffer::<[u8; 96]>::new();
+    report
+        .push_str("heap usage: ")
+        .push(&ink_allocator::usage())
+        .push_str(" bytes, peak: ")
+        .push(&ink_allocator::peak_usage())
+        .push_str(" of ")
+        .push(&ink_allocator::ARENA_SIZE)
+        .push_str(" bytes");
+    debug_println(report.as_str())
+}
+
 /// Conducts the crypto hash of the given input and stores the result in `output`.
 pub fn hash_bytes<H>(input: &[u8], output: &mut <H as HashOutput>::Type)
 where
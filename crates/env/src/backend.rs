@@ -17,6 +17,7 @@ use crate::{
         utils::ReturnType,
         CallParams,
         CreateParams,
+        LangError,
     },
     hash::{
         CryptoHash,
@@ -24,8 +25,10 @@ use crate::{
     },
     topics::Topics,
     Environment,
+    EventError,
     Result,
 };
+use ink_prelude::vec::Vec;
 use ink_primitives::Key;
 
 /// The flags to indicate further information about the end of a contract execution.
@@ -71,6 +74,18 @@ pub trait EnvBackend {
     where
         R: scale::Decode;
 
+    /// Returns the encoded values stored under the given keys in the contract's storage.
+    ///
+    /// The returned vector has one entry per key in the same order as `keys`.
+    ///
+    /// # Errors
+    ///
+    /// - If loading one of the entries failed
+    fn get_contract_storage_batch(
+        &mut self,
+        keys: &[Key],
+    ) -> Result<Vec<Option<Vec<u8>>>>;
+
     /// Clears the contract's storage key entry.
     fn clear_contract_storage(&mut self, key: &Key);
 
@@ -101,6 +116,23 @@ pub trait EnvBackend {
     where
         T: scale::Decode;
 
+    /// Reads the input of the executed contract and decodes it unless it is
+    /// longer than `max_len` bytes.
+    ///
+    /// # Note
+    ///
+    /// The input is read only once so that large inputs are rejected before
+    /// they are decoded.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InputTooLarge`](`crate::Error::InputTooLarge`) if the input
+    ///   is longer than `max_len` bytes.
+    /// - If the given `T` cannot be properly decoded from the expected input.
+    fn decode_input_bounded<T>(&mut self, max_len: usize) -> Result<T>
+    where
+        T: scale::Decode;
+
     /// Returns the value back to the caller of the executed contract.
     ///
     /// # Note
@@ -117,6 +149,12 @@ pub trait EnvBackend {
     /// Prints the given contents to the console log.
     fn println(&mut self, content: &str);
 
+    /// Sets whether self-calls via [`call_self`](`crate::call_self`) are denied.
+    fn set_self_calls_denied(&mut self, denied: bool);
+
+    /// Returns `true` if self-calls via [`call_self`](`crate::call_self`) are denied.
+    fn self_calls_denied(&self) -> bool;
+
     /// Conducts the crypto hash of the given input and stores the result in `output`.
     fn hash_bytes<H>(&mut self, input: &[u8], output: &mut <H as HashOutput>::Type)
     where
@@ -234,6 +272,19 @@ pub trait TypedEnvBackend: EnvBackend {
         T: Environment,
         Event: Topics + scale::Encode;
 
+    /// Emits an event with the given event data if it fits into the environmental buffer.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::try_emit_event`]
+    fn try_emit_event<T, Event>(
+        &mut self,
+        event: Event,
+    ) -> core::result::Result<(), EventError>
+    where
+        T: Environment,
+        Event: Topics + scale::Encode;
+
     /// Sets the rent allowance of the executed contract to the new value.
     ///
     /// # Note
@@ -283,6 +334,46 @@ pub trait TypedEnvBackend: EnvBackend {
         T: Environment,
         Args: scale::Encode;
 
+    /// Invokes a contract message and decodes the language level error it reverted with.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::try_invoke_contract`]
+    fn try_invoke_contract<T, Args>(
+        &mut self,
+        call_data: &CallParams<T, Args, ()>,
+    ) -> Result<core::result::Result<(), LangError>>
+    where
+        T: Environment,
+        Args: scale::Encode;
+
+    /// Evaluates a contract message and decodes the language level error it reverted with.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::try_eval_contract`]
+    fn try_eval_contract<T, Args, R>(
+        &mut self,
+        call_data: &CallParams<T, Args, ReturnType<R>>,
+    ) -> Result<core::result::Result<R, LangError>>
+    where
+        T: Environment,
+        Args: scale::Encode,
+        R: scale::Decode;
+
+    /// Instantiates another contract and decodes the language level error it reverted with.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::try_instantiate_contract`]
+    fn try_instantiate_contract<T, Args, C>(
+        &mut self,
+        params: &CreateParams<T, Args, C>,
+    ) -> Result<core::result::Result<T::AccountId, LangError>>
+    where
+        T: Environment,
+        Args: scale::Encode;
+
     /// Restores a smart contract tombstone.
     ///
     /// # Note
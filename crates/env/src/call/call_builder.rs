@@ -22,6 +22,8 @@ use crate::{
             Unwrap,
         },
         ExecutionInput,
+        Forwarded,
+        LangError,
     },
     Environment,
     Error,
@@ -36,8 +38,8 @@ where
 {
     /// The account ID of the to-be-called smart contract.
     callee: E::AccountId,
-    /// The maximum gas costs allowed for the call.
-    gas_limit: u64,
+    /// The policy of how much gas is forwarded to the call.
+    gas_limit: Forwarded,
     /// The transferred value for the call.
     transferred_value: E::Balance,
     /// The expected return type.
@@ -46,12 +48,6 @@ where
     exec_input: ExecutionInput<Args>,
 }
 
-#[cfg(
-    // We do not currently support cross-contract calling in the off-chain
-    // environment so we do not have to provide these getters in case of
-    // off-chain environment compilation.
-    all(not(feature = "std"), target_arch = "wasm32")
-)]
 impl<E, Args, R> CallParams<E, Args, R>
 where
     E: Environment,
@@ -62,9 +58,9 @@ where
         &self.callee
     }
 
-    /// Returns the chosen gas limit for the called contract execution.
+    /// Returns the chosen gas forwarding policy for the called contract execution.
     #[inline]
-    pub(crate) fn gas_limit(&self) -> u64 {
+    pub(crate) fn gas_limit(&self) -> Forwarded {
         self.gas_limit
     }
 
@@ -95,6 +91,14 @@ where
     pub fn invoke(&self) -> Result<(), crate::Error> {
         crate::invoke_contract(self)
     }
+
+    /// Invokes the contract with the given built-up call parameters.
+    ///
+    /// Returns the language level error of the called contract instead of
+    /// an error if the called contract reverted because of it.
+    pub fn try_invoke(&self) -> Result<Result<(), LangError>, crate::Error> {
+        crate::try_invoke_contract(self)
+    }
 }
 
 impl<E, Args, R> CallParams<E, Args, ReturnType<R>>
@@ -114,6 +118,14 @@ where
     pub fn eval(&self) -> Result<R, crate::Error> {
         crate::eval_contract(self)
     }
+
+    /// Evaluates the contract with the given built-up call parameters.
+    ///
+    /// Returns the language level error of the called contract instead of
+    /// an error if the called contract reverted because of it.
+    pub fn try_eval(&self) -> Result<Result<R, LangError>, crate::Error> {
+        crate::try_eval_contract(self)
+    }
 }
 
 /// Returns a new [`CallBuilder`] to build up the parameters to a cross-contract call.
@@ -197,7 +209,7 @@ where
 pub fn build_call<E>() -> CallBuilder<
     E,
     Unset<E::AccountId>,
-    Unset<u64>,
+    Unset<Forwarded>,
     Unset<E::Balance>,
     Unset<ExecutionInput<EmptyArgumentList>>,
     Unset<ReturnType<()>>,
@@ -253,25 +265,51 @@ where
 }
 
 impl<E, Callee, TransferredValue, Args, RetType>
-    CallBuilder<E, Callee, Unset<u64>, TransferredValue, Args, RetType>
+    CallBuilder<E, Callee, Unset<Forwarded>, TransferredValue, Args, RetType>
 where
     E: Environment,
 {
     /// Sets the maximumly allowed gas costs for the call.
+    ///
+    /// Either an absolute amount of gas or a policy of how much of the gas
+    /// that is left at the time of the call is forwarded, e.g. `Forwarded::Percent(90)`
+    /// in order to reserve some gas for the caller after the call.
+    /// If not set all the gas that is left is forwarded.
     #[inline]
-    pub fn gas_limit(
+    pub fn gas_limit<G>(
         self,
-        gas_limit: u64,
-    ) -> CallBuilder<E, Callee, Set<u64>, TransferredValue, Args, RetType> {
+        gas_limit: G,
+    ) -> CallBuilder<E, Callee, Set<Forwarded>, TransferredValue, Args, RetType>
+    where
+        G: Into<Forwarded>,
+    {
         CallBuilder {
             env_types: Default::default(),
             callee: self.callee,
-            gas_limit: Set(gas_limit),
+            gas_limit: Set(gas_limit.into()),
             transferred_value: self.transferred_value,
             exec_input: self.exec_input,
             return_type: self.return_type,
         }
     }
+
+    /// Sets the gas limit for the call to the estimated gas plus a safety margin.
+    ///
+    /// The margin is an integer percentage of the estimate and not a floating
+    /// point multiplier, e.g. `.gas_margin(estimate, 120)` in order to forward
+    /// 1.2 times the estimate. The estimate itself has to be provided by the
+    /// caller since ink_env does not perform dry-runs.
+    ///
+    /// This is a shorthand for `.gas_limit(Forwarded::with_margin(estimate, margin))`.
+    /// See [`with_margin`](`crate::call::with_margin`) for details.
+    #[inline]
+    pub fn gas_margin(
+        self,
+        estimated_gas: u64,
+        margin_percent: u16,
+    ) -> CallBuilder<E, Callee, Set<Forwarded>, TransferredValue, Args, RetType> {
+        self.gas_limit(Forwarded::with_margin(estimated_gas, margin_percent))
+    }
 }
 
 impl<E, Callee, GasLimit, Args, RetType>
@@ -383,14 +421,14 @@ impl<E, GasLimit, TransferredValue, Args, RetType>
     >
 where
     E: Environment,
-    GasLimit: Unwrap<Output = u64>,
+    GasLimit: Unwrap<Output = Forwarded>,
     TransferredValue: Unwrap<Output = E::Balance>,
 {
     /// Finalizes the call builder to call a function.
     pub fn params(self) -> CallParams<E, Args, RetType> {
         CallParams {
             callee: self.callee.value(),
-            gas_limit: self.gas_limit.unwrap_or_else(|| 0),
+            gas_limit: self.gas_limit.unwrap_or_else(Forwarded::default),
             transferred_value: self
                 .transferred_value
                 .unwrap_or_else(|| E::Balance::from(0u32)),
@@ -411,7 +449,7 @@ impl<E, GasLimit, TransferredValue, Args>
     >
 where
     E: Environment,
-    GasLimit: Unwrap<Output = u64>,
+    GasLimit: Unwrap<Output = Forwarded>,
     Args: scale::Encode,
     TransferredValue: Unwrap<Output = E::Balance>,
 {
@@ -419,6 +457,14 @@ where
     pub fn fire(self) -> Result<(), Error> {
         self.params().invoke()
     }
+
+    /// Invokes the cross-chain function call.
+    ///
+    /// Returns the language level error of the called contract instead of
+    /// an error if the called contract reverted because of it.
+    pub fn try_invoke(self) -> Result<Result<(), LangError>, Error> {
+        self.params().try_invoke()
+    }
 }
 
 impl<E, GasLimit, TransferredValue, Args, R>
@@ -432,7 +478,7 @@ impl<E, GasLimit, TransferredValue, Args, R>
     >
 where
     E: Environment,
-    GasLimit: Unwrap<Output = u64>,
+    GasLimit: Unwrap<Output = Forwarded>,
     Args: scale::Encode,
     R: scale::Decode,
     TransferredValue: Unwrap<Output = E::Balance>,
@@ -441,4 +487,12 @@ where
     pub fn fire(self) -> Result<R, Error> {
         self.params().eval()
     }
+
+    /// Invokes the cross-chain function call and returns the result.
+    ///
+    /// Returns the language level error of the called contract instead of
+    /// an error if the called contract reverted because of it.
+    pub fn try_invoke(self) -> Result<Result<R, LangError>, Error> {
+        self.params().try_eval()
+    }
 }
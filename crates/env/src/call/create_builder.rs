@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    arithmetic::Saturating as _,
     call::{
         utils::{
             EmptyArgumentList,
@@ -21,7 +22,16 @@ use crate::{
             Unset,
             Unwrap,
         },
+        contract_address,
+        with_margin,
         ExecutionInput,
+        LangError,
+        Salt,
+        SaltedArgs,
+    },
+    hash::{
+        CryptoHash,
+        HashOutput,
     },
     Environment,
     Error,
@@ -106,6 +116,144 @@ where
     pub fn instantiate(&self) -> Result<R, crate::Error> {
         crate::instantiate_contract(self).map(FromAccountId::from_account_id)
     }
+
+    /// Instantiates the contract and returns its account ID back to the caller.
+    ///
+    /// Returns the language level error of the instantiated contract instead of
+    /// an error if the instantiated contract reverted because of it.
+    #[inline]
+    pub fn try_instantiate(&self) -> Result<Result<R, LangError>, crate::Error> {
+        crate::try_instantiate_contract(self)
+            .map(|result| result.map(FromAccountId::from_account_id))
+    }
+
+    /// Instantiates the contract and returns the details of the instantiation.
+    ///
+    /// In contrast to [`CreateParams::instantiate`] this also returns the account ID
+    /// and the amount by which the instantiation decreased the balance of the caller.
+    #[inline]
+    pub fn instantiate_detailed(&self) -> Result<Instantiated<E, R>, crate::Error> {
+        let balance_before = crate::balance::<E>()?;
+        let account_id = crate::instantiate_contract(self)?;
+        Instantiated::new(account_id, balance_before)
+    }
+
+    /// Instantiates the contract and returns the details of the instantiation.
+    ///
+    /// Returns the language level error of the instantiated contract instead of
+    /// an error if the instantiated contract reverted because of it.
+    #[inline]
+    pub fn try_instantiate_detailed(
+        &self,
+    ) -> Result<Result<Instantiated<E, R>, LangError>, crate::Error> {
+        let balance_before = crate::balance::<E>()?;
+        match crate::try_instantiate_contract(self)? {
+            Ok(account_id) => Instantiated::new(account_id, balance_before).map(Ok),
+            Err(lang_error) => Ok(Err(lang_error)),
+        }
+    }
+}
+
+impl<E, Args, R> CreateParams<E, Args, R>
+where
+    E: Environment,
+    Args: scale::Encode,
+{
+    /// Returns the account ID that is assigned to the contract if the `deployer`
+    /// instantiates it using these parameters.
+    ///
+    /// `H` must be the hasher of the runtime, see
+    /// [`contract_address`](`crate::call::contract_address`).
+    #[inline]
+    pub fn predict_account_id<H>(
+        &self,
+        deployer: &E::AccountId,
+    ) -> Result<E::AccountId, crate::Error>
+    where
+        H: CryptoHash + HashOutput<Type = [u8; 32]>,
+    {
+        let input = scale::Encode::encode(&self.exec_input);
+        contract_address::<E, H>(deployer, &self.code_hash, &input)
+    }
+}
+
+/// The details of a successful contract instantiation.
+#[derive(Debug)]
+pub struct Instantiated<E, R>
+where
+    E: Environment,
+{
+    /// The account ID of the instantiated contract.
+    account_id: E::AccountId,
+    /// The amount by which the instantiation decreased the balance of the caller.
+    balance_spent: E::Balance,
+    /// The instantiated contract.
+    contract: R,
+}
+
+impl<E, R> Instantiated<E, R>
+where
+    E: Environment,
+    R: FromAccountId<E>,
+{
+    /// Creates the details of the instantiation of the contract with the given
+    /// account ID given the balance of the caller before the instantiation.
+    fn new(account_id: E::AccountId, balance_before: E::Balance) -> Result<Self, Error> {
+        let balance_after = crate::balance::<E>()?;
+        Ok(Self::from_balances(
+            account_id,
+            balance_before,
+            balance_after,
+        ))
+    }
+
+    /// Creates the details of the instantiation of the contract with the given
+    /// account ID given the balance of the caller before and after the instantiation.
+    fn from_balances(
+        account_id: E::AccountId,
+        balance_before: E::Balance,
+        balance_after: E::Balance,
+    ) -> Self {
+        Self {
+            contract: FromAccountId::from_account_id(account_id.clone()),
+            account_id,
+            balance_spent: balance_before.saturating_sub(balance_after),
+        }
+    }
+}
+
+impl<E, R> Instantiated<E, R>
+where
+    E: Environment,
+{
+    /// Returns the account ID of the instantiated contract.
+    #[inline]
+    pub fn account_id(&self) -> &E::AccountId {
+        &self.account_id
+    }
+
+    /// Returns the amount by which the instantiation decreased the balance of the caller.
+    ///
+    /// This is the difference of the balance of the caller before and after the
+    /// instantiation, i.e. the endowment of the instantiated contract together with
+    /// everything else the chain charged the caller for it. The contracts pallet does
+    /// not expose the storage deposit on its own, so it cannot be reported separately.
+    #[inline]
+    pub fn balance_spent(&self) -> E::Balance {
+        self.balance_spent
+    }
+
+    /// Returns a shared reference to the instantiated contract.
+    #[inline]
+    pub fn contract(&self) -> &R {
+        &self.contract
+    }
+
+    /// Returns the instantiated contract.
+    #[inline]
+    pub fn into_contract(self) -> R {
+        self.contract
+    }
 }
 
 /// Builds up contract instantiations.
@@ -232,6 +380,24 @@ where
             return_type: self.return_type,
         }
     }
+
+    /// Sets the gas limit for the instantiation to the estimated gas plus a
+    /// safety margin.
+    ///
+    /// The margin is an integer percentage of the estimate and not a floating
+    /// point multiplier, e.g. `.gas_margin(estimate, 120)` in order to provide
+    /// 1.2 times the estimate. The estimate itself has to be provided by the
+    /// caller since ink_env does not perform dry-runs.
+    ///
+    /// See [`with_margin`](`crate::call::with_margin`).
+    #[inline]
+    pub fn gas_margin(
+        self,
+        estimated_gas: u64,
+        margin_percent: u16,
+    ) -> CreateBuilder<E, CodeHash, Set<u64>, Endowment, Args, R> {
+        self.gas_limit(with_margin(estimated_gas, margin_percent))
+    }
 }
 
 impl<E, CodeHash, GasLimit, Args, R>
@@ -286,6 +452,37 @@ where
     }
 }
 
+impl<E, CodeHash, GasLimit, Endowment, Args, R>
+    CreateBuilder<E, CodeHash, GasLimit, Endowment, Set<ExecutionInput<Args>>, R>
+where
+    E: Environment,
+{
+    /// Appends the salt to the input of the contract instantiation.
+    ///
+    /// See [`Salt`](`crate::call::Salt`).
+    #[inline]
+    pub fn salt(
+        self,
+        salt: Salt,
+    ) -> CreateBuilder<
+        E,
+        CodeHash,
+        GasLimit,
+        Endowment,
+        Set<ExecutionInput<SaltedArgs<Args>>>,
+        R,
+    > {
+        CreateBuilder {
+            env_types: Default::default(),
+            code_hash: self.code_hash,
+            gas_limit: self.gas_limit,
+            endowment: self.endowment,
+            exec_input: Set(self.exec_input.value().salted(salt)),
+            return_type: self.return_type,
+        }
+    }
+}
+
 impl<E, GasLimit, Args, R>
     CreateBuilder<
         E,
@@ -332,4 +529,106 @@ where
     pub fn instantiate(self) -> Result<R, Error> {
         self.params().instantiate()
     }
+
+    /// Instantiates the contract using the given instantiation parameters.
+    ///
+    /// Returns the language level error of the instantiated contract instead of
+    /// an error if the instantiated contract reverted because of it.
+    #[inline]
+    pub fn try_instantiate(self) -> Result<Result<R, LangError>, Error> {
+        self.params().try_instantiate()
+    }
+
+    /// Instantiates the contract and returns the details of the instantiation.
+    #[inline]
+    pub fn instantiate_detailed(self) -> Result<Instantiated<E, R>, Error> {
+        self.params().instantiate_detailed()
+    }
+
+    /// Instantiates the contract and returns the details of the instantiation.
+    ///
+    /// Returns the language level error of the instantiated contract instead of
+    /// an error if the instantiated contract reverted because of it.
+    #[inline]
+    pub fn try_instantiate_detailed(
+        self,
+    ) -> Result<Result<Instantiated<E, R>, LangError>, Error> {
+        self.params().try_instantiate_detailed()
+    }
+
+    /// Instantiates the contract under the account ID derived from the salt.
+    ///
+    /// Appends the salt to the input, predicts the account ID of the contract
+    /// instantiated by the executed contract and checks the account ID that the
+    /// chain assigned to the instantiated contract against the prediction.
+    ///
+    /// `H` must be the hasher of the runtime, see
+    /// [`contract_address`](`crate::call::contract_address`).
+    ///
+    /// # Errors
+    ///
+    /// If the instantiation failed.
+    ///
+    /// # Panics
+    ///
+    /// If the assigned account ID differs from the predicted account ID.
+    /// The instantiated contract already holds its endowment at this point so
+    /// the contract traps in order to revert the instantiation as a whole
+    /// instead of leaving behind a contract that is not known to the caller.
+    #[inline]
+    pub fn instantiate_salted<H>(self, salt: Salt) -> Result<Instantiated<E, R>, Error>
+    where
+        H: CryptoHash + HashOutput<Type = [u8; 32]>,
+    {
+        let params = self.salt(salt).params();
+        let predicted = params.predict_account_id::<H>(&crate::account_id::<E>()?)?;
+        let instantiated = params.instantiate_detailed()?;
+        assert!(
+            instantiated.account_id() == &predicted,
+            "encountered unexpected account ID of the instantiated contract",
+        );
+        Ok(instantiated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccountId,
+        DefaultEnvironment,
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct MockContract(AccountId);
+
+    impl FromAccountId<DefaultEnvironment> for MockContract {
+        fn from_account_id(account_id: AccountId) -> Self {
+            Self(account_id)
+        }
+    }
+
+    #[test]
+    fn instantiated_from_balances_works() {
+        let account_id = AccountId::from([0x42; 32]);
+        let instantiated =
+            Instantiated::<DefaultEnvironment, MockContract>::from_balances(
+                account_id, 1000, 900,
+            );
+        assert_eq!(instantiated.account_id(), &account_id);
+        assert_eq!(instantiated.balance_spent(), 100);
+        assert_eq!(instantiated.contract(), &MockContract(account_id));
+        assert_eq!(instantiated.into_contract(), MockContract(account_id));
+    }
+
+    #[test]
+    fn instantiated_balance_spent_saturates() {
+        let instantiated =
+            Instantiated::<DefaultEnvironment, MockContract>::from_balances(
+                AccountId::from([0x42; 32]),
+                900,
+                1000,
+            );
+        assert_eq!(instantiated.balance_spent(), 0);
+    }
 }
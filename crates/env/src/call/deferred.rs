@@ -0,0 +1,336 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    call::{
+        build_call,
+        CallParams,
+        ExecutionInput,
+        Forwarded,
+        Selector,
+    },
+    Environment,
+    Result,
+};
+use ink_prelude::vec::Vec;
+
+/// A cross-contract call that is stored in order to be executed later.
+///
+/// Allows contracts such as timelocks or governance contracts to store calls
+/// in their contract storage and to replay them at a later point in time.
+///
+/// The arguments of the call are stored in their encoded form so that calls
+/// with arbitrary arguments can be stored by the same type.
+pub struct DeferredCall<E>
+where
+    E: Environment,
+{
+    /// The account ID of the to-be-called smart contract.
+    callee: E::AccountId,
+    /// The policy of how much gas is forwarded to the call.
+    gas_limit: Forwarded,
+    /// The transferred value for the call.
+    transferred_value: E::Balance,
+    /// The selector of the called message.
+    selector: Selector,
+    /// The encoded arguments of the called message.
+    args: Vec<u8>,
+}
+
+// The traits are implemented manually since deriving them would require
+// the environment `E` itself to implement them.
+
+impl<E> core::fmt::Debug for DeferredCall<E>
+where
+    E: Environment,
+    E::AccountId: core::fmt::Debug,
+    E::Balance: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DeferredCall")
+            .field("callee", &self.callee)
+            .field("gas_limit", &self.gas_limit)
+            .field("transferred_value", &self.transferred_value)
+            .field("selector", &self.selector)
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl<E> Clone for DeferredCall<E>
+where
+    E: Environment,
+{
+    fn clone(&self) -> Self {
+        Self {
+            callee: self.callee.clone(),
+            gas_limit: self.gas_limit,
+            transferred_value: self.transferred_value,
+            selector: self.selector,
+            args: self.args.clone(),
+        }
+    }
+}
+
+impl<E> PartialEq for DeferredCall<E>
+where
+    E: Environment,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.callee == other.callee
+            && self.gas_limit == other.gas_limit
+            && self.transferred_value == other.transferred_value
+            && self.selector == other.selector
+            && self.args == other.args
+    }
+}
+
+impl<E> Eq for DeferredCall<E> where E: Environment {}
+
+impl<E> scale::Encode for DeferredCall<E>
+where
+    E: Environment,
+{
+    fn size_hint(&self) -> usize {
+        scale::Encode::size_hint(&self.callee)
+            + scale::Encode::size_hint(&self.gas_limit)
+            + scale::Encode::size_hint(&self.transferred_value)
+            + scale::Encode::size_hint(&self.selector)
+            + scale::Encode::size_hint(&self.args)
+    }
+
+    fn encode_to<O: scale::Output>(&self, dest: &mut O) {
+        scale::Encode::encode_to(&self.callee, dest);
+        scale::Encode::encode_to(&self.gas_limit, dest);
+        scale::Encode::encode_to(&self.transferred_value, dest);
+        scale::Encode::encode_to(&self.selector, dest);
+        scale::Encode::encode_to(&self.args, dest);
+    }
+}
+
+impl<E> scale::EncodeLike for DeferredCall<E> where E: Environment {}
+
+impl<E> scale::Decode for DeferredCall<E>
+where
+    E: Environment,
+{
+    fn decode<I: scale::Input>(
+        input: &mut I,
+    ) -> core::result::Result<Self, scale::Error> {
+        Ok(Self {
+            callee: scale::Decode::decode(input)?,
+            gas_limit: scale::Decode::decode(input)?,
+            transferred_value: scale::Decode::decode(input)?,
+            selector: scale::Decode::decode(input)?,
+            args: scale::Decode::decode(input)?,
+        })
+    }
+}
+
+impl<E> DeferredCall<E>
+where
+    E: Environment,
+{
+    /// Creates a deferred call from the given built-up call parameters.
+    ///
+    /// # Note
+    ///
+    /// The return type of the call parameters is discarded since the result
+    /// of a deferred call is not returned to its executor.
+    pub fn from_params<Args, R>(params: &CallParams<E, Args, R>) -> Self
+    where
+        Args: scale::Encode,
+    {
+        Self {
+            callee: params.callee().clone(),
+            gas_limit: params.gas_limit(),
+            transferred_value: *params.transferred_value(),
+            selector: params.exec_input().selector(),
+            args: scale::Encode::encode(params.exec_input().args()),
+        }
+    }
+
+    /// Returns the account ID of the called contract instance.
+    pub fn callee(&self) -> &E::AccountId {
+        &self.callee
+    }
+
+    /// Returns the chosen gas forwarding policy for the called contract execution.
+    pub fn gas_limit(&self) -> Forwarded {
+        self.gas_limit
+    }
+
+    /// Returns the transferred value for the called contract.
+    pub fn transferred_value(&self) -> &E::Balance {
+        &self.transferred_value
+    }
+
+    /// Returns the selector of the called message.
+    pub fn selector(&self) -> Selector {
+        self.selector
+    }
+
+    /// Returns the encoded arguments of the called message.
+    pub fn args(&self) -> &[u8] {
+        &self.args
+    }
+
+    /// Executes the deferred call.
+    ///
+    /// Equal to calling [`execute_deferred`].
+    pub fn execute(&self) -> Result<()> {
+        execute_deferred(self)
+    }
+}
+
+/// Executes the given deferred call.
+///
+/// # Errors
+///
+/// If the called contract has trapped or reverted.
+pub fn execute_deferred<E>(call: &DeferredCall<E>) -> Result<()>
+where
+    E: Environment,
+{
+    build_call::<E>()
+        .callee(call.callee.clone())
+        .gas_limit(call.gas_limit)
+        .transferred_value(call.transferred_value)
+        .exec_input(ExecutionInput::new(call.selector).push_arg(EncodedArgs(&call.args)))
+        .returns::<()>()
+        .fire()
+}
+
+/// Encodes already encoded arguments as they are without a length prefix.
+struct EncodedArgs<'a>(&'a [u8]);
+
+impl scale::Encode for EncodedArgs<'_> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    fn encode_to<O: scale::Output>(&self, output: &mut O) {
+        output.write(self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use scale_info::{
+        build::Fields,
+        Path,
+        Type,
+        TypeInfo,
+    };
+
+    impl<E> TypeInfo for DeferredCall<E>
+    where
+        E: Environment + 'static,
+        E::AccountId: TypeInfo,
+        E::Balance: TypeInfo,
+    {
+        fn type_info() -> Type {
+            Type::builder()
+                .path(Path::new("DeferredCall", "ink_env::call"))
+                .composite(
+                    Fields::named()
+                        .field_of::<E::AccountId>("callee")
+                        .field_of::<Forwarded>("gas_limit")
+                        .field_of::<E::Balance>("transferred_value")
+                        .field_of::<[u8; 4]>("selector")
+                        .field_of::<Vec<u8>>("args"),
+                )
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccountId,
+        DefaultEnvironment,
+    };
+
+    /// An environment that implements none of the traits of `DeferredCall`.
+    enum BareEnvironment {}
+
+    impl Environment for BareEnvironment {
+        const MAX_EVENT_TOPICS: usize = 4;
+
+        type AccountId = AccountId;
+        type Balance = <DefaultEnvironment as Environment>::Balance;
+        type Hash = <DefaultEnvironment as Environment>::Hash;
+        type Timestamp = <DefaultEnvironment as Environment>::Timestamp;
+        type BlockNumber = <DefaultEnvironment as Environment>::BlockNumber;
+        type TopicHasher = crate::hash::Blake2x256;
+    }
+
+    #[test]
+    fn from_params_works() {
+        let params = build_call::<DefaultEnvironment>()
+            .callee(AccountId::from([0x42; 32]))
+            .gas_limit(5000)
+            .transferred_value(10)
+            .exec_input(
+                ExecutionInput::new(Selector::new([0xDE, 0xAD, 0xBE, 0xEF]))
+                    .push_arg(42i32)
+                    .push_arg(true),
+            )
+            .returns::<()>()
+            .params();
+        let deferred = DeferredCall::from_params(&params);
+        assert_eq!(deferred.callee(), &AccountId::from([0x42; 32]));
+        assert_eq!(deferred.gas_limit(), Forwarded::Absolute(5000));
+        assert_eq!(deferred.transferred_value(), &10);
+        assert_eq!(deferred.selector(), Selector::new([0xDE, 0xAD, 0xBE, 0xEF]));
+        assert_eq!(deferred.args(), &scale::Encode::encode(&(42i32, true))[..]);
+        let encoded = scale::Encode::encode(&deferred);
+        let decoded = <DeferredCall<DefaultEnvironment> as scale::Decode>::decode(
+            &mut &encoded[..],
+        )
+        .unwrap();
+        assert_eq!(decoded, deferred);
+        assert_eq!(
+            scale::Encode::encode(params.exec_input()),
+            scale::Encode::encode(
+                &ExecutionInput::new(deferred.selector())
+                    .push_arg(EncodedArgs(deferred.args()))
+            ),
+        );
+    }
+
+    #[test]
+    fn traits_do_not_require_environment_traits() {
+        let deferred = DeferredCall::<BareEnvironment> {
+            callee: AccountId::from([0x42; 32]),
+            gas_limit: Forwarded::Absolute(5000),
+            transferred_value: 10,
+            selector: Selector::new([0xDE, 0xAD, 0xBE, 0xEF]),
+            args: vec![0x01, 0x02],
+        };
+        let encoded = scale::Encode::encode(&deferred);
+        let decoded =
+            <DeferredCall<BareEnvironment> as scale::Decode>::decode(&mut &encoded[..])
+                .unwrap();
+        assert_eq!(decoded, deferred.clone());
+        assert!(format!("{:?}", deferred).starts_with("DeferredCall {"));
+        assert_eq!(
+            <DeferredCall<BareEnvironment> as scale_info::TypeInfo>::type_info(),
+            <DeferredCall<DefaultEnvironment> as scale_info::TypeInfo>::type_info(),
+        );
+    }
+}
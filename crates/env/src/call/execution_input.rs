@@ -12,7 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::call::Selector;
+use crate::call::{
+    solidity::{
+        SolidityEncode,
+        WORD_LEN,
+    },
+    Salt,
+    SaltedArgs,
+    Selector,
+};
 
 /// The input data for a smart contract execution.
 #[derive(Debug)]
@@ -63,6 +71,91 @@ impl<'a, Head, Rest> ExecutionInput<ArgumentList<Argument<Head>, Rest>> {
     }
 }
 
+impl ExecutionInput<SolidityArgs<EmptyArgumentList>> {
+    /// Creates a new execution input for calling a Solidity smart contract.
+    ///
+    /// The selector is made up of the first four bytes of the Keccak-256 hash of
+    /// the function signature, e.g. `transfer(address,uint256)`.
+    /// Arguments are encoded according to the Solidity contract ABI.
+    #[inline]
+    pub fn new_solidity(selector: Selector) -> Self {
+        Self {
+            selector,
+            args: SolidityArgs {
+                args: ArgumentList::empty(),
+            },
+        }
+    }
+
+    /// Pushes an argument to the execution input.
+    #[inline]
+    pub fn push_arg<T>(
+        self,
+        arg: T,
+    ) -> ExecutionInput<SolidityArgs<ArgumentList<Argument<T>, EmptyArgumentList>>>
+    where
+        T: SolidityEncode,
+    {
+        ExecutionInput {
+            selector: self.selector,
+            args: SolidityArgs {
+                args: self.args.args.push_solidity_arg(arg),
+            },
+        }
+    }
+}
+
+impl<Head, Rest> ExecutionInput<SolidityArgs<ArgumentList<Argument<Head>, Rest>>> {
+    /// Pushes an argument to the execution input.
+    #[inline]
+    pub fn push_arg<T>(
+        self,
+        arg: T,
+    ) -> ExecutionInput<SolidityArgs<ArgsList<T, ArgsList<Head, Rest>>>>
+    where
+        T: SolidityEncode,
+    {
+        ExecutionInput {
+            selector: self.selector,
+            args: SolidityArgs {
+                args: self.args.args.push_solidity_arg(arg),
+            },
+        }
+    }
+}
+
+impl<Args> ExecutionInput<Args> {
+    /// Appends the salt to the arguments of the execution input.
+    ///
+    /// See [`Salt`] for how the salt affects contract instantiations.
+    #[inline]
+    pub fn salted(self, salt: Salt) -> ExecutionInput<SaltedArgs<Args>> {
+        ExecutionInput {
+            selector: self.selector,
+            args: SaltedArgs::new(self.args, salt),
+        }
+    }
+
+    /// Returns the selector of the execution input.
+    #[inline]
+    pub(crate) fn selector(&self) -> Selector {
+        self.selector
+    }
+
+    /// Returns the arguments of the execution input.
+    #[inline]
+    pub(crate) fn args(&self) -> &Args {
+        &self.args
+    }
+}
+
+/// An argument list that is encoded according to the Solidity contract ABI.
+#[derive(Debug)]
+pub struct SolidityArgs<Args> {
+    /// The underlying argument list.
+    args: Args,
+}
+
 /// An argument list.
 ///
 /// This type is constructed mainly at compile type via type constructions
@@ -126,6 +219,18 @@ impl EmptyArgumentList {
             rest: self,
         }
     }
+
+    /// Pushes the first Solidity ABI encoded argument to the empty argument list.
+    #[inline]
+    fn push_solidity_arg<T>(self, arg: T) -> ArgumentList<Argument<T>, Self>
+    where
+        T: SolidityEncode,
+    {
+        ArgumentList {
+            head: Argument::new(arg),
+            rest: self,
+        }
+    }
 }
 
 impl<Head, Rest> ArgumentList<Argument<Head>, Rest> {
@@ -140,6 +245,18 @@ impl<Head, Rest> ArgumentList<Argument<Head>, Rest> {
             rest: self,
         }
     }
+
+    /// Pushes another Solidity ABI encoded argument to the argument list.
+    #[inline]
+    fn push_solidity_arg<T>(self, arg: T) -> ArgumentList<Argument<T>, Self>
+    where
+        T: SolidityEncode,
+    {
+        ArgumentList {
+            head: Argument::new(arg),
+            rest: self,
+        }
+    }
 }
 
 impl<T> scale::Encode for Argument<T>
@@ -188,6 +305,103 @@ where
     }
 }
 
+/// Argument lists that can be encoded according to the Solidity contract ABI.
+///
+/// Statically sized arguments are encoded in place while dynamically sized
+/// arguments are referred to by their offset and appended after all other
+/// arguments.
+pub trait SolidityEncodeArgs {
+    /// Returns the number of arguments.
+    fn num_args(&self) -> usize;
+
+    /// Returns the accumulated length of all dynamically sized arguments.
+    fn dynamic_len(&self) -> usize;
+
+    /// Encodes all statically sized arguments and the offsets of all
+    /// dynamically sized arguments starting at the given offset.
+    fn encode_static<O: scale::Output>(&self, offset: &mut usize, output: &mut O);
+
+    /// Encodes all dynamically sized arguments.
+    fn encode_dynamic<O: scale::Output>(&self, output: &mut O);
+}
+
+impl SolidityEncodeArgs for EmptyArgumentList {
+    #[inline]
+    fn num_args(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn dynamic_len(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn encode_static<O: scale::Output>(&self, _offset: &mut usize, _output: &mut O) {}
+
+    #[inline]
+    fn encode_dynamic<O: scale::Output>(&self, _output: &mut O) {}
+}
+
+impl<Head, Rest> SolidityEncodeArgs for ArgumentList<Argument<Head>, Rest>
+where
+    Head: SolidityEncode,
+    Rest: SolidityEncodeArgs,
+{
+    #[inline]
+    fn num_args(&self) -> usize {
+        self.rest.num_args() + 1
+    }
+
+    #[inline]
+    fn dynamic_len(&self) -> usize {
+        let head_len = if Head::IS_DYNAMIC {
+            self.head.arg.encoded_len()
+        } else {
+            0
+        };
+        self.rest.dynamic_len() + head_len
+    }
+
+    #[inline]
+    fn encode_static<O: scale::Output>(&self, offset: &mut usize, output: &mut O) {
+        // Just like for the SCALE encoding the arguments are encoded in reverse
+        // order of the argument list which is the order in which they have been pushed.
+        self.rest.encode_static(offset, output);
+        if Head::IS_DYNAMIC {
+            (*offset as u128).encode_solidity(output);
+            *offset += self.head.arg.encoded_len();
+        } else {
+            self.head.arg.encode_solidity(output);
+        }
+    }
+
+    #[inline]
+    fn encode_dynamic<O: scale::Output>(&self, output: &mut O) {
+        self.rest.encode_dynamic(output);
+        if Head::IS_DYNAMIC {
+            self.head.arg.encode_solidity(output);
+        }
+    }
+}
+
+impl<Args> scale::Encode for SolidityArgs<Args>
+where
+    Args: SolidityEncodeArgs,
+{
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.args.num_args() * WORD_LEN + self.args.dynamic_len()
+    }
+
+    #[inline]
+    fn encode_to<O: scale::Output>(&self, output: &mut O) {
+        let mut offset = self.args.num_args() * WORD_LEN;
+        self.args.encode_static(&mut offset, output);
+        self.args.encode_dynamic(output);
+    }
+}
+
 impl<Args> scale::Encode for ExecutionInput<Args>
 where
     Args: scale::Encode,
@@ -234,6 +448,33 @@ mod tests {
         assert_eq!(decoded, 1i32);
     }
 
+    #[test]
+    fn solidity_arguments_works() {
+        use crate::call::solidity::{
+            Address,
+            Bytes,
+        };
+        let selector = Selector::new([0x01, 0x02, 0x03, 0x04]);
+        let exec_input = ExecutionInput::new_solidity(selector)
+            .push_arg(Bytes::from(&[0x42_u8; 3][..]))
+            .push_arg(Address::from([0x11; 20]))
+            .push_arg(7_u64);
+        let encoded = scale::Encode::encode(&exec_input);
+        assert_eq!(encoded.len(), scale::Encode::size_hint(&exec_input));
+        let (selector_bytes, args) = encoded.split_at(4);
+        assert_eq!(selector_bytes, &[0x01, 0x02, 0x03, 0x04]);
+        let words = args.chunks(WORD_LEN).collect::<Vec<_>>();
+        assert_eq!(words.len(), 5);
+        // The offset of the dynamically sized bytes behind the three static words.
+        assert_eq!(words[0][31], 3 * WORD_LEN as u8);
+        assert_eq!(&words[1][12..], &[0x11; 20]);
+        assert_eq!(words[2][31], 7);
+        // The length followed by the padded bytes.
+        assert_eq!(words[3][31], 3);
+        assert_eq!(&words[4][..3], &[0x42; 3]);
+        assert_eq!(&words[4][3..], &[0x00; WORD_LEN - 3][..]);
+    }
+
     #[test]
     fn multiple_arguments_works() {
         let empty_list = ArgumentList::empty()
@@ -0,0 +1,140 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Result;
+
+/// The policy of how much gas is forwarded to a called contract.
+///
+/// Allows callers to reserve gas for their own bookkeeping after the call
+/// instead of forwarding either all or an absolute amount of gas.
+///
+/// # Note
+///
+/// Absolute amounts of gas convert into this type so that they can be
+/// provided directly to [`CallBuilder::gas_limit`](`crate::call::CallBuilder::gas_limit`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Forwarded {
+    /// Forwards all the gas that is left for the execution of the caller.
+    All,
+    /// Forwards the given percentage of the gas that is left for the execution
+    /// of the caller at the time of the call.
+    ///
+    /// Percentages above 100 are treated as 100.
+    Percent(u8),
+    /// Forwards the given absolute amount of gas.
+    Absolute(u64),
+}
+
+impl Default for Forwarded {
+    fn default() -> Self {
+        Self::All
+    }
+}
+
+impl From<u64> for Forwarded {
+    fn from(gas_limit: u64) -> Self {
+        Self::Absolute(gas_limit)
+    }
+}
+
+/// Returns the estimated amount of gas plus a safety margin.
+///
+/// The margin is an integer percentage of the estimate and not a multiplier,
+/// e.g. `120` yields 1.2 times the estimate. Margins below 100 are treated
+/// as 100.
+///
+/// # Note
+///
+/// The margin is not given as floating point multiplier such as `1.2` since
+/// floating point operations are not permitted within smart contracts.
+///
+/// Obtaining the estimate is up to the caller, e.g. by dry-running the call
+/// off-chain via the `contracts_call` RPC. Performing such dry-runs or retrying
+/// calls that ran out of gas is out of scope of ink_env.
+pub fn with_margin(estimated_gas: u64, margin_percent: u16) -> u64 {
+    let margin_percent = core::cmp::max(margin_percent, 100) as u128;
+    let gas_limit = estimated_gas as u128 * margin_percent / 100;
+    core::cmp::min(gas_limit, u64::MAX as u128) as u64
+}
+
+impl Forwarded {
+    /// Forwards the estimated amount of gas plus a safety margin.
+    ///
+    /// See [`with_margin`] for the meaning of the margin.
+    pub fn with_margin(estimated_gas: u64, margin_percent: u16) -> Self {
+        Self::Absolute(with_margin(estimated_gas, margin_percent))
+    }
+
+    /// Returns the gas limit to be provided to the host for the call.
+    ///
+    /// A gas limit of `0` instructs the host to forward all the gas that is left.
+    /// Queries the gas that is left using the given closure only if required.
+    ///
+    /// # Errors
+    ///
+    /// If the gas left could not be queried.
+    pub fn to_gas_limit<F>(self, gas_left: F) -> Result<u64>
+    where
+        F: FnOnce() -> Result<u64>,
+    {
+        match self {
+            Self::All => Ok(0),
+            Self::Absolute(gas_limit) => Ok(gas_limit),
+            Self::Percent(percent) if percent >= 100 => Ok(0),
+            Self::Percent(percent) => {
+                let gas_left = gas_left()? as u128;
+                let gas_limit = gas_left * percent as u128 / 100;
+                // Forwarding no gas at all must not turn into forwarding all gas.
+                Ok(core::cmp::max(gas_limit as u64, 1))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_gas_limit_works() {
+        let gas_left = || Ok(1_000);
+        assert_eq!(Forwarded::All.to_gas_limit(gas_left).unwrap(), 0);
+        assert_eq!(Forwarded::Absolute(42).to_gas_limit(gas_left).unwrap(), 42);
+        assert_eq!(Forwarded::from(42).to_gas_limit(gas_left).unwrap(), 42);
+        assert_eq!(Forwarded::Percent(90).to_gas_limit(gas_left).unwrap(), 900);
+        assert_eq!(Forwarded::Percent(100).to_gas_limit(gas_left).unwrap(), 0);
+        assert_eq!(Forwarded::Percent(150).to_gas_limit(gas_left).unwrap(), 0);
+        assert_eq!(Forwarded::Percent(0).to_gas_limit(gas_left).unwrap(), 1);
+    }
+
+    #[test]
+    fn with_margin_works() {
+        assert_eq!(with_margin(1_000, 120), 1_200);
+        assert_eq!(with_margin(1_000, 100), 1_000);
+        assert_eq!(with_margin(1_000, 50), 1_000);
+        assert_eq!(with_margin(u64::MAX, 200), u64::MAX);
+        assert_eq!(
+            Forwarded::with_margin(1_000, 120),
+            Forwarded::Absolute(1_200)
+        );
+    }
+
+    #[test]
+    fn gas_left_is_only_queried_for_percentages() {
+        let gas_left = || panic!("unexpectedly queried the gas left");
+        assert_eq!(Forwarded::All.to_gas_limit(gas_left).unwrap(), 0);
+        assert_eq!(Forwarded::Absolute(42).to_gas_limit(gas_left).unwrap(), 42);
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A language level error that a called contract has reverted with.
+///
+/// ink! smart contracts revert with a language level error if they fail to
+/// dispatch a call or instantiation before the user provided code is executed,
+/// e.g. if the selector is unknown or if the input could not be decoded.
+///
+/// The meaning of the error codes is part of the contract metadata.
+///
+/// # Note
+///
+/// The encoding starts with [`LangError::MAGIC`] followed by the SCALE encoded
+/// code. This distinguishes language level errors from data that contracts
+/// revert with on their own behalf, e.g. any 4 byte value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LangError {
+    /// The code of the language level error.
+    code: u32,
+}
+
+impl LangError {
+    /// The bytes every encoded language level error starts with.
+    pub const MAGIC: [u8; 4] = *b"\xFFlng";
+
+    /// Creates a new language level error from the given error code.
+    #[inline]
+    pub fn from_code(code: u32) -> Self {
+        Self { code }
+    }
+
+    /// Returns the code of the language level error.
+    #[inline]
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// Decodes the language level error from the data of a reverted contract
+    /// execution.
+    ///
+    /// Returns `None` if the data is not exactly an encoded language level error.
+    pub fn decode_from(data: &[u8]) -> Option<Self> {
+        let mut input = data;
+        let lang_error = <Self as scale::Decode>::decode(&mut input).ok()?;
+        if !input.is_empty() {
+            return None
+        }
+        Some(lang_error)
+    }
+}
+
+impl scale::Encode for LangError {
+    fn size_hint(&self) -> usize {
+        Self::MAGIC.len() + scale::Encode::size_hint(&self.code)
+    }
+
+    fn encode_to<O: scale::Output>(&self, dest: &mut O) {
+        dest.write(&Self::MAGIC);
+        scale::Encode::encode_to(&self.code, dest);
+    }
+}
+
+impl scale::Decode for LangError {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let magic = <[u8; 4] as scale::Decode>::decode(input)?;
+        if magic != Self::MAGIC {
+            return Err("encountered invalid language level error".into())
+        }
+        Ok(Self {
+            code: scale::Decode::decode(input)?,
+        })
+    }
+}
@@ -17,8 +17,14 @@
 mod call_builder;
 mod common;
 mod create_builder;
+mod deferred;
 mod execution_input;
+mod gas;
+mod lang_error;
+mod revert_envelope;
+mod salt;
 mod selector;
+pub mod solidity;
 
 /// Utility types for the cross-contract calling API.
 pub mod utils {
@@ -36,7 +42,10 @@ pub mod utils {
             ArgumentList,
             ArgumentListEnd,
             EmptyArgumentList,
+            SolidityArgs,
+            SolidityEncodeArgs,
         },
+        salt::SaltedArgs,
     };
 }
 
@@ -51,7 +60,22 @@ pub use self::{
         CreateBuilder,
         CreateParams,
         FromAccountId,
+        Instantiated,
+    },
+    deferred::{
+        execute_deferred,
+        DeferredCall,
     },
     execution_input::ExecutionInput,
+    gas::{
+        with_margin,
+        Forwarded,
+    },
+    lang_error::LangError,
+    revert_envelope::RevertEnvelope,
+    salt::{
+        contract_address,
+        Salt,
+    },
     selector::Selector,
 };
@@ -0,0 +1,111 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ink_prelude::vec::Vec;
+
+/// The data an ink! smart contract reverts with via a contract defined error code.
+///
+/// ink! smart contracts revert with an envelope via `ink_lang::revert!` in order
+/// to provide callers and frontends with a machine readable error code and an
+/// optional SCALE encoded payload. Use [`Error::revert_envelope`] to decode the
+/// envelope from the data of a reverted call.
+///
+/// # Note
+///
+/// The encoding starts with [`RevertEnvelope::MAGIC`] followed by the SCALE encoded
+/// code and payload bytes. This distinguishes envelopes from language level errors
+/// and from data that contracts revert with on their own behalf.
+///
+/// [`Error::revert_envelope`]: `crate::Error::revert_envelope`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevertEnvelope {
+    /// The contract defined error code.
+    code: u32,
+    /// The SCALE encoded payload.
+    data: Vec<u8>,
+}
+
+impl RevertEnvelope {
+    /// The bytes every encoded envelope starts with.
+    pub const MAGIC: [u8; 4] = *b"\xFFink";
+
+    /// Creates a new envelope from the error code and the encoded payload.
+    #[inline]
+    pub fn new(code: u32, data: Vec<u8>) -> Self {
+        Self { code, data }
+    }
+
+    /// Returns the contract defined error code.
+    #[inline]
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// Returns the SCALE encoded payload.
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Decodes the payload as a value of type `T`.
+    ///
+    /// # Errors
+    ///
+    /// If the payload is not a valid encoding of `T`.
+    pub fn decode_data<T>(&self) -> Result<T, scale::Error>
+    where
+        T: scale::Decode,
+    {
+        <T as scale::Decode>::decode(&mut &self.data[..])
+    }
+
+    /// Decodes the envelope from the data of a reverted contract execution.
+    ///
+    /// Returns `None` if the data is not exactly an encoded envelope.
+    pub fn decode_from(data: &[u8]) -> Option<Self> {
+        let mut input = data;
+        let envelope = <Self as scale::Decode>::decode(&mut input).ok()?;
+        if !input.is_empty() {
+            return None
+        }
+        Some(envelope)
+    }
+}
+
+impl scale::Encode for RevertEnvelope {
+    fn size_hint(&self) -> usize {
+        Self::MAGIC.len()
+            + scale::Encode::size_hint(&self.code)
+            + scale::Encode::size_hint(&self.data)
+    }
+
+    fn encode_to<O: scale::Output>(&self, dest: &mut O) {
+        dest.write(&Self::MAGIC);
+        scale::Encode::encode_to(&self.code, dest);
+        scale::Encode::encode_to(&self.data, dest);
+    }
+}
+
+impl scale::Decode for RevertEnvelope {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let magic = <[u8; 4] as scale::Decode>::decode(input)?;
+        if magic != Self::MAGIC {
+            return Err("encountered invalid revert envelope".into())
+        }
+        Ok(Self {
+            code: scale::Decode::decode(input)?,
+            data: scale::Decode::decode(input)?,
+        })
+    }
+}
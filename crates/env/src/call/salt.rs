@@ -0,0 +1,201 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    hash::{
+        Blake2x256,
+        CryptoHash,
+        HashOutput,
+    },
+    Environment,
+    Error,
+};
+use ink_prelude::vec::Vec;
+
+/// A salt that is appended to the input of a contract instantiation.
+///
+/// The `seal0` host API of the contracts pallet does not accept a salt for
+/// instantiations but derives the account ID of an instantiated contract from
+/// the code hash, the hash of the input and the account ID of the deployer.
+/// Appending distinct salts to the input gives distinct account IDs to
+/// contracts instantiated from the same code with the same arguments.
+/// The instantiated contract ignores the trailing salt while decoding the
+/// arguments of its constructor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+pub struct Salt([u8; 32]);
+
+impl Salt {
+    /// Creates a salt from its raw bytes.
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Derives the salt for the given key.
+    ///
+    /// The salt is the BLAKE-2 256-bit hash of the encoded key prefixed with
+    /// `ink_env::call::Salt` so that distinct keys yield distinct salts.
+    pub fn derive<K>(key: &K) -> Self
+    where
+        K: scale::Encode,
+    {
+        let mut salt = [0x00; 32];
+        crate::hash_encoded::<Blake2x256, _>(&(b"ink_env::call::Salt", key), &mut salt);
+        Self(salt)
+    }
+
+    /// Returns the raw bytes of the salt.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+/// An argument list with a trailing salt.
+#[derive(Debug)]
+pub struct SaltedArgs<Args> {
+    /// The underlying argument list.
+    args: Args,
+    /// The salt encoded after the arguments.
+    salt: Salt,
+}
+
+impl<Args> SaltedArgs<Args> {
+    /// Appends the salt to the argument list.
+    #[inline]
+    pub(crate) fn new(args: Args, salt: Salt) -> Self {
+        Self { args, salt }
+    }
+}
+
+impl<Args> scale::Encode for SaltedArgs<Args>
+where
+    Args: scale::Encode,
+{
+    #[inline]
+    fn size_hint(&self) -> usize {
+        scale::Encode::size_hint(&self.args) + scale::Encode::size_hint(&self.salt)
+    }
+
+    #[inline]
+    fn encode_to<O: scale::Output>(&self, output: &mut O) {
+        scale::Encode::encode_to(&self.args, output);
+        scale::Encode::encode_to(&self.salt, output);
+    }
+}
+
+/// Returns the account ID of the contract that the `deployer` instantiates
+/// from the code hash with the given encoded input.
+///
+/// This mirrors the `SimpleAddressDeterminer` of the contracts pallet serving
+/// the `seal0` host API which computes the hash of the code hash, the hash of
+/// the input and the encoded account ID of the deployer. The salt of salted
+/// instantiations trails the input and thus enters through the hash of the input.
+///
+/// `H` must be the `Hashing` of the runtime which is [`Blake2x256`] for
+/// Substrate based chains in their default configuration.
+///
+/// # Errors
+///
+/// If the hash cannot be decoded into an account ID of the environment.
+pub fn contract_address<E, H>(
+    deployer: &E::AccountId,
+    code_hash: &E::Hash,
+    input: &[u8],
+) -> Result<E::AccountId, Error>
+where
+    E: Environment,
+    H: CryptoHash + HashOutput<Type = [u8; 32]>,
+{
+    let mut input_hash = [0x00; 32];
+    crate::hash_bytes::<H>(input, &mut input_hash);
+    let mut buffer = Vec::new();
+    buffer.extend_from_slice(code_hash.as_ref());
+    buffer.extend_from_slice(&input_hash);
+    scale::Encode::encode_to(deployer, &mut buffer);
+    let mut address = [0x00; 32];
+    crate::hash_bytes::<H>(&buffer, &mut address);
+    let account_id = scale::Decode::decode(&mut &address[..])?;
+    Ok(account_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        call::{
+            ExecutionInput,
+            Selector,
+        },
+        AccountId,
+        DefaultEnvironment,
+        Hash,
+    };
+
+    #[test]
+    fn salted_input_works() {
+        let salt = Salt::derive(&42u32);
+        let input = ExecutionInput::new(Selector::new([0x01, 0x02, 0x03, 0x04]))
+            .push_arg(true)
+            .salted(salt);
+        let encoded = scale::Encode::encode(&input);
+        assert_eq!(&encoded[..5], &[0x01, 0x02, 0x03, 0x04, 0x01]);
+        assert_eq!(&encoded[5..], salt.as_bytes());
+    }
+
+    #[test]
+    fn derive_works() {
+        assert_eq!(Salt::derive(&1u32), Salt::derive(&1u32));
+        assert_ne!(Salt::derive(&1u32), Salt::derive(&2u32));
+    }
+
+    #[test]
+    fn contract_address_works() {
+        let deployer = AccountId::from([0x01; 32]);
+        let code_hash = Hash::from([0x02; 32]);
+        let address = |input: &[u8]| {
+            contract_address::<DefaultEnvironment, Blake2x256>(
+                &deployer, &code_hash, input,
+            )
+            .unwrap()
+        };
+        assert_eq!(address(&[0x00; 4]), address(&[0x00; 4]));
+        assert_ne!(address(&[0x00; 4]), address(&[0x00; 5]));
+        assert_ne!(
+            address(&[0x00; 4]),
+            contract_address::<DefaultEnvironment, Blake2x256>(
+                &AccountId::from([0x03; 32]),
+                &code_hash,
+                &[0x00; 4]
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn contract_address_matches_pallet() {
+        // Computed as `blake2_256(code_hash ++ blake2_256(input) ++ deployer)`
+        // following the `SimpleAddressDeterminer` of the contracts pallet.
+        const EXPECTED: [u8; 32] = [
+            0xE6, 0x9C, 0x14, 0xD8, 0x85, 0xEE, 0xC9, 0xD8, 0x02, 0x45, 0x4C, 0xAC, 0x64,
+            0x74, 0xA1, 0x82, 0x43, 0x3F, 0x19, 0x83, 0xD7, 0x4F, 0xCB, 0x71, 0x5B, 0xD9,
+            0x92, 0x70, 0x51, 0x19, 0x40, 0xE5,
+        ];
+        let address = contract_address::<DefaultEnvironment, Blake2x256>(
+            &AccountId::from([0x01; 32]),
+            &Hash::from([0x02; 32]),
+            &[0x00; 4],
+        )
+        .unwrap();
+        assert_eq!(address, AccountId::from(EXPECTED));
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encoding of arguments according to the Solidity contract ABI.
+//!
+//! Used to call EVM smart contracts on chains that provide access to them,
+//! e.g. through a precompile or chain extension.
+//! See [the Solidity ABI specification](https://docs.soliditylang.org/en/latest/abi-spec.html).
+
+use ink_prelude::vec::Vec;
+
+/// The size of a single word of the Solidity ABI encoding.
+pub const WORD_LEN: usize = 32;
+
+/// Types that can be encoded according to the Solidity contract ABI.
+pub trait SolidityEncode {
+    /// `true` if the type is dynamically sized, e.g. `bytes`.
+    ///
+    /// Dynamically sized values are encoded after all static values
+    /// and are referred to by their offset instead.
+    const IS_DYNAMIC: bool;
+
+    /// Returns the length of the encoding in bytes.
+    ///
+    /// This is always one word for statically sized types.
+    fn encoded_len(&self) -> usize;
+
+    /// Encodes the value according to the Solidity contract ABI.
+    fn encode_solidity<O: scale::Output>(&self, output: &mut O);
+}
+
+/// A Solidity `uint256` encoded as 32 big endian bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Uint256([u8; WORD_LEN]);
+
+impl Uint256 {
+    /// Creates a `uint256` from its big endian bytes.
+    pub const fn from_be_bytes(bytes: [u8; WORD_LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the big endian bytes of the `uint256`.
+    pub const fn to_be_bytes(self) -> [u8; WORD_LEN] {
+        self.0
+    }
+}
+
+macro_rules! impl_uint256_from {
+    ( $( $prim:ty ),* ) => {
+        $(
+            impl From<$prim> for Uint256 {
+                fn from(value: $prim) -> Self {
+                    let bytes = value.to_be_bytes();
+                    let mut word = [0x00; WORD_LEN];
+                    word[WORD_LEN - bytes.len()..].copy_from_slice(&bytes);
+                    Self(word)
+                }
+            }
+
+            impl SolidityEncode for $prim {
+                const IS_DYNAMIC: bool = false;
+
+                fn encoded_len(&self) -> usize {
+                    WORD_LEN
+                }
+
+                fn encode_solidity<O: scale::Output>(&self, output: &mut O) {
+                    Uint256::from(*self).encode_solidity(output)
+                }
+            }
+        )*
+    };
+}
+impl_uint256_from!(u8, u16, u32, u64, u128);
+
+impl SolidityEncode for Uint256 {
+    const IS_DYNAMIC: bool = false;
+
+    fn encoded_len(&self) -> usize {
+        WORD_LEN
+    }
+
+    fn encode_solidity<O: scale::Output>(&self, output: &mut O) {
+        output.write(&self.0)
+    }
+}
+
+impl SolidityEncode for bool {
+    const IS_DYNAMIC: bool = false;
+
+    fn encoded_len(&self) -> usize {
+        WORD_LEN
+    }
+
+    fn encode_solidity<O: scale::Output>(&self, output: &mut O) {
+        Uint256::from(*self as u8).encode_solidity(output)
+    }
+}
+
+/// A Solidity `address` consisting of 20 bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Address([u8; 20]);
+
+impl From<[u8; 20]> for Address {
+    fn from(bytes: [u8; 20]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl Address {
+    /// Returns the underlying bytes of the address.
+    pub const fn to_bytes(self) -> [u8; 20] {
+        self.0
+    }
+}
+
+impl SolidityEncode for Address {
+    const IS_DYNAMIC: bool = false;
+
+    fn encoded_len(&self) -> usize {
+        WORD_LEN
+    }
+
+    fn encode_solidity<O: scale::Output>(&self, output: &mut O) {
+        output.write(&[0x00; WORD_LEN - 20]);
+        output.write(&self.0)
+    }
+}
+
+/// Dynamically sized Solidity `bytes`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Bytes<'a>(&'a [u8]);
+
+impl<'a> From<&'a [u8]> for Bytes<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<'a> From<&'a Vec<u8>> for Bytes<'a> {
+    fn from(bytes: &'a Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl SolidityEncode for Bytes<'_> {
+    const IS_DYNAMIC: bool = true;
+
+    fn encoded_len(&self) -> usize {
+        WORD_LEN + padded_len(self.0.len())
+    }
+
+    fn encode_solidity<O: scale::Output>(&self, output: &mut O) {
+        Uint256::from(self.0.len() as u128).encode_solidity(output);
+        output.write(self.0);
+        let padding = padded_len(self.0.len()) - self.0.len();
+        output.write(&[0x00; WORD_LEN][..padding]);
+    }
+}
+
+impl<T> SolidityEncode for &T
+where
+    T: SolidityEncode,
+{
+    const IS_DYNAMIC: bool = T::IS_DYNAMIC;
+
+    fn encoded_len(&self) -> usize {
+        <T as SolidityEncode>::encoded_len(*self)
+    }
+
+    fn encode_solidity<O: scale::Output>(&self, output: &mut O) {
+        <T as SolidityEncode>::encode_solidity(*self, output)
+    }
+}
+
+/// Returns the length rounded up to the next multiple of the word length.
+fn padded_len(len: usize) -> usize {
+    (len + WORD_LEN - 1) / WORD_LEN * WORD_LEN
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode<T: SolidityEncode>(value: T) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        value.encode_solidity(&mut encoded);
+        assert_eq!(encoded.len(), value.encoded_len());
+        encoded
+    }
+
+    #[test]
+    fn uint256_works() {
+        let mut expected = [0x00; WORD_LEN];
+        expected[30] = 0x01;
+        expected[31] = 0x02;
+        assert_eq!(encode(0x0102_u16), expected.to_vec());
+        assert_eq!(encode(0x0102_u128), expected.to_vec());
+        assert_eq!(encode(Uint256::from(0x0102_u32)), expected.to_vec());
+        assert_eq!(Uint256::from(0x0102_u64).to_be_bytes(), expected);
+    }
+
+    #[test]
+    fn address_works() {
+        let encoded = encode(Address::from([0x11; 20]));
+        assert_eq!(&encoded[..12], &[0x00; 12]);
+        assert_eq!(&encoded[12..], &[0x11; 20]);
+    }
+
+    #[test]
+    fn bytes_works() {
+        let encoded = encode(Bytes::from(&[0x42_u8; 33][..]));
+        assert_eq!(encoded.len(), 3 * WORD_LEN);
+        assert_eq!(&encoded[..WORD_LEN], &encode(33_u8)[..]);
+        assert_eq!(&encoded[WORD_LEN..WORD_LEN + 33], &[0x42; 33][..]);
+        assert_eq!(&encoded[WORD_LEN + 33..], &[0x00; WORD_LEN - 1][..]);
+        assert_eq!(encode(Bytes::from(&[0x00_u8; 0][..])), encode(0_u8));
+    }
+}
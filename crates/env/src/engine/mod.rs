@@ -18,6 +18,11 @@ use crate::backend::{
 };
 use cfg_if::cfg_if;
 
+/// The capacity of the buffer used to encode values before handing them to the host.
+///
+/// The off-chain environment applies the same limit to emitted events.
+const BUFFER_CAPACITY: usize = 1 << 14; // 16kB
+
 pub trait OnInstance: EnvBackend + TypedEnvBackend {
     fn on_instance<F, R>(f: F) -> R
     where
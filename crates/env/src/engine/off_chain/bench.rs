@@ -0,0 +1,255 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weight estimates of ink! messages executed in the off-chain environment.
+//!
+//! The off-chain environment does not execute Wasm and therefore cannot meter
+//! the real weight of a contract execution. Instead the storage accesses and
+//! emitted events of an execution are counted and weighted by a [`GasModel`].
+//! Storage accesses are weighted per cell and per encoded byte.
+//! This is good enough to track the weight of messages over time, e.g. in CI,
+//! but the estimates are not the weights that a chain charges for them.
+
+use super::{
+    EnvInstance,
+    OnInstance,
+};
+use crate::Result;
+use ink_prelude::string::String;
+use std::io::Write as _;
+
+/// The name of the environment variable holding the path of the file
+/// benchmark results are appended to.
+///
+/// If it is not set the results are not reported.
+pub const BENCH_OUTPUT_VAR: &str = "INK_BENCH_OUTPUT";
+
+/// The weights used to estimate the weight of a contract execution.
+///
+/// # Note
+///
+/// The default weights are illustrative only. They are not taken from the
+/// benchmarked weights of the contracts pallet and only put the counted
+/// operations into a plausible relation to each other. Use the weights of the
+/// targeted runtime via [`Bencher::set_gas_model`] for estimates that are
+/// comparable to the weights charged on-chain.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GasModel {
+    /// The base weight of every contract execution.
+    pub base: u64,
+    /// The weight of a single read of a storage cell.
+    pub storage_read: u64,
+    /// The weight of a single write or removal of a storage cell.
+    pub storage_write: u64,
+    /// The weight of a single byte read from a storage cell.
+    pub storage_read_byte: u64,
+    /// The weight of a single byte written to a storage cell.
+    pub storage_write_byte: u64,
+    /// The weight of emitting a single event.
+    pub event: u64,
+}
+
+impl Default for GasModel {
+    fn default() -> Self {
+        Self {
+            base: 10_000_000,
+            storage_read: 25_000_000,
+            storage_write: 100_000_000,
+            storage_read_byte: 10_000,
+            storage_write_byte: 50_000,
+            event: 10_000_000,
+        }
+    }
+}
+
+impl GasModel {
+    /// Returns the weight estimate of an execution with the given counts.
+    pub fn weight(&self, counts: &Counts) -> u64 {
+        self.base
+            .saturating_add(self.storage_read.saturating_mul(counts.storage_reads))
+            .saturating_add(self.storage_write.saturating_mul(counts.storage_writes))
+            .saturating_add(self.storage_read_byte.saturating_mul(counts.bytes_read))
+            .saturating_add(self.storage_write_byte.saturating_mul(counts.bytes_written))
+            .saturating_add(self.event.saturating_mul(counts.events))
+    }
+}
+
+/// The operations counted for a contract execution.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Counts {
+    /// The number of storage cells read.
+    pub storage_reads: u64,
+    /// The number of storage cells written or removed.
+    pub storage_writes: u64,
+    /// The number of encoded bytes read from storage cells.
+    pub bytes_read: u64,
+    /// The number of encoded bytes written to storage cells.
+    pub bytes_written: u64,
+    /// The number of events emitted.
+    pub events: u64,
+}
+
+impl Counts {
+    /// Returns the current counts of the executing contract.
+    fn current() -> Result<Self> {
+        <EnvInstance as OnInstance>::on_instance(|instance| {
+            let callee = &instance.exec_context()?.callee;
+            let account = instance
+                .accounts
+                .get_account_off(callee)
+                .expect("the executing contract must have an account");
+            let (reads, writes) = account.get_storage_rw()?;
+            let (bytes_read, bytes_written) = account.get_storage_bytes_rw()?;
+            Ok(Self {
+                storage_reads: reads as u64,
+                storage_writes: writes as u64,
+                bytes_read: bytes_read as u64,
+                bytes_written: bytes_written as u64,
+                events: instance.emitted_events.emitted_events().len() as u64,
+            })
+        })
+    }
+
+    /// Returns the counts that have been added since `earlier`.
+    fn since(&self, earlier: &Self) -> Self {
+        Self {
+            storage_reads: self.storage_reads - earlier.storage_reads,
+            storage_writes: self.storage_writes - earlier.storage_writes,
+            bytes_read: self.bytes_read - earlier.bytes_read,
+            bytes_written: self.bytes_written - earlier.bytes_written,
+            events: self.events - earlier.events,
+        }
+    }
+}
+
+/// The result of benchmarking a single ink! message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    /// The name of the benchmarked message.
+    pub name: String,
+    /// The number of times the message has been executed.
+    pub iterations: u32,
+    /// The counts of the most expensive execution.
+    pub counts: Counts,
+    /// The weight estimate of the most expensive execution.
+    pub weight: u64,
+}
+
+impl BenchResult {
+    /// Returns the result encoded as a single line JSON object.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"name\":{:?},\"iterations\":{},\"storage_reads\":{},\"storage_writes\":{},\"bytes_read\":{},\"bytes_written\":{},\"events\":{},\"weight\":{}}}",
+            self.name,
+            self.iterations,
+            self.counts.storage_reads,
+            self.counts.storage_writes,
+            self.counts.bytes_read,
+            self.counts.bytes_written,
+            self.counts.events,
+            self.weight,
+        )
+    }
+}
+
+/// Executes ink! messages repeatedly and collects their weight estimates.
+///
+/// Used by the `#[ink::bench]` macro.
+#[derive(Debug)]
+pub struct Bencher {
+    /// The number of times every message is executed.
+    iterations: u32,
+    /// The weights to estimate the weight of an execution.
+    gas_model: GasModel,
+    /// The results of the benchmarked messages.
+    results: Vec<BenchResult>,
+}
+
+impl Bencher {
+    /// Creates a bencher executing every message `iterations` times.
+    pub fn new(iterations: u32) -> Self {
+        Self {
+            iterations: core::cmp::max(iterations, 1),
+            gas_model: GasModel::default(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Sets the weights to estimate the weight of an execution.
+    pub fn set_gas_model(&mut self, gas_model: GasModel) {
+        self.gas_model = gas_model;
+    }
+
+    /// Executes `f` repeatedly and records its worst-case weight estimate
+    /// under the given name.
+    ///
+    /// # Errors
+    ///
+    /// If the off-chain environment has no executing contract.
+    pub fn iter<F, R>(&mut self, name: &str, mut f: F) -> Result<()>
+    where
+        F: FnMut() -> R,
+    {
+        let mut worst = Counts::default();
+        let mut worst_weight = 0;
+        for _ in 0..self.iterations {
+            let before = Counts::current()?;
+            let _ = f();
+            let counts = Counts::current()?.since(&before);
+            let weight = self.gas_model.weight(&counts);
+            if weight >= worst_weight {
+                worst = counts;
+                worst_weight = weight;
+            }
+        }
+        self.results.push(BenchResult {
+            name: name.into(),
+            iterations: self.iterations,
+            counts: worst,
+            weight: worst_weight,
+        });
+        Ok(())
+    }
+
+    /// Returns the results of all benchmarked messages.
+    pub fn results(&self) -> &[BenchResult] {
+        &self.results
+    }
+
+    /// Reports the results as JSON lines.
+    ///
+    /// The lines are appended to the file denoted by [`BENCH_OUTPUT_VAR`].
+    /// Nothing is reported if it is not set.
+    ///
+    /// # Panics
+    ///
+    /// If the output file cannot be written.
+    pub fn report(&self) {
+        let path = match std::env::var_os(BENCH_OUTPUT_VAR) {
+            Some(path) => path,
+            None => return,
+        };
+        let lines = self
+            .results
+            .iter()
+            .map(|result| result.to_json() + "\n")
+            .collect::<String>();
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(lines.as_bytes()))
+            .expect("could not write the benchmark results")
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::call::Selector;
+use std::collections::HashMap;
+
+/// The outcome of a call to a registered contract.
+///
+/// Either the encoded return value or the data the contract reverted with.
+pub type CallOutcome = core::result::Result<Vec<u8>, Vec<u8>>;
+
+/// A raw contract function.
+///
+/// Receives the encoded input of a call, i.e. its selector followed
+/// by its encoded arguments.
+type ContractFn = Box<dyn FnMut(&[u8]) -> CallOutcome>;
+
+/// Routes calls from the tested contract to contracts registered by the test.
+///
+/// Registered contracts are identified by their encoded account ID.
+pub struct CallRouter {
+    /// The currently registered contracts.
+    registered: HashMap<Vec<u8>, ContractFn>,
+}
+
+impl CallRouter {
+    /// Creates a new call router.
+    ///
+    /// Initialized with an empty set of contracts.
+    pub fn new() -> Self {
+        Self {
+            registered: HashMap::new(),
+        }
+    }
+
+    /// Resets the call router to uninitialized state.
+    pub fn reset(&mut self) {
+        self.registered.clear()
+    }
+
+    /// Registers a contract under the given encoded account ID.
+    ///
+    /// Replaces the contract previously registered under the same account ID.
+    pub fn register(&mut self, callee: Vec<u8>, contract: ContractFn) {
+        self.registered.insert(callee, contract);
+    }
+
+    /// Calls the contract registered under the given encoded account ID.
+    ///
+    /// Returns `None` if no contract has been registered under the account ID.
+    pub fn call(&mut self, callee: &[u8], input: &[u8]) -> Option<CallOutcome> {
+        self.registered
+            .get_mut(callee)
+            .map(|contract| contract(input))
+    }
+}
+
+/// A contract with programmable responses to its messages.
+///
+/// Allows to unit test contracts that call other contracts without having to
+/// deploy them. Messages without a registered response revert with empty data.
+///
+/// # Note
+///
+/// The registered responses must not access the off-chain environment
+/// since they are evaluated while the calling contract accesses it.
+#[derive(Default)]
+pub struct MockContract {
+    /// The registered responses by the selectors of their messages.
+    responses: HashMap<[u8; 4], ContractFn>,
+}
+
+impl MockContract {
+    /// Creates a new mock contract without any registered responses.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Registers the response to the message with the given selector.
+    ///
+    /// The response receives the decoded inputs of the message which is a tuple
+    /// for multiple inputs and returns the output of the message.
+    /// Inputs that cannot be decoded make the mock contract revert with empty data.
+    pub fn on_message<I, O, F>(&mut self, selector: Selector, mut response: F)
+    where
+        I: scale::Decode,
+        O: scale::Encode,
+        F: FnMut(I) -> O + 'static,
+    {
+        self.responses.insert(
+            selector.to_bytes(),
+            Box::new(move |mut encoded_input: &[u8]| {
+                let input = <I as scale::Decode>::decode(&mut encoded_input)
+                    .map_err(|_| Vec::new())?;
+                Ok(scale::Encode::encode(&response(input)))
+            }),
+        );
+    }
+
+    /// Registers the response that reverts with the given data for the message
+    /// with the given selector.
+    pub fn revert_message(&mut self, selector: Selector, data: Vec<u8>) {
+        self.responses.insert(
+            selector.to_bytes(),
+            Box::new(move |_: &[u8]| Err(data.clone())),
+        );
+    }
+
+    /// Returns the raw contract function routing calls to their responses.
+    pub(super) fn into_contract_fn(mut self) -> ContractFn {
+        Box::new(move |encoded_input: &[u8]| {
+            if encoded_input.len() < 4 {
+                return Err(Vec::new())
+            }
+            let (selector, args) = encoded_input.split_at(4);
+            match self.responses.get_mut(selector) {
+                Some(response) => response(args),
+                None => Err(Vec::new()),
+            }
+        })
+    }
+}
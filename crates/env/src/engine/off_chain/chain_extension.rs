@@ -42,6 +42,44 @@ pub trait ChainExtension {
     fn call(&mut self, input: &Self::Input) -> Result<Self::Output>;
 }
 
+/// Chain extension emulating the runtime receiving the runtime events of type `E`.
+///
+/// Forwards all received events to the handler.
+pub struct RuntimeEventHandler<E, F> {
+    /// The handler of the received runtime events.
+    handler: F,
+    /// The type of the received runtime events.
+    marker: core::marker::PhantomData<fn() -> E>,
+}
+
+impl<E, F> RuntimeEventHandler<E, F> {
+    /// Creates a chain extension forwarding the received runtime events to the handler.
+    pub fn new(handler: F) -> Self {
+        Self {
+            handler,
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<E, F> ChainExtension for RuntimeEventHandler<E, F>
+where
+    E: crate::RuntimeEvent + Clone,
+    F: FnMut(E),
+{
+    type Input = E;
+    type Output = ();
+
+    fn func_id(&self) -> u32 {
+        <E as crate::RuntimeEvent>::FUNC_ID
+    }
+
+    fn call(&mut self, input: &Self::Input) -> Result<Self::Output> {
+        (self.handler)(input.clone());
+        Ok(())
+    }
+}
+
 /// A raw chain extension function.
 ///
 /// This is mostly a wrapper closure around the real chain extension function
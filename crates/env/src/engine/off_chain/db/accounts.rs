@@ -65,6 +65,7 @@ impl From<scale::Error> for AccountError {
 pub type Result<T> = core::result::Result<T, AccountError>;
 
 /// The database that stores all accounts.
+#[derive(Clone)]
 pub struct AccountsDb {
     /// The mapping from account ID to an actual account.
     accounts: BTreeMap<OffAccountId, Account>,
@@ -173,6 +174,7 @@ impl AccountsDb {
 }
 
 /// An account within the chain.
+#[derive(Clone)]
 pub struct Account {
     /// The balance of the account.
     balance: OffBalance,
@@ -263,11 +265,24 @@ impl Account {
             .and_then(|contract| contract.storage.get_storage::<T>(at))
     }
 
+    /// Returns the encoded values stored in the contract storage at the given keys.
+    pub fn get_storage_batch(&self, keys: &[Key]) -> Result<Vec<Option<Vec<u8>>>> {
+        self.contract_or_err()
+            .map(|contract| contract.storage.get_storage_batch(keys))
+    }
+
     /// Returns the total number of reads and write from and to the contract's storage.
     pub fn get_storage_rw(&self) -> Result<(usize, usize)> {
         self.contract_or_err().map(|contract| contract.get_rw())
     }
 
+    /// Returns the total number of bytes read and written from and to the contract's
+    /// storage.
+    pub fn get_storage_bytes_rw(&self) -> Result<(usize, usize)> {
+        self.contract_or_err()
+            .map(|contract| contract.storage.get_bytes_rw())
+    }
+
     /// Returns the amount of used storage entries.
     pub fn count_used_storage_cells(&self) -> Result<usize> {
         self.contract_or_err()
@@ -278,12 +293,14 @@ impl Account {
 /// The kind of the account.
 ///
 /// Can be either a user account or a (more complicated) contract account.
+#[derive(Clone)]
 pub enum AccountKind {
     User,
     Contract(ContractAccount),
 }
 
 /// Extraneous fields for contract accounts.
+#[derive(Clone)]
 pub struct ContractAccount {
     /// The contract's rent allowance.
     rent_allowance: OffBalance,
@@ -315,6 +332,7 @@ impl ContractAccount {
 }
 
 /// The storage of a contract instance.
+#[derive(Clone)]
 pub struct ContractStorage {
     /// The entries within the contract storage.
     entries: BTreeMap<Key, Vec<u8>>,
@@ -322,6 +340,10 @@ pub struct ContractStorage {
     count_reads: Cell<usize>,
     /// The total number of writes to the storage.
     count_writes: usize,
+    /// The total number of bytes read from the storage.
+    bytes_read: Cell<usize>,
+    /// The total number of bytes written to the storage.
+    bytes_written: usize,
 }
 
 impl ContractStorage {
@@ -331,6 +353,8 @@ impl ContractStorage {
             entries: BTreeMap::new(),
             count_reads: Cell::new(0),
             count_writes: 0,
+            bytes_read: Cell::new(0),
+            bytes_written: 0,
         }
     }
 
@@ -339,26 +363,57 @@ impl ContractStorage {
         (self.count_reads.get(), self.count_writes)
     }
 
+    /// Returns the number of bytes read and written from and to the contract storage.
+    pub fn get_bytes_rw(&self) -> (usize, usize) {
+        (self.bytes_read.get(), self.bytes_written)
+    }
+
+    /// Records reading the given encoded storage entry if any.
+    fn record_read(&self, encoded: Option<&Vec<u8>>) {
+        let len = encoded.map(Vec::len).unwrap_or_default();
+        self.bytes_read.set(self.bytes_read.get() + len);
+    }
+
     /// Returns the decoded storage at the key if any.
     pub fn get_storage<T>(&self, at: Key) -> Result<Option<T>>
     where
         T: scale::Decode,
     {
         self.count_reads.set(self.count_reads.get() + 1);
-        self.entries
-            .get(&at)
+        let encoded = self.entries.get(&at);
+        self.record_read(encoded);
+        encoded
             .map(|encoded| T::decode(&mut &encoded[..]))
             .transpose()
             .map_err(Into::into)
     }
 
+    /// Returns the encoded storage at each of the keys if any.
+    ///
+    /// # Note
+    ///
+    /// Counts one read per key since the on-chain environment loads every
+    /// entry by its own host function call.
+    pub fn get_storage_batch(&self, keys: &[Key]) -> Vec<Option<Vec<u8>>> {
+        keys.iter()
+            .map(|key| {
+                self.count_reads.set(self.count_reads.get() + 1);
+                let encoded = self.entries.get(key);
+                self.record_read(encoded);
+                encoded.cloned()
+            })
+            .collect()
+    }
+
     /// Writes the encoded value into the contract storage at the given key.
     pub fn set_storage<T>(&mut self, at: Key, new_value: &T)
     where
         T: scale::Encode,
     {
         self.count_writes += 1;
-        self.entries.insert(at, new_value.encode());
+        let encoded = new_value.encode();
+        self.bytes_written += encoded.len();
+        self.entries.insert(at, encoded);
     }
 
     /// Removes the value from storage entries at the given key.
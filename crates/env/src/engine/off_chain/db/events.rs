@@ -14,8 +14,8 @@
 
 use super::super::OffHash;
 use crate::{
+    engine::BUFFER_CAPACITY,
     hash::{
-        Blake2x256,
         CryptoHash,
         HashOutput,
     },
@@ -25,34 +25,56 @@ use crate::{
     },
     Clear,
     Environment,
+    EventError,
 };
+use scale::Encode as _;
 
+/// Emulates the on-chain topics serialization including its buffer limits.
 #[derive(Default)]
 pub struct TopicsBuilder {
     topics: Vec<OffHash>,
+    /// The length of the serialized topics.
+    len: usize,
+    /// Set if one of the topics would not fit into the on-chain buffer.
+    error: Option<EventError>,
 }
 
 impl<E> TopicsBuilderBackend<E> for TopicsBuilder
 where
     E: Environment,
 {
-    type Output = Vec<OffHash>;
+    /// The topics and the length of their serialization.
+    type Output = Result<(Vec<OffHash>, usize), EventError>;
 
-    fn expect(&mut self, _expected_topics: usize) {}
+    fn expect(&mut self, expected_topics: usize) {
+        self.len = scale::Compact(expected_topics as u32).encode().len();
+    }
 
     fn push_topic<T>(&mut self, topic_value: &T)
     where
         T: scale::Encode,
     {
+        if self.error.is_some() {
+            return
+        }
         let encoded = topic_value.encode();
         let len_encoded = encoded.len();
         let mut result = <E as Environment>::Hash::clear();
         let len_result = result.as_ref().len();
+        if core::cmp::max(len_encoded, len_result) > BUFFER_CAPACITY - self.len {
+            self.error = Some(EventError::TopicTooLarge);
+            return
+        }
         if len_encoded <= len_result {
             result.as_mut()[..len_encoded].copy_from_slice(&encoded[..]);
         } else {
-            let mut hash_output = <Blake2x256 as HashOutput>::Type::default();
-            <Blake2x256 as CryptoHash>::hash(&encoded[..], &mut hash_output);
+            let mut hash_output =
+                <<E as Environment>::TopicHasher as HashOutput>::Type::default();
+            <<E as Environment>::TopicHasher as CryptoHash>::hash(
+                &encoded[..],
+                &mut hash_output,
+            );
+            let hash_output = hash_output.as_ref();
             let copy_len = core::cmp::min(hash_output.len(), len_result);
             result.as_mut()[0..copy_len].copy_from_slice(&hash_output[0..copy_len]);
         }
@@ -62,10 +84,14 @@ where
             "duplicate topic hash discovered!"
         );
         self.topics.push(off_hash);
+        self.len += result.encode().len();
     }
 
     fn output(self) -> Self::Output {
-        self.topics
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok((self.topics, self.len)),
+        }
     }
 }
 
@@ -80,16 +106,22 @@ pub struct EmittedEvent {
 
 impl EmittedEvent {
     /// Creates a new emitted event.
-    pub fn new<T, E>(emitted_event: E) -> Self
+    ///
+    /// # Errors
+    ///
+    /// If the event would not fit into the buffer of the on-chain environment.
+    pub fn new<T, E>(emitted_event: E) -> Result<Self, EventError>
     where
         T: Environment,
         E: Topics + scale::Encode,
     {
-        let topics = emitted_event.topics::<T, _>(TopicsBuilder::default().into());
-        Self {
-            topics,
-            data: emitted_event.encode(),
+        let (topics, len_topics) =
+            emitted_event.topics::<T, _>(TopicsBuilder::default().into())?;
+        let data = emitted_event.encode();
+        if data.len() > BUFFER_CAPACITY - len_topics {
+            return Err(EventError::DataTooLarge)
         }
+        Ok(Self { topics, data })
     }
 }
 
@@ -112,17 +144,33 @@ impl EmittedEventsRecorder {
     }
 
     /// Records a new emitted event.
-    pub fn record<T, E>(&mut self, new_event: E)
+    ///
+    /// # Errors
+    ///
+    /// If the event would not fit into the buffer of the on-chain environment.
+    /// No event is recorded in this case.
+    pub fn record<T, E>(&mut self, new_event: E) -> Result<(), EventError>
     where
         T: Environment,
         E: Topics + scale::Encode,
     {
-        self.emitted_events
-            .push(EmittedEvent::new::<T, E>(new_event));
+        let emitted_event = EmittedEvent::new::<T, E>(new_event)?;
+        self.emitted_events.push(emitted_event);
+        Ok(())
     }
 
     /// Returns an iterator over the emitted events in their emission order.
     pub fn emitted_events(&self) -> core::slice::Iter<EmittedEvent> {
         self.emitted_events.iter()
     }
+
+    /// Returns the number of recorded events.
+    pub fn count(&self) -> usize {
+        self.emitted_events.len()
+    }
+
+    /// Removes all events that have been recorded after the first `len` events.
+    pub fn truncate(&mut self, len: usize) {
+        self.emitted_events.truncate(len)
+    }
 }
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use super::{
+    call_router::CallOutcome,
     hashing,
+    test_api::ContractReturn,
     Account,
     EnvInstance,
 };
@@ -22,6 +24,7 @@ use crate::{
         utils::ReturnType,
         CallParams,
         CreateParams,
+        LangError,
     },
     hash::{
         Blake2x128,
@@ -35,6 +38,7 @@ use crate::{
     EnvBackend,
     Environment,
     Error,
+    EventError,
     Result,
     ReturnFlags,
     TypedEnvBackend,
@@ -67,6 +71,22 @@ impl EnvInstance {
             .get_account_off_mut(&callee)
             .expect("callee account does not exist")
     }
+
+    /// Routes the call to the contract registered for its callee.
+    ///
+    /// Returns `None` if no contract has been registered for the callee.
+    fn route_call<T, Args, R>(
+        &mut self,
+        params: &CallParams<T, Args, R>,
+    ) -> Option<CallOutcome>
+    where
+        T: Environment,
+        Args: scale::Encode,
+    {
+        let callee = scale::Encode::encode(params.callee());
+        let input = scale::Encode::encode(params.exec_input());
+        self.call_router.call(&callee, &input)
+    }
 }
 
 impl CryptoHash for Blake2x128 {
@@ -117,6 +137,17 @@ impl CryptoHash for Keccak256 {
     }
 }
 
+/// Decodes the language level error from the data of a reverted contract.
+///
+/// Returns [`Error::CalleeReverted`] if the data does not hold a language level
+/// error, e.g. because the called contract reverted on its own behalf.
+fn decode_lang_error<T>(data: &[u8]) -> Result<core::result::Result<T, LangError>> {
+    match LangError::decode_from(data) {
+        Some(lang_error) => Ok(Err(lang_error)),
+        None => Err(Error::CalleeReverted(data.to_vec())),
+    }
+}
+
 impl EnvBackend for EnvInstance {
     fn set_contract_storage<V>(&mut self, key: &Key, value: &V)
     where
@@ -136,6 +167,15 @@ impl EnvBackend for EnvInstance {
             .map_err(Into::into)
     }
 
+    fn get_contract_storage_batch(
+        &mut self,
+        keys: &[Key],
+    ) -> Result<Vec<Option<Vec<u8>>>> {
+        self.callee_account()
+            .get_storage_batch(keys)
+            .map_err(Into::into)
+    }
+
     fn clear_contract_storage(&mut self, key: &Key) {
         if !self.clear_storage_disabled {
             self.callee_account_mut()
@@ -159,6 +199,22 @@ impl EnvBackend for EnvInstance {
             })
     }
 
+    fn decode_input_bounded<T>(&mut self, max_len: usize) -> Result<T>
+    where
+        T: scale::Decode,
+    {
+        let encoded = self
+            .exec_context()
+            .map(|exec_ctx| exec_ctx.call_data.to_bytes())
+            .map_err(Error::from)?;
+        if encoded.len() > max_len {
+            return Err(Error::InputTooLarge)
+        }
+        <T as scale::Decode>::decode(&mut &encoded[..])
+            .map_err(|_| scale::Error::from("could not decode input call data"))
+            .map_err(Into::into)
+    }
+
     fn return_value<R>(&mut self, flags: ReturnFlags, return_value: &R) -> !
     where
         R: scale::Encode,
@@ -166,7 +222,14 @@ impl EnvBackend for EnvInstance {
         let ctx = self
             .exec_context_mut()
             .expect("uninitialized execution context");
-        ctx.output = Some(return_value.encode());
+        let output = return_value.encode();
+        ctx.output = Some(output.clone());
+        if self.unwind_on_return {
+            std::panic::resume_unwind(Box::new(ContractReturn {
+                reverted: flags.into_u32() & 1 != 0,
+                output,
+            }))
+        }
         std::process::exit(flags.into_u32() as i32)
     }
 
@@ -174,6 +237,14 @@ impl EnvBackend for EnvInstance {
         self.console.println(content)
     }
 
+    fn set_self_calls_denied(&mut self, denied: bool) {
+        self.self_calls_denied = denied;
+    }
+
+    fn self_calls_denied(&self) -> bool {
+        self.self_calls_denied
+    }
+
     fn hash_bytes<H>(&mut self, input: &[u8], output: &mut <H as HashOutput>::Type)
     where
         H: CryptoHash,
@@ -357,6 +428,18 @@ impl TypedEnvBackend for EnvInstance {
     }
 
     fn emit_event<T, Event>(&mut self, new_event: Event)
+    where
+        T: Environment,
+        Event: Topics + scale::Encode,
+    {
+        self.try_emit_event::<T, Event>(new_event)
+            .expect("encountered event that exceeds the environmental buffer")
+    }
+
+    fn try_emit_event<T, Event>(
+        &mut self,
+        new_event: Event,
+    ) -> core::result::Result<(), EventError>
     where
         T: Environment,
         Event: Topics + scale::Encode,
@@ -375,25 +458,43 @@ impl TypedEnvBackend for EnvInstance {
 
     fn invoke_contract<T, Args>(
         &mut self,
-        _call_params: &CallParams<T, Args, ()>,
+        call_params: &CallParams<T, Args, ()>,
     ) -> Result<()>
     where
         T: Environment,
         Args: scale::Encode,
     {
-        unimplemented!("off-chain environment does not support contract invocation")
+        match self.route_call(call_params) {
+            Some(Ok(_)) => Ok(()),
+            Some(Err(data)) => Err(Error::CalleeReverted(data)),
+            None => {
+                unimplemented!(
+                    "off-chain environment does not support contract invocation"
+                )
+            }
+        }
     }
 
     fn eval_contract<T, Args, R>(
         &mut self,
-        _call_params: &CallParams<T, Args, ReturnType<R>>,
+        call_params: &CallParams<T, Args, ReturnType<R>>,
     ) -> Result<R>
     where
         T: Environment,
         Args: scale::Encode,
         R: scale::Decode,
     {
-        unimplemented!("off-chain environment does not support contract evaluation")
+        match self.route_call(call_params) {
+            Some(Ok(output)) => {
+                scale::Decode::decode(&mut &output[..]).map_err(Into::into)
+            }
+            Some(Err(data)) => Err(Error::CalleeReverted(data)),
+            None => {
+                unimplemented!(
+                    "off-chain environment does not support contract evaluation"
+                )
+            }
+        }
     }
 
     fn instantiate_contract<T, Args, C>(
@@ -407,6 +508,60 @@ impl TypedEnvBackend for EnvInstance {
         unimplemented!("off-chain environment does not support contract instantiation")
     }
 
+    fn try_invoke_contract<T, Args>(
+        &mut self,
+        call_params: &CallParams<T, Args, ()>,
+    ) -> Result<core::result::Result<(), LangError>>
+    where
+        T: Environment,
+        Args: scale::Encode,
+    {
+        match self.route_call(call_params) {
+            Some(Ok(_)) => Ok(Ok(())),
+            Some(Err(data)) => decode_lang_error(&data),
+            None => {
+                unimplemented!(
+                    "off-chain environment does not support contract invocation"
+                )
+            }
+        }
+    }
+
+    fn try_eval_contract<T, Args, R>(
+        &mut self,
+        call_params: &CallParams<T, Args, ReturnType<R>>,
+    ) -> Result<core::result::Result<R, LangError>>
+    where
+        T: Environment,
+        Args: scale::Encode,
+        R: scale::Decode,
+    {
+        match self.route_call(call_params) {
+            Some(Ok(output)) => {
+                scale::Decode::decode(&mut &output[..])
+                    .map(Ok)
+                    .map_err(Into::into)
+            }
+            Some(Err(data)) => decode_lang_error(&data),
+            None => {
+                unimplemented!(
+                    "off-chain environment does not support contract evaluation"
+                )
+            }
+        }
+    }
+
+    fn try_instantiate_contract<T, Args, C>(
+        &mut self,
+        _params: &CreateParams<T, Args, C>,
+    ) -> Result<core::result::Result<T::AccountId, LangError>>
+    where
+        T: Environment,
+        Args: scale::Encode,
+    {
+        unimplemented!("off-chain environment does not support contract instantiation")
+    }
+
     fn terminate_contract<T>(&mut self, beneficiary: T::AccountId) -> !
     where
         T: Environment,
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bench;
 mod call_data;
+mod call_router;
 #[cfg(feature = "ink-unstable-chain-extensions")]
 mod chain_extension;
 mod db;
@@ -37,6 +39,7 @@ pub use self::{
     typed_encoded::TypedEncodedError,
 };
 use self::{
+    call_router::CallRouter,
     db::{
         Account,
         AccountsDb,
@@ -91,10 +94,17 @@ pub struct EnvInstance {
     /// Handler for registered chain extensions.
     #[cfg(feature = "ink-unstable-chain-extensions")]
     chain_extension_handler: ChainExtensionHandler,
+    /// Router for calls to contracts registered by tests.
+    call_router: CallRouter,
     /// Emitted events recorder.
     emitted_events: EmittedEventsRecorder,
     /// Set to true to disable clearing storage
     clear_storage_disabled: bool,
+    /// Set to true to end contract executions that return a value by unwinding
+    /// instead of exiting the process.
+    unwind_on_return: bool,
+    /// Set to true while self-calls via `call_self` are denied.
+    self_calls_denied: bool,
 }
 
 impl EnvInstance {
@@ -108,8 +118,11 @@ impl EnvInstance {
             console: Console::new(),
             #[cfg(feature = "ink-unstable-chain-extensions")]
             chain_extension_handler: ChainExtensionHandler::new(),
+            call_router: CallRouter::new(),
             emitted_events: EmittedEventsRecorder::new(),
             clear_storage_disabled: false,
+            unwind_on_return: false,
+            self_calls_denied: false,
         }
     }
 
@@ -140,8 +153,11 @@ impl EnvInstance {
         self.console.reset();
         #[cfg(feature = "ink-unstable-chain-extensions")]
         self.chain_extension_handler.reset();
+        self.call_router.reset();
         self.emitted_events.reset();
         self.clear_storage_disabled = false;
+        self.unwind_on_return = false;
+        self.self_calls_denied = false;
     }
 
     /// Initializes the whole off-chain environment.
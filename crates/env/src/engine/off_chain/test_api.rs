@@ -15,8 +15,19 @@
 //! Operations on the off-chain testing environment.
 
 #[cfg(feature = "ink-unstable-chain-extensions")]
-use super::chain_extension::ChainExtension;
+use super::chain_extension::{
+    ChainExtension,
+    RuntimeEventHandler,
+};
 pub use super::{
+    bench::{
+        BenchResult,
+        Bencher,
+        Counts,
+        GasModel,
+        BENCH_OUTPUT_VAR,
+    },
+    call_router::MockContract,
     db::ChainSpec,
     CallData,
     EmittedEvent,
@@ -194,6 +205,40 @@ where
     })
 }
 
+/// Registers a handler for the runtime events of type `E`.
+///
+/// Emulates the runtime receiving the events handed to it through
+/// [`deposit_runtime_event`](`crate::deposit_runtime_event`). Replaces the chain
+/// extension previously registered under the function ID of the events.
+#[cfg(feature = "ink-unstable-chain-extensions")]
+pub fn register_runtime_event_handler<E, F>(handler: F)
+where
+    E: crate::RuntimeEvent + Clone,
+    F: FnMut(E) + 'static,
+{
+    register_chain_extension(RuntimeEventHandler::new(handler))
+}
+
+/// Registers a contract under the given account ID.
+///
+/// Calls to the account ID from the tested contract are routed to the contract.
+/// Replaces the contract previously registered under the same account ID.
+///
+/// # Note
+///
+/// Value transfers and switches of the execution context are not emulated
+/// upon routed calls.
+pub fn register_contract<T>(callee: T::AccountId, contract: MockContract)
+where
+    T: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .call_router
+            .register(scale::Encode::encode(&callee), contract.into_contract_fn());
+    })
+}
+
 /// Set the entropy hash of the current block.
 ///
 /// # Note
@@ -272,6 +317,88 @@ pub fn set_clear_storage_disabled(disable: bool) {
     })
 }
 
+/// The value a contract execution run by [`execute_contract`] has returned.
+pub(super) struct ContractReturn {
+    /// If the contract execution reverted.
+    pub reverted: bool,
+    /// The SCALE encoded return value.
+    pub output: Vec<u8>,
+}
+
+/// The outcome of a contract execution run by [`execute_contract`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutionOutcome {
+    /// The contract execution ended normally or by returning a value.
+    ///
+    /// The output is empty if the contract did not return a value.
+    Returned { reverted: bool, output: Vec<u8> },
+    /// The contract terminated itself.
+    Terminated,
+    /// The contract execution panicked which traps on-chain.
+    Trapped,
+}
+
+impl ExecutionOutcome {
+    /// Returns `true` if the contract execution reverted or trapped.
+    pub fn is_reverted(&self) -> bool {
+        matches!(self, Self::Returned { reverted: true, .. } | Self::Trapped)
+    }
+}
+
+/// Runs the contract execution with the on-chain semantics of returning a value.
+///
+/// Contracts returning a value via `ink_env::return_value` end the execution
+/// instead of exiting the process. All changes to the accounts and all events
+/// emitted by the execution are discarded if it reverted or trapped.
+///
+/// # Note
+///
+/// The panic message of a trapping contract execution is printed as usual.
+pub fn execute_contract<F>(f: F) -> ExecutionOutcome
+where
+    F: FnOnce(),
+{
+    let (accounts, count_events, unwind_on_return) =
+        <EnvInstance as OnInstance>::on_instance(|instance| {
+            let unwind_on_return = instance.unwind_on_return;
+            instance.unwind_on_return = true;
+            (
+                instance.accounts.clone(),
+                instance.emitted_events.count(),
+                unwind_on_return,
+            )
+        });
+    let outcome = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+        Ok(()) => {
+            ExecutionOutcome::Returned {
+                reverted: false,
+                output: Vec::new(),
+            }
+        }
+        Err(payload) => {
+            match payload.downcast::<ContractReturn>() {
+                Ok(returned) => {
+                    ExecutionOutcome::Returned {
+                        reverted: returned.reverted,
+                        output: returned.output,
+                    }
+                }
+                // Terminating contracts panic with the encoded termination result.
+                Err(payload) if payload.is::<Vec<u8>>() => ExecutionOutcome::Terminated,
+                Err(_) => ExecutionOutcome::Trapped,
+            }
+        }
+    };
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance.unwind_on_return = unwind_on_return;
+        if outcome.is_reverted() {
+            instance.accounts = accounts;
+            instance.emitted_events.truncate(count_events);
+        }
+    });
+    outcome
+}
+
 /// The default accounts.
 pub struct DefaultAccounts<T>
 where
@@ -352,6 +479,28 @@ where
     })
 }
 
+/// Returns the total number of bytes read and written from and to the contract's
+/// storage.
+///
+/// # Note
+///
+/// Removals of storage cells do not count any bytes.
+pub fn get_contract_storage_bytes_rw<T>(
+    account_id: &T::AccountId,
+) -> Result<(usize, usize)>
+where
+    T: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .accounts
+            .get_account::<T>(account_id)
+            .ok_or_else(|| AccountError::no_account_for_id::<T>(account_id))
+            .map_err(Into::into)
+            .and_then(|account| account.get_storage_bytes_rw().map_err(Into::into))
+    })
+}
+
 /// Returns the amount of storage cells used by the account `account_id`.
 ///
 /// Returns `None` if the `account_id` is non-existent.
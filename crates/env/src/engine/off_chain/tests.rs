@@ -31,6 +31,33 @@ fn store_load_clear() -> Result<()> {
     })
 }
 
+#[test]
+fn get_contract_storage_batch_works() -> Result<()> {
+    use scale::Encode as _;
+    type Env = crate::DefaultEnvironment;
+    crate::test::run_test::<Env, _>(|_| {
+        let key_a = Key::from([0x01; 32]);
+        let key_b = Key::from([0x02; 32]);
+        let key_c = Key::from([0x03; 32]);
+        crate::set_contract_storage(&key_a, &1_u32);
+        crate::set_contract_storage(&key_c, &[0x05_u8; 3]);
+        let contract = crate::test::get_current_contract_account_id::<Env>()?;
+        let (reads_before, _) = crate::test::get_contract_storage_rw::<Env>(&contract)?;
+        assert_eq!(
+            crate::get_contract_storage_batch(&[key_c, key_b, key_a]),
+            Ok(vec![
+                Some([0x05_u8; 3].encode()),
+                None,
+                Some(1_u32.encode())
+            ]),
+        );
+        let (reads_after, _) = crate::test::get_contract_storage_rw::<Env>(&contract)?;
+        assert_eq!(reads_after, reads_before + 3);
+        assert_eq!(crate::get_contract_storage_batch(&[]), Ok(Vec::new()));
+        Ok(())
+    })
+}
+
 #[test]
 fn key_add() -> Result<()> {
     crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
@@ -94,3 +121,464 @@ fn gas_price() -> crate::Result<()> {
         Ok(())
     })
 }
+
+#[test]
+fn call_registered_contract() -> crate::Result<()> {
+    use crate::{
+        call::{
+            build_call,
+            utils::ReturnType,
+            ExecutionInput,
+            Selector,
+        },
+        DefaultEnvironment,
+        Error,
+    };
+    crate::test::run_test::<DefaultEnvironment, _>(|_| {
+        let callee = [0x07; 32].into();
+        let mut contract = crate::test::MockContract::new();
+        contract.on_message(Selector::new([0x01; 4]), |(a, b): (i32, i32)| a + b);
+        contract.revert_message(Selector::new([0x02; 4]), vec![0x42]);
+        crate::test::register_contract::<DefaultEnvironment>(callee, contract);
+
+        let sum = build_call::<DefaultEnvironment>()
+            .callee([0x07; 32].into())
+            .exec_input(
+                ExecutionInput::new(Selector::new([0x01; 4]))
+                    .push_arg(2_i32)
+                    .push_arg(3_i32),
+            )
+            .returns::<ReturnType<i32>>()
+            .fire();
+        assert_eq!(sum, Ok(5));
+        let reverted = build_call::<DefaultEnvironment>()
+            .callee([0x07; 32].into())
+            .exec_input(ExecutionInput::new(Selector::new([0x02; 4])))
+            .returns::<()>()
+            .fire();
+        assert_eq!(reverted, Err(Error::CalleeReverted(vec![0x42])));
+        Ok(())
+    })
+}
+
+#[test]
+fn revert_data_forwarding_works() -> crate::Result<()> {
+    use crate::{
+        call::{
+            build_call,
+            ExecutionInput,
+            Selector,
+        },
+        test::ExecutionOutcome,
+        DefaultEnvironment,
+        Error,
+    };
+    crate::test::run_test::<DefaultEnvironment, _>(|_| {
+        let callee = [0x07; 32].into();
+        let mut contract = crate::test::MockContract::new();
+        contract.revert_message(Selector::new([0x01; 4]), vec![0x01, 0x02, 0x03]);
+        crate::test::register_contract::<DefaultEnvironment>(callee, contract);
+
+        let error = build_call::<DefaultEnvironment>()
+            .callee([0x07; 32].into())
+            .exec_input(ExecutionInput::new(Selector::new([0x01; 4])))
+            .returns::<()>()
+            .fire()
+            .expect_err("encountered unexpected success of reverting call");
+        assert_eq!(error.revert_data(), Some(&[0x01, 0x02, 0x03][..]));
+        assert_eq!(Error::CalleeTrapped.revert_data(), None);
+        // The forwarded data is reverted with as is without being encoded again.
+        let outcome = crate::test::execute_contract(|| {
+            crate::revert_with_data(error.revert_data().unwrap())
+        });
+        assert_eq!(
+            outcome,
+            ExecutionOutcome::Returned {
+                reverted: true,
+                output: vec![0x01, 0x02, 0x03],
+            }
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn try_invoke_decodes_lang_errors() -> crate::Result<()> {
+    use crate::{
+        call::{
+            build_call,
+            utils::ReturnType,
+            ExecutionInput,
+            LangError,
+            Selector,
+        },
+        DefaultEnvironment,
+        Error,
+    };
+    crate::test::run_test::<DefaultEnvironment, _>(|_| {
+        let callee = [0x07; 32].into();
+        let lang_error = LangError::from_code(3);
+        let mut contract = crate::test::MockContract::new();
+        contract.on_message(Selector::new([0x01; 4]), |value: i32| value + 1);
+        contract
+            .revert_message(Selector::new([0x02; 4]), scale::Encode::encode(&lang_error));
+        // Data that contracts revert with on their own behalf is no language level
+        // error even if it happens to be a valid encoding of an error code.
+        contract.revert_message(Selector::new([0x03; 4]), vec![0x03, 0x00, 0x00, 0x00]);
+        crate::test::register_contract::<DefaultEnvironment>(callee, contract);
+
+        let call = |selector| {
+            build_call::<DefaultEnvironment>()
+                .callee([0x07; 32].into())
+                .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(41_i32))
+                .returns::<ReturnType<i32>>()
+        };
+        assert_eq!(call([0x01; 4]).try_invoke(), Ok(Ok(42)));
+        assert_eq!(call([0x02; 4]).try_invoke(), Ok(Err(lang_error)));
+        assert_eq!(
+            call([0x03; 4]).try_invoke(),
+            Err(Error::CalleeReverted(vec![0x03, 0x00, 0x00, 0x00]))
+        );
+        assert_eq!(
+            build_call::<DefaultEnvironment>()
+                .callee([0x07; 32].into())
+                .exec_input(ExecutionInput::new(Selector::new([0x02; 4])))
+                .returns::<()>()
+                .try_invoke(),
+            Ok(Err(lang_error))
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn call_self_works() -> crate::Result<()> {
+    use crate::{
+        call::Selector,
+        DefaultEnvironment,
+        Error,
+    };
+    crate::test::run_test::<DefaultEnvironment, _>(|_| {
+        let mut contract = crate::test::MockContract::new();
+        contract.on_message(Selector::new([0x01; 4]), |value: u32| value + 1);
+        crate::test::register_contract::<DefaultEnvironment>(
+            crate::account_id::<DefaultEnvironment>()?,
+            contract,
+        );
+
+        let call = || {
+            crate::call_self::<DefaultEnvironment, _, u32>(
+                Selector::new([0x01; 4]),
+                41_u32,
+            )
+        };
+        assert_eq!(call(), Ok(42));
+        crate::set_self_calls_denied(true);
+        assert_eq!(call(), Err(Error::SelfCallDenied));
+        crate::set_self_calls_denied(false);
+        assert_eq!(call(), Ok(42));
+        Ok(())
+    })
+}
+
+#[test]
+fn lang_error_encoding_works() {
+    use crate::call::LangError;
+    let encoded = scale::Encode::encode(&LangError::from_code(7));
+    assert_eq!(&encoded[..4], &LangError::MAGIC);
+    assert_eq!(
+        LangError::decode_from(&encoded),
+        Some(LangError::from_code(7))
+    );
+    assert_eq!(LangError::decode_from(&encoded[..7]), None);
+    assert_eq!(LangError::decode_from(&[0x07, 0x00, 0x00, 0x00]), None);
+    assert_eq!(
+        LangError::decode_from(&[&encoded[..], &[0x00]].concat()),
+        None
+    );
+}
+
+#[test]
+fn decode_input_bounded_works() -> Result<()> {
+    use crate::{
+        call::Selector,
+        test::CallData,
+        DefaultEnvironment,
+    };
+    crate::test::run_test::<DefaultEnvironment, _>(|default_accounts| {
+        assert_eq!(crate::decode_input_bounded::<[u8; 4]>(4), Ok([0x00; 4]));
+        let mut call_data = CallData::new(Selector::new([0x01; 4]));
+        call_data.push_arg(&[0x42_u8; 10].to_vec());
+        crate::test::push_execution_context::<DefaultEnvironment>(
+            default_accounts.alice,
+            [0x07; 32].into(),
+            1_000_000,
+            0,
+            call_data,
+        );
+        assert_eq!(
+            crate::decode_input_bounded::<([u8; 4], Vec<u8>)>(4 + 1 + 10),
+            Ok(([0x01; 4], vec![0x42; 10]))
+        );
+        assert_eq!(
+            crate::decode_input_bounded::<([u8; 4], Vec<u8>)>(4 + 1 + 9),
+            Err(crate::Error::InputTooLarge)
+        );
+        crate::test::pop_execution_context();
+        Ok(())
+    })
+}
+
+#[test]
+fn topic_hasher_works() -> Result<()> {
+    use crate::{
+        hash::Keccak256,
+        topics::{
+            Topics,
+            TopicsBuilder,
+            TopicsBuilderBackend,
+        },
+        DefaultEnvironment,
+        Environment,
+        Hash,
+    };
+
+    enum KeccakEnvironment {}
+
+    impl Environment for KeccakEnvironment {
+        const MAX_EVENT_TOPICS: usize = 4;
+
+        type AccountId = <DefaultEnvironment as Environment>::AccountId;
+        type Balance = <DefaultEnvironment as Environment>::Balance;
+        type Hash = <DefaultEnvironment as Environment>::Hash;
+        type Timestamp = <DefaultEnvironment as Environment>::Timestamp;
+        type BlockNumber = <DefaultEnvironment as Environment>::BlockNumber;
+        type TopicHasher = Keccak256;
+    }
+
+    #[derive(scale::Encode)]
+    struct Transferred {
+        value: [u8; 40],
+    }
+
+    impl Topics for Transferred {
+        type RemainingTopics = [crate::topics::state::HasRemainingTopics; 1];
+
+        fn topics<E, B>(
+            &self,
+            builder: TopicsBuilder<crate::topics::state::Uninit, E, B>,
+        ) -> <B as TopicsBuilderBackend<E>>::Output
+        where
+            E: Environment,
+            B: TopicsBuilderBackend<E>,
+        {
+            builder.build::<Self>().push_topic(&self.value).finish()
+        }
+    }
+
+    crate::test::run_test::<DefaultEnvironment, _>(|_| {
+        let event = Transferred { value: [0x42; 40] };
+        crate::emit_event::<KeccakEnvironment, _>(event);
+        let mut expected = [0x00; 32];
+        crate::hash_bytes::<Keccak256>(&[0x42; 40], &mut expected);
+        let topics = crate::test::recorded_events()
+            .flat_map(|event| event.topics)
+            .map(|topic| topic.decode::<Hash>())
+            .collect::<core::result::Result<Vec<_>, _>>()?;
+        assert_eq!(topics, vec![Hash::from(expected)]);
+        Ok(())
+    })
+}
+
+#[test]
+fn try_emit_event_works() -> Result<()> {
+    use crate::{
+        topics::{
+            Topics,
+            TopicsBuilder,
+            TopicsBuilderBackend,
+        },
+        DefaultEnvironment,
+        Environment,
+        EventError,
+    };
+
+    #[derive(scale::Encode)]
+    struct Logged {
+        topic: Vec<u8>,
+        data: Vec<u8>,
+    }
+
+    impl Topics for Logged {
+        type RemainingTopics = [crate::topics::state::HasRemainingTopics; 1];
+
+        fn topics<E, B>(
+            &self,
+            builder: TopicsBuilder<crate::topics::state::Uninit, E, B>,
+        ) -> <B as TopicsBuilderBackend<E>>::Output
+        where
+            E: Environment,
+            B: TopicsBuilderBackend<E>,
+        {
+            builder.build::<Self>().push_topic(&self.topic).finish()
+        }
+    }
+
+    crate::test::run_test::<DefaultEnvironment, _>(|_| {
+        let too_large = vec![0x42; 20_000];
+        let emit = |topic: &[u8], data: &[u8]| {
+            crate::try_emit_event::<DefaultEnvironment, _>(Logged {
+                topic: topic.to_vec(),
+                data: data.to_vec(),
+            })
+        };
+        assert_eq!(emit(&too_large, &[]), Err(EventError::TopicTooLarge));
+        assert_eq!(emit(&[0x01], &too_large), Err(EventError::DataTooLarge));
+        assert_eq!(crate::test::recorded_events().count(), 0);
+        assert_eq!(emit(&[0x01], &[0x02; 100]), Ok(()));
+        assert_eq!(crate::test::recorded_events().count(), 1);
+        Ok(())
+    })
+}
+
+#[test]
+#[cfg(feature = "ink-unstable-chain-extensions")]
+fn deposit_runtime_event_works() -> Result<()> {
+    use crate::RuntimeEvent;
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    struct Transferred {
+        value: u32,
+    }
+
+    impl RuntimeEvent for Transferred {
+        const FUNC_ID: u32 = 42;
+    }
+
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        assert!(crate::deposit_runtime_event(&Transferred { value: 1 }).is_err());
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let handler_received = received.clone();
+        crate::test::register_runtime_event_handler(move |event: Transferred| {
+            handler_received.borrow_mut().push(event)
+        });
+        crate::deposit_runtime_event(&Transferred { value: 2 })?;
+        crate::deposit_runtime_event(&Transferred { value: 3 })?;
+        assert_eq!(
+            *received.borrow(),
+            vec![Transferred { value: 2 }, Transferred { value: 3 }]
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn bencher_works() -> Result<()> {
+    use crate::test::{
+        Bencher,
+        Counts,
+        GasModel,
+    };
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        let key = Key::from([0x42; 32]);
+        let mut bencher = Bencher::new(3);
+        bencher.iter("store_load", || {
+            crate::set_contract_storage(&key, &42_u32);
+            crate::get_contract_storage::<u32>(&key)
+        })?;
+        let result = &bencher.results()[0];
+        let expected = Counts {
+            storage_reads: 1,
+            storage_writes: 1,
+            bytes_read: 4,
+            bytes_written: 4,
+            events: 0,
+        };
+        assert_eq!(result.iterations, 3);
+        assert_eq!(result.counts, expected);
+        assert_eq!(result.weight, GasModel::default().weight(&expected));
+        assert_eq!(
+            result.to_json(),
+            format!(
+                "{{\"name\":\"store_load\",\"iterations\":3,\"storage_reads\":1,\"storage_writes\":1,\"bytes_read\":4,\"bytes_written\":4,\"events\":0,\"weight\":{}}}",
+                result.weight,
+            ),
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn execute_contract_works() -> Result<()> {
+    use crate::{
+        test::ExecutionOutcome,
+        ReturnFlags,
+    };
+    use scale::Encode as _;
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        let key = Key::from([0x42; 32]);
+        crate::set_contract_storage(&key, &1_u32);
+        // Reverting discards the storage changes.
+        let outcome = crate::test::execute_contract(|| {
+            crate::set_contract_storage(&key, &2_u32);
+            crate::return_value(ReturnFlags::default().set_reverted(true), &42_u8)
+        });
+        assert_eq!(
+            outcome,
+            ExecutionOutcome::Returned {
+                reverted: true,
+                output: 42_u8.encode(),
+            }
+        );
+        assert_eq!(crate::get_contract_storage::<u32>(&key), Ok(Some(1)));
+        // Returning normally keeps the storage changes.
+        let outcome = crate::test::execute_contract(|| {
+            crate::set_contract_storage(&key, &3_u32);
+        });
+        assert!(!outcome.is_reverted());
+        assert_eq!(crate::get_contract_storage::<u32>(&key), Ok(Some(3)));
+        // Trapping discards the storage changes.
+        let outcome = crate::test::execute_contract(|| {
+            crate::set_contract_storage(&key, &4_u32);
+            panic!("trapped")
+        });
+        assert_eq!(outcome, ExecutionOutcome::Trapped);
+        assert_eq!(crate::get_contract_storage::<u32>(&key), Ok(Some(3)));
+        Ok(())
+    })
+}
+
+#[test]
+fn revert_with_code_works() -> Result<()> {
+    use crate::{
+        call::RevertEnvelope,
+        test::ExecutionOutcome,
+        Error,
+    };
+    crate::test::run_test::<crate::DefaultEnvironment, _>(|_| {
+        let outcome = crate::test::execute_contract(|| {
+            crate::revert_with_code(7, &(true, 42_u32))
+        });
+        let output = match outcome {
+            ExecutionOutcome::Returned {
+                reverted: true,
+                output,
+            } => output,
+            outcome => panic!("encountered unexpected outcome: {:?}", outcome),
+        };
+        assert_eq!(&output[..4], &RevertEnvelope::MAGIC);
+        let envelope = Error::CalleeReverted(output)
+            .revert_envelope()
+            .expect("encountered missing revert envelope");
+        assert_eq!(envelope.code(), 7);
+        assert_eq!(envelope.decode_data::<(bool, u32)>(), Ok((true, 42)));
+        // Data that contracts revert with on their own behalf is no envelope.
+        assert_eq!(Error::CalleeReverted(vec![1, 2, 3]).revert_envelope(), None);
+        assert_eq!(RevertEnvelope::decode_from(&[0xFF, b'i', b'n', b'k']), None);
+        Ok(())
+    })
+}
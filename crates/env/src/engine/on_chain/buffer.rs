@@ -20,7 +20,7 @@ pub struct StaticBuffer {
 
 impl StaticBuffer {
     /// The capacity of the static buffer.
-    const CAPACITY: usize = 1 << 14; // 16kB
+    const CAPACITY: usize = crate::engine::BUFFER_CAPACITY;
 
     /// Creates a new static buffer.
     pub const fn new() -> Self {
@@ -101,6 +101,26 @@ impl<'a> scale::Output for EncodeScope<'a> {
     }
 }
 
+/// Utility to compute the length of an encoding without writing it anywhere.
+#[derive(Default)]
+struct EncodedLen(usize);
+
+impl scale::Output for EncodedLen {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 += bytes.len();
+    }
+}
+
+/// Returns the length of the encoding of `value`.
+pub fn encoded_len<T>(value: &T) -> usize
+where
+    T: scale::Encode,
+{
+    let mut len = EncodedLen::default();
+    scale::Encode::encode_to(value, &mut len);
+    len.0
+}
+
 /// Scoped access to an underlying bytes buffer.
 ///
 /// # Note
@@ -130,6 +150,11 @@ impl<'a> ScopedBuffer<'a> {
         }
     }
 
+    /// Returns the number of bytes that can still be appended or taken.
+    pub fn remaining_len(&self) -> usize {
+        self.buffer.len() - self.offset
+    }
+
     /// Returns the first `len` bytes of the buffer as mutable slice.
     pub fn take(&mut self, len: usize) -> &'a mut [u8] {
         debug_assert_eq!(self.offset, 0);
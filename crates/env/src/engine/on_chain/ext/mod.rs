@@ -15,11 +15,37 @@
 //! External C API to communicate with substrate contracts runtime module.
 //!
 //! Refer to substrate SRML contract module for more documentation.
+//!
+//! The contracts runtime module versions its host functions through the Wasm
+//! import modules `seal0`, `seal1`, `seal2`, etc. A revised host function is
+//! added to a newer import module while the older versions stay available.
+//! The host functions that have been revised so far are wrapped by the backends
+//! of the respective API version which are selected by the `seal1` and `seal2`
+//! crate features. Without any of them the `seal0` backend is used. All other
+//! host functions are imported from `seal0` regardless of the API version.
 
 use crate::ReturnFlags;
+use cfg_if::cfg_if;
 use core::marker::PhantomData;
 use ink_primitives::Key;
 
+cfg_if! {
+    if #[cfg(all(feature = "seal1", feature = "seal2"))] {
+        compile_error! {
+            "the `seal1` and `seal2` features of ink_env are mutually exclusive"
+        }
+    } else if #[cfg(feature = "seal2")] {
+        mod seal2;
+        pub use self::seal2::*;
+    } else if #[cfg(feature = "seal1")] {
+        mod seal1;
+        pub use self::seal1::*;
+    } else {
+        mod seal0;
+        pub use self::seal0::*;
+    }
+}
+
 macro_rules! define_error_codes {
     (
         $(
@@ -191,32 +217,6 @@ mod sys {
 
     #[link(wasm_import_module = "seal0")]
     extern "C" {
-        pub fn seal_instantiate(
-            init_code_ptr: Ptr32<[u8]>,
-            init_code_len: u32,
-            gas: u64,
-            endowment_ptr: Ptr32<[u8]>,
-            endowment_len: u32,
-            input_ptr: Ptr32<[u8]>,
-            input_len: u32,
-            address_ptr: Ptr32Mut<[u8]>,
-            address_len_ptr: Ptr32Mut<u32>,
-            output_ptr: Ptr32Mut<[u8]>,
-            output_len_ptr: Ptr32Mut<u32>,
-        ) -> ReturnCode;
-
-        pub fn seal_call(
-            callee_ptr: Ptr32<[u8]>,
-            callee_len: u32,
-            gas: u64,
-            transferred_value_ptr: Ptr32<[u8]>,
-            transferred_value_len: u32,
-            input_ptr: Ptr32<[u8]>,
-            input_len: u32,
-            output_ptr: Ptr32Mut<[u8]>,
-            output_len_ptr: Ptr32Mut<u32>,
-        ) -> ReturnCode;
-
         pub fn seal_transfer(
             account_id_ptr: Ptr32<[u8]>,
             account_id_len: u32,
@@ -231,18 +231,6 @@ mod sys {
             data_len: u32,
         );
 
-        pub fn seal_set_storage(
-            key_ptr: Ptr32<[u8]>,
-            value_ptr: Ptr32<[u8]>,
-            value_len: u32,
-        );
-        pub fn seal_get_storage(
-            key_ptr: Ptr32<[u8]>,
-            output_ptr: Ptr32Mut<[u8]>,
-            output_len_ptr: Ptr32Mut<u32>,
-        ) -> ReturnCode;
-        pub fn seal_clear_storage(key_ptr: Ptr32<[u8]>);
-
         pub fn seal_restore_to(
             dest_ptr: Ptr32<[u8]>,
             dest_len: u32,
@@ -253,7 +241,6 @@ mod sys {
             delta_ptr: Ptr32<[Key]>,
             delta_count: u32,
         );
-        pub fn seal_terminate(beneficiary_ptr: Ptr32<[u8]>, beneficiary_len: u32) -> !;
 
         #[cfg(feature = "ink-unstable-chain-extensions")]
         pub fn seal_call_chain_extension(
@@ -337,65 +324,6 @@ fn extract_from_slice(output: &mut &mut [u8], new_len: usize) {
     *output = &mut tmp[..new_len];
 }
 
-pub fn instantiate(
-    code_hash: &[u8],
-    gas_limit: u64,
-    endowment: &[u8],
-    input: &[u8],
-    out_address: &mut &mut [u8],
-    out_return_value: &mut &mut [u8],
-) -> Result {
-    let mut address_len = out_address.len() as u32;
-    let mut return_value_len = out_return_value.len() as u32;
-    let ret_code = {
-        unsafe {
-            sys::seal_instantiate(
-                Ptr32::from_slice(code_hash),
-                code_hash.len() as u32,
-                gas_limit,
-                Ptr32::from_slice(endowment),
-                endowment.len() as u32,
-                Ptr32::from_slice(input),
-                input.len() as u32,
-                Ptr32Mut::from_slice(out_address),
-                Ptr32Mut::from_ref(&mut address_len),
-                Ptr32Mut::from_slice(out_return_value),
-                Ptr32Mut::from_ref(&mut return_value_len),
-            )
-        }
-    };
-    extract_from_slice(out_address, address_len as usize);
-    extract_from_slice(out_return_value, return_value_len as usize);
-    ret_code.into()
-}
-
-pub fn call(
-    callee: &[u8],
-    gas_limit: u64,
-    value: &[u8],
-    input: &[u8],
-    output: &mut &mut [u8],
-) -> Result {
-    let mut output_len = output.len() as u32;
-    let ret_code = {
-        unsafe {
-            sys::seal_call(
-                Ptr32::from_slice(callee),
-                callee.len() as u32,
-                gas_limit,
-                Ptr32::from_slice(value),
-                value.len() as u32,
-                Ptr32::from_slice(input),
-                input.len() as u32,
-                Ptr32Mut::from_slice(output),
-                Ptr32Mut::from_ref(&mut output_len),
-            )
-        }
-    };
-    extract_from_slice(output, output_len as usize);
-    ret_code.into()
-}
-
 pub fn transfer(account_id: &[u8], value: &[u8]) -> Result {
     let ret_code = unsafe {
         sys::seal_transfer(
@@ -419,35 +347,6 @@ pub fn deposit_event(topics: &[u8], data: &[u8]) {
     }
 }
 
-pub fn set_storage(key: &[u8], encoded_value: &[u8]) {
-    unsafe {
-        sys::seal_set_storage(
-            Ptr32::from_slice(key),
-            Ptr32::from_slice(encoded_value),
-            encoded_value.len() as u32,
-        )
-    }
-}
-
-pub fn clear_storage(key: &[u8]) {
-    unsafe { sys::seal_clear_storage(Ptr32::from_slice(key)) }
-}
-
-pub fn get_storage(key: &[u8], output: &mut &mut [u8]) -> Result {
-    let mut output_len = output.len() as u32;
-    let ret_code = {
-        unsafe {
-            sys::seal_get_storage(
-                Ptr32::from_slice(key),
-                Ptr32Mut::from_slice(output),
-                Ptr32Mut::from_ref(&mut output_len),
-            )
-        }
-    };
-    extract_from_slice(output, output_len as usize);
-    ret_code.into()
-}
-
 /// Restores a tombstone to the original smart contract.
 ///
 /// # Params
@@ -479,12 +378,6 @@ pub fn restore_to(
     }
 }
 
-pub fn terminate(beneficiary: &[u8]) -> ! {
-    unsafe {
-        sys::seal_terminate(Ptr32::from_slice(beneficiary), beneficiary.len() as u32)
-    }
-}
-
 #[cfg(feature = "ink-unstable-chain-extensions")]
 pub fn call_chain_extension(
     func_id: u32,
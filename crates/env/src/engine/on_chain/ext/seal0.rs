@@ -0,0 +1,169 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Host functions of the `seal0` API version.
+//!
+//! This is the initial API version of the contracts runtime module.
+
+use super::{
+    extract_from_slice,
+    Ptr32,
+    Ptr32Mut,
+    Result,
+};
+
+mod sys {
+    use super::super::{
+        Ptr32,
+        Ptr32Mut,
+        ReturnCode,
+    };
+
+    #[link(wasm_import_module = "seal0")]
+    extern "C" {
+        pub fn seal_instantiate(
+            init_code_ptr: Ptr32<[u8]>,
+            init_code_len: u32,
+            gas: u64,
+            endowment_ptr: Ptr32<[u8]>,
+            endowment_len: u32,
+            input_ptr: Ptr32<[u8]>,
+            input_len: u32,
+            address_ptr: Ptr32Mut<[u8]>,
+            address_len_ptr: Ptr32Mut<u32>,
+            output_ptr: Ptr32Mut<[u8]>,
+            output_len_ptr: Ptr32Mut<u32>,
+        ) -> ReturnCode;
+
+        pub fn seal_call(
+            callee_ptr: Ptr32<[u8]>,
+            callee_len: u32,
+            gas: u64,
+            transferred_value_ptr: Ptr32<[u8]>,
+            transferred_value_len: u32,
+            input_ptr: Ptr32<[u8]>,
+            input_len: u32,
+            output_ptr: Ptr32Mut<[u8]>,
+            output_len_ptr: Ptr32Mut<u32>,
+        ) -> ReturnCode;
+
+        pub fn seal_set_storage(
+            key_ptr: Ptr32<[u8]>,
+            value_ptr: Ptr32<[u8]>,
+            value_len: u32,
+        );
+        pub fn seal_get_storage(
+            key_ptr: Ptr32<[u8]>,
+            output_ptr: Ptr32Mut<[u8]>,
+            output_len_ptr: Ptr32Mut<u32>,
+        ) -> ReturnCode;
+        pub fn seal_clear_storage(key_ptr: Ptr32<[u8]>);
+
+        pub fn seal_terminate(beneficiary_ptr: Ptr32<[u8]>, beneficiary_len: u32) -> !;
+    }
+}
+
+pub fn instantiate(
+    code_hash: &[u8],
+    gas_limit: u64,
+    endowment: &[u8],
+    input: &[u8],
+    out_address: &mut &mut [u8],
+    out_return_value: &mut &mut [u8],
+) -> Result {
+    let mut address_len = out_address.len() as u32;
+    let mut return_value_len = out_return_value.len() as u32;
+    let ret_code = {
+        unsafe {
+            sys::seal_instantiate(
+                Ptr32::from_slice(code_hash),
+                code_hash.len() as u32,
+                gas_limit,
+                Ptr32::from_slice(endowment),
+                endowment.len() as u32,
+                Ptr32::from_slice(input),
+                input.len() as u32,
+                Ptr32Mut::from_slice(out_address),
+                Ptr32Mut::from_ref(&mut address_len),
+                Ptr32Mut::from_slice(out_return_value),
+                Ptr32Mut::from_ref(&mut return_value_len),
+            )
+        }
+    };
+    extract_from_slice(out_address, address_len as usize);
+    extract_from_slice(out_return_value, return_value_len as usize);
+    ret_code.into()
+}
+
+pub fn call(
+    callee: &[u8],
+    gas_limit: u64,
+    value: &[u8],
+    input: &[u8],
+    output: &mut &mut [u8],
+) -> Result {
+    let mut output_len = output.len() as u32;
+    let ret_code = {
+        unsafe {
+            sys::seal_call(
+                Ptr32::from_slice(callee),
+                callee.len() as u32,
+                gas_limit,
+                Ptr32::from_slice(value),
+                value.len() as u32,
+                Ptr32::from_slice(input),
+                input.len() as u32,
+                Ptr32Mut::from_slice(output),
+                Ptr32Mut::from_ref(&mut output_len),
+            )
+        }
+    };
+    extract_from_slice(output, output_len as usize);
+    ret_code.into()
+}
+
+pub fn set_storage(key: &[u8], encoded_value: &[u8]) {
+    unsafe {
+        sys::seal_set_storage(
+            Ptr32::from_slice(key),
+            Ptr32::from_slice(encoded_value),
+            encoded_value.len() as u32,
+        )
+    }
+}
+
+pub fn clear_storage(key: &[u8]) {
+    unsafe { sys::seal_clear_storage(Ptr32::from_slice(key)) }
+}
+
+pub fn get_storage(key: &[u8], output: &mut &mut [u8]) -> Result {
+    let mut output_len = output.len() as u32;
+    let ret_code = {
+        unsafe {
+            sys::seal_get_storage(
+                Ptr32::from_slice(key),
+                Ptr32Mut::from_slice(output),
+                Ptr32Mut::from_ref(&mut output_len),
+            )
+        }
+    };
+    extract_from_slice(output, output_len as usize);
+    ret_code.into()
+}
+
+pub fn terminate(beneficiary: &[u8]) -> ! {
+    unsafe {
+        sys::seal_terminate(Ptr32::from_slice(beneficiary), beneficiary.len() as u32)
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ext;
+use core::alloc::Layout;
+use ink_prelude::tinyfmt::Buffer;
+
+/// Prints the requested size and the current heap usage to the environmental log.
+///
+/// The message is formatted on the stack since the heap has been exhausted.
+fn report_oom(layout: Layout) {
+    let mut message = Buffer::<[u8; 128]>::new();
+    message
+        .push_str("out of memory: requested ")
+        .push(&layout.size())
+        .push_str(" bytes aligned to ")
+        .push(&layout.align());
+    #[cfg(feature = "bump-allocator")]
+    message
+        .push_str(", heap usage: ")
+        .push(&ink_allocator::usage())
+        .push_str(" bytes, peak: ")
+        .push(&ink_allocator::peak_usage())
+        .push_str(" bytes");
+    ext::println(message.as_str())
+}
+
+#[alloc_error_handler]
+fn oom(layout: Layout) -> ! {
+    report_oom(layout);
+    // SAFETY: The on-chain environment is only compiled for Wasm32.
+    unsafe { core::arch::wasm32::unreachable() }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::{
+    encoded_len,
     ext,
     EnvInstance,
     Error as ExtError,
@@ -23,6 +24,8 @@ use crate::{
         utils::ReturnType,
         CallParams,
         CreateParams,
+        Forwarded,
+        LangError,
     },
     hash::{
         Blake2x128,
@@ -40,10 +43,13 @@ use crate::{
     EnvBackend,
     Environment,
     Error,
+    EventError,
     Result,
     ReturnFlags,
     TypedEnvBackend,
 };
+use core::convert::TryInto as _;
+use ink_prelude::vec::Vec;
 use ink_primitives::Key;
 
 impl CryptoHash for Blake2x128 {
@@ -99,7 +105,7 @@ impl From<ext::Error> for Error {
         match ext_error {
             ext::Error::UnknownError => Self::UnknownError,
             ext::Error::CalleeTrapped => Self::CalleeTrapped,
-            ext::Error::CalleeReverted => Self::CalleeReverted,
+            ext::Error::CalleeReverted => Self::CalleeReverted(Vec::new()),
             ext::Error::KeyNotFound => Self::KeyNotFound,
             ext::Error::BelowSubsistenceThreshold => Self::BelowSubsistenceThreshold,
             ext::Error::TransferFailed => Self::TransferFailed,
@@ -112,6 +118,8 @@ impl From<ext::Error> for Error {
 
 pub struct TopicsBuilder<'a, E> {
     scoped_buffer: ScopedBuffer<'a>,
+    /// Set if one of the topics did not fit into the scoped buffer.
+    error: Option<EventError>,
     marker: core::marker::PhantomData<fn() -> E>,
 }
 
@@ -122,6 +130,7 @@ where
     fn from(scoped_buffer: ScopedBuffer<'a>) -> Self {
         Self {
             scoped_buffer,
+            error: None,
             marker: Default::default(),
         }
     }
@@ -131,7 +140,7 @@ impl<'a, E> TopicsBuilderBackend<E> for TopicsBuilder<'a, E>
 where
     E: Environment,
 {
-    type Output = (ScopedBuffer<'a>, &'a mut [u8]);
+    type Output = core::result::Result<(ScopedBuffer<'a>, &'a mut [u8]), EventError>;
 
     fn expect(&mut self, expected_topics: usize) {
         self.scoped_buffer
@@ -142,16 +151,30 @@ where
     where
         T: scale::Encode,
     {
-        let mut split = self.scoped_buffer.split();
-        let encoded = split.take_encoded(topic_value);
-        let len_encoded = encoded.len();
+        if self.error.is_some() {
+            return
+        }
+        let len_encoded = encoded_len(topic_value);
         let mut result = <E as Environment>::Hash::clear();
         let len_result = result.as_ref().len();
+        // The topic is encoded into the remaining buffer before it is replaced
+        // by its hash so both must fit into the remaining buffer.
+        if core::cmp::max(len_encoded, len_result) > self.scoped_buffer.remaining_len() {
+            self.error = Some(EventError::TopicTooLarge);
+            return
+        }
+        let mut split = self.scoped_buffer.split();
+        let encoded = split.take_encoded(topic_value);
         if len_encoded <= len_result {
             result.as_mut()[..len_encoded].copy_from_slice(encoded);
         } else {
-            let mut hash_output = <Blake2x256 as HashOutput>::Type::default();
-            <Blake2x256 as CryptoHash>::hash(encoded, &mut hash_output);
+            let mut hash_output =
+                <<E as Environment>::TopicHasher as HashOutput>::Type::default();
+            <<E as Environment>::TopicHasher as CryptoHash>::hash(
+                encoded,
+                &mut hash_output,
+            );
+            let hash_output = hash_output.as_ref();
             let copy_len = core::cmp::min(hash_output.len(), len_result);
             result.as_mut()[0..copy_len].copy_from_slice(&hash_output[0..copy_len]);
         }
@@ -159,8 +182,11 @@ where
     }
 
     fn output(mut self) -> Self::Output {
+        if let Some(error) = self.error {
+            return Err(error)
+        }
         let encoded_topics = self.scoped_buffer.take_appended();
-        (self.scoped_buffer, encoded_topics)
+        Ok((self.scoped_buffer, encoded_topics))
     }
 }
 
@@ -190,8 +216,8 @@ impl EnvInstance {
         Args: scale::Encode,
         R: scale::Decode,
     {
+        let gas_limit = self.forwarded_gas_limit::<T>(params.gas_limit())?;
         let mut scope = self.scoped_buffer();
-        let gas_limit = params.gas_limit();
         let enc_callee = scope.take_encoded(params.callee());
         let enc_transferred_value = scope.take_encoded(params.transferred_value());
         let enc_input = scope.take_encoded(params.exec_input());
@@ -202,10 +228,75 @@ impl EnvInstance {
             enc_transferred_value,
             enc_input,
             output,
-        )?;
+        )
+        .map_err(|error| with_revert_data(error, output))?;
         let decoded = scale::Decode::decode(&mut &output[..])?;
         Ok(decoded)
     }
+
+    /// Returns the gas limit for a call according to the gas forwarding policy.
+    fn forwarded_gas_limit<T>(&mut self, forwarded: Forwarded) -> Result<u64>
+    where
+        T: Environment,
+    {
+        forwarded.to_gas_limit(|| {
+            let gas_left = self.gas_left::<T>()?;
+            Ok(gas_left.try_into().unwrap_or(u64::MAX))
+        })
+    }
+
+    /// Reusable implementation for invoking another contract message that
+    /// decodes the language level error the called contract reverted with.
+    fn try_invoke_contract_impl<T, Args, RetType, R>(
+        &mut self,
+        params: &CallParams<T, Args, RetType>,
+    ) -> Result<core::result::Result<R, LangError>>
+    where
+        T: Environment,
+        Args: scale::Encode,
+        R: scale::Decode,
+    {
+        let gas_limit = self.forwarded_gas_limit::<T>(params.gas_limit())?;
+        let mut scope = self.scoped_buffer();
+        let enc_callee = scope.take_encoded(params.callee());
+        let enc_transferred_value = scope.take_encoded(params.transferred_value());
+        let enc_input = scope.take_encoded(params.exec_input());
+        let output = &mut scope.take_rest();
+        let result = ext::call(
+            enc_callee,
+            gas_limit,
+            enc_transferred_value,
+            enc_input,
+            output,
+        );
+        match result {
+            Ok(()) => {
+                let decoded = scale::Decode::decode(&mut &output[..])?;
+                Ok(Ok(decoded))
+            }
+            Err(ExtError::CalleeReverted) => decode_lang_error(output),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+/// Attaches the output of a reverted contract execution to the error.
+fn with_revert_data(error: ExtError, output: &[u8]) -> Error {
+    match error {
+        ExtError::CalleeReverted => Error::CalleeReverted(output.to_vec()),
+        error => error.into(),
+    }
+}
+
+/// Decodes the language level error from the output of a reverted contract execution.
+///
+/// Returns [`Error::CalleeReverted`] if the output does not hold a language level
+/// error, e.g. because the called contract reverted on its own behalf.
+fn decode_lang_error<T>(output: &[u8]) -> Result<core::result::Result<T, LangError>> {
+    match LangError::decode_from(output) {
+        Some(lang_error) => Ok(Err(lang_error)),
+        None => Err(Error::CalleeReverted(output.to_vec())),
+    }
 }
 
 impl EnvBackend for EnvInstance {
@@ -231,6 +322,23 @@ impl EnvBackend for EnvInstance {
         Ok(Some(decoded))
     }
 
+    fn get_contract_storage_batch(
+        &mut self,
+        keys: &[Key],
+    ) -> Result<Vec<Option<Vec<u8>>>> {
+        let buffer = self.scoped_buffer().take_rest();
+        keys.iter()
+            .map(|key| {
+                let output = &mut &mut buffer[..];
+                match ext::get_storage(key.as_bytes(), output) {
+                    Ok(_) => Ok(Some(output.to_vec())),
+                    Err(ExtError::KeyNotFound) => Ok(None),
+                    Err(error) => Err(error.into()),
+                }
+            })
+            .collect()
+    }
+
     fn clear_contract_storage(&mut self, key: &Key) {
         ext::clear_storage(key.as_bytes())
     }
@@ -242,12 +350,26 @@ impl EnvBackend for EnvInstance {
         self.get_property::<T>(ext::input)
     }
 
+    fn decode_input_bounded<T>(&mut self, max_len: usize) -> Result<T>
+    where
+        T: scale::Decode,
+    {
+        let full_scope = &mut self.scoped_buffer().take_rest();
+        ext::input(full_scope);
+        if full_scope.len() > max_len {
+            return Err(Error::InputTooLarge)
+        }
+        scale::Decode::decode(&mut &full_scope[..]).map_err(Into::into)
+    }
+
     fn return_value<R>(&mut self, flags: ReturnFlags, return_value: &R) -> !
     where
         R: scale::Encode,
     {
         let mut scope = self.scoped_buffer();
         let enc_return_value = scope.take_encoded(return_value);
+        #[cfg(feature = "heap-instrumentation")]
+        crate::report_heap_usage();
         ext::return_value(flags, enc_return_value);
     }
 
@@ -255,6 +377,14 @@ impl EnvBackend for EnvInstance {
         ext::println(content)
     }
 
+    fn set_self_calls_denied(&mut self, denied: bool) {
+        self.self_calls_denied = denied;
+    }
+
+    fn self_calls_denied(&self) -> bool {
+        self.self_calls_denied
+    }
+
     fn hash_bytes<H>(&mut self, input: &[u8], output: &mut <H as HashOutput>::Type)
     where
         H: CryptoHash,
@@ -328,14 +458,30 @@ impl TypedEnvBackend for EnvInstance {
     }
 
     fn emit_event<T, Event>(&mut self, event: Event)
+    where
+        T: Environment,
+        Event: Topics + scale::Encode,
+    {
+        self.try_emit_event::<T, Event>(event)
+            .expect("encountered event that exceeds the environmental buffer")
+    }
+
+    fn try_emit_event<T, Event>(
+        &mut self,
+        event: Event,
+    ) -> core::result::Result<(), EventError>
     where
         T: Environment,
         Event: Topics + scale::Encode,
     {
         let (mut scope, enc_topics) =
-            event.topics::<T, _>(TopicsBuilder::from(self.scoped_buffer()).into());
+            event.topics::<T, _>(TopicsBuilder::from(self.scoped_buffer()).into())?;
+        if encoded_len(&event) > scope.remaining_len() {
+            return Err(EventError::DataTooLarge)
+        }
         let enc_data = scope.take_encoded(&event);
         ext::deposit_event(enc_topics, enc_data);
+        Ok(())
     }
 
     fn set_rent_allowance<T>(&mut self, new_value: T::Balance)
@@ -387,7 +533,8 @@ impl TypedEnvBackend for EnvInstance {
         // In the default configuration encoded `AccountId` require 32 bytes.
         let out_address = &mut scoped.take(1024);
         let out_return_value = &mut scoped.take_rest();
-        // We currently do nothing with the `out_return_value` buffer.
+        // We currently do nothing with the `out_return_value` buffer
+        // unless the instantiated contract reverted with it.
         // This should change in the future but for that we need to add support
         // for constructors that may return values.
         // This is useful to support fallible constructors for example.
@@ -398,11 +545,68 @@ impl TypedEnvBackend for EnvInstance {
             enc_input,
             out_address,
             out_return_value,
-        )?;
+        )
+        .map_err(|error| with_revert_data(error, out_return_value))?;
         let account_id = scale::Decode::decode(&mut &out_address[..])?;
         Ok(account_id)
     }
 
+    fn try_invoke_contract<T, Args>(
+        &mut self,
+        call_params: &CallParams<T, Args, ()>,
+    ) -> Result<core::result::Result<(), LangError>>
+    where
+        T: Environment,
+        Args: scale::Encode,
+    {
+        self.try_invoke_contract_impl(call_params)
+    }
+
+    fn try_eval_contract<T, Args, R>(
+        &mut self,
+        call_params: &CallParams<T, Args, ReturnType<R>>,
+    ) -> Result<core::result::Result<R, LangError>>
+    where
+        T: Environment,
+        Args: scale::Encode,
+        R: scale::Decode,
+    {
+        self.try_invoke_contract_impl(call_params)
+    }
+
+    fn try_instantiate_contract<T, Args, C>(
+        &mut self,
+        params: &CreateParams<T, Args, C>,
+    ) -> Result<core::result::Result<T::AccountId, LangError>>
+    where
+        T: Environment,
+        Args: scale::Encode,
+    {
+        let mut scoped = self.scoped_buffer();
+        let gas_limit = params.gas_limit();
+        let enc_code_hash = scoped.take_encoded(params.code_hash());
+        let enc_endowment = scoped.take_encoded(params.endowment());
+        let enc_input = scoped.take_encoded(params.exec_input());
+        let out_address = &mut scoped.take(1024);
+        let out_return_value = &mut scoped.take_rest();
+        let result = ext::instantiate(
+            enc_code_hash,
+            gas_limit,
+            enc_endowment,
+            enc_input,
+            out_address,
+            out_return_value,
+        );
+        match result {
+            Ok(()) => {
+                let account_id = scale::Decode::decode(&mut &out_address[..])?;
+                Ok(Ok(account_id))
+            }
+            Err(ExtError::CalleeReverted) => decode_lang_error(out_return_value),
+            Err(error) => Err(error.into()),
+        }
+    }
+
     fn restore_contract<T>(
         &mut self,
         account_id: T::AccountId,
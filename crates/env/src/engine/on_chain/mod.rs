@@ -14,10 +14,12 @@
 
 mod buffer;
 mod ext;
+mod handlers;
 mod impls;
 
 use self::{
     buffer::{
+        encoded_len,
         ScopedBuffer,
         StaticBuffer,
     },
@@ -37,6 +39,8 @@ pub struct EnvInstance {
     /// might change. Users should generally avoid storing too big values
     /// into single storage entries.
     buffer: StaticBuffer,
+    /// Set to true while self-calls via `call_self` are denied.
+    self_calls_denied: bool,
 }
 
 impl OnInstance for EnvInstance {
@@ -46,6 +50,7 @@ impl OnInstance for EnvInstance {
     {
         static mut INSTANCE: EnvInstance = EnvInstance {
             buffer: StaticBuffer::new(),
+            self_calls_denied: false,
         };
         f(unsafe { &mut INSTANCE })
     }
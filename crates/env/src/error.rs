@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::call::RevertEnvelope;
 use derive_more::From;
+use ink_prelude::vec::Vec;
 
 #[cfg(any(feature = "std", test, doc))]
 use crate::engine::off_chain::OffChainError;
@@ -28,7 +30,10 @@ pub enum Error {
     /// The call to another contract has trapped.
     CalleeTrapped,
     /// The call to another contract has been reverted.
-    CalleeReverted,
+    ///
+    /// Holds the data that the called contract has reverted with.
+    #[from(ignore)]
+    CalleeReverted(Vec<u8>),
     /// The queried contract storage entry is missing.
     KeyNotFound,
     /// Transfer failed because it would have brought the sender's total balance
@@ -44,9 +49,46 @@ pub enum Error {
     CodeNotFound,
     /// The account that was called is either no contract (e.g. user account) or is a tombstone.
     NotCallable,
+    /// The input of the contract execution is longer than the accepted length.
+    InputTooLarge,
+    /// A self-call via [`call_self`](`crate::call_self`) has been issued from
+    /// an ink! message that mutates the contract storage.
+    SelfCallDenied,
     /// An unknown error has occured.
     UnknownError,
 }
 
+impl Error {
+    /// Returns the data that the called contract has reverted with if any.
+    ///
+    /// The data can be forwarded using [`revert_with_data`](`crate::revert_with_data`).
+    pub fn revert_data(&self) -> Option<&[u8]> {
+        match self {
+            Self::CalleeReverted(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns the envelope with the error code that the called contract has
+    /// reverted with if any.
+    ///
+    /// Returns `None` if the called contract did not revert via `ink_lang::revert!`.
+    pub fn revert_envelope(&self) -> Option<RevertEnvelope> {
+        self.revert_data().and_then(RevertEnvelope::decode_from)
+    }
+}
+
+/// Errors that can be encountered upon emitting an event.
+///
+/// Returned by [`try_emit_event`](`crate::try_emit_event`) instead of trapping
+/// the contract execution so that contracts can decide to drop the event.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EventError {
+    /// The encoding of one of the event topics exceeds the environmental buffer.
+    TopicTooLarge,
+    /// The encoding of the event data exceeds the environmental buffer.
+    DataTooLarge,
+}
+
 /// A result of environmental operations.
 pub type Result<T> = core::result::Result<T, Error>;
@@ -21,6 +21,7 @@
 //! emulator for simple off-chain testing.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), feature(alloc_error_handler))]
 #![deny(
     missing_docs,
     bad_style,
@@ -66,6 +67,8 @@ pub mod call;
 mod engine;
 mod error;
 pub mod hash;
+#[cfg(feature = "ink-unstable-chain-extensions")]
+mod runtime_event;
 #[doc(hidden)]
 pub mod topics;
 mod types;
@@ -81,11 +84,14 @@ use self::backend::{
     EnvBackend,
     TypedEnvBackend,
 };
+#[cfg(feature = "ink-unstable-chain-extensions")]
+pub use self::runtime_event::RuntimeEvent;
 pub use self::{
     api::*,
     backend::ReturnFlags,
     error::{
         Error,
+        EventError,
         Result,
     },
     topics::Topics,
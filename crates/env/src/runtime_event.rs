@@ -0,0 +1,50 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Notifications of the runtime about contract events.
+//!
+//! Some runtimes mirror the events of contracts into their pallets, e.g. to
+//! index them or to react to them on-chain. Contract events emitted with
+//! [`emit_event`](`crate::emit_event`) are opaque to the runtime though.
+//! Therefore such contracts additionally hand their events to a chain extension
+//! of the runtime that decodes them into the types of the respective pallet.
+//!
+//! The [`RuntimeEvent`] trait associates the contract side type of such an
+//! event with the chain extension function receiving it so that contracts
+//! and runtimes share a single integration point for every event.
+
+/// A contract event that is handed to the runtime through a chain extension.
+///
+/// The SCALE encoding of the event must match the type the chain extension
+/// function of the runtime decodes the event into.
+///
+/// # Example
+///
+/// ```
+/// # use ink_env::RuntimeEvent;
+/// #[derive(scale::Encode, scale::Decode)]
+/// pub struct Transferred {
+///     from: [u8; 32],
+///     to: [u8; 32],
+///     value: u128,
+/// }
+///
+/// impl RuntimeEvent for Transferred {
+///     const FUNC_ID: u32 = 0x0001_0000;
+/// }
+/// ```
+pub trait RuntimeEvent: scale::Codec + 'static {
+    /// The ID of the chain extension function of the runtime receiving the event.
+    const FUNC_ID: u32;
+}
@@ -32,6 +32,10 @@
 //! the trait bounds on the `Environment` trait types.
 
 use super::arithmetic::AtLeast32BitUnsigned;
+use crate::hash::{
+    Blake2x256,
+    CryptoHash,
+};
 use core::{
     array::TryFromSliceError,
     convert::TryFrom,
@@ -92,6 +96,19 @@ pub trait Environment {
         + PartialEq
         + Eq
         + AtLeast32BitUnsigned;
+
+    /// The crypto hash used to hash event topics.
+    ///
+    /// Topics whose SCALE encoding does not fit into a `Hash` are hashed with
+    /// this hasher before they are handed to the runtime. Chains that index
+    /// events by e.g. KECCAK topics can use `Keccak256` here. Use `Blake2x256`
+    /// to hash topics the same way as the [`DefaultEnvironment`].
+    ///
+    /// # Note
+    ///
+    /// Message and constructor selectors are computed by the ink! code generator
+    /// at compile time and always use BLAKE2 regardless of this hasher.
+    type TopicHasher: CryptoHash;
 }
 
 /// The fundamental types of the default configuration.
@@ -107,6 +124,7 @@ impl Environment for DefaultEnvironment {
     type Hash = Hash;
     type Timestamp = Timestamp;
     type BlockNumber = BlockNumber;
+    type TopicHasher = Blake2x256;
 }
 
 /// The default balance type.
@@ -141,6 +159,15 @@ pub type BlockNumber = u64;
 #[cfg_attr(feature = "std", derive(TypeInfo))]
 pub struct AccountId([u8; 32]);
 
+impl AccountId {
+    /// Creates the account identifier from its raw bytes.
+    ///
+    /// Unlike the `From` implementation this can be used in constant contexts.
+    pub const fn from_raw(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
 impl<'a> TryFrom<&'a [u8]> for AccountId {
     type Error = TryFromSliceError;
 
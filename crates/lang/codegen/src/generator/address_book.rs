@@ -0,0 +1,85 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generates code for the `addresses!` macro.
+///
+/// Every address is expanded to an `AccountId` constant that is only defined
+/// if the crate feature named after its network is enabled.
+#[derive(From)]
+pub struct AddressBook<'a> {
+    address_book: &'a ir::AddressBook,
+}
+
+impl AddressBook<'_> {
+    /// Generates the constants of the addresses of the network.
+    fn generate_network(network: &ir::Network) -> TokenStream2 {
+        let feature = network.ident().to_string();
+        let addresses = network.addresses().iter().map(|address| {
+            let attrs = address.attrs();
+            let ident = address.ident();
+            let account = address.account();
+            quote! {
+                #( #attrs )*
+                #[cfg(feature = #feature)]
+                pub const #ident: ::ink_env::AccountId =
+                    ::ink_env::AccountId::from_raw([ #( #account ),* ]);
+            }
+        });
+        quote! {
+            #( #addresses )*
+        }
+    }
+
+    /// Generates compile errors in case more than one network is selected.
+    fn generate_exclusive_networks_guards(&self) -> TokenStream2 {
+        let networks = self.address_book.networks();
+        let guards = networks.iter().enumerate().flat_map(|(n, lhs)| {
+            networks[n + 1..].iter().map(move |rhs| {
+                let lhs = lhs.ident().to_string();
+                let rhs = rhs.ident().to_string();
+                let error = format!(
+                    "the `{}` and `{}` features of the ink! address book are mutually exclusive",
+                    lhs, rhs,
+                );
+                quote! {
+                    #[cfg(all(feature = #lhs, feature = #rhs))]
+                    ::core::compile_error!(#error);
+                }
+            })
+        });
+        quote! {
+            #( #guards )*
+        }
+    }
+}
+
+impl GenerateCode for AddressBook<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let guards = self.generate_exclusive_networks_guards();
+        let networks = self
+            .address_book
+            .networks()
+            .iter()
+            .map(Self::generate_network);
+        quote! {
+            #guards
+            #( #networks )*
+        }
+    }
+}
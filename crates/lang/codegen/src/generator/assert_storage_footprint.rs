@@ -0,0 +1,44 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generates code for the `assert_storage_footprint!` macro.
+#[derive(From)]
+pub struct AssertStorageFootprint<'a> {
+    /// The assertion to generate code for.
+    assert_storage_footprint: &'a ir::AssertStorageFootprint,
+}
+
+impl GenerateCode for AssertStorageFootprint<'_> {
+    /// Generates a constant assertion that only compiles if the spread footprint
+    /// of the ink! storage struct does not exceed its budget of storage cells.
+    fn generate_code(&self) -> TokenStream2 {
+        let storage = self.assert_storage_footprint.storage();
+        let max_cells = self.assert_storage_footprint.max_cells();
+        quote! {
+            const _: () = {
+                #[allow(non_upper_case_globals)]
+                const __ink_storage_footprint: u64 =
+                    <#storage as ::ink_storage::traits::SpreadLayout>::FOOTPRINT;
+                ::ink_lang::static_assertions::const_assert!(
+                    __ink_storage_footprint <= #max_cells
+                );
+            };
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generates code for the `assert_trait_impl!` macro.
+#[derive(From)]
+pub struct AssertTraitImpl<'a> {
+    /// The assertion to generate code for.
+    assert_trait_impl: &'a ir::AssertTraitImpl,
+}
+
+impl GenerateCode for AssertTraitImpl<'_> {
+    /// Generates a function whose signature is only well-formed if the ink! smart
+    /// contract implements the ink! trait definition with matching selectors.
+    fn generate_code(&self) -> TokenStream2 {
+        let contract = self.assert_trait_impl.contract();
+        let assert_impl_path = self.assert_trait_impl.assert_impl_path();
+        quote! {
+            const _: () = {
+                #[allow(dead_code)]
+                fn __ink_assert_trait_impl(_: #assert_impl_path<#contract>) {}
+            };
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use ir::Callable as _;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+/// Generates compile time assertions that all constructor and message inputs
+/// have a bounded SCALE encoded length.
+///
+/// Only generates code if the `bounded_inputs` ink! config argument is enabled.
+#[derive(From)]
+pub struct BoundedInputs<'a> {
+    /// The contract to generate code for.
+    contract: &'a ir::Contract,
+}
+
+impl GenerateCode for BoundedInputs<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        if !self.contract.config().is_bounded_inputs_enabled() {
+            return quote! {}
+        }
+        let module = self.contract.module();
+        let constructor_inputs = module
+            .impls()
+            .flat_map(|item_impl| item_impl.iter_constructors())
+            .flat_map(|constructor| constructor.callable().inputs());
+        let message_inputs = module
+            .impls()
+            .flat_map(|item_impl| item_impl.iter_messages())
+            .flat_map(|message| message.callable().inputs());
+        let assertions = constructor_inputs.chain(message_inputs).map(|input| {
+            let ty = &input.ty;
+            quote_spanned!(ty.span()=>
+                __ink_assert_bounded_input::<#ty>();
+            )
+        });
+        quote! {
+            const _: () = {
+                fn __ink_assert_bounded_input<T>()
+                where
+                    T: ::ink_storage::traits::MaxEncodedLen,
+                {}
+
+                fn __ink_assert_bounded_inputs() {
+                    #( #assertions )*
+                }
+            };
+        }
+    }
+}
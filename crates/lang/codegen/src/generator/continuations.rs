@@ -0,0 +1,136 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    generator,
+    GenerateCode,
+    GenerateCodeUsing as _,
+};
+use derive_more::From;
+use heck::ShoutySnakeCase as _;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+
+/// Generates code for the ink! continuations of the contract.
+///
+/// This generates a `Step` associated constant of the storage struct for every
+/// ink! continuation and the routing of resumed continuation tokens to them.
+#[derive(From)]
+pub struct Continuations<'a> {
+    contract: &'a ir::Contract,
+}
+
+impl AsRef<ir::Contract> for Continuations<'_> {
+    fn as_ref(&self) -> &ir::Contract {
+        self.contract
+    }
+}
+
+impl GenerateCode for Continuations<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        if self.contract.module().continuations().next().is_none() {
+            return quote! {}
+        }
+        let steps = self.generate_steps();
+        let resume_impl = self.generate_resume_impl();
+        quote! {
+            #steps
+            #resume_impl
+        }
+    }
+}
+
+impl Continuations<'_> {
+    /// Generates the `Step` associated constants of the ink! continuations.
+    ///
+    /// # Note
+    ///
+    /// Those are also generated while the contract is a dependency of another
+    /// so that the other contract can encode the inputs of the continuations.
+    fn generate_steps(&self) -> TokenStream2 {
+        let storage_ident = self.contract.module().storage().ident();
+        let steps = self.contract.module().continuations().map(|continuation| {
+            let span = continuation.span();
+            let ident = continuation.ident();
+            let step_ident =
+                format_ident!("{}", ident.to_string().to_shouty_snake_case());
+            let input_type = continuation.input_type();
+            let selector_bytes = continuation.selector().as_bytes().to_owned();
+            let doc = format!(" Refers to the `{}` ink! continuation.", ident);
+            quote_spanned!(span=>
+                #[doc = #doc]
+                pub const #step_ident: ::ink_lang::continuation::Step<#input_type> =
+                    ::ink_lang::continuation::Step::from_selector([ #( #selector_bytes ),* ]);
+            )
+        });
+        quote! {
+            const _: () = {
+                impl #storage_ident {
+                    #( #steps )*
+                }
+            };
+        }
+    }
+
+    /// Generates the routing of resumed continuation tokens to the ink! continuations.
+    fn generate_resume_impl(&self) -> TokenStream2 {
+        let storage_ident = self.contract.module().storage().ident();
+        let no_cross_calling_cfg =
+            self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        let arms = self.contract.module().continuations().map(|continuation| {
+            let span = continuation.span();
+            let ident = continuation.ident();
+            let input_type = continuation.input_type();
+            let selector_bytes = continuation.selector().as_bytes().to_owned();
+            quote_spanned!(span=>
+                [ #( #selector_bytes ),* ] => {
+                    let mut remaining = &input[..];
+                    let input = <#input_type as ::scale::Decode>::decode(&mut remaining)
+                        .map_err(|_| ::ink_lang::continuation::ContinuationError::InvalidInput)?;
+                    // Trailing bytes indicate an input meant for another continuation.
+                    if !remaining.is_empty() {
+                        return ::core::result::Result::Err(
+                            ::ink_lang::continuation::ContinuationError::InvalidInput
+                        )
+                    }
+                    self.#ident(input);
+                    ::core::result::Result::Ok(())
+                }
+            )
+        });
+        quote! {
+            #no_cross_calling_cfg
+            const _: () = {
+                impl ::ink_lang::continuation::Continuations for #storage_ident {
+                    fn resume_continuation(
+                        &mut self,
+                        token: ::ink_lang::continuation::ContinuationToken,
+                        input: &[u8],
+                    ) -> ::core::result::Result<(), ::ink_lang::continuation::ContinuationError> {
+                        match token.selector() {
+                            #( #arms )*
+                            _ => ::core::result::Result::Err(
+                                ::ink_lang::continuation::ContinuationError::UnknownContinuation
+                            ),
+                        }
+                    }
+                }
+            };
+        }
+    }
+}
@@ -46,8 +46,11 @@ impl GenerateCode for Contract<'_> {
         let events = self.generate_code_using::<generator::Events>();
         let dispatch = self.generate_code_using::<generator::Dispatch>();
         let item_impls = self.generate_code_using::<generator::ItemImpls>();
+        let continuations = self.generate_code_using::<generator::Continuations>();
         let cross_calling = self.generate_code_using::<generator::CrossCalling>();
         let metadata = self.generate_code_using::<generator::Metadata>();
+        let fingerprint = self.generate_code_using::<generator::Fingerprint>();
+        let bounded_inputs = self.generate_code_using::<generator::BoundedInputs>();
         let non_ink_items = self
             .contract
             .module()
@@ -62,8 +65,11 @@ impl GenerateCode for Contract<'_> {
                 #events
                 #dispatch
                 #item_impls
+                #continuations
                 #cross_calling
                 #metadata
+                #fingerprint
+                #bounded_inputs
                 #( #non_ink_items )*
             }
         }
@@ -0,0 +1,39 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generates code for the `contract_ref!` macro.
+#[derive(From)]
+pub struct ContractRef<'a> {
+    /// The contract reference to generate code for.
+    contract_ref: &'a ir::ContractRef,
+}
+
+impl GenerateCode for ContractRef<'_> {
+    /// Generates the type of the contract reference for the ink! trait definition.
+    fn generate_code(&self) -> TokenStream2 {
+        let contract_ref_path = self.contract_ref.contract_ref_path();
+        let env = self.contract_ref.env().map_or_else(
+            || quote! { ::ink_env::DefaultEnvironment },
+            |env| quote! { #env },
+        );
+        quote! {
+            #contract_ref_path<#env>
+        }
+    }
+}
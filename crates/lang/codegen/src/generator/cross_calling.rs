@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::GenerateCode;
+use crate::{
+    generator,
+    GenerateCode,
+};
 use derive_more::From;
 use heck::CamelCase as _;
 use ir::Callable;
@@ -165,13 +168,13 @@ impl CrossCalling<'_> {
         let span = message.span();
         let ident = message.ident();
         let output_ident = format_ident!("{}Out", ident.to_string().to_camel_case());
-        let composed_selector = message.composed_selector().as_bytes().to_owned();
+        let composed_id = message.composed_id().as_bytes().to_owned();
         let linker_error_ident = format_ident!(
             "__ink_enforce_error_for_message_0x{:02X}{:02X}{:02X}{:02X}",
-            composed_selector[0],
-            composed_selector[1],
-            composed_selector[2],
-            composed_selector[3]
+            composed_id[0],
+            composed_id[1],
+            composed_id[2],
+            composed_id[3]
         );
         let attrs = message.attrs();
         let input_bindings = message
@@ -219,7 +222,8 @@ impl CrossCalling<'_> {
         let span = message.span();
         let ident = message.ident();
         let output_ident = format_ident!("{}Out", ident.to_string().to_camel_case());
-        let composed_selector = message.composed_selector().as_bytes().to_owned();
+        let selector_bytes =
+            generator::ItemImpls::generate_message_selector(quote! { Self }, message);
         let attrs = message.attrs();
         let input_bindings = message
             .inputs()
@@ -249,7 +253,7 @@ impl CrossCalling<'_> {
             type #output_ident = ::ink_env::call::CallBuilder<
                 Environment,
                 ::ink_env::call::utils::Set<AccountId>,
-                ::ink_env::call::utils::Unset<u64>,
+                ::ink_env::call::utils::Unset<::ink_env::call::Forwarded>,
                 ::ink_env::call::utils::Unset<Balance>,
                 ::ink_env::call::utils::Set<::ink_env::call::ExecutionInput<#arg_list>>,
                 ::ink_env::call::utils::Set<#output_sig>,
@@ -264,7 +268,7 @@ impl CrossCalling<'_> {
                     .callee(::ink_lang::ToAccountId::to_account_id(self.contract))
                     .exec_input(
                         ::ink_env::call::ExecutionInput::new(
-                            ::ink_env::call::Selector::new([ #( #composed_selector ),* ])
+                            ::ink_env::call::Selector::new(#selector_bytes)
                         )
                         #(
                             .push_arg(#input_bindings)
@@ -366,27 +370,13 @@ impl CrossCalling<'_> {
         let trait_ident = item_impl
             .trait_ident()
             .expect("encountered missing trait identifier for trait impl block");
-        let hash = ir::InkTrait::compute_verify_hash(
-            trait_ident,
-            item_impl.iter_constructors().map(|constructor| {
-                let ident = constructor.ident().clone();
-                let len_inputs = constructor.inputs().count();
-                (ident, len_inputs)
-            }),
-            item_impl.iter_messages().map(|message| {
-                let ident = message.ident().clone();
-                let len_inputs = message.inputs().count() + 1;
-                let is_mut = message.receiver().is_ref_mut();
-                (ident, len_inputs, is_mut)
-            }),
-        );
-        let checksum = u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) as usize;
+        let checksum = generator::ItemImpls::trait_checksum(item_impl, trait_ident);
+        let trait_impl_id = generator::ItemImpls::trait_impl_id(item_impl);
         quote_spanned!(span =>
-            unsafe impl<'a> ::ink_lang::CheckedInkTrait<[(); #checksum]> for #forwarder_ident<&'a #mut_tok #storage_ident> {}
-
             #( #attrs )*
             impl<'a> #trait_path for #forwarder_ident<&'a #mut_tok #storage_ident> {
                 type __ink_Checksum = [(); #checksum];
+                type __ink_TraitImplId = [(); #trait_impl_id];
 
                 #( #constructors )*
                 #( #messages )*
@@ -430,7 +420,7 @@ impl CrossCalling<'_> {
             ) -> ::ink_env::call::CallBuilder<
                 Environment,
                 ::ink_env::call::utils::Set<AccountId>,
-                ::ink_env::call::utils::Unset<u64>,
+                ::ink_env::call::utils::Unset<::ink_env::call::Forwarded>,
                 ::ink_env::call::utils::Unset<Balance>,
                 ::ink_env::call::utils::Set<::ink_env::call::ExecutionInput<#arg_list>>,
                 ::ink_env::call::utils::Set<#output_sig>,
@@ -482,6 +472,16 @@ impl CrossCalling<'_> {
     /// The `mutable` parameter indicates whether only read-only (`false`) or
     /// write-only (`true`) messages and constructors are to be considered.
     fn generate_call_forwarder_impl_blocks(&self, mutable: bool) -> TokenStream2 {
+        let forwarder_ident = Self::call_forwarder_ident();
+        let storage_ident = self.contract.module().storage().ident();
+        let mut_tok = if mutable { Some(quote! { mut }) } else { None };
+        let checked_traits =
+            generator::ItemImpls::trait_checksums(self.contract).map(|checksum| {
+                quote! {
+                    unsafe impl<'a> ::ink_lang::CheckedInkTrait<[(); #checksum]>
+                        for #forwarder_ident<&'a #mut_tok #storage_ident> {}
+                }
+            });
         let impl_blocks = self.contract.module().impls().map(|item_impl| {
             match item_impl.trait_path() {
                 Some(_) => {
@@ -492,7 +492,10 @@ impl CrossCalling<'_> {
                 }
             }
         });
-        quote! { #( #impl_blocks )* }
+        quote! {
+            #( #checked_traits )*
+            #( #impl_blocks )*
+        }
     }
 
     /// Generates code for the call forwarder utility struct.
@@ -558,6 +561,8 @@ impl CrossCalling<'_> {
             "encountered error while calling <{} as {}>::{}",
             storage_ident_str, trait_path, ident_str
         );
+        let trace_target =
+            format!("<{} as {}>::{}", storage_ident_str, trait_path, ident_str);
         let inputs_sig = message.inputs();
         let inputs_params = message.inputs().map(|pat_type| &pat_type.pat);
         let output_ty = message
@@ -586,10 +591,12 @@ impl CrossCalling<'_> {
 
             #[inline]
             #opt_pub fn #ident( #receiver #(, #inputs_sig )* ) -> Self::#output_ident {
-                <&#opt_mut Self as ::ink_lang::#forward_trait>::#forward_ident(self)
-                    .#ident( #( #inputs_params ),* )
-                    .fire()
-                    .expect(#error_str)
+                ::ink_lang::trace::in_span(::ink_lang::trace::CALL, #trace_target, move || {
+                    <&#opt_mut Self as ::ink_lang::#forward_trait>::#forward_ident(self)
+                        .#ident( #( #inputs_params ),* )
+                        .fire()
+                })
+                .expect(#error_str)
             }
         )
     }
@@ -660,29 +667,14 @@ impl CrossCalling<'_> {
         let constructors = impl_block
             .iter_constructors()
             .map(Self::generate_trait_impl_block_constructor);
-        let hash = ir::InkTrait::compute_verify_hash(
-            trait_ident,
-            impl_block.iter_constructors().map(|constructor| {
-                let ident = constructor.ident().clone();
-                let len_inputs = constructor.inputs().count();
-                (ident, len_inputs)
-            }),
-            impl_block.iter_messages().map(|message| {
-                let ident = message.ident().clone();
-                let len_inputs = message.inputs().count() + 1;
-                let is_mut = message.receiver().is_ref_mut();
-                (ident, len_inputs, is_mut)
-            }),
-        );
-        let checksum = u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) as usize;
+        let checksum = generator::ItemImpls::trait_checksum(impl_block, trait_ident);
+        let trait_impl_id = generator::ItemImpls::trait_impl_id(impl_block);
         quote_spanned!(span =>
-            #cfg
-            unsafe impl ::ink_lang::CheckedInkTrait<[(); #checksum]> for #self_type {}
-
             #cfg
             #( #attrs )*
             impl #trait_path for #self_type {
                 type __ink_Checksum = [(); #checksum];
+                type __ink_TraitImplId = [(); #trait_impl_id];
 
                 #( #messages )*
                 #( #constructors )*
@@ -752,6 +744,7 @@ impl CrossCalling<'_> {
             "encountered error while calling {}::{}",
             storage_ident_str, ident_str
         );
+        let trace_target = format!("{}::{}", storage_ident_str, ident_str);
         let inputs_sig = message.inputs();
         let inputs_params = message.inputs().map(|pat_type| &pat_type.pat);
         let output_sig = message.output().map(|output| quote! { -> #output });
@@ -775,10 +768,12 @@ impl CrossCalling<'_> {
         quote_spanned!(span =>
             #[inline]
             #opt_pub fn #ident( #receiver #(, #inputs_sig )* ) #output_sig {
-                <&#opt_mut Self as ::ink_lang::#forward_trait>::#forward_ident(self)
-                    .#ident( #( #inputs_params ),* )
-                    .fire()
-                    .expect(#error_str)
+                ::ink_lang::trace::in_span(::ink_lang::trace::CALL, #trace_target, move || {
+                    <&#opt_mut Self as ::ink_lang::#forward_trait>::#forward_ident(self)
+                        .#ident( #( #inputs_params ),* )
+                        .fire()
+                })
+                .expect(#error_str)
             }
         )
     }
@@ -806,6 +801,15 @@ impl CrossCalling<'_> {
     }
 
     fn generate_impl_blocks(&self) -> TokenStream2 {
+        let cfg = self.generate_cfg();
+        let storage_ident = self.contract.module().storage().ident();
+        let checked_traits =
+            generator::ItemImpls::trait_checksums(self.contract).map(|checksum| {
+                quote! {
+                    #cfg
+                    unsafe impl ::ink_lang::CheckedInkTrait<[(); #checksum]> for #storage_ident {}
+                }
+            });
         let impl_blocks = self.contract.module().impls().map(|impl_block| {
             match impl_block.trait_path() {
                 Some(_) => self.generate_trait_impl_block(impl_block),
@@ -813,6 +817,7 @@ impl CrossCalling<'_> {
             }
         });
         quote! {
+            #( #checked_traits )*
             #( #impl_blocks )*
         }
     }
@@ -68,7 +68,9 @@ impl GenerateCode for Dispatch<'_> {
             // We do not generate contract dispatch code while the contract
             // is being tested or the contract is a dependency of another
             // since both resulting compilations do not require dispatching.
-            #[cfg(not(test))]
+            // Tests replaying recorded contract executions enable the dispatch
+            // via the `ink-replay` crate feature.
+            #[cfg(any(not(test), feature = "ink-replay"))]
             #no_cross_calling_cfg
             const _: () = {
                 #entry_points
@@ -91,32 +93,54 @@ impl Dispatch<'_> {
     /// They guide the dispatch, set-up and tear-down of a smart contract.
     fn generate_entry_points(&self) -> TokenStream2 {
         let storage_ident = self.contract.module().storage().ident();
-        let all_messages_deny_payment = self.all_messages_deny_payment();
         quote! {
             #[cfg(not(test))]
             #[no_mangle]
             fn deploy() -> u32 {
-                ::ink_lang::DispatchRetCode::from(
+                ::ink_lang::finalize_dispatch(
                     <#storage_ident as ::ink_lang::DispatchUsingMode>::dispatch_using_mode(
                         ::ink_lang::DispatchMode::Instantiate,
                     ),
                 )
-                .to_u32()
             }
 
             #[cfg(not(test))]
             #[no_mangle]
             fn call() -> u32 {
-                if #all_messages_deny_payment {
+                if <#storage_ident as ::ink_lang::DispatchUsingMode>::ALL_MESSAGES_DENY_PAYMENT {
                     ::ink_lang::deny_payment::<<#storage_ident as ::ink_lang::ContractEnv>::Env>()
                         .expect("caller transferred value even though all ink! message deny payments")
                 }
-                ::ink_lang::DispatchRetCode::from(
+                ::ink_lang::finalize_dispatch(
                     <#storage_ident as ::ink_lang::DispatchUsingMode>::dispatch_using_mode(
                         ::ink_lang::DispatchMode::Call,
                     ),
                 )
-                .to_u32()
+            }
+        }
+    }
+
+    /// Generates the decoding of the contract input for the given dispatcher trait.
+    ///
+    /// Rejects inputs that are longer than the `max_input_len` ink! config argument
+    /// without reading the input twice.
+    fn generate_decode_input(&self, dispatcher: TokenStream2) -> TokenStream2 {
+        let storage_ident = self.contract.module().storage().ident();
+        match self.contract.config().max_input_len() {
+            Some(max_input_len) => {
+                quote! {
+                    ::ink_env::decode_input_bounded::<<#storage_ident as #dispatcher>::Type>(#max_input_len)
+                        .map_err(|error| match error {
+                            ::ink_env::Error::InputTooLarge => ::ink_lang::DispatchError::InputTooLarge,
+                            _ => ::ink_lang::DispatchError::CouldNotReadInput,
+                        })?
+                }
+            }
+            None => {
+                quote! {
+                    ::ink_env::decode_input::<<#storage_ident as #dispatcher>::Type>()
+                        .map_err(|_| ::ink_lang::DispatchError::CouldNotReadInput)?
+                }
             }
         }
     }
@@ -124,8 +148,15 @@ impl Dispatch<'_> {
     /// Generates the `DispatchUsingMode` trait implementation to guide contract dispatch.
     fn generate_dispatch_using_mode(&self) -> TokenStream2 {
         let storage_ident = self.contract.module().storage().ident();
+        let all_messages_deny_payment = self.all_messages_deny_payment();
+        let decode_constructor =
+            self.generate_decode_input(quote! { ::ink_lang::ConstructorDispatcher });
+        let decode_message =
+            self.generate_decode_input(quote! { ::ink_lang::MessageDispatcher });
         quote! {
             impl ::ink_lang::DispatchUsingMode for #storage_ident {
+                const ALL_MESSAGES_DENY_PAYMENT: bool = #all_messages_deny_payment;
+
                 #[allow(unused_parens)]
                 fn dispatch_using_mode(
                     mode: ::ink_lang::DispatchMode
@@ -133,14 +164,12 @@ impl Dispatch<'_> {
                     match mode {
                         ::ink_lang::DispatchMode::Instantiate => {
                             <<#storage_ident as ::ink_lang::ConstructorDispatcher>::Type as ::ink_lang::Execute>::execute(
-                                ::ink_env::decode_input::<<#storage_ident as ::ink_lang::ConstructorDispatcher>::Type>()
-                                    .map_err(|_| ::ink_lang::DispatchError::CouldNotReadInput)?
+                                #decode_constructor
                             )
                         }
                         ::ink_lang::DispatchMode::Call => {
                             <<#storage_ident as ::ink_lang::MessageDispatcher>::Type as ::ink_lang::Execute>::execute(
-                                ::ink_env::decode_input::<<#storage_ident as ::ink_lang::MessageDispatcher>::Type>()
-                                    .map_err(|_| ::ink_lang::DispatchError::CouldNotReadInput)?
+                                #decode_message
                             )
                         }
                     }
@@ -195,17 +224,19 @@ impl Dispatch<'_> {
     }
 
     /// Generates code for the dispatch trait impls for a generic ink! callable.
+    ///
+    /// The given selector bytes are the bytes the ink! callable is dispatched with.
     fn generate_trait_impls_for_callable<C>(
         &self,
         cws: ir::CallableWithSelector<'_, C>,
+        selector_bytes: TokenStream2,
     ) -> TokenStream2
     where
         C: ir::Callable + quote::ToTokens,
     {
         let callable = cws.callable();
         let callable_span = callable.span();
-        let selector = cws.composed_selector();
-        let (selector_bytes, selector_id) = (selector.as_bytes(), selector.unique_id());
+        let selector_id = cws.composed_id().unique_id();
         let input_types = callable
             .inputs()
             .map(|pat_type| &pat_type.ty)
@@ -227,9 +258,9 @@ impl Dispatch<'_> {
         );
         let fn_selector_impl = quote_spanned!(callable_span =>
             impl ::ink_lang::FnSelector for #namespace<[(); #selector_id]> {
-                const SELECTOR: ::ink_env::call::Selector = ::ink_env::call::Selector::new([
-                    #( #selector_bytes ),*
-                ]);
+                const SELECTOR: ::ink_env::call::Selector = ::ink_env::call::Selector::new(
+                    #selector_bytes
+                );
             }
         );
         let fn_state_impl = quote_spanned!(callable_span =>
@@ -304,8 +335,7 @@ impl Dispatch<'_> {
     ) -> TokenStream2 {
         let message = cws.callable();
         let message_span = message.span();
-        let selector = cws.composed_selector();
-        let selector_id = selector.unique_id();
+        let selector_id = cws.composed_id().unique_id();
         let output_tokens = message
             .output()
             .map(quote::ToTokens::to_token_stream)
@@ -320,7 +350,9 @@ impl Dispatch<'_> {
                 type Output = #output_tokens;
             }
         );
-        let callable_impl = self.generate_trait_impls_for_callable(cws);
+        let selector_bytes =
+            generator::ItemImpls::generate_message_selector(storage_ident, cws);
+        let callable_impl = self.generate_trait_impls_for_callable(cws, selector_bytes);
         let (mut_token, message_trait_ident) = if is_mut {
             (
                 Some(syn::token::Mut::default()),
@@ -361,12 +393,16 @@ impl Dispatch<'_> {
         let constructor = cws.callable();
         let constructor_span = constructor.span();
         let selector = cws.composed_selector();
-        let selector_id = selector.unique_id();
+        let selector_id = cws.composed_id().unique_id();
         let storage_ident = self.contract.module().storage().ident();
         let constructor_ident = constructor.ident();
         let namespace =
             Self::dispatch_trait_impl_namespace(ir::CallableKind::Constructor);
-        let callable_impl = self.generate_trait_impls_for_callable(cws);
+        let selector_bytes = selector.as_bytes();
+        let callable_impl = self.generate_trait_impls_for_callable(
+            cws,
+            quote! { [ #( #selector_bytes ),* ] },
+        );
         let (input_bindings, inputs_as_tuple_or_wildcard) =
             Self::generate_input_bindings(constructor);
         let as_trait = cws.item_impl().trait_path().map(|trait_path| {
@@ -406,7 +442,9 @@ impl Dispatch<'_> {
     /// Generates variant identifiers for the generated dispatch enum.
     ///
     /// Since we want to avoid generating random names we generate identifiers
-    /// in terms of the selectors of the associated ink! messages or constructors.
+    /// in terms of the composed identifiers of the associated ink! messages or
+    /// constructors which equal their selectors unless they inherit the selector
+    /// of a trait definition.
     ///
     /// ## Example
     ///
@@ -414,7 +452,7 @@ impl Dispatch<'_> {
     /// generate the following idenfitier: `__ink_Message_0xDEADBEEF`
     ///
     /// This way it is clear that this is an ink! generated identifier and even
-    /// encodes the unique selector bytes to make the identifier unique.
+    /// encodes the unique identifier bytes to make the identifier unique.
     fn generate_dispatch_variant_ident<C>(
         &self,
         cws: ir::CallableWithSelector<'_, C>,
@@ -422,7 +460,7 @@ impl Dispatch<'_> {
     where
         C: ir::Callable,
     {
-        let selector_bytes = cws.composed_selector().as_bytes().to_owned();
+        let id_bytes = cws.composed_id().as_bytes().to_owned();
         let prefix = match cws.callable().kind() {
             ir::CallableKind::Message => "Message",
             ir::CallableKind::Constructor => "Constructor",
@@ -430,15 +468,41 @@ impl Dispatch<'_> {
         quote::format_ident!(
             "__ink_{}_0x{:02X}{:02X}{:02X}{:02X}",
             prefix,
-            selector_bytes[0],
-            selector_bytes[1],
-            selector_bytes[2],
-            selector_bytes[3]
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3]
         )
     }
 
+    /// Returns the target of the tracing span entered upon dispatching the callable.
+    ///
+    /// The target is the path of the callable, e.g. `Flipper::flip` or
+    /// `<Flipper as Flip>::flip` for callables of trait implementations.
+    fn generate_trace_target<C>(&self, cws: ir::CallableWithSelector<'_, C>) -> String
+    where
+        C: ir::Callable,
+    {
+        let storage_ident = self.contract.module().storage().ident();
+        let ident = cws.callable().ident();
+        match cws.item_impl().trait_path() {
+            Some(trait_path) => {
+                let trait_path = trait_path
+                    .segments
+                    .iter()
+                    .map(|segment| segment.ident.to_string())
+                    .collect::<Vec<_>>()
+                    .join("::");
+                format!("<{} as {}>::{}", storage_ident, trait_path, ident)
+            }
+            None => format!("{}::{}", storage_ident, ident),
+        }
+    }
+
     /// Generates one match arm of the dispatch `scale::Decode` implementation.
     ///
+    /// The given pattern matches the selector the ink! callable is dispatched with.
+    ///
     /// # Note
     ///
     /// There is one match arm per ink! constructor or message for the dispatch
@@ -446,15 +510,15 @@ impl Dispatch<'_> {
     fn generate_dispatch_variant_decode<C>(
         &self,
         cws: ir::CallableWithSelector<'_, C>,
+        selector_pattern: TokenStream2,
     ) -> TokenStream2
     where
         C: ir::Callable,
     {
-        let selector_bytes = cws.composed_selector().as_bytes().to_owned();
         let variant_ident = self.generate_dispatch_variant_ident(cws);
         let variant_types = cws.callable().inputs().map(|arg| &arg.ty);
         quote! {
-            [ #( #selector_bytes ),* ] => {
+            #selector_pattern => {
                 Ok(Self::#variant_ident(
                     #(
                         <#variant_types as ::scale::Decode>::decode(input)?
@@ -489,12 +553,39 @@ impl Dispatch<'_> {
     /// # Note
     ///
     /// This information is used to produce better code in this scenario.
+    /// Contracts embedding ink! mixins are never considered to deny payments
+    /// for all messages since the messages of the mixins are unknown here.
     fn all_messages_deny_payment(&self) -> bool {
         self.contract
             .module()
             .impls()
             .flat_map(ir::ItemImpl::iter_messages)
             .all(|message| !message.is_payable())
+            && self
+                .contract
+                .module()
+                .storage()
+                .mixin_fields()
+                .next()
+                .is_none()
+    }
+
+    /// Generates the dispatch variant identifier for the ink! mixin of the given
+    /// storage field, e.g. `__ink_Mixin_escrow`.
+    fn generate_mixin_variant_ident(member: &syn::Member) -> Ident {
+        match member {
+            syn::Member::Named(ident) => format_ident!("__ink_Mixin_{}", ident),
+            syn::Member::Unnamed(index) => format_ident!("__ink_Mixin_{}", index.index),
+        }
+    }
+
+    /// Returns `true` if any ink! message requires the caller to have a role.
+    fn any_message_requires_role(&self) -> bool {
+        self.contract
+            .module()
+            .impls()
+            .flat_map(ir::ItemImpl::iter_messages)
+            .any(|message| message.callable().only_role().is_some())
     }
 
     /// Generates one match arm of the dispatch message for the `execute` implementation.
@@ -528,7 +619,7 @@ impl Dispatch<'_> {
                 (None, quote! { MessageRef }, quote! { execute_message })
             }
         };
-        let selector_id = cws.composed_selector().unique_id();
+        let selector_id = cws.composed_id().unique_id();
         let namespace = Self::dispatch_trait_impl_namespace(ir::CallableKind::Message);
         // If all ink! messages deny payment we can move the payment check to before
         // the message dispatch which is more efficient.
@@ -537,21 +628,176 @@ impl Dispatch<'_> {
             .contract
             .config()
             .is_dynamic_storage_allocator_enabled();
+        let role_check = message.only_role().map(|role| {
+            quote! {
+                ::ink_lang::access_control::ensure_caller_has_role::<
+                    <#storage_ident as ::ink_lang::ContractEnv>::Env
+                >(#role)?;
+            }
+        });
+        let owner_check = if message.is_only_owner() {
+            Some(quote! {
+                ::ink_lang::ownable::ensure_caller_is_owner::<
+                    <#storage_ident as ::ink_lang::ContractEnv>::Env
+                >()?;
+            })
+        } else {
+            None
+        };
+        let pause_check = if message.is_when_not_paused() {
+            Some(quote! { ::ink_lang::pausable::ensure_not_paused()?; })
+        } else {
+            None
+        };
+        let throttle_check = message.throttle().map(|rate| {
+            let id = cws.composed_id().as_bytes().to_owned();
+            let calls = rate.calls();
+            let blocks = rate.blocks();
+            quote! {
+                ::ink_lang::throttle::ensure_caller_not_throttled::<
+                    <#storage_ident as ::ink_lang::ContractEnv>::Env
+                >(::ink_lang::throttle::Throttle::new(&[ #( #id ),* ], #calls, #blocks))?;
+            }
+        });
+        let selector =
+            generator::ItemImpls::generate_message_selector(storage_ident, cws);
+        // Only `&mut self` messages are counted so that `&self` messages do not
+        // have to write to the contract storage.
+        let telemetry_record = if self.contract.config().is_telemetry_enabled()
+            && message.receiver().is_ref_mut()
+        {
+            Some(quote! { ::ink_lang::telemetry::record_call(#selector); })
+        } else {
+            None
+        };
+        let trace_target = self.generate_trace_target(cws);
+        let (before_hook, after_hook) = (
+            self.generate_message_hook_call(
+                ir::MessageHookKind::Before,
+                message,
+                &selector,
+            ),
+            self.generate_message_hook_call(
+                ir::MessageHookKind::After,
+                message,
+                &selector,
+            ),
+        );
+        // The guard has to be released within the closure since messages with
+        // return values do not return to the dispatcher.
+        let (enter_guard, leave_guard) = if message.is_non_reentrant() {
+            (
+                Some(quote! { ::ink_lang::enter_non_reentrant()?; }),
+                Some(quote! { ::ink_lang::leave_non_reentrant(); }),
+            )
+        } else {
+            (None, None)
+        };
         quote! {
             Self::#ident(#(#arg_pats),*) => {
-                ::ink_lang::#exec_fn::<<#storage_ident as ::ink_lang::ContractEnv>::Env, #namespace<[(); #selector_id]>, _>(
-                    ::ink_lang::AcceptsPayments(#accepts_payments),
-                    ::ink_lang::EnablesDynamicStorageAllocator(#is_dynamic_storage_allocation_enabled),
-                    move |state: &#mut_mod #storage_ident| {
-                        <#namespace<[(); #selector_id]> as ::ink_lang::#msg_trait>::CALLABLE(
-                            state, #arg_inputs
-                        )
-                    }
-                )
+                #role_check
+                #owner_check
+                #pause_check
+                #throttle_check
+                #telemetry_record
+                #enter_guard
+                ::ink_lang::trace::in_span(::ink_lang::trace::DISPATCH, #trace_target, move || {
+                    ::ink_lang::#exec_fn::<<#storage_ident as ::ink_lang::ContractEnv>::Env, #namespace<[(); #selector_id]>, _>(
+                        ::ink_lang::AcceptsPayments(#accepts_payments),
+                        ::ink_lang::EnablesDynamicStorageAllocator(#is_dynamic_storage_allocation_enabled),
+                        move |state: &#mut_mod #storage_ident| {
+                            #before_hook
+                            let result = <#namespace<[(); #selector_id]> as ::ink_lang::#msg_trait>::CALLABLE(
+                                state, #arg_inputs
+                            );
+                            #after_hook
+                            #leave_guard
+                            result
+                        }
+                    )
+                })
+            }
+        }
+    }
+
+    /// Generates the call to the ink! message hook of the given kind if any.
+    ///
+    /// # Note
+    ///
+    /// Hooks with a `&mut self` receiver are skipped for ink! messages with a
+    /// `&self` receiver since their storage is not written back.
+    ///
+    /// Hooks receive the selector the message is dispatched with which is the
+    /// selector pinned by the ink! trait definition for inherited selectors.
+    fn generate_message_hook_call(
+        &self,
+        kind: ir::MessageHookKind,
+        message: &ir::Message,
+        selector: &TokenStream2,
+    ) -> Option<TokenStream2> {
+        let hook = self.contract.module().message_hook(kind)?;
+        if hook.receiver().is_ref_mut() && message.receiver().is_ref() {
+            return None
+        }
+        let ident = hook.ident();
+        Some(quote! {
+            state.#ident(#selector);
+        })
+    }
+
+    /// Generates the closure calling the ink! message hook of the given kind around
+    /// the messages of embedded ink! mixins.
+    ///
+    /// # Note
+    ///
+    /// Hooks with a `&mut self` receiver are skipped for mixin messages with a
+    /// `&self` receiver like they are for the messages of the contract.
+    fn generate_mixin_hook_closure(&self, kind: ir::MessageHookKind) -> TokenStream2 {
+        let storage_ident = self.contract.module().storage().ident();
+        let hook = match self.contract.module().message_hook(kind) {
+            Some(hook) => hook,
+            None => return quote! { |_: &mut #storage_ident| () },
+        };
+        let ident = hook.ident();
+        if hook.receiver().is_ref_mut() {
+            quote! {
+                move |state: &mut #storage_ident| if mutates { state.#ident(selector) }
             }
+        } else {
+            quote! { move |state: &mut #storage_ident| state.#ident(selector) }
         }
     }
 
+    /// Generates the compile time check that the selectors the ink! messages of
+    /// the contract and its embedded ink! mixins are dispatched with do not overlap.
+    ///
+    /// # Note
+    ///
+    /// The check is performed by the codegen since the selectors pinned by ink!
+    /// trait definitions and the selectors of the mixin messages are only known
+    /// to the compiler. Overlapping selectors would otherwise silently make all
+    /// but the first of the overlapping ink! messages uncallable.
+    fn generate_selector_overlap_check(&self) -> TokenStream2 {
+        let storage = self.contract.module().storage();
+        let storage_ident = storage.ident();
+        let mixin_types = storage.mixin_fields().map(|(_, field)| &field.ty);
+        let span = storage.span();
+        let host_selectors = self.contract_messages().map(|message| {
+            generator::ItemImpls::generate_message_selector(storage_ident, message)
+        });
+        quote_spanned!(span =>
+            const _: () = {
+                #[allow(non_upper_case_globals)]
+                const encountered_overlapping_ink_message_selectors: [(); 0] = [();
+                    ::ink_lang::mixin::count_overlapping_selectors(
+                        &[ #( #host_selectors ),* ],
+                        &[ #( <#mixin_types as ::ink_lang::mixin::Mixin>::SELECTORS ),* ],
+                    )
+                ];
+            };
+        )
+    }
+
     /// Returns an iterator over all ink! messages of the ink! contract.
     fn contract_messages(
         &self,
@@ -569,17 +815,139 @@ impl Dispatch<'_> {
         let message_variants = self
             .contract_messages()
             .map(|message| self.generate_dispatch_variant_arm(message));
-        let decode_message = self
-            .contract_messages()
-            .map(|message| self.generate_dispatch_variant_decode(message));
+        let decode_message = self.contract_messages().map(|message| {
+            let selector_pattern = match message.inherited_selector_trait() {
+                Some(_) => {
+                    let selector_bytes = generator::ItemImpls::generate_message_selector(
+                        storage_ident,
+                        message,
+                    );
+                    quote! { selector if selector == #selector_bytes }
+                }
+                None => {
+                    let selector_bytes =
+                        message.composed_selector().as_bytes().to_owned();
+                    quote! { [ #( #selector_bytes ),* ] }
+                }
+            };
+            self.generate_dispatch_variant_decode(message, selector_pattern)
+        });
         let execute_variants = self
             .contract_messages()
             .map(|message| self.generate_dispatch_execute_message_arm(message));
+        let mixin_fields = self
+            .contract
+            .module()
+            .storage()
+            .mixin_fields()
+            .map(|(member, field)| {
+                (
+                    member.clone(),
+                    Self::generate_mixin_variant_ident(&member),
+                    &field.ty,
+                )
+            })
+            .collect::<Vec<_>>();
+        let mixin_variants = mixin_fields.iter().map(|(_, variant_ident, ty)| {
+            quote! {
+                #variant_ident(<#ty as ::ink_lang::mixin::Mixin>::Dispatch)
+            }
+        });
+        let overlap_check = self.generate_selector_overlap_check();
+        let decode_fallback = if mixin_fields.is_empty() {
+            quote! {
+                _invalid => Err(::scale::Error::from("encountered unknown ink! message selector"))
+            }
+        } else {
+            let decode_mixins = mixin_fields.iter().map(|(_, variant_ident, ty)| {
+                quote! {
+                    if let Some(message) = <
+                        <#ty as ::ink_lang::mixin::Mixin>::Dispatch
+                            as ::ink_lang::mixin::MixinDispatch<#ty>
+                    >::decode_message(selector, input)? {
+                        return Ok(Self::#variant_ident(message))
+                    }
+                }
+            });
+            quote! {
+                selector => {
+                    #( #decode_mixins )*
+                    Err(::scale::Error::from("encountered unknown ink! message selector"))
+                }
+            }
+        };
+        let is_dynamic_storage_allocation_enabled = self
+            .contract
+            .config()
+            .is_dynamic_storage_allocator_enabled();
+        let is_telemetry_enabled = self.contract.config().is_telemetry_enabled();
+        let before_hook = self.generate_mixin_hook_closure(ir::MessageHookKind::Before);
+        let after_hook = self.generate_mixin_hook_closure(ir::MessageHookKind::After);
+        let module = self.contract.module();
+        let hooks = [
+            module.message_hook(ir::MessageHookKind::Before),
+            module.message_hook(ir::MessageHookKind::After),
+        ];
+        let uses_selector = is_telemetry_enabled || hooks.iter().any(Option::is_some);
+        let uses_mutates = is_telemetry_enabled
+            || hooks
+                .iter()
+                .flatten()
+                .any(|hook| hook.receiver().is_ref_mut());
+        let execute_mixins = mixin_fields.iter().map(|(member, variant_ident, ty)| {
+            let dispatch = quote! {
+                <<#ty as ::ink_lang::mixin::Mixin>::Dispatch as ::ink_lang::mixin::MixinDispatch<#ty>>
+            };
+            let selector = if uses_selector {
+                Some(quote! { let selector = #dispatch::selector(&message); })
+            } else {
+                None
+            };
+            let mutates = if uses_mutates {
+                Some(quote! { let mutates = #dispatch::mutates(&message); })
+            } else {
+                None
+            };
+            let telemetry_record = if is_telemetry_enabled {
+                Some(quote! {
+                    if mutates {
+                        ::ink_lang::telemetry::record_call(selector);
+                    }
+                })
+            } else {
+                None
+            };
+            quote! {
+                Self::#variant_ident(message) => {
+                    #selector
+                    #mutates
+                    #dispatch::check_guards::<<#storage_ident as ::ink_lang::ContractEnv>::Env>(&message)?;
+                    #telemetry_record
+                    ::ink_lang::mixin::execute_mixin_message::<
+                        <#storage_ident as ::ink_lang::ContractEnv>::Env,
+                        #storage_ident,
+                        #ty,
+                        _,
+                        _,
+                        _,
+                    >(
+                        ::ink_lang::EnablesDynamicStorageAllocator(#is_dynamic_storage_allocation_enabled),
+                        message,
+                        |state| &mut state.#member,
+                        #before_hook,
+                        #after_hook,
+                    )
+                }
+            }
+        });
         quote! {
+            #overlap_check
+
             const _: () = {
                 #[doc(hidden)]
                 pub enum __ink_MessageDispatchEnum {
-                    #( #message_variants ),*
+                    #( #message_variants, )*
+                    #( #mixin_variants, )*
                 }
 
                 impl ::ink_lang::MessageDispatcher for #storage_ident {
@@ -590,7 +958,7 @@ impl Dispatch<'_> {
                     fn decode<I: ::scale::Input>(input: &mut I) -> ::core::result::Result<Self, ::scale::Error> {
                         match <[u8; 4] as ::scale::Decode>::decode(input)? {
                             #( #decode_message )*
-                            _invalid => Err(::scale::Error::from("encountered unknown ink! message selector"))
+                            #decode_fallback
                         }
                     }
                 }
@@ -599,6 +967,7 @@ impl Dispatch<'_> {
                     fn execute(self) -> ::core::result::Result<(), ::ink_lang::DispatchError> {
                         match self {
                             #( #execute_variants )*
+                            #( #execute_mixins )*
                         }
                     }
                 }
@@ -624,23 +993,49 @@ impl Dispatch<'_> {
         } else {
             quote! { ( #(#arg_pats),* ) }
         };
-        let selector_id = cws.composed_selector().unique_id();
+        let selector_id = cws.composed_id().unique_id();
         let namespace =
             Self::dispatch_trait_impl_namespace(ir::CallableKind::Constructor);
         let is_dynamic_storage_allocation_enabled = self
             .contract
             .config()
             .is_dynamic_storage_allocator_enabled();
+        let storage_ident = self.contract.module().storage().ident();
+        let trace_target = self.generate_trace_target(cws);
+        // The instantiator of a contract with role guarded messages is made
+        // its first admin so that roles can be granted at all.
+        let grant_admin = if self.any_message_requires_role() {
+            Some(quote! {
+                ::ink_lang::access_control::grant_admin_role_to_caller::<
+                    <#storage_ident as ::ink_lang::ContractEnv>::Env
+                >();
+            })
+        } else {
+            None
+        };
+        let init_owner = if self.contract.config().is_ownable() {
+            Some(quote! {
+                ::ink_lang::ownable::init_owner_to_caller::<
+                    <#storage_ident as ::ink_lang::ContractEnv>::Env
+                >();
+            })
+        } else {
+            None
+        };
         quote! {
             Self::#ident(#(#arg_pats),*) => {
-                ::ink_lang::execute_constructor::<#namespace<[(); #selector_id]>, _>(
-                    ::ink_lang::EnablesDynamicStorageAllocator(#is_dynamic_storage_allocation_enabled),
-                    move || {
-                        <#namespace<[(); #selector_id]> as ::ink_lang::Constructor>::CALLABLE(
-                            #arg_inputs
-                        )
-                    }
-                )
+                #grant_admin
+                #init_owner
+                ::ink_lang::trace::in_span(::ink_lang::trace::DISPATCH, #trace_target, move || {
+                    ::ink_lang::execute_constructor::<#namespace<[(); #selector_id]>, _>(
+                        ::ink_lang::EnablesDynamicStorageAllocator(#is_dynamic_storage_allocation_enabled),
+                        move || {
+                            <#namespace<[(); #selector_id]> as ::ink_lang::Constructor>::CALLABLE(
+                                #arg_inputs
+                            )
+                        }
+                    )
+                })
             }
         }
     }
@@ -662,9 +1057,13 @@ impl Dispatch<'_> {
         let message_variants = self
             .contract_constructors()
             .map(|message| self.generate_dispatch_variant_arm(message));
-        let decode_message = self
-            .contract_constructors()
-            .map(|message| self.generate_dispatch_variant_decode(message));
+        let decode_message = self.contract_constructors().map(|constructor| {
+            let selector_bytes = constructor.composed_selector().as_bytes().to_owned();
+            self.generate_dispatch_variant_decode(
+                constructor,
+                quote! { [ #( #selector_bytes ),* ] },
+            )
+        });
         let execute_variants = self
             .contract_constructors()
             .map(|cws| self.generate_dispatch_execute_constructor_arm(cws));
@@ -42,17 +42,19 @@ impl AsRef<ir::Contract> for Events<'_> {
 
 impl GenerateCode for Events<'_> {
     fn generate_code(&self) -> TokenStream2 {
-        if self.contract.module().events().next().is_none() {
+        if !self.contract.module().has_events() {
             // Generate no code in case there are no event definitions.
             return TokenStream2::new()
         }
         let emit_event_trait_impl = self.generate_emit_event_trait_impl();
+        let event_imports = self.generate_event_imports();
         let event_base = self.generate_event_base();
         let topic_guards = self.generate_topic_guards();
         let topics_impls = self.generate_topics_impls();
         let event_structs = self.generate_event_structs();
         quote! {
             #emit_event_trait_impl
+            #( #event_imports )*
             #event_base
             #( #topic_guards )*
             #( #event_structs )*
@@ -81,14 +83,40 @@ impl<'a> Events<'a> {
                             <#storage_ident as ::ink_lang::BaseEvent>::Type
                         >(event.into());
                     }
+
+                    fn try_emit_event<E>(self, event: E) -> ::core::result::Result<(), ::ink_env::EventError>
+                    where
+                        E: Into<<#storage_ident as ::ink_lang::BaseEvent>::Type>,
+                    {
+                        ::ink_env::try_emit_event::<
+                            Environment,
+                            <#storage_ident as ::ink_lang::BaseEvent>::Type
+                        >(event.into())
+                    }
                 }
             };
         }
     }
 
+    /// Generates the imports of the events defined via `#[ink::event]`.
+    fn generate_event_imports(&'a self) -> impl Iterator<Item = TokenStream2> + 'a {
+        let no_cross_calling_cfg =
+            self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        self.contract.module().imported_events().map(move |event| {
+            let span = event.span();
+            quote_spanned!(span =>
+                #no_cross_calling_cfg
+                #event
+            )
+        })
+    }
+
     /// Generates the base event enum that comprises all user defined events.
     /// All emitted events are converted into a variant of this enum before being
     /// serialized and emitted to apply their unique event discriminant (ID).
+    ///
+    /// Events imported via `#[ink(event)] use path::to::Event;` follow the event
+    /// definitions of the ink! module in the order of their imports.
     fn generate_event_base(&self) -> TokenStream2 {
         let storage_ident = &self.contract.module().storage().ident();
         let no_cross_calling_cfg =
@@ -97,7 +125,13 @@ impl<'a> Events<'a> {
             .contract
             .module()
             .events()
-            .map(|event| event.ident())
+            .map(ir::Event::ident)
+            .chain(
+                self.contract
+                    .module()
+                    .imported_events()
+                    .map(ir::ImportedEvent::ident),
+            )
             .collect::<Vec<_>>();
         let base_event_ident =
             proc_macro2::Ident::new("__ink_EventBase", Span::call_site());
@@ -220,76 +254,92 @@ impl<'a> Events<'a> {
         let contract_ident = self.contract.module().storage().ident();
         self.contract.module().events().map(move |event| {
             let span = event.span();
-            let event_ident = event.ident();
-            let event_signature = syn::LitByteStr::new(
-                format!("{}::{}", contract_ident, event_ident
-            ).as_bytes(), span);
-            let len_event_signature = event_signature.value().len();
-            let len_topics = event.fields().filter(|field| field.is_topic).count();
-            let topic_impls = event
-                .fields()
-                .enumerate()
-                .filter(|(_, field)| field.is_topic)
-                .map(|(n, topic_field)| {
-                    let span = topic_field.span();
-                    let field_ident = topic_field
-                        .ident()
-                        .map(quote::ToTokens::into_token_stream)
-                        .unwrap_or_else(|| quote_spanned!(span => #n));
-                    let field_type = topic_field.ty();
-                    let signature = syn::LitByteStr::new(
-                        format!("{}::{}::{}", contract_ident, event_ident,
-                            field_ident
-                        ).as_bytes(), span);
-                    quote_spanned!(span =>
-                        .push_topic::<::ink_env::topics::PrefixedValue<#field_type>>(
-                            &::ink_env::topics::PrefixedValue { value: &self.#field_ident, prefix: #signature }
-                        )
-                    )
-                });
-            // Only include topic for event signature in case of non-anonymous event.
-            let event_signature_topic = match event.anonymous {
-                true => None,
-                false => Some(quote_spanned!(span=>
-                    .push_topic::<::ink_env::topics::PrefixedValue<[u8; #len_event_signature]>>(
-                        &::ink_env::topics::PrefixedValue { value: #event_signature, prefix: b"" }
-                    )
-                ))
-            };
-            // Anonymous events require 1 fewer topics since they do not include their signature.
-            let anonymous_topics_offset = if event.anonymous { 0 } else { 1 };
-            let remaining_topics_ty = match len_topics + anonymous_topics_offset {
-                0 => quote_spanned!(span=> ::ink_env::topics::state::NoRemainingTopics),
-                n => quote_spanned!(span=> [::ink_env::topics::state::HasRemainingTopics; #n]),
-            };
+            let signature = format!("{}::{}", contract_ident, event.ident());
+            let topics_impl = Self::generate_topics_impl(event, &signature);
             quote_spanned!(span =>
                 #no_cross_calling_cfg
-                const _: () = {
-                    impl ::ink_env::Topics for #event_ident {
-                        type RemainingTopics = #remaining_topics_ty;
-
-                        fn topics<E, B>(
-                            &self,
-                            builder: ::ink_env::topics::TopicsBuilder<::ink_env::topics::state::Uninit, E, B>,
-                        ) -> <B as ::ink_env::topics::TopicsBuilderBackend<E>>::Output
-                        where
-                            E: ::ink_env::Environment,
-                            B: ::ink_env::topics::TopicsBuilderBackend<E>,
-                        {
-                            builder
-                                .build::<Self>()
-                                #event_signature_topic
-                                #(
-                                    #topic_impls
-                                )*
-                                .finish()
-                        }
-                    }
-                };
+                #topics_impl
             )
         })
     }
 
+    /// Generates the `Topics` trait implementation for the given event.
+    ///
+    /// The signature topic of the event is the given signature, e.g. `Contract::Event`,
+    /// and the topic fields are prefixed with the signature and their field name.
+    pub(crate) fn generate_topics_impl(
+        event: &ir::Event,
+        signature: &str,
+    ) -> TokenStream2 {
+        let span = event.span();
+        let event_ident = event.ident();
+        let event_signature = syn::LitByteStr::new(signature.as_bytes(), span);
+        let len_event_signature = event_signature.value().len();
+        let len_topics = event.fields().filter(|field| field.is_topic).count();
+        let topic_impls = event
+            .fields()
+            .enumerate()
+            .filter(|(_, field)| field.is_topic)
+            .map(|(n, topic_field)| {
+                let span = topic_field.span();
+                let field_ident = topic_field
+                    .ident()
+                    .map(quote::ToTokens::into_token_stream)
+                    .unwrap_or_else(|| quote_spanned!(span => #n));
+                let field_type = topic_field.ty();
+                let signature = syn::LitByteStr::new(
+                    format!("{}::{}", signature, field_ident).as_bytes(),
+                    span,
+                );
+                quote_spanned!(span =>
+                    .push_topic::<::ink_env::topics::PrefixedValue<#field_type>>(
+                        &::ink_env::topics::PrefixedValue { value: &self.#field_ident, prefix: #signature }
+                    )
+                )
+            });
+        // Only include topic for event signature in case of non-anonymous event.
+        let event_signature_topic = match event.anonymous {
+            true => None,
+            false => Some(quote_spanned!(span=>
+                .push_topic::<::ink_env::topics::PrefixedValue<[u8; #len_event_signature]>>(
+                    &::ink_env::topics::PrefixedValue { value: #event_signature, prefix: b"" }
+                )
+            ))
+        };
+        // Anonymous events require 1 fewer topics since they do not include their signature.
+        let anonymous_topics_offset = if event.anonymous { 0 } else { 1 };
+        let remaining_topics_ty = match len_topics + anonymous_topics_offset {
+            0 => quote_spanned!(span=> ::ink_env::topics::state::NoRemainingTopics),
+            n => {
+                quote_spanned!(span=> [::ink_env::topics::state::HasRemainingTopics; #n])
+            }
+        };
+        quote_spanned!(span =>
+            const _: () = {
+                impl ::ink_env::Topics for #event_ident {
+                    type RemainingTopics = #remaining_topics_ty;
+
+                    fn topics<E, B>(
+                        &self,
+                        builder: ::ink_env::topics::TopicsBuilder<::ink_env::topics::state::Uninit, E, B>,
+                    ) -> <B as ::ink_env::topics::TopicsBuilderBackend<E>>::Output
+                    where
+                        E: ::ink_env::Environment,
+                        B: ::ink_env::topics::TopicsBuilderBackend<E>,
+                    {
+                        builder
+                            .build::<Self>()
+                            #event_signature_topic
+                            #(
+                                #topic_impls
+                            )*
+                            .finish()
+                    }
+                }
+            };
+        )
+    }
+
     /// Generates all the user defined event struct definitions.
     fn generate_event_structs(&'a self) -> impl Iterator<Item = TokenStream2> + 'a {
         let no_cross_calling_cfg =
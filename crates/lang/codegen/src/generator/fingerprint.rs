@@ -0,0 +1,182 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    generator,
+    GenerateCode,
+    GenerateCodeUsing as _,
+};
+use derive_more::From;
+use ir::Callable as _;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    ToTokens as _,
+};
+
+/// Generates code for the fingerprint of the contract code.
+///
+/// The fingerprint is the BLAKE2 256-bit hash of the dispatch table of the
+/// ink! smart contract.
+#[derive(From)]
+pub struct Fingerprint<'a> {
+    /// The contract to generate code for.
+    contract: &'a ir::Contract,
+}
+
+impl AsRef<ir::Contract> for Fingerprint<'_> {
+    fn as_ref(&self) -> &ir::Contract {
+        self.contract
+    }
+}
+
+impl GenerateCode for Fingerprint<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let storage_ident = self.contract.module().storage().ident();
+        let fingerprint = Self::code_fingerprint(self.contract);
+        let cfg = self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        quote! {
+            #cfg
+            const _: () = {
+                impl ::ink_lang::reflect::ContractFingerprint for #storage_ident {
+                    const CODE_FINGERPRINT: [u8; 32] = [ #( #fingerprint ),* ];
+                }
+
+                // The name of the custom section must match
+                // `ink_metadata::CODE_FINGERPRINT_SECTION`.
+                #[cfg(not(feature = "std"))]
+                #[cfg(not(feature = "ink-as-dependency"))]
+                #[link_section = "ink_code_fingerprint"]
+                #[used]
+                static __INK_CODE_FINGERPRINT: [u8; 32] = [ #( #fingerprint ),* ];
+            };
+        }
+    }
+}
+
+impl Fingerprint<'_> {
+    /// Computes the fingerprint of the dispatch table of the contract.
+    ///
+    /// The constructors and messages are sorted by their selectors so that
+    /// reordering them does not change the fingerprint. Every entry consists
+    /// of its selector and the tokens of its argument and return types.
+    ///
+    /// # Note
+    ///
+    /// The types are hashed as they are spelled in the contract source since
+    /// procedural macros cannot resolve types. Therefore different spellings of
+    /// the same type, e.g. `Balance` and `u128` or `u128` and
+    /// `::core::primitive::u128`, yield different fingerprints even though the
+    /// resulting dispatch table is identical. Only the resolved type information
+    /// of the contract metadata is able to identify such types.
+    fn code_fingerprint(contract: &ir::Contract) -> [u8; 32] {
+        use blake2::digest::{
+            Update as _,
+            VariableOutput as _,
+        };
+        let mut entries = Vec::new();
+        for item_impl in contract.module().impls() {
+            for constructor in item_impl.iter_constructors() {
+                let types = constructor
+                    .callable()
+                    .inputs()
+                    .map(|arg| arg.ty.to_token_stream().to_string())
+                    .collect::<Vec<_>>();
+                entries.push((b'c', constructor.composed_selector(), types, None));
+            }
+            for message in item_impl.iter_messages() {
+                let types = message
+                    .callable()
+                    .inputs()
+                    .map(|arg| arg.ty.to_token_stream().to_string())
+                    .collect::<Vec<_>>();
+                let output = message
+                    .callable()
+                    .output()
+                    .map(|ty| ty.to_token_stream().to_string());
+                entries.push((b'm', message.composed_selector(), types, output));
+            }
+        }
+        entries.sort_by(|(kind_a, selector_a, ..), (kind_b, selector_b, ..)| {
+            (kind_a, selector_a.as_bytes()).cmp(&(kind_b, selector_b.as_bytes()))
+        });
+        let mut buffer = Vec::new();
+        for (kind, selector, types, output) in entries {
+            buffer.push(kind);
+            buffer.extend_from_slice(selector.as_bytes());
+            for ty in types {
+                buffer.extend_from_slice(ty.as_bytes());
+                buffer.push(b',');
+            }
+            buffer.push(b'>');
+            if let Some(output) = output {
+                buffer.extend_from_slice(output.as_bytes());
+            }
+            buffer.push(b';');
+        }
+        let mut fingerprint = [0x00; 32];
+        let mut blake2 = blake2::VarBlake2b::new(32).expect("32 is a valid output size");
+        blake2.update(&buffer);
+        blake2.finalize_variable(|result| fingerprint.copy_from_slice(result));
+        fingerprint
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the fingerprint of a contract with a message taking `arg_type`.
+    fn fingerprint_for(arg_type: TokenStream2) -> [u8; 32] {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod flipper {
+                    pub type Balance = u128;
+
+                    #[ink(storage)]
+                    pub struct Flipper {}
+
+                    impl Flipper {
+                        #[ink(constructor)]
+                        pub fn new() -> Self {
+                            Self {}
+                        }
+
+                        #[ink(message)]
+                        pub fn set(&mut self, value: #arg_type) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        Fingerprint::code_fingerprint(&contract)
+    }
+
+    #[test]
+    fn code_fingerprint_hashes_type_spellings() {
+        assert_eq!(
+            fingerprint_for(quote! { u128 }),
+            fingerprint_for(quote! { u128 })
+        );
+        assert_ne!(
+            fingerprint_for(quote! { u128 }),
+            fingerprint_for(quote! { Balance })
+        );
+        assert_ne!(
+            fingerprint_for(quote! { u128 }),
+            fingerprint_for(quote! { ::core::primitive::u128 })
+        );
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generates code for the `[ink::bench]` macro.
+#[derive(From)]
+pub struct InkBench<'a> {
+    /// The benchmark function to generate code for.
+    bench: &'a ir::InkBench,
+}
+
+impl GenerateCode for InkBench<'_> {
+    /// Generates the code for `#[ink:bench]`.
+    fn generate_code(&self) -> TokenStream2 {
+        let item_fn = &self.bench.item_fn;
+        let attrs = &item_fn.attrs;
+        let sig = &item_fn.sig;
+        let fn_name = &sig.ident;
+        let fn_block = &item_fn.block;
+        let vis = &item_fn.vis;
+        let bencher_input = self.bench.bencher_input();
+        let iterations = self.bench.iterations();
+        let expect_msg = format!(
+            "{}: the off-chain testing environment returned an error",
+            fn_name
+        );
+        let run_bench = match &sig.output {
+            syn::ReturnType::Default => {
+                quote! {
+                    let __ink_bench = |#bencher_input| {
+                        let _: () = #fn_block;
+                    };
+                    __ink_bench(&mut __ink_bencher);
+                    __ink_bencher.report();
+                    Ok(())
+                }
+            }
+            syn::ReturnType::Type(rarrow, ret_type) => {
+                quote! {
+                    let __ink_bench = |#bencher_input| #rarrow #ret_type #fn_block;
+                    let __ink_result = __ink_bench(&mut __ink_bencher);
+                    __ink_bencher.report();
+                    __ink_result
+                }
+            }
+        };
+        quote! {
+            #( #attrs )*
+            #[test]
+            #vis fn #fn_name() {
+                ::ink_env::test::run_test::<::ink_env::DefaultEnvironment, _>(|_| {
+                    let mut __ink_bencher = ::ink_env::test::Bencher::new(#iterations);
+                    #run_bench
+                })
+                .expect(#expect_msg);
+            }
+        }
+    }
+}
+
+impl GenerateCode for ir::InkBench {
+    fn generate_code(&self) -> TokenStream2 {
+        InkBench::from(self).generate_code()
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    generator,
+    GenerateCode,
+};
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote_spanned;
+use syn::spanned::Spanned as _;
+
+/// Generates code for the `#[ink::event]` macro.
+#[derive(From)]
+pub struct InkEvent<'a> {
+    /// The event to generate code for.
+    event: &'a ir::InkEvent,
+}
+
+impl GenerateCode for InkEvent<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let event = self.event.event();
+        let span = event.span();
+        let ident = event.ident();
+        let attrs = event.attrs();
+        let vis = event.vis();
+        let fields = event.fields().map(|event_field| {
+            let span = event_field.span();
+            let attrs = event_field.attrs();
+            let vis = event_field.vis();
+            let ident = event_field.ident();
+            let ty = event_field.ty();
+            quote_spanned!(span=>
+                #( #attrs )*
+                #vis #ident : #ty
+            )
+        });
+        let topics_impl =
+            generator::Events::generate_topics_impl(event, &self.event.signature());
+        let metadata_impl = self.generate_metadata_impl();
+        quote_spanned!(span =>
+            #( #attrs )*
+            #[derive(::scale::Encode, ::scale::Decode)]
+            #[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+            #vis struct #ident {
+                #( #fields ),*
+            }
+
+            #topics_impl
+            #metadata_impl
+        )
+    }
+}
+
+impl InkEvent<'_> {
+    /// Generates the metadata of the event for the ink! smart contracts importing it.
+    fn generate_metadata_impl(&self) -> TokenStream2 {
+        let event = self.event.event();
+        let span = event.span();
+        let ident = event.ident();
+        let ident_lit = ident.to_string();
+        let docs = generator::Metadata::extract_doc_comments(event.attrs());
+        let args = generator::Metadata::generate_event_args(event);
+        let anonymous = event.anonymous;
+        let signature = self.event.signature();
+        quote_spanned!(span =>
+            #[cfg(feature = "std")]
+            const _: () = {
+                impl ::ink_lang::EventMetadata for #ident {
+                    fn event_spec() -> ::ink_metadata::EventSpec {
+                        ::ink_metadata::EventSpec::new(#ident_lit)
+                            .args(vec![
+                                #( #args, )*
+                            ])
+                            .docs(vec![
+                                #( #docs, )*
+                            ])
+                            .anonymous(#anonymous)
+                            .signature(#signature)
+                            .done()
+                    }
+                }
+            };
+        )
+    }
+}
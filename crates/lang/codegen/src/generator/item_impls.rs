@@ -20,7 +20,10 @@ use crate::{
 use derive_more::From;
 use heck::CamelCase as _;
 use ir::Callable as _;
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{
+    Ident,
+    TokenStream as TokenStream2,
+};
 use quote::{
     format_ident,
     quote,
@@ -48,6 +51,18 @@ impl GenerateCode for ItemImpls<'_> {
             .module()
             .impls()
             .map(|item_impl| self.generate_item_impl(item_impl));
+        let trait_message_selectors = self
+            .contract
+            .module()
+            .impls()
+            .filter(|item_impl| item_impl.trait_path().is_some())
+            .map(Self::generate_trait_message_selectors);
+        let storage_ident = self.contract.module().storage().ident();
+        let checked_traits = Self::trait_checksums(self.contract).map(|checksum| {
+            quote! {
+                unsafe impl ::ink_lang::CheckedInkTrait<[(); #checksum]> for #storage_ident {}
+            }
+        });
         let no_cross_calling_cfg =
             self.generate_code_using::<generator::CrossCallingConflictCfg>();
         quote! {
@@ -55,8 +70,13 @@ impl GenerateCode for ItemImpls<'_> {
             const _: () = {
                 use ::ink_lang::{Env, EmitEvent, StaticEnv};
 
+                #( #checked_traits )*
                 #( #item_impls )*
             };
+
+            const _: () = {
+                #( #trait_message_selectors )*
+            };
         }
     }
 }
@@ -136,6 +156,23 @@ impl ItemImpls<'_> {
             .trait_ident()
             .expect("encountered missing trait identifier for trait impl block");
         let self_type = item_impl.self_type();
+        let checksum = Self::trait_checksum(item_impl, trait_ident);
+        let trait_impl_id = Self::trait_impl_id(item_impl);
+        quote_spanned!(span =>
+            #( #attrs )*
+            impl #trait_path for #self_type {
+                type __ink_Checksum = [(); #checksum];
+                type __ink_TraitImplId = [(); #trait_impl_id];
+
+                #( #constructors )*
+                #( #messages )*
+                #( #other_items )*
+            }
+        )
+    }
+
+    /// Returns the checksum of the ink! trait definition implemented by the block.
+    pub(crate) fn trait_checksum(item_impl: &ir::ItemImpl, trait_ident: &Ident) -> usize {
         let hash = ir::InkTrait::compute_verify_hash(
             trait_ident,
             item_impl.iter_constructors().map(|constructor| {
@@ -150,18 +187,95 @@ impl ItemImpls<'_> {
                 (ident, len_inputs, is_mut)
             }),
         );
-        let checksum = u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) as usize;
-        quote_spanned!(span =>
-            unsafe impl ::ink_lang::CheckedInkTrait<[(); #checksum]> for #self_type {}
+        u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) as usize
+    }
 
-            #( #attrs )*
-            impl #trait_path for #self_type {
-                type __ink_Checksum = [(); #checksum];
+    /// Returns the deduplicated checksums of all implemented ink! trait definitions.
+    ///
+    /// Different ink! trait definitions share their checksum if they have the same
+    /// identifier and the same shape, e.g. different versions of the same trait.
+    /// Since the ink! smart contract must implement `CheckedInkTrait` only once per
+    /// checksum every checksum is yielded once.
+    pub(crate) fn trait_checksums(
+        contract: &ir::Contract,
+    ) -> impl Iterator<Item = usize> {
+        contract
+            .module()
+            .impls()
+            .filter_map(|item_impl| {
+                item_impl
+                    .trait_ident()
+                    .map(|trait_ident| Self::trait_checksum(item_impl, trait_ident))
+            })
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+    }
 
-                #( #constructors )*
-                #( #messages )*
-                #( #other_items )*
+    /// Returns the unique identifier of the given trait implementation block.
+    ///
+    /// Used to tell apart the implementations of ink! trait definitions that
+    /// share the same checksum.
+    pub(crate) fn trait_impl_id(item_impl: &ir::ItemImpl) -> usize {
+        item_impl
+            .trait_impl_id()
+            .expect("encountered missing trait path for trait impl block")
+            .unique_id()
+    }
+
+    /// Generates the selector bytes the ink! message is dispatched with.
+    ///
+    /// Messages of trait implementation blocks inherit the selectors pinned by
+    /// their ink! trait definition which are resolved through the given type
+    /// implementing the trait. Otherwise the composed selector bytes are used.
+    pub(crate) fn generate_message_selector<T>(
+        self_type: T,
+        message: ir::CallableWithSelector<ir::Message>,
+    ) -> TokenStream2
+    where
+        T: ToTokens,
+    {
+        let composed_bytes = message.composed_selector().as_bytes().to_owned();
+        match message.inherited_selector_trait() {
+            Some(trait_path) => {
+                let pinned_ident = ir::InkTrait::pinned_selector_ident(message.ident());
+                quote! {
+                    ::ink_lang::inherit_selector(
+                        <#self_type as #trait_path>::#pinned_ident,
+                        [ #( #composed_bytes ),* ],
+                    )
+                }
             }
+            None => quote! { [ #( #composed_bytes ),* ] },
+        }
+    }
+
+    /// Generates the selector information of all ink! messages of the given
+    /// trait implementation block.
+    ///
+    /// Used by `ink::assert_trait_impl!` to check that the selectors match the
+    /// selectors of the implemented ink! trait definition.
+    fn generate_trait_message_selectors(item_impl: &ir::ItemImpl) -> TokenStream2 {
+        let span = item_impl.span();
+        let trait_ident = item_impl
+            .trait_ident()
+            .expect("encountered missing trait identifier for trait impl block");
+        let self_type = item_impl.self_type();
+        let trait_impl_id = Self::trait_impl_id(item_impl);
+        let message_selectors = item_impl.iter_messages().map(|message| {
+            let message_id =
+                ir::InkTrait::compose_message_selector(trait_ident, message.ident())
+                    .unique_id();
+            let selector = Self::generate_message_selector(self_type, message);
+            quote_spanned!(message.span() =>
+                impl ::ink_lang::TraitMessageSelector<[(); #trait_impl_id], [(); #message_id]>
+                    for #self_type
+                {
+                    type Selector = [(); ::ink_lang::selector_id(#selector)];
+                }
+            )
+        });
+        quote_spanned!(span =>
+            #( #message_selectors )*
         )
     }
 
@@ -209,7 +323,7 @@ impl ItemImpls<'_> {
         )
     }
 
-    fn generate_inherent_item_impl(item_impl: &ir::ItemImpl) -> TokenStream2 {
+    pub(crate) fn generate_inherent_item_impl(item_impl: &ir::ItemImpl) -> TokenStream2 {
         assert!(item_impl.trait_path().is_none());
         let span = item_impl.span();
         let attrs = item_impl.attrs();
@@ -219,6 +333,10 @@ impl ItemImpls<'_> {
         let constructors = item_impl
             .iter_constructors()
             .map(|cws| Self::generate_inherent_constructor(cws.callable()));
+        let hooks = item_impl.iter_hooks().map(ToTokens::to_token_stream);
+        let continuations = item_impl
+            .iter_continuations()
+            .map(ToTokens::to_token_stream);
         let other_items = item_impl
             .items()
             .iter()
@@ -230,6 +348,8 @@ impl ItemImpls<'_> {
             impl #self_type {
                 #( #constructors )*
                 #( #messages )*
+                #( #hooks )*
+                #( #continuations )*
                 #( #other_items )*
             }
         )
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::GenerateCode;
+use crate::{
+    generator,
+    GenerateCode,
+};
 use derive_more::From;
 use ir::Callable as _;
 use proc_macro2::TokenStream as TokenStream2;
@@ -33,8 +36,11 @@ impl GenerateCode for Metadata<'_> {
     fn generate_code(&self) -> TokenStream2 {
         let contract = self.generate_contract();
         let layout = self.generate_layout();
+        let metadata_hash = self.generate_metadata_hash();
 
         quote! {
+            #metadata_hash
+
             #[cfg(feature = "std")]
             #[cfg(not(feature = "ink-as-dependency"))]
             const _: () = {
@@ -54,6 +60,22 @@ impl GenerateCode for Metadata<'_> {
 }
 
 impl Metadata<'_> {
+    /// Generates the Wasm custom section that holds the configured metadata hash.
+    ///
+    /// The name of the custom section must match `ink_metadata::METADATA_HASH_SECTION`.
+    fn generate_metadata_hash(&self) -> Option<TokenStream2> {
+        let metadata_hash = self.contract.config().metadata_hash()?;
+        Some(quote! {
+            #[cfg(not(feature = "std"))]
+            #[cfg(not(feature = "ink-as-dependency"))]
+            const _: () = {
+                #[link_section = "ink_metadata_hash"]
+                #[used]
+                static __INK_METADATA_HASH: [u8; 32] = [ #( #metadata_hash ),* ];
+            };
+        })
+    }
+
     fn generate_layout(&self) -> TokenStream2 {
         let contract_ident = self.contract.module().storage().ident();
         quote! {
@@ -68,6 +90,18 @@ impl Metadata<'_> {
         let messages = self.generate_messages();
         let events = self.generate_events();
         let docs = self.generate_docs();
+        let traits = self.generate_traits();
+        let features = self.contract.config().features();
+        let storage_ident = self.contract.module().storage().ident();
+        let mixin_messages =
+            self.contract
+                .module()
+                .storage()
+                .mixin_fields()
+                .map(|(_, field)| {
+                    let ty = &field.ty;
+                    quote! { .chain(<#ty as ::ink_lang::mixin::Mixin>::messages()) }
+                });
 
         quote! {
             ::ink_metadata::ContractSpec::new()
@@ -76,19 +110,72 @@ impl Metadata<'_> {
                 ])
                 .messages(vec![
                     #(#messages ,)*
-                ])
+                ].into_iter()#( #mixin_messages )*)
                 .events(vec![
                     #(#events ,)*
                 ])
                 .docs(vec![
                     #(#docs ,)*
                 ])
+                .lang_error(::ink_lang::lang_error_spec())
+                .traits(vec![
+                    #(#traits ,)*
+                ])
+                .code_fingerprint(::ink_lang::reflect::code_fingerprint::<#storage_ident>())
+                .features(
+                    vec![
+                        #( (#features, ::core::cfg!(feature = #features)) ,)*
+                    ]
+                    .into_iter()
+                    .filter_map(|(feature, enabled)| if enabled { Some(feature) } else { None })
+                )
                 .done()
         }
     }
 
+    /// Generates ink! metadata for all implemented trait definitions.
+    fn generate_traits(&self) -> impl Iterator<Item = TokenStream2> + '_ {
+        let storage_ident = self.contract.module().storage().ident();
+        self.contract
+            .module()
+            .impls()
+            .filter_map(move |impl_block| {
+                let trait_ident = impl_block.trait_ident()?;
+                let trait_path = impl_block.trait_path()?;
+                let version_ident = ir::InkTrait::version_ident();
+                let span = impl_block.span();
+                let trait_ident_lit = trait_ident.to_string();
+                let namespace = impl_block.namespace().map(|namespace| {
+                    let namespace_lit = String::from_utf8_lossy(namespace.as_bytes());
+                    quote_spanned!(span => .namespace(#namespace_lit))
+                });
+                let constructor_selectors =
+                    impl_block.iter_constructors().map(|constructor| {
+                        let selector_bytes =
+                            constructor.composed_selector().as_bytes().to_owned();
+                        quote! { [#(#selector_bytes),*] }
+                    });
+                let message_selectors = impl_block.iter_messages().map(|message| {
+                    generator::ItemImpls::generate_message_selector(
+                        storage_ident,
+                        message,
+                    )
+                });
+                let selectors = constructor_selectors.chain(message_selectors);
+                Some(quote_spanned!(span =>
+                    ::ink_metadata::TraitSpec::new(#trait_ident_lit)
+                        #namespace
+                        .version(<#storage_ident as #trait_path>::#version_ident)
+                        .selectors(vec![
+                            #(#selectors ,)*
+                        ])
+                        .done()
+                ))
+            })
+    }
+
     /// Extracts the doc strings from the given slice of attributes.
-    fn extract_doc_comments(
+    pub(crate) fn extract_doc_comments(
         attributes: &[syn::Attribute],
     ) -> impl Iterator<Item = String> + '_ {
         attributes
@@ -128,12 +215,15 @@ impl Metadata<'_> {
                 let docs = Self::extract_doc_comments(&attrs);
                 let selector = constructor.composed_selector();
                 let selector_bytes = selector.as_bytes();
+                let is_default = constructor.is_default();
                 let constructor = constructor.callable();
+                let is_payable = constructor.is_payable();
                 let ident = constructor.ident();
                 let ident_lit = ident.to_string();
                 let args = constructor
                     .inputs()
-                    .map(|arg| Self::generate_message_param(arg));
+                    .zip(constructor.input_docs())
+                    .map(|(arg, docs)| Self::generate_message_param(arg, docs));
                 let constr = match trait_ident {
                     Some(trait_ident) => {
                         let trait_ident_lit = trait_ident.to_string();
@@ -146,6 +236,8 @@ impl Metadata<'_> {
                 quote_spanned!(span =>
                     ::ink_metadata::ConstructorSpec::#constr
                         .selector([#(#selector_bytes),*])
+                        .payable(#is_payable)
+                        .default(#is_default)
                         .args(vec![
                             #(#args ,)*
                         ])
@@ -158,16 +250,23 @@ impl Metadata<'_> {
     }
 
     /// Generates the ink! metadata for the given parameter and parameter type.
-    fn generate_message_param(pat_type: &syn::PatType) -> TokenStream2 {
+    pub(crate) fn generate_message_param(
+        pat_type: &syn::PatType,
+        attrs: &[syn::Attribute],
+    ) -> TokenStream2 {
         let ident = match &*pat_type.pat {
             syn::Pat::Ident(ident) => &ident.ident,
             _ => unreachable!("encountered unexpected non identifier in ink! parameter"),
         };
         let ident_lit = ident.to_string();
         let type_spec = Self::generate_type_spec(&pat_type.ty);
+        let docs = Self::extract_doc_comments(attrs);
         quote! {
             ::ink_metadata::MessageParamSpec::new(#ident_lit)
                 .of_type(#type_spec)
+                .docs(vec![
+                    #( #docs, )*
+                ])
                 .done()
         }
     }
@@ -201,6 +300,7 @@ impl Metadata<'_> {
     }
 
     fn generate_messages(&self) -> impl Iterator<Item = TokenStream2> + '_ {
+        let storage_ident = self.contract.module().storage().ident();
         self.contract
             .module()
             .impls()
@@ -213,20 +313,22 @@ impl Metadata<'_> {
                     .iter_messages()
                     .map(move |message| (trait_ident, message))
             })
-            .map(|(trait_ident, message)| {
+            .map(move |(trait_ident, message)| {
                 let span = message.span();
                 let attrs = message.attrs();
                 let docs = Self::extract_doc_comments(&attrs);
-                let selector = message.composed_selector();
-                let selector_bytes = selector.as_bytes();
+                let selector_bytes =
+                    generator::ItemImpls::generate_message_selector(storage_ident, message);
                 let is_payable = message.is_payable();
                 let message = message.callable();
                 let mutates = message.receiver().is_ref_mut();
+                let permissions = message.permissions();
                 let ident = message.ident();
                 let ident_lit = ident.to_string();
                 let args = message
                     .inputs()
-                    .map(|arg| Self::generate_message_param(arg));
+                    .zip(message.input_docs())
+                    .map(|(arg, docs)| Self::generate_message_param(arg, docs));
                 let ret_ty = Self::generate_return_type(message.output());
                 let constr = match trait_ident {
                     Some(trait_ident) => {
@@ -239,7 +341,7 @@ impl Metadata<'_> {
                 };
                 quote_spanned!(span =>
                     ::ink_metadata::MessageSpec::#constr
-                        .selector([#(#selector_bytes),*])
+                        .selector(#selector_bytes)
                         .args(vec![
                             #(#args ,)*
                         ])
@@ -249,13 +351,16 @@ impl Metadata<'_> {
                         .docs(vec![
                             #(#docs ,)*
                         ])
+                        .permissions(vec![
+                            #(#permissions ,)*
+                        ])
                         .done()
                 )
             })
     }
 
     /// Generates ink! metadata for the given return type.
-    fn generate_return_type(ret_ty: Option<&syn::Type>) -> TokenStream2 {
+    pub(crate) fn generate_return_type(ret_ty: Option<&syn::Type>) -> TokenStream2 {
         match ret_ty {
             None => {
                 quote! {
@@ -272,13 +377,24 @@ impl Metadata<'_> {
     }
 
     /// Generates ink! metadata for all user provided ink! event definitions.
+    ///
+    /// Imported events provide their metadata themselves and are listed after the
+    /// event definitions of the ink! module in the order of the event enum.
     fn generate_events(&self) -> impl Iterator<Item = TokenStream2> + '_ {
-        self.contract.module().events().map(|event| {
+        let imported_events = self.contract.module().imported_events().map(|event| {
+            let span = event.span();
+            let ident = event.ident();
+            quote_spanned!(span =>
+                <#ident as ::ink_lang::EventMetadata>::event_spec()
+            )
+        });
+        let events = self.contract.module().events().map(|event| {
             let span = event.span();
             let ident = event.ident();
             let ident_lit = ident.to_string();
             let docs = Self::extract_doc_comments(event.attrs());
             let args = Self::generate_event_args(event);
+            let anonymous = event.anonymous;
             quote_spanned!(span =>
                 ::ink_metadata::EventSpec::new(#ident_lit)
                     .args(vec![
@@ -287,13 +403,17 @@ impl Metadata<'_> {
                     .docs(vec![
                         #( #docs, )*
                     ])
+                    .anonymous(#anonymous)
                     .done()
             )
-        })
+        });
+        events.chain(imported_events)
     }
 
     /// Generate ink! metadata for a single argument of an ink! event definition.
-    fn generate_event_args(event: &ir::Event) -> impl Iterator<Item = TokenStream2> + '_ {
+    pub(crate) fn generate_event_args(
+        event: &ir::Event,
+    ) -> impl Iterator<Item = TokenStream2> + '_ {
         event.fields().map(|event_field| {
             let span = event_field.span();
             let ident = event_field.ident();
@@ -0,0 +1,398 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    generator,
+    GenerateCode,
+};
+use derive_more::From;
+use ir::Callable as _;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+/// Generates code for an ink! mixin definition.
+#[derive(From)]
+pub struct Mixin<'a> {
+    mixin: &'a ir::InkMixin,
+}
+
+impl GenerateCode for Mixin<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let attrs = self.mixin.attrs();
+        let vis = self.mixin.vis();
+        let ident = self.mixin.ident();
+        let storage_struct = self.generate_storage_struct();
+        let item_impls = self
+            .mixin
+            .impls()
+            .map(generator::ItemImpls::generate_inherent_item_impl);
+        let dispatch = self.generate_dispatch();
+        let rust_items = self.mixin.rust_items();
+        quote! {
+            #( #attrs )*
+            #vis mod #ident {
+                #storage_struct
+
+                const _: () = {
+                    #( #item_impls )*
+                };
+
+                #dispatch
+
+                #( #rust_items )*
+            }
+        }
+    }
+}
+
+impl Mixin<'_> {
+    /// Returns an iterator over all ink! messages of the ink! mixin.
+    fn mixin_messages(
+        &self,
+    ) -> impl Iterator<Item = ir::CallableWithSelector<ir::Message>> {
+        self.mixin.impls().flat_map(ir::ItemImpl::iter_messages)
+    }
+
+    /// Generates the storage struct definition of the ink! mixin.
+    fn generate_storage_struct(&self) -> TokenStream2 {
+        let storage = self.mixin.storage();
+        let span = storage.span();
+        let ident = storage.ident();
+        let attrs = storage.attrs();
+        let fields = storage.fields();
+        quote_spanned!(span =>
+            #( #attrs )*
+            #[cfg_attr(
+                feature = "std",
+                derive(::core::fmt::Debug, ::ink_storage::traits::StorageLayout)
+            )]
+            #[derive(::ink_storage::traits::SpreadLayout)]
+            pub struct #ident {
+                #( #fields ),*
+            }
+        )
+    }
+
+    /// Generates the dispatch enum of the ink! mixin messages and its implementation
+    /// of the `ink_lang::mixin::Mixin` trait.
+    fn generate_dispatch(&self) -> TokenStream2 {
+        let storage_ident = self.mixin.storage().ident();
+        let variants = self.mixin_messages().map(|message| {
+            let ident = message.ident();
+            let input_types = message.inputs().map(|arg| &arg.ty);
+            quote! { #ident( #( #input_types ),* ) }
+        });
+        let selectors = self.mixin_messages().map(|message| {
+            let selector_bytes = message.composed_selector().as_bytes().to_owned();
+            quote! { [ #( #selector_bytes ),* ] }
+        });
+        let message_specs = self
+            .mixin_messages()
+            .map(|message| self.generate_message_spec(message));
+        let decode_message = self.mixin_messages().map(|message| {
+            let ident = message.ident();
+            let selector_bytes = message.composed_selector().as_bytes().to_owned();
+            let input_types = message.inputs().map(|arg| &arg.ty);
+            quote! {
+                [ #( #selector_bytes ),* ] => {
+                    Ok(Some(Self::#ident(
+                        #(
+                            <#input_types as ::scale::Decode>::decode(input)?
+                        ),*
+                    )))
+                }
+            }
+        });
+        let is_payable = self.mixin_messages().map(|message| {
+            let ident = message.ident();
+            let is_payable = message.is_payable();
+            quote! { Self::#ident(..) => #is_payable, }
+        });
+        let mutates = self.mixin_messages().map(|message| {
+            let ident = message.ident();
+            let mutates = message.receiver().is_ref_mut();
+            quote! { Self::#ident(..) => #mutates, }
+        });
+        let message_selectors = self.mixin_messages().map(|message| {
+            let ident = message.ident();
+            let selector_bytes = message.composed_selector().as_bytes().to_owned();
+            quote! { Self::#ident(..) => [ #( #selector_bytes ),* ], }
+        });
+        let is_non_reentrant = self.mixin_messages().map(|message| {
+            let ident = message.ident();
+            let is_non_reentrant = message.is_non_reentrant();
+            quote! { Self::#ident(..) => #is_non_reentrant, }
+        });
+        let check_guards = self.mixin_messages().map(Self::generate_check_guards_arm);
+        let execute = self.mixin_messages().map(|message| {
+            let ident = message.ident();
+            let input_bindings = message
+                .inputs()
+                .enumerate()
+                .map(|(n, _)| quote::format_ident!("__ink_binding_{}", n))
+                .collect::<Vec<_>>();
+            let call = quote! {
+                <#storage_ident>::#ident(mixin, #( #input_bindings ),* )
+            };
+            let output = match message.output() {
+                Some(_) => {
+                    quote! {
+                        let result = #call;
+                        Some(::ink_lang::mixin::MixinOutput::new(&result))
+                    }
+                }
+                None => {
+                    quote! {
+                        #call;
+                        None
+                    }
+                }
+            };
+            quote! {
+                Self::#ident( #( #input_bindings ),* ) => {
+                    #output
+                }
+            }
+        });
+        quote! {
+            const _: () = {
+                #[doc(hidden)]
+                #[allow(non_camel_case_types)]
+                pub enum __ink_MixinDispatchEnum {
+                    #( #variants ),*
+                }
+
+                impl ::ink_lang::mixin::Mixin for #storage_ident {
+                    type Dispatch = __ink_MixinDispatchEnum;
+
+                    const SELECTORS: &'static [[u8; 4]] = &[ #( #selectors ),* ];
+
+                    #[cfg(feature = "std")]
+                    fn messages() -> Vec<::ink_metadata::MessageSpec> {
+                        vec![
+                            #( #message_specs ),*
+                        ]
+                    }
+                }
+
+                impl ::ink_lang::mixin::MixinDispatch<#storage_ident> for __ink_MixinDispatchEnum {
+                    fn decode_message<I: ::scale::Input>(
+                        selector: [u8; 4],
+                        input: &mut I,
+                    ) -> ::core::result::Result<::core::option::Option<Self>, ::scale::Error> {
+                        match selector {
+                            #( #decode_message )*
+                            _unknown => Ok(None),
+                        }
+                    }
+
+                    fn selector(&self) -> [u8; 4] {
+                        match self {
+                            #( #message_selectors )*
+                        }
+                    }
+
+                    fn is_payable(&self) -> bool {
+                        match self {
+                            #( #is_payable )*
+                        }
+                    }
+
+                    fn mutates(&self) -> bool {
+                        match self {
+                            #( #mutates )*
+                        }
+                    }
+
+                    fn is_non_reentrant(&self) -> bool {
+                        match self {
+                            #( #is_non_reentrant )*
+                        }
+                    }
+
+                    fn check_guards<E: ::ink_env::Environment>(
+                        &self,
+                    ) -> ::core::result::Result<(), ::ink_lang::DispatchError> {
+                        match self {
+                            #( #check_guards )*
+                        }
+                    }
+
+                    fn execute(
+                        self,
+                        mixin: &mut #storage_ident,
+                    ) -> ::core::option::Option<::ink_lang::mixin::MixinOutput> {
+                        match self {
+                            #( #execute )*
+                        }
+                    }
+                }
+            };
+        }
+    }
+
+    /// Generates the match arm checking the guards of the given ink! mixin message.
+    ///
+    /// # Note
+    ///
+    /// The guards are checked in the same order as for the messages of the host
+    /// contract. The throttle of the message is identified by its composed
+    /// identifier which includes the namespace of the mixin.
+    fn generate_check_guards_arm(
+        message: ir::CallableWithSelector<ir::Message>,
+    ) -> TokenStream2 {
+        let ident = message.ident();
+        let id = message.composed_id().as_bytes().to_owned();
+        let message = message.callable();
+        let role_check = message.only_role().map(|role| {
+            quote! { ::ink_lang::access_control::ensure_caller_has_role::<E>(#role)?; }
+        });
+        let owner_check = if message.is_only_owner() {
+            Some(quote! { ::ink_lang::ownable::ensure_caller_is_owner::<E>()?; })
+        } else {
+            None
+        };
+        let pause_check = if message.is_when_not_paused() {
+            Some(quote! { ::ink_lang::pausable::ensure_not_paused()?; })
+        } else {
+            None
+        };
+        let throttle_check = message.throttle().map(|rate| {
+            let calls = rate.calls();
+            let blocks = rate.blocks();
+            quote! {
+                ::ink_lang::throttle::ensure_caller_not_throttled::<E>(
+                    ::ink_lang::throttle::Throttle::new(&[ #( #id ),* ], #calls, #blocks)
+                )?;
+            }
+        });
+        quote! {
+            Self::#ident(..) => {
+                #role_check
+                #owner_check
+                #pause_check
+                #throttle_check
+                Ok(())
+            }
+        }
+    }
+
+    /// Generates the ink! metadata of the given ink! mixin message.
+    ///
+    /// The name of the message is prefixed with the name of the mixin storage struct.
+    fn generate_message_spec(
+        &self,
+        message: ir::CallableWithSelector<ir::Message>,
+    ) -> TokenStream2 {
+        let span = message.span();
+        let storage_ident_lit = self.mixin.storage().ident().to_string();
+        let docs = generator::Metadata::extract_doc_comments(message.attrs());
+        let selector_bytes = message.composed_selector().as_bytes().to_owned();
+        let is_payable = message.is_payable();
+        let message = message.callable();
+        let mutates = message.receiver().is_ref_mut();
+        let permissions = message.permissions();
+        let ident_lit = message.ident().to_string();
+        let args = message
+            .inputs()
+            .zip(message.input_docs())
+            .map(|(arg, docs)| generator::Metadata::generate_message_param(arg, docs));
+        let ret_ty = generator::Metadata::generate_return_type(message.output());
+        quote_spanned!(span =>
+            ::ink_metadata::MessageSpec::from_trait_and_name(#storage_ident_lit, #ident_lit)
+                .selector([ #( #selector_bytes ),* ])
+                .args(vec![
+                    #( #args ,)*
+                ])
+                .returns(#ret_ty)
+                .mutates(#mutates)
+                .payable(#is_payable)
+                .docs(vec![
+                    #( #docs ,)*
+                ])
+                .permissions(vec![
+                    #( #permissions ,)*
+                ])
+                .done()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the guard checks generated for the messages of the given ink! mixin.
+    fn check_guards_arms(input: TokenStream2) -> Vec<String> {
+        let mixin = ir::InkMixin::new(TokenStream2::new(), input).unwrap();
+        mixin
+            .impls()
+            .flat_map(ir::ItemImpl::iter_messages)
+            .map(|message| Mixin::generate_check_guards_arm(message).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn check_guards_works() {
+        let arms = check_guards_arms(quote! {
+            mod escrow {
+                #[ink(storage)]
+                pub struct Escrow {}
+
+                impl Escrow {
+                    #[ink(message)]
+                    pub fn deposited(&self) {}
+
+                    #[ink(message, only_owner)]
+                    pub fn release(&mut self) {}
+
+                    #[ink(message, only_role = "refunder", when_not_paused)]
+                    pub fn refund(&mut self) {}
+
+                    #[ink(message, throttle = "1/block")]
+                    pub fn claim(&mut self) {}
+                }
+            }
+        });
+        let guards = [
+            "ensure_caller_has_role",
+            "ensure_caller_is_owner",
+            "ensure_not_paused",
+            "ensure_caller_not_throttled",
+        ];
+        let checked = arms
+            .iter()
+            .map(|arm| {
+                guards
+                    .iter()
+                    .filter(|guard| arm.contains(*guard))
+                    .copied()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            checked,
+            vec![
+                vec![],
+                vec!["ensure_caller_is_owner"],
+                vec!["ensure_caller_has_role", "ensure_not_paused"],
+                vec!["ensure_caller_not_throttled"],
+            ]
+        );
+    }
+}
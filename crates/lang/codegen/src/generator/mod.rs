@@ -12,19 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod address_book;
+mod assert_storage_footprint;
+mod assert_trait_impl;
+mod bounded_inputs;
 mod contract;
+mod contract_ref;
+mod continuations;
 mod cross_calling;
 mod dispatch;
 mod env;
 mod events;
+mod fingerprint;
+mod ink_bench;
+mod ink_event;
 mod ink_test;
 mod item_impls;
 mod metadata;
+mod mixin;
+mod sign_payload;
 mod storage;
 mod trait_def;
 
 pub use self::{
+    address_book::AddressBook,
+    assert_storage_footprint::AssertStorageFootprint,
+    assert_trait_impl::AssertTraitImpl,
+    bounded_inputs::BoundedInputs,
     contract::Contract,
+    contract_ref::ContractRef,
+    continuations::Continuations,
     cross_calling::{
         CrossCalling,
         CrossCallingConflictCfg,
@@ -32,9 +49,14 @@ pub use self::{
     dispatch::Dispatch,
     env::Env,
     events::Events,
+    fingerprint::Fingerprint,
+    ink_bench::InkBench,
+    ink_event::InkEvent,
     ink_test::InkTest,
     item_impls::ItemImpls,
     metadata::Metadata,
+    mixin::Mixin,
+    sign_payload::SignPayload,
     storage::Storage,
     trait_def::TraitDefinition,
 };
@@ -0,0 +1,42 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generates code for the `#[derive(SignPayload)]` macro.
+#[derive(From)]
+pub struct SignPayload<'a> {
+    /// The struct to generate code for.
+    sign_payload: &'a ir::SignPayload,
+}
+
+impl GenerateCode for SignPayload<'_> {
+    /// Implements `SignPayload` using the type string of the struct.
+    fn generate_code(&self) -> TokenStream2 {
+        let ident = self.sign_payload.ident();
+        let type_string = self.sign_payload.type_string();
+        let (impl_generics, ty_generics, where_clause) =
+            self.sign_payload.generics().split_for_impl();
+        quote! {
+            impl #impl_generics ::ink_lang::typed_data::SignPayload for #ident #ty_generics
+            #where_clause
+            {
+                const TYPE_STRING: &'static str = #type_string;
+            }
+        }
+    }
+}
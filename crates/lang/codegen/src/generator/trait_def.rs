@@ -47,7 +47,7 @@ impl<'a> TraitDefinition<'a> {
         )
     }
 
-    fn generate_for_message(message: ir::InkTraitMessage<'a>) -> TokenStream2 {
+    fn generate_for_message(&self, message: ir::InkTraitMessage<'a>) -> TokenStream2 {
         let span = message.span();
         let attrs = message.attrs();
         let sig = message.sig();
@@ -58,7 +58,20 @@ impl<'a> TraitDefinition<'a> {
             syn::ReturnType::Type(_, ty) => quote! { #ty },
         };
         let output_ident = format_ident!("{}Out", ident.to_string().to_camel_case());
+        let pinned_ident = ir::InkTrait::pinned_selector_ident(ident);
+        let pinned_selector = match self.trait_def.pinned_message_selector(&message) {
+            Some(selector) => {
+                let selector_bytes = selector.as_bytes();
+                quote! { ::core::option::Option::Some([ #( #selector_bytes ),* ]) }
+            }
+            None => quote! { ::core::option::Option::None },
+        };
         quote_spanned!(span =>
+            /// Selector pinned by the trait definition for the respective trait message.
+            #[doc(hidden)]
+            #[allow(non_upper_case_globals)]
+            const #pinned_ident: ::core::option::Option<[u8; 4]> = #pinned_selector;
+
             /// Output type of the respective trait message.
             type #output_ident: ::ink_lang::ImpliesReturn<#output>;
 
@@ -66,6 +79,392 @@ impl<'a> TraitDefinition<'a> {
             fn #ident(#inputs) -> Self::#output_ident;
         )
     }
+
+    /// Generates the default implemented method of the trait definition.
+    ///
+    /// Default implemented methods emitting ink! events defined via `#[ink::event]`
+    /// are bounded by `Self: ::ink_lang::EmitTraitEvent<Event>` themselves and are
+    /// therefore only available to ink! smart contracts importing the events.
+    fn generate_for_default_method(method: &'a syn::TraitItemMethod) -> TokenStream2 {
+        quote! { #method }
+    }
+}
+
+impl<'a> TraitDefinition<'a> {
+    /// Generates the contract reference that allows to call ink! smart contracts
+    /// implementing the trait definition given only their account identifier.
+    ///
+    /// The contract reference is used through the `contract_ref!` macro.
+    fn generate_contract_ref(&self) -> TokenStream2 {
+        let span = self.trait_def.span();
+        let ident = self.trait_def.ident();
+        let contract_ref_ident = ir::InkTrait::contract_ref_ident(ident);
+        let contract_ref_name = contract_ref_ident.to_string();
+        let interface_id = self.trait_def.interface_id();
+        let messages = self
+            .trait_def
+            .iter_items()
+            .flat_map(ir::InkTraitItem::filter_map_message)
+            .map(|message| self.generate_contract_ref_message(message));
+        quote_spanned!(span =>
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            pub struct #contract_ref_ident<E>
+            where
+                E: ::ink_env::Environment,
+            {
+                account_id: <E as ::ink_env::Environment>::AccountId,
+            }
+
+            const _: () = {
+                // The traits are implemented manually since deriving them would
+                // require the environment `E` itself to implement them.
+                impl<E> ::core::fmt::Debug for #contract_ref_ident<E>
+                where
+                    E: ::ink_env::Environment,
+                    <E as ::ink_env::Environment>::AccountId: ::core::fmt::Debug,
+                {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct(#contract_ref_name)
+                            .field("account_id", &self.account_id)
+                            .finish()
+                    }
+                }
+
+                impl<E> ::core::clone::Clone for #contract_ref_ident<E>
+                where
+                    E: ::ink_env::Environment,
+                {
+                    #[inline]
+                    fn clone(&self) -> Self {
+                        Self { account_id: self.account_id.clone() }
+                    }
+                }
+
+                impl<E> ::scale::Encode for #contract_ref_ident<E>
+                where
+                    E: ::ink_env::Environment,
+                {
+                    #[inline]
+                    fn size_hint(&self) -> usize {
+                        ::scale::Encode::size_hint(&self.account_id)
+                    }
+
+                    #[inline]
+                    fn encode_to<O: ::scale::Output>(&self, dest: &mut O) {
+                        ::scale::Encode::encode_to(&self.account_id, dest)
+                    }
+                }
+
+                impl<E> ::scale::EncodeLike for #contract_ref_ident<E>
+                where
+                    E: ::ink_env::Environment,
+                {}
+
+                impl<E> ::scale::Decode for #contract_ref_ident<E>
+                where
+                    E: ::ink_env::Environment,
+                {
+                    #[inline]
+                    fn decode<I: ::scale::Input>(input: &mut I) -> ::core::result::Result<Self, ::scale::Error> {
+                        Ok(Self {
+                            account_id: ::scale::Decode::decode(input)?,
+                        })
+                    }
+                }
+
+                #[cfg(feature = "std")]
+                impl<E> ::scale_info::TypeInfo for #contract_ref_ident<E>
+                where
+                    E: ::ink_env::Environment + 'static,
+                    <E as ::ink_env::Environment>::AccountId: ::scale_info::TypeInfo,
+                {
+                    fn type_info() -> ::scale_info::Type {
+                        ::scale_info::Type::builder()
+                            .path(::scale_info::Path::new(#contract_ref_name, ::core::module_path!()))
+                            .composite(
+                                ::scale_info::build::Fields::named()
+                                    .field_of::<<E as ::ink_env::Environment>::AccountId>("account_id"),
+                            )
+                    }
+                }
+
+                impl<E> ::ink_env::call::FromAccountId<E> for #contract_ref_ident<E>
+                where
+                    E: ::ink_env::Environment,
+                {
+                    #[inline]
+                    fn from_account_id(
+                        account_id: <E as ::ink_env::Environment>::AccountId,
+                    ) -> Self {
+                        Self { account_id }
+                    }
+                }
+
+                impl<E> ::ink_lang::ToAccountId<E> for #contract_ref_ident<E>
+                where
+                    E: ::ink_env::Environment,
+                {
+                    #[inline]
+                    fn to_account_id(&self) -> <E as ::ink_env::Environment>::AccountId {
+                        self.account_id.clone()
+                    }
+                }
+
+                impl<E> ::ink_lang::interface::InterfaceId for #contract_ref_ident<E>
+                where
+                    E: ::ink_env::Environment,
+                {
+                    const INTERFACE_ID: [u8; 4] = [ #( #interface_id ),* ];
+                }
+
+                impl<E> #contract_ref_ident<E>
+                where
+                    E: ::ink_env::Environment,
+                {
+                    #( #messages )*
+                }
+            };
+        )
+    }
+
+    /// Generates the type asserting that an ink! smart contract implements the
+    /// trait definition with the selectors of the trait definition.
+    ///
+    /// Every ink! message is checked through its own helper trait so that
+    /// compile errors mention the ink! messages with mismatching selectors.
+    /// The assertion is used through the `assert_trait_impl!` macro.
+    fn generate_assert_impl(&self) -> TokenStream2 {
+        let span = self.trait_def.span();
+        let ident = self.trait_def.ident();
+        let assert_impl_ident = ir::InkTrait::assert_impl_ident(ident);
+        let (helper_idents, helper_traits): (Vec<_>, Vec<_>) = self
+            .trait_def
+            .iter_items()
+            .flat_map(ir::InkTraitItem::filter_map_message)
+            .map(|message| {
+                let span = message.span();
+                let helper_ident =
+                    format_ident!("__ink_{}_{}", ident, message.sig().ident);
+                let message_id =
+                    ir::InkTrait::compose_message_selector(ident, &message.sig().ident)
+                        .unique_id();
+                let selector_id = self.trait_def.message_selector(&message).unique_id();
+                let helper_trait = quote_spanned!(span =>
+                    #[doc(hidden)]
+                    #[allow(non_camel_case_types)]
+                    pub trait #helper_ident {}
+
+                    impl<T> #helper_ident for T
+                    where
+                        T: #ident + ::ink_lang::TraitMessageSelector<
+                            <T as #ident>::__ink_TraitImplId,
+                            [(); #message_id],
+                            Selector = [(); #selector_id],
+                        >,
+                    {
+                    }
+                );
+                (helper_ident, helper_trait)
+            })
+            .unzip();
+        quote_spanned!(span =>
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            pub struct #assert_impl_ident<T>
+            where
+                T: #ident #( + #helper_idents )*,
+            {
+                marker: ::core::marker::PhantomData<fn() -> T>,
+            }
+
+            #( #helper_traits )*
+        )
+    }
+
+    fn generate_contract_ref_message(
+        &self,
+        message: ir::InkTraitMessage<'a>,
+    ) -> TokenStream2 {
+        let span = message.span();
+        let attrs = message.attrs();
+        let sig = message.sig();
+        let ident = &sig.ident;
+        let selector = self.trait_def.message_selector(&message);
+        let selector_bytes = selector.as_bytes().to_owned();
+        let input_types = sig
+            .inputs
+            .iter()
+            .filter_map(|fn_arg| {
+                match fn_arg {
+                    syn::FnArg::Typed(pat_type) => Some(&*pat_type.ty),
+                    syn::FnArg::Receiver(_) => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        let input_bindings = input_types
+            .iter()
+            .enumerate()
+            .map(|(n, _)| format_ident!("__ink_binding_{}", n))
+            .collect::<Vec<_>>();
+        let (output, output_sig) = match &sig.output {
+            syn::ReturnType::Default => (quote! { () }, quote! { () }),
+            syn::ReturnType::Type(_, ty) => {
+                (
+                    quote! { #ty },
+                    quote! { ::ink_env::call::utils::ReturnType<#ty> },
+                )
+            }
+        };
+        let receiver = if message.mutates() {
+            quote! { &mut self }
+        } else {
+            quote! { &self }
+        };
+        let error_str = format!(
+            "encountered error while calling {}::{}",
+            self.trait_def.ident(),
+            ident
+        );
+        quote_spanned!(span =>
+            #( #attrs )*
+            #[inline]
+            pub fn #ident(
+                #receiver #(, #input_bindings : #input_types )*
+            ) -> #output {
+                ::ink_env::call::build_call::<E>()
+                    .callee(self.account_id.clone())
+                    .exec_input(
+                        ::ink_env::call::ExecutionInput::new(
+                            ::ink_env::call::Selector::new([ #( #selector_bytes ),* ])
+                        )
+                        #(
+                            .push_arg(#input_bindings)
+                        )*
+                    )
+                    .returns::<#output_sig>()
+                    .fire()
+                    .expect(#error_str)
+            }
+        )
+    }
+}
+
+impl<'a> TraitDefinition<'a> {
+    /// Generates the mock contract that allows to program the responses
+    /// to the messages of the trait definition in off-chain tests.
+    ///
+    /// The mock contract is only generated for the unit tests of the crate
+    /// defining the trait definition.
+    fn generate_mock(&self) -> TokenStream2 {
+        let span = self.trait_def.span();
+        let ident = self.trait_def.ident();
+        let mock_ident = ir::InkTrait::mock_ident(ident);
+        let doc_str = format!(
+            "Mock contract with programmable responses to the messages of `{}`.",
+            ident
+        );
+        let messages = self
+            .trait_def
+            .iter_items()
+            .flat_map(ir::InkTraitItem::filter_map_message)
+            .map(|message| self.generate_mock_message(message));
+        quote_spanned!(span =>
+            #[cfg(test)]
+            #[doc = #doc_str]
+            ///
+            /// Calls from the tested contract to the account ID the mock
+            /// contract has been registered under are answered by the registered
+            /// responses. Messages without a registered response revert.
+            #[derive(Default)]
+            pub struct #mock_ident {
+                contract: ::ink_env::test::MockContract,
+            }
+
+            #[cfg(test)]
+            impl #mock_ident {
+                /// Creates a new mock contract without any registered responses.
+                pub fn new() -> Self {
+                    Default::default()
+                }
+
+                #( #messages )*
+
+                /// Registers the mock contract under the given account ID.
+                ///
+                /// Replaces the contract previously registered under the account ID.
+                pub fn register<E>(self, account_id: <E as ::ink_env::Environment>::AccountId)
+                where
+                    E: ::ink_env::Environment,
+                {
+                    ::ink_env::test::register_contract::<E>(account_id, self.contract)
+                }
+            }
+        )
+    }
+
+    fn generate_mock_message(&self, message: ir::InkTraitMessage<'a>) -> TokenStream2 {
+        let span = message.span();
+        let ident = &message.sig().ident;
+        let on_ident = format_ident!("on_{}", ident);
+        let revert_ident = format_ident!("revert_{}", ident);
+        let selector = self.trait_def.message_selector(&message);
+        let selector_bytes = selector.as_bytes().to_owned();
+        let input_types = message
+            .sig()
+            .inputs
+            .iter()
+            .filter_map(|fn_arg| {
+                match fn_arg {
+                    syn::FnArg::Typed(pat_type) => Some(&*pat_type.ty),
+                    syn::FnArg::Receiver(_) => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        let input_bindings = input_types
+            .iter()
+            .enumerate()
+            .map(|(n, _)| format_ident!("__ink_binding_{}", n))
+            .collect::<Vec<_>>();
+        let output = match &message.sig().output {
+            syn::ReturnType::Default => quote! { () },
+            syn::ReturnType::Type(_, ty) => quote! { #ty },
+        };
+        let on_doc_str = format!(
+            "Registers the response to calls of `{}::{}`.",
+            self.trait_def.ident(),
+            ident
+        );
+        let revert_doc_str = format!(
+            "Makes calls of `{}::{}` revert with the given data.",
+            self.trait_def.ident(),
+            ident
+        );
+        quote_spanned!(span =>
+            #[doc = #on_doc_str]
+            pub fn #on_ident<F>(mut self, mut response: F) -> Self
+            where
+                F: FnMut( #( #input_types ),* ) -> #output + 'static,
+            {
+                self.contract.on_message(
+                    ::ink_env::call::Selector::new([ #( #selector_bytes ),* ]),
+                    move |( #( #input_bindings, )* ): ( #( #input_types, )* )| {
+                        response( #( #input_bindings ),* )
+                    },
+                );
+                self
+            }
+
+            #[doc = #revert_doc_str]
+            pub fn #revert_ident(mut self, data: ::ink_prelude::vec::Vec<u8>) -> Self {
+                self.contract.revert_message(
+                    ::ink_env::call::Selector::new([ #( #selector_bytes ),* ]),
+                    data,
+                );
+                self
+            }
+        )
+    }
 }
 
 impl GenerateCode for TraitDefinition<'_> {
@@ -93,7 +492,18 @@ impl GenerateCode for TraitDefinition<'_> {
             .trait_def
             .iter_items()
             .flat_map(ir::InkTraitItem::filter_map_message)
-            .map(Self::generate_for_message);
+            .map(|message| self.generate_for_message(message));
+        let default_methods = self
+            .trait_def
+            .iter_default_methods()
+            .map(Self::generate_for_default_method);
+        let version = self.trait_def.version();
+        let version_ident = ir::InkTrait::version_ident();
+        let interface_id = self.trait_def.interface_id();
+        let interface_id_ident = ir::InkTrait::interface_id_ident();
+        let contract_ref = self.generate_contract_ref();
+        let assert_impl = self.generate_assert_impl();
+        let mock = self.generate_mock();
         quote_spanned!(span =>
             #(#attrs)*
             pub trait #ident: ::ink_lang::CheckedInkTrait<[(); #verify_hash_id]> {
@@ -101,8 +511,27 @@ impl GenerateCode for TraitDefinition<'_> {
                 #[allow(non_camel_case_types)]
                 type __ink_Checksum: #helper_ident;
 
+                /// The identifier of the implementation of the trait definition.
+                ///
+                /// Tells apart implementations of trait definitions with the same
+                /// checksum, e.g. of different versions of the same trait.
+                #[doc(hidden)]
+                #[allow(non_camel_case_types)]
+                type __ink_TraitImplId;
+
+                /// The version of the trait definition.
+                #[doc(hidden)]
+                #[allow(non_upper_case_globals)]
+                const #version_ident: u32 = #version;
+
+                /// The interface identifier of the trait definition.
+                #[doc(hidden)]
+                #[allow(non_upper_case_globals)]
+                const #interface_id_ident: [u8; 4] = [ #( #interface_id ),* ];
+
                 #(#constructors)*
                 #(#messages)*
+                #(#default_methods)*
             }
 
             #[doc(hidden)]
@@ -112,6 +541,10 @@ impl GenerateCode for TraitDefinition<'_> {
             const _: () = {
                 unsafe impl #helper_ident for [(); #verify_hash_id] {}
             };
+
+            #contract_ref
+            #assert_impl
+            #mock
         )
     }
 }
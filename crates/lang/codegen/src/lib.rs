@@ -13,8 +13,15 @@
 // limitations under the License.
 
 mod generator;
+mod size_report;
 mod traits;
 
+pub use self::size_report::{
+    ItemKind,
+    ItemSize,
+    SizeReport,
+    SizeReportError,
+};
 use self::traits::{
     GenerateCode,
     GenerateCodeUsing,
@@ -28,18 +35,50 @@ pub trait CodeGenerator: Sized {
     type Generator: From<Self> + GenerateCode;
 }
 
+impl<'a> CodeGenerator for &'a ir::AddressBook {
+    type Generator = generator::AddressBook<'a>;
+}
+
+impl<'a> CodeGenerator for &'a ir::AssertStorageFootprint {
+    type Generator = generator::AssertStorageFootprint<'a>;
+}
+
+impl<'a> CodeGenerator for &'a ir::AssertTraitImpl {
+    type Generator = generator::AssertTraitImpl<'a>;
+}
+
 impl<'a> CodeGenerator for &'a ir::Contract {
     type Generator = generator::Contract<'a>;
 }
 
+impl<'a> CodeGenerator for &'a ir::ContractRef {
+    type Generator = generator::ContractRef<'a>;
+}
+
 impl<'a> CodeGenerator for &'a ir::InkTrait {
     type Generator = generator::TraitDefinition<'a>;
 }
 
+impl<'a> CodeGenerator for &'a ir::InkBench {
+    type Generator = generator::InkBench<'a>;
+}
+
+impl<'a> CodeGenerator for &'a ir::InkEvent {
+    type Generator = generator::InkEvent<'a>;
+}
+
+impl<'a> CodeGenerator for &'a ir::InkMixin {
+    type Generator = generator::Mixin<'a>;
+}
+
 impl<'a> CodeGenerator for &'a ir::InkTest {
     type Generator = generator::InkTest<'a>;
 }
 
+impl<'a> CodeGenerator for &'a ir::SignPayload {
+    type Generator = generator::SignPayload<'a>;
+}
+
 /// Generates the entire code for the given ink! contract.
 pub fn generate_code<T>(entity: T) -> TokenStream2
 where
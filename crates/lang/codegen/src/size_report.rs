@@ -0,0 +1,579 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Maps the sizes of the functions of a compiled ink! smart contract back to
+//! the ink! items they have been generated for.
+//!
+//! The Wasm blob of the contract must have been built with debug names, i.e.
+//! it must contain the Wasm `name` custom section, for example by building
+//! the contract without stripping symbols and by running `wasm-opt` with
+//! `--debuginfo`. The function names are demangled and matched against the
+//! ink! messages, constructors, events and the storage struct of the contract.
+//! Functions that have been inlined into others are accounted for in the
+//! functions they have been inlined into.
+
+use core::fmt;
+use ir::Callable as _;
+use std::collections::BTreeMap;
+
+/// The kind of ink! item a function has been attributed to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ItemKind {
+    /// An ink! constructor.
+    Constructor,
+    /// An ink! message.
+    Message,
+    /// An ink! event.
+    Event,
+    /// The trait implementations of the ink! storage struct.
+    Storage,
+    /// The ink! generated dispatch and glue code.
+    Dispatch,
+    /// Code that could not be attributed to any ink! item.
+    Other,
+}
+
+impl fmt::Display for ItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self {
+            Self::Constructor => "constructor",
+            Self::Message => "message",
+            Self::Event => "event",
+            Self::Storage => "storage",
+            Self::Dispatch => "dispatch",
+            Self::Other => "other",
+        };
+        f.pad(kind)
+    }
+}
+
+/// The accumulated code size of an ink! item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemSize {
+    /// The kind of the ink! item.
+    kind: ItemKind,
+    /// The name of the ink! item, e.g. `Flipper::flip`.
+    name: String,
+    /// The number of Wasm functions attributed to the ink! item.
+    functions: usize,
+    /// The accumulated size of the Wasm function bodies in bytes.
+    size: usize,
+}
+
+impl ItemSize {
+    /// Returns the kind of the ink! item.
+    pub fn kind(&self) -> ItemKind {
+        self.kind
+    }
+
+    /// Returns the name of the ink! item.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of Wasm functions attributed to the ink! item.
+    pub fn functions(&self) -> usize {
+        self.functions
+    }
+
+    /// Returns the accumulated size of the Wasm function bodies in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// An error encountered upon generating a size report.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SizeReportError {
+    /// The input is not a valid Wasm blob.
+    InvalidWasm,
+    /// The Wasm blob does not contain function names.
+    MissingNames,
+}
+
+impl fmt::Display for SizeReportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidWasm => write!(f, "encountered invalid Wasm blob"),
+            Self::MissingNames => {
+                write!(
+                    f,
+                    "encountered Wasm blob without function names, \
+                     build the contract with debug names"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SizeReportError {}
+
+/// The code sizes of the ink! items of a compiled ink! smart contract.
+///
+/// Items are ordered by their size starting with the largest item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizeReport {
+    /// The code sizes of all ink! items.
+    items: Vec<ItemSize>,
+}
+
+impl SizeReport {
+    /// Generates the size report for the given ink! contract and its compiled Wasm blob.
+    ///
+    /// # Errors
+    ///
+    /// - If the Wasm blob is malformed.
+    /// - If the Wasm blob does not contain function names.
+    pub fn new(contract: &ir::Contract, wasm: &[u8]) -> Result<Self, SizeReportError> {
+        let functions = parse_functions(wasm)?;
+        let matcher = Matcher::new(contract);
+        let mut items = <BTreeMap<(ItemKind, String), ItemSize>>::new();
+        for (name, size) in functions {
+            let demangled = format!("{:#}", rustc_demangle::demangle(&name));
+            let (kind, name) = matcher.attribute(&demangled);
+            let item = items
+                .entry((kind, name.clone()))
+                .or_insert_with(|| {
+                    ItemSize {
+                        kind,
+                        name,
+                        functions: 0,
+                        size: 0,
+                    }
+                });
+            item.functions += 1;
+            item.size += size;
+        }
+        let mut items = items.into_iter().map(|(_, item)| item).collect::<Vec<_>>();
+        items.sort_by(|lhs, rhs| rhs.size.cmp(&lhs.size));
+        Ok(Self { items })
+    }
+
+    /// Returns the code sizes of all ink! items.
+    pub fn items(&self) -> &[ItemSize] {
+        &self.items
+    }
+
+    /// Returns the code sizes of all ink! messages.
+    pub fn messages(&self) -> impl Iterator<Item = &ItemSize> {
+        self.items
+            .iter()
+            .filter(|item| item.kind == ItemKind::Message)
+    }
+
+    /// Returns the accumulated size of all Wasm function bodies in bytes.
+    pub fn total_size(&self) -> usize {
+        self.items.iter().map(ItemSize::size).sum()
+    }
+}
+
+impl fmt::Display for SizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for item in &self.items {
+            writeln!(
+                f,
+                "{:<11} {:>8} bytes {:>4} functions  {}",
+                item.kind, item.size, item.functions, item.name
+            )?;
+        }
+        write!(f, "{:<11} {:>8} bytes", "total", self.total_size())
+    }
+}
+
+/// Attributes demangled Wasm function names to the ink! items of a contract.
+struct Matcher {
+    /// The identifier of the ink! storage struct.
+    storage: String,
+    /// The kinds, demangled path suffixes and names of the ink! messages and constructors.
+    callables: Vec<(ItemKind, String, String)>,
+    /// The identifiers of the ink! events.
+    events: Vec<String>,
+}
+
+impl Matcher {
+    /// Creates a matcher for the items of the given ink! contract.
+    fn new(contract: &ir::Contract) -> Self {
+        let storage = contract.module().storage().ident().to_string();
+        let mut callables = Vec::new();
+        for item_impl in contract.module().impls() {
+            let trait_ident = item_impl
+                .trait_path()
+                .and_then(|path| path.segments.last())
+                .map(|segment| &segment.ident);
+            // Trait methods are demangled as `<a::Storage as b::Trait>::method`.
+            let path_and_name = |ident: &proc_macro2::Ident| {
+                if let Some(trait_ident) = trait_ident {
+                    let path = format!(" as {}>::{}", trait_ident, ident);
+                    let name = format!("<{} as {}>::{}", storage, trait_ident, ident);
+                    return (path, name)
+                }
+                let name = format!("{}::{}", storage, ident);
+                (name.clone(), name)
+            };
+            for message in item_impl.iter_messages() {
+                let (path, name) = path_and_name(message.ident());
+                callables.push((ItemKind::Message, path, name));
+            }
+            for constructor in item_impl.iter_constructors() {
+                let (path, name) = path_and_name(constructor.ident());
+                callables.push((ItemKind::Constructor, path, name));
+            }
+        }
+        let events = contract
+            .module()
+            .events()
+            .map(ir::Event::ident)
+            .chain(
+                contract
+                    .module()
+                    .imported_events()
+                    .map(ir::ImportedEvent::ident),
+            )
+            .map(ToString::to_string)
+            .collect();
+        Self {
+            storage,
+            callables,
+            events,
+        }
+    }
+
+    /// Returns the kind and name of the ink! item the function belongs to.
+    fn attribute(&self, function: &str) -> (ItemKind, String) {
+        let function = strip_closures(function);
+        for (kind, path, name) in &self.callables {
+            if ends_with_path(function, path) {
+                return (*kind, name.clone())
+            }
+        }
+        if let Some(self_ty) = self_type(function) {
+            let event = self
+                .events
+                .iter()
+                .find(|event| ends_with_path(self_ty, event));
+            if let Some(event) = event {
+                return (ItemKind::Event, event.clone())
+            }
+            if ends_with_path(self_ty, &self.storage) {
+                return (ItemKind::Storage, self.storage.clone())
+            }
+        }
+        if function.contains("__ink_") {
+            return (ItemKind::Dispatch, "dispatch".to_string())
+        }
+        (ItemKind::Other, "other".to_string())
+    }
+}
+
+/// Strips trailing closure segments, e.g. `f::{{closure}}` becomes `f`.
+fn strip_closures(mut function: &str) -> &str {
+    while let Some(stripped) = function.strip_suffix("::{{closure}}") {
+        function = stripped;
+    }
+    function
+}
+
+/// Returns `true` if `path` is a suffix of `function` starting at a path segment.
+fn ends_with_path(function: &str, path: &str) -> bool {
+    if path.starts_with(' ') {
+        return function.ends_with(path)
+    }
+    match function.strip_suffix(path) {
+        Some(rest) => rest.is_empty() || rest.ends_with("::") || rest.ends_with('<'),
+        None => false,
+    }
+}
+
+/// Returns the self type of a trait method, e.g. `a::B` for `<a::B as c::D>::e`.
+fn self_type(function: &str) -> Option<&str> {
+    let rest = function.strip_prefix('<')?;
+    let end = rest.find(" as ")?;
+    Some(&rest[..end])
+}
+
+/// Returns the names and body sizes of all functions defined by the Wasm blob.
+fn parse_functions(wasm: &[u8]) -> Result<Vec<(String, usize)>, SizeReportError> {
+    const WASM_HEADER: [u8; 8] = [0x00, 0x61, 0x73, 0x6D, 0x01, 0x00, 0x00, 0x00];
+    if wasm.get(..WASM_HEADER.len()) != Some(&WASM_HEADER[..]) {
+        return Err(SizeReportError::InvalidWasm)
+    }
+    let mut reader = Reader::new(&wasm[WASM_HEADER.len()..]);
+    let mut imported_functions = 0;
+    let mut sizes = Vec::new();
+    let mut names = BTreeMap::new();
+    while !reader.is_empty() {
+        let id = reader.byte()?;
+        let len = reader.leb128()?;
+        let mut section = Reader::new(reader.bytes(len)?);
+        match id {
+            // Custom section.
+            0 => {
+                if section.name()? == "name" {
+                    names = parse_function_names(section)?;
+                }
+            }
+            // Import section.
+            2 => {
+                for _ in 0..section.leb128()? {
+                    section.name()?;
+                    section.name()?;
+                    match section.byte()? {
+                        // Function import with its type index.
+                        0x00 => {
+                            section.leb128()?;
+                            imported_functions += 1;
+                        }
+                        // Table import with its element type and limits.
+                        0x01 => {
+                            section.byte()?;
+                            section.limits()?;
+                        }
+                        // Memory import with its limits.
+                        0x02 => section.limits()?,
+                        // Global import with its value type and mutability.
+                        0x03 => {
+                            section.bytes(2)?;
+                        }
+                        _ => return Err(SizeReportError::InvalidWasm),
+                    }
+                }
+            }
+            // Code section.
+            10 => {
+                for _ in 0..section.leb128()? {
+                    let size = section.leb128()?;
+                    section.bytes(size)?;
+                    sizes.push(size);
+                }
+            }
+            _ => (),
+        }
+    }
+    if names.is_empty() {
+        return Err(SizeReportError::MissingNames)
+    }
+    Ok(sizes
+        .into_iter()
+        .enumerate()
+        .map(|(n, size)| {
+            let index = imported_functions + n;
+            let name = names
+                .remove(&index)
+                .unwrap_or_else(|| format!("<function {}>", index));
+            (name, size)
+        })
+        .collect())
+}
+
+/// Returns the function names of the Wasm `name` custom section.
+fn parse_function_names(
+    mut section: Reader,
+) -> Result<BTreeMap<usize, String>, SizeReportError> {
+    const FUNCTION_NAMES: u8 = 1;
+    let mut names = BTreeMap::new();
+    while !section.is_empty() {
+        let id = section.byte()?;
+        let len = section.leb128()?;
+        let mut subsection = Reader::new(section.bytes(len)?);
+        if id == FUNCTION_NAMES {
+            for _ in 0..subsection.leb128()? {
+                let index = subsection.leb128()?;
+                let name = subsection.name()?;
+                names.insert(index, name.to_string());
+            }
+        }
+    }
+    Ok(names)
+}
+
+/// Reads the primitives of the Wasm binary format.
+struct Reader<'a> {
+    /// The bytes that have not yet been read.
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Creates a reader for the given bytes.
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns `true` if all bytes have been read.
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Reads the given amount of bytes.
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], SizeReportError> {
+        if len > self.bytes.len() {
+            return Err(SizeReportError::InvalidWasm)
+        }
+        let (bytes, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(bytes)
+    }
+
+    /// Reads a single byte.
+    fn byte(&mut self) -> Result<u8, SizeReportError> {
+        self.bytes(1).map(|bytes| bytes[0])
+    }
+
+    /// Reads an unsigned LEB128 encoded integer of at most 32 bits.
+    fn leb128(&mut self) -> Result<usize, SizeReportError> {
+        let mut value = 0usize;
+        for shift in (0..35).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7F) as usize) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value)
+            }
+        }
+        Err(SizeReportError::InvalidWasm)
+    }
+
+    /// Reads a length prefixed UTF-8 encoded name.
+    fn name(&mut self) -> Result<&'a str, SizeReportError> {
+        let len = self.leb128()?;
+        let bytes = self.bytes(len)?;
+        core::str::from_utf8(bytes).map_err(|_| SizeReportError::InvalidWasm)
+    }
+
+    /// Reads the limits of a table or memory.
+    fn limits(&mut self) -> Result<(), SizeReportError> {
+        let has_max = self.byte()? == 0x01;
+        self.leb128()?;
+        if has_max {
+            self.leb128()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes an unsigned LEB128 integer.
+    fn leb128(mut value: usize) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+            if value == 0 {
+                encoded.push(byte);
+                return encoded
+            }
+            encoded.push(byte | 0x80);
+        }
+    }
+
+    /// Encodes a length prefixed name.
+    fn name(name: &str) -> Vec<u8> {
+        let mut encoded = leb128(name.len());
+        encoded.extend(name.as_bytes());
+        encoded
+    }
+
+    /// Encodes a section with the given identifier.
+    fn section(id: u8, payload: Vec<u8>) -> Vec<u8> {
+        let mut encoded = vec![id];
+        encoded.extend(leb128(payload.len()));
+        encoded.extend(payload);
+        encoded
+    }
+
+    /// Returns a Wasm blob with one imported and two defined functions.
+    fn wasm(names: &[&str]) -> Vec<u8> {
+        let mut wasm = vec![0x00, 0x61, 0x73, 0x6D, 0x01, 0x00, 0x00, 0x00];
+        let mut imports = vec![1];
+        imports.extend(name("seal0"));
+        imports.extend(name("seal_input"));
+        imports.extend(&[0x00, 0x00]);
+        wasm.extend(section(2, imports));
+        wasm.extend(section(10, vec![2, 3, 1, 2, 3, 5, 1, 2, 3, 4, 5]));
+        let mut function_names = vec![names.len() as u8];
+        for (n, function_name) in names.iter().enumerate() {
+            function_names.push(n as u8 + 1);
+            function_names.extend(name(function_name));
+        }
+        let mut custom = name("name");
+        custom.extend(section(1, function_names));
+        wasm.extend(section(0, custom));
+        wasm
+    }
+
+    fn contract() -> ir::Contract {
+        ir::Contract::new(
+            Default::default(),
+            quote::quote! {
+                mod flipper {
+                    #[ink(storage)]
+                    pub struct Flipper {
+                        value: bool,
+                    }
+
+                    impl Flipper {
+                        #[ink(constructor)]
+                        pub fn new() -> Self {
+                            Self { value: false }
+                        }
+
+                        #[ink(message)]
+                        pub fn flip(&mut self) {
+                            self.value = !self.value;
+                        }
+                    }
+                }
+            },
+        )
+        .expect("encountered invalid ink! contract")
+    }
+
+    #[test]
+    fn size_report_works() {
+        let report = SizeReport::new(
+            &contract(),
+            &wasm(&[
+                "flipper::flipper::Flipper::flip::{{closure}}",
+                "<flipper::flipper::Flipper as ink_storage::traits::spread::SpreadLayout>::pull_spread",
+            ]),
+        )
+        .expect("encountered invalid size report");
+        assert_eq!(
+            report
+                .items()
+                .iter()
+                .map(|item| (item.kind(), item.name(), item.size()))
+                .collect::<Vec<_>>(),
+            vec![
+                (ItemKind::Storage, "Flipper", 5),
+                (ItemKind::Message, "Flipper::flip", 3),
+            ]
+        );
+        assert_eq!(report.messages().count(), 1);
+        assert_eq!(report.total_size(), 8);
+    }
+
+    #[test]
+    fn invalid_or_unnamed_wasm_fails() {
+        assert_eq!(
+            SizeReport::new(&contract(), &wasm(&[])),
+            Err(SizeReportError::MissingNames)
+        );
+        assert_eq!(
+            SizeReport::new(&contract(), b"\0asm"),
+            Err(SizeReportError::InvalidWasm)
+        );
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::{
+    Ident,
+    TokenStream as TokenStream2,
+};
+use std::collections::HashSet;
+use syn::{
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    punctuated::Punctuated,
+    Token,
+};
+
+/// The input of the `addresses!` macro.
+///
+/// Defines the addresses of well known accounts for every network a contract
+/// is deployed to, e.g.
+///
+/// ```text
+/// addresses! {
+///     mainnet: { DEX: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY" },
+///     testnet: { DEX: "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty" },
+/// }
+/// ```
+pub struct AddressBook {
+    networks: Vec<Network>,
+}
+
+/// The addresses of a single network of an address book.
+///
+/// The identifier of the network is the name of the crate feature that selects it.
+pub struct Network {
+    ident: Ident,
+    addresses: Vec<Address>,
+}
+
+/// A named address of a network.
+pub struct Address {
+    attrs: Vec<syn::Attribute>,
+    ident: Ident,
+    account: [u8; 32],
+}
+
+/// A parsed but not yet checked address.
+struct AddressDef {
+    attrs: Vec<syn::Attribute>,
+    ident: Ident,
+    address: syn::LitStr,
+}
+
+/// A parsed but not yet checked network.
+struct NetworkDef {
+    ident: Ident,
+    addresses: Punctuated<AddressDef, Token![,]>,
+}
+
+impl Parse for AddressDef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let ident = input.parse::<Ident>()?;
+        input.parse::<Token![:]>()?;
+        let address = input.parse::<syn::LitStr>()?;
+        Ok(Self {
+            attrs,
+            ident,
+            address,
+        })
+    }
+}
+
+impl Parse for NetworkDef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        input.parse::<Token![:]>()?;
+        let content;
+        syn::braced!(content in input);
+        let addresses = content.parse_terminated(AddressDef::parse)?;
+        Ok(Self { ident, addresses })
+    }
+}
+
+impl AddressBook {
+    /// Returns `Ok` if the input is a valid address book.
+    ///
+    /// # Errors
+    ///
+    /// - If the address book does not define any network.
+    /// - If a network or an address of a network is defined twice.
+    /// - If an address is not a valid SS58 address.
+    /// - If the networks do not define the same named addresses.
+    pub fn new(input: TokenStream2) -> Result<Self, syn::Error> {
+        let network_defs = syn::parse::Parser::parse2(
+            Punctuated::<NetworkDef, Token![,]>::parse_terminated,
+            input,
+        )?;
+        if network_defs.is_empty() {
+            return Err(format_err!(
+                proc_macro2::Span::call_site(),
+                "expected at least one network in ink! address book",
+            ))
+        }
+        let mut network_idents = HashSet::new();
+        let mut networks = Vec::new();
+        for network_def in network_defs {
+            if !network_idents.insert(network_def.ident.clone()) {
+                return Err(format_err_spanned!(
+                    network_def.ident,
+                    "encountered duplicate ink! address book network `{}`",
+                    network_def.ident,
+                ))
+            }
+            networks.push(Network::try_from_def(network_def)?);
+        }
+        let first = &networks[0];
+        for network in &networks[1..] {
+            Self::ensure_same_addresses(first, network)?;
+            Self::ensure_same_addresses(network, first)?;
+        }
+        Ok(Self { networks })
+    }
+
+    /// Returns `Ok` if all addresses of `lhs` are also defined by `rhs`.
+    fn ensure_same_addresses(lhs: &Network, rhs: &Network) -> Result<(), syn::Error> {
+        for address in &lhs.addresses {
+            if !rhs
+                .addresses
+                .iter()
+                .any(|other| other.ident == address.ident)
+            {
+                return Err(format_err_spanned!(
+                    rhs.ident,
+                    "ink! address `{}` of network `{}` is missing in network `{}`",
+                    address.ident,
+                    lhs.ident,
+                    rhs.ident,
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the networks of the address book.
+    pub fn networks(&self) -> &[Network] {
+        &self.networks
+    }
+}
+
+impl Network {
+    /// Checks the addresses of the parsed network.
+    fn try_from_def(network_def: NetworkDef) -> Result<Self, syn::Error> {
+        let mut address_idents = HashSet::new();
+        let mut addresses = Vec::new();
+        for address_def in network_def.addresses {
+            if !address_idents.insert(address_def.ident.clone()) {
+                return Err(format_err_spanned!(
+                    address_def.ident,
+                    "encountered duplicate ink! address `{}` in network `{}`",
+                    address_def.ident,
+                    network_def.ident,
+                ))
+            }
+            let account = ink_primitives::ss58::decode(&address_def.address.value())
+                .map_err(|error| format_err_spanned!(address_def.address, "{}", error))?;
+            addresses.push(Address {
+                attrs: address_def.attrs,
+                ident: address_def.ident,
+                account,
+            });
+        }
+        Ok(Self {
+            ident: network_def.ident,
+            addresses,
+        })
+    }
+
+    /// Returns the identifier of the network.
+    ///
+    /// This is the name of the crate feature selecting the network.
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Returns the addresses of the network.
+    pub fn addresses(&self) -> &[Address] {
+        &self.addresses
+    }
+}
+
+impl Address {
+    /// Returns the attributes of the address, e.g. its documentation.
+    pub fn attrs(&self) -> &[syn::Attribute] {
+        &self.attrs
+    }
+
+    /// Returns the identifier of the address.
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Returns the bytes of the account the address refers to.
+    pub fn account(&self) -> &[u8; 32] {
+        &self.account
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    /// The public key of the well known `//Alice` development account.
+    const ALICE: [u8; 32] = [
+        0xD4, 0x35, 0x93, 0xC7, 0x15, 0xFD, 0xD3, 0x1C, 0x61, 0x14, 0x1A, 0xBD, 0x04,
+        0xA9, 0x9F, 0xD6, 0x82, 0x2C, 0x85, 0x58, 0x85, 0x4C, 0xCD, 0xE3, 0x9A, 0x56,
+        0x84, 0xE7, 0xA5, 0x6D, 0xA2, 0x7D,
+    ];
+
+    fn assert_address_book_fails(input: TokenStream2, expected: &str) {
+        assert_eq!(
+            AddressBook::new(input)
+                .map(|_| ())
+                .map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn address_book_works() {
+        let address_book = AddressBook::new(quote! {
+            mainnet: {
+                /// The decentralized exchange.
+                DEX: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+            },
+            testnet: { DEX: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY" },
+        })
+        .unwrap();
+        let networks = address_book.networks();
+        assert_eq!(networks.len(), 2);
+        assert_eq!(networks[0].ident(), "mainnet");
+        assert_eq!(networks[0].addresses()[0].attrs().len(), 1);
+        assert_eq!(networks[1].addresses()[0].ident(), "DEX");
+        assert_eq!(networks[1].addresses()[0].account(), &ALICE);
+    }
+
+    #[test]
+    fn invalid_address_book_fails() {
+        assert_address_book_fails(
+            quote! {},
+            "expected at least one network in ink! address book",
+        );
+        assert_address_book_fails(
+            quote! { mainnet: {}, mainnet: {} },
+            "encountered duplicate ink! address book network `mainnet`",
+        );
+        assert_address_book_fails(
+            quote! {
+                mainnet: {
+                    DEX: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+                    DEX: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+                },
+            },
+            "encountered duplicate ink! address `DEX` in network `mainnet`",
+        );
+        assert_address_book_fails(
+            quote! { mainnet: { DEX: "0x42" } },
+            "encountered invalid base58 in SS58 address",
+        );
+        assert_address_book_fails(
+            quote! {
+                mainnet: { DEX: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY" },
+                testnet: {},
+            },
+            "ink! address `DEX` of network `mainnet` is missing in network `testnet`",
+        );
+    }
+}
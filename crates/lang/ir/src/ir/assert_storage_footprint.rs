@@ -0,0 +1,116 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    Token,
+};
+
+/// The input of the `assert_storage_footprint!` macro.
+///
+/// Refers to an ink! storage struct and its budget of storage cells,
+/// e.g. `assert_storage_footprint!(MyStorage, max_cells = 4)`.
+pub struct AssertStorageFootprint {
+    /// The type of the ink! storage struct.
+    storage: syn::Type,
+    /// The maximum number of storage cells the storage struct may occupy.
+    max_cells: u64,
+}
+
+impl Parse for AssertStorageFootprint {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let storage = input.parse::<syn::Type>()?;
+        input.parse::<Token![,]>()?;
+        let name = input.parse::<syn::Ident>()?;
+        if name != "max_cells" {
+            return Err(format_err_spanned!(
+                name,
+                "expected the `max_cells` argument of the storage footprint assertion"
+            ))
+        }
+        input.parse::<Token![=]>()?;
+        let lit_int = input.parse::<syn::LitInt>()?;
+        let max_cells = lit_int.base10_parse::<u64>()?;
+        input.parse::<Option<Token![,]>>()?;
+        if !input.is_empty() {
+            return Err(
+                input.error("expected an ink! storage struct followed by `max_cells = N`")
+            )
+        }
+        Ok(Self { storage, max_cells })
+    }
+}
+
+impl AssertStorageFootprint {
+    /// Returns `Ok` if the input refers to a type and its budget of storage cells.
+    ///
+    /// # Errors
+    ///
+    /// - If the input is not a type followed by `max_cells = N`.
+    /// - If `N` is not an unsigned 64-bit integer literal.
+    pub fn new(input: TokenStream2) -> Result<Self, syn::Error> {
+        syn::parse2::<AssertStorageFootprint>(input)
+    }
+
+    /// Returns the type of the ink! storage struct.
+    pub fn storage(&self) -> &syn::Type {
+        &self.storage
+    }
+
+    /// Returns the maximum number of storage cells the storage struct may occupy.
+    pub fn max_cells(&self) -> u64 {
+        self.max_cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assert_storage_footprint_works() {
+        let assertion = AssertStorageFootprint::new(quote::quote! {
+            my_contract::MyStorage, max_cells = 4,
+        })
+        .unwrap();
+        assert_eq!(
+            assertion.storage(),
+            &syn::parse_quote! { my_contract::MyStorage }
+        );
+        assert_eq!(assertion.max_cells(), 4);
+    }
+
+    #[test]
+    fn assert_storage_footprint_invalid_input_fails() {
+        assert!(AssertStorageFootprint::new(quote::quote! {}).is_err());
+        assert!(AssertStorageFootprint::new(quote::quote! { MyStorage }).is_err());
+        assert!(AssertStorageFootprint::new(quote::quote! { MyStorage, 4 }).is_err());
+        assert!(
+            AssertStorageFootprint::new(quote::quote! { MyStorage, max_keys = 4 })
+                .is_err()
+        );
+        assert!(
+            AssertStorageFootprint::new(quote::quote! { MyStorage, max_cells = -1 })
+                .is_err()
+        );
+        assert!(AssertStorageFootprint::new(
+            quote::quote! { MyStorage, max_cells = 4, other = 5 }
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ir;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    Token,
+};
+
+/// The input of the `assert_trait_impl!` macro.
+///
+/// Refers to an ink! smart contract and an ink! trait definition,
+/// e.g. `assert_trait_impl!(MyContract, my_traits::Erc20)`.
+pub struct AssertTraitImpl {
+    /// The storage type of the ink! smart contract.
+    contract: syn::Type,
+    /// The path to the ink! trait definition.
+    trait_path: syn::Path,
+}
+
+impl Parse for AssertTraitImpl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let contract = input.parse::<syn::Type>()?;
+        input.parse::<Token![,]>()?;
+        let trait_path = input.parse::<syn::Path>()?;
+        input.parse::<Option<Token![,]>>()?;
+        if !input.is_empty() {
+            return Err(
+                input.error("expected an ink! smart contract and an ink! trait path")
+            )
+        }
+        Ok(Self {
+            contract,
+            trait_path,
+        })
+    }
+}
+
+impl AssertTraitImpl {
+    /// Returns `Ok` if the input refers to an ink! smart contract and an ink! trait definition.
+    ///
+    /// # Errors
+    ///
+    /// - If the input is not a type followed by an ink! trait path.
+    /// - If the ink! trait path has generic arguments.
+    pub fn new(input: TokenStream2) -> Result<Self, syn::Error> {
+        let assert_trait_impl = syn::parse2::<AssertTraitImpl>(input)?;
+        let last = assert_trait_impl
+            .trait_path
+            .segments
+            .last()
+            .expect("encountered empty ink! trait path");
+        if !last.arguments.is_empty() {
+            return Err(format_err_spanned!(
+                last.arguments,
+                "ink! trait definitions cannot have generic arguments"
+            ))
+        }
+        Ok(assert_trait_impl)
+    }
+
+    /// Returns the storage type of the ink! smart contract.
+    pub fn contract(&self) -> &syn::Type {
+        &self.contract
+    }
+
+    /// Returns the path to the ink! trait definition.
+    pub fn trait_path(&self) -> &syn::Path {
+        &self.trait_path
+    }
+
+    /// Returns the path to the assertion type of the ink! trait definition.
+    pub fn assert_impl_path(&self) -> syn::Path {
+        let mut path = self.trait_path.clone();
+        let last = path
+            .segments
+            .last_mut()
+            .expect("encountered empty ink! trait path");
+        last.ident = ir::InkTrait::assert_impl_ident(&last.ident);
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_impl_path_of(input: TokenStream2) -> String {
+        let path = AssertTraitImpl::new(input).unwrap().assert_impl_path();
+        let mut path = quote::quote! { #path }.to_string();
+        path.retain(|c| !c.is_whitespace());
+        path
+    }
+
+    #[test]
+    fn assert_trait_impl_works() {
+        assert_eq!(
+            assert_impl_path_of(quote::quote! { MyContract, Erc20 }),
+            "__ink_Erc20AssertImpl",
+        );
+        assert_eq!(
+            assert_impl_path_of(
+                quote::quote! { my_contract::MyContract, ::my_traits::Erc20, }
+            ),
+            "::my_traits::__ink_Erc20AssertImpl",
+        );
+    }
+
+    #[test]
+    fn assert_trait_impl_invalid_input_fails() {
+        assert!(AssertTraitImpl::new(quote::quote! {}).is_err());
+        assert!(AssertTraitImpl::new(quote::quote! { MyContract }).is_err());
+        assert!(AssertTraitImpl::new(quote::quote! { MyContract, Erc20<u8> }).is_err());
+        assert!(
+            AssertTraitImpl::new(quote::quote! { MyContract, Erc20, Other }).is_err()
+        );
+    }
+}
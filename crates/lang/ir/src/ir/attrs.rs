@@ -127,7 +127,7 @@ impl InkAttribute {
     pub fn ensure_first(&self, expected: &AttributeArgKind) -> Result<(), syn::Error> {
         if &self.first().kind != expected {
             return Err(format_err!(
-                self.span(),
+                self.first().span(),
                 "unexpected first ink! attribute argument",
             ))
         }
@@ -230,12 +230,68 @@ impl InkAttribute {
         })
     }
 
+    /// Returns the name of the role required by the ink! attribute if any.
+    pub fn only_role(&self) -> Option<String> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArgKind::OnlyRole(role) = arg.kind() {
+                return Some(role.clone())
+            }
+            None
+        })
+    }
+
+    /// Returns the permissions documented by the ink! attribute.
+    pub fn requires(&self) -> Vec<String> {
+        self.args()
+            .filter_map(|arg| {
+                if let ir::AttributeArgKind::Requires(permission) = arg.kind() {
+                    return Some(permission.clone())
+                }
+                None
+            })
+            .collect()
+    }
+
+    /// Returns the throttle rate of the ink! attribute if any.
+    pub fn throttle(&self) -> Option<ThrottleRate> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArgKind::Throttle(rate) = arg.kind() {
+                return Some(*rate)
+            }
+            None
+        })
+    }
+
     /// Returns `true` if the ink! attribute contains the `payable` argument.
     pub fn is_payable(&self) -> bool {
         self.args()
             .any(|arg| matches!(arg.kind(), AttributeArgKind::Payable))
     }
 
+    /// Returns `true` if the ink! attribute contains the `non_reentrant` argument.
+    pub fn is_non_reentrant(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArgKind::NonReentrant))
+    }
+
+    /// Returns `true` if the ink! attribute contains the `when_not_paused` argument.
+    pub fn is_when_not_paused(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArgKind::WhenNotPaused))
+    }
+
+    /// Returns `true` if the ink! attribute contains the `only_owner` argument.
+    pub fn is_only_owner(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArgKind::OnlyOwner))
+    }
+
+    /// Returns `true` if the ink! attribute contains the `default` argument.
+    pub fn is_default(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArgKind::Default))
+    }
+
     /// Returns `true` if the ink! attribute contains the `anonymous` argument.
     pub fn is_anonymous(&self) -> bool {
         self.args()
@@ -287,6 +343,11 @@ pub enum AttributeArgKind {
     ///
     /// Applied on fields of ink! event types to indicate that they are topics.
     Topic,
+    /// `#[ink(mixin)]`
+    ///
+    /// Applied on fields of ink! storage structs in order to embed the ink! mixin
+    /// of the field type into the contract.
+    Mixin,
     /// `#[ink(message)]`
     ///
     /// Applied on `&self` or `&mut self` methods to flag them for being an ink!
@@ -297,11 +358,46 @@ pub enum AttributeArgKind {
     /// Applied on inherent methods returning `Self` to flag them for being ink!
     /// exported contract constructors.
     Constructor,
+    /// `#[ink(before_message)]`
+    ///
+    /// Applied on inherent methods of the ink! storage struct in order to invoke
+    /// them before every ink! message of the contract.
+    BeforeMessage,
+    /// `#[ink(after_message)]`
+    ///
+    /// Applied on inherent methods of the ink! storage struct in order to invoke
+    /// them after every ink! message of the contract.
+    AfterMessage,
+    /// `#[ink(continuation)]`
+    ///
+    /// Applied on inherent methods of the ink! storage struct in order to resume
+    /// suspended multi-step workflows of the contract with them.
+    Continuation,
     /// `#[ink(payable)]`
     ///
     /// Applied on ink! constructors or messages in order to specify that they
     /// can receive funds from callers.
     Payable,
+    /// `#[ink(non_reentrant)]`
+    ///
+    /// Applied on ink! messages in order to reject calls to them while
+    /// another call to a non-reentrant message of the contract is in progress.
+    NonReentrant,
+    /// `#[ink(when_not_paused)]`
+    ///
+    /// Applied on ink! messages in order to reject calls to them while
+    /// the contract is paused.
+    WhenNotPaused,
+    /// `#[ink(only_owner)]`
+    ///
+    /// Applied on ink! messages of ownable ink! smart contracts in order to
+    /// allow only the owner of the contract to call them.
+    OnlyOwner,
+    /// `#[ink(default)]`
+    ///
+    /// Applied on ink! constructors in order to flag them as the default
+    /// constructor for off-chain tooling such as deployment UIs.
+    Default,
     /// `#[ink(selector = "0xDEADBEEF")]`
     ///
     /// Applied on ink! constructors or messages to manually control their
@@ -312,6 +408,22 @@ pub enum AttributeArgKind {
     /// Applied on ink! trait implementation blocks to disambiguate other trait
     /// implementation blocks with equal names.
     Namespace(Namespace),
+    /// `#[ink(only_role = "ADMIN")]`
+    ///
+    /// Applied on ink! messages in order to allow only callers that have been
+    /// granted the role with the given name to call them.
+    OnlyRole(String),
+    /// `#[ink(requires = "owner")]`
+    ///
+    /// Applied on ink! messages in order to document a permission required by
+    /// callers in the contract metadata. Unlike `only_role` this is not checked
+    /// by the ink! dispatch.
+    Requires(String),
+    /// `#[ink(throttle = "1/block")]`
+    ///
+    /// Applied on ink! messages in order to limit how often every caller is
+    /// allowed to call them within a number of blocks.
+    Throttle(ThrottleRate),
     /// `#[ink(impl)]`
     ///
     /// This attribute supports a niche case that is rarely needed.
@@ -333,13 +445,24 @@ impl core::fmt::Display for AttributeArgKind {
             Self::Event => write!(f, "event"),
             Self::Anonymous => write!(f, "anonymous"),
             Self::Topic => write!(f, "topic"),
+            Self::Mixin => write!(f, "mixin"),
             Self::Message => write!(f, "message"),
             Self::Constructor => write!(f, "constructor"),
+            Self::BeforeMessage => write!(f, "before_message"),
+            Self::AfterMessage => write!(f, "after_message"),
+            Self::Continuation => write!(f, "continuation"),
             Self::Payable => write!(f, "payable"),
+            Self::NonReentrant => write!(f, "non_reentrant"),
+            Self::WhenNotPaused => write!(f, "when_not_paused"),
+            Self::OnlyOwner => write!(f, "only_owner"),
+            Self::Default => write!(f, "default"),
             Self::Selector(selector) => write!(f, "selector = {:?}", selector.as_bytes()),
             Self::Namespace(namespace) => {
                 write!(f, "namespace = {:?}", namespace.as_bytes())
             }
+            Self::OnlyRole(role) => write!(f, "only_role = {:?}", role),
+            Self::Requires(permission) => write!(f, "requires = {:?}", permission),
+            Self::Throttle(rate) => write!(f, "throttle = {:?}", rate.to_string()),
             Self::Implementation => write!(f, "impl"),
         }
     }
@@ -365,6 +488,55 @@ impl Namespace {
     }
 }
 
+/// The rate at which every caller may call a throttled ink! message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ThrottleRate {
+    /// The number of calls allowed within the period.
+    calls: u32,
+    /// The length of the period in blocks.
+    blocks: u32,
+}
+
+impl ThrottleRate {
+    /// Parses a rate of the form `N/block` or `N/M blocks`.
+    ///
+    /// Returns `None` if the rate is malformed or if any of its numbers is zero.
+    fn parse(rate: &str) -> Option<Self> {
+        let (calls, period) = rate.split_at(rate.find('/')?);
+        let period = period[1..].trim();
+        let blocks = match period.strip_suffix("blocks") {
+            Some(blocks) => blocks.trim().parse::<u32>().ok()?,
+            None if period == "block" => 1,
+            None => return None,
+        };
+        let calls = calls.trim().parse::<u32>().ok()?;
+        if calls == 0 || blocks == 0 {
+            return None
+        }
+        Some(Self { calls, blocks })
+    }
+
+    /// Returns the number of calls allowed within the period.
+    pub fn calls(&self) -> u32 {
+        self.calls
+    }
+
+    /// Returns the length of the period in blocks.
+    pub fn blocks(&self) -> u32 {
+        self.blocks
+    }
+}
+
+impl core::fmt::Display for ThrottleRate {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        if self.blocks == 1 {
+            write!(f, "{}/block", self.calls)
+        } else {
+            write!(f, "{}/{} blocks", self.calls, self.blocks)
+        }
+    }
+}
+
 /// Returns `true` if the given iterator yields at least one attribute of the form
 /// `#[ink(..)]` or `#[ink]`.
 ///
@@ -553,12 +725,17 @@ impl InkAttribute {
     where
         P: FnMut(&'a ir::AttributeArg) -> bool,
     {
+        use crate::error::ExtError as _;
         for arg in self.args() {
             if is_conflicting(arg) {
                 return Err(format_err!(
                     arg.span(),
                     "encountered conflicting ink! attribute argument",
-                ))
+                )
+                .into_combine(format_err!(
+                    self.first().span(),
+                    "conflicts with this ink! attribute argument",
+                )))
             }
         }
         Ok(())
@@ -624,6 +801,51 @@ impl TryFrom<syn::NestedMeta> for AttributeArg {
                                 })
                             }
                         }
+                        if name_value.path.is_ident("only_role") {
+                            if let syn::Lit::Str(lit_str) = &name_value.lit {
+                                let role = lit_str.value();
+                                if role.is_empty() {
+                                    return Err(format_err_spanned!(
+                                        meta,
+                                        "encountered empty role name for ink! attribute argument",
+                                    ))
+                                }
+                                return Ok(AttributeArg {
+                                    ast: meta,
+                                    kind: AttributeArgKind::OnlyRole(role),
+                                })
+                            }
+                        }
+                        if name_value.path.is_ident("requires") {
+                            if let syn::Lit::Str(lit_str) = &name_value.lit {
+                                let permission = lit_str.value();
+                                if permission.is_empty() {
+                                    return Err(format_err_spanned!(
+                                        meta,
+                                        "encountered empty permission for ink! attribute argument",
+                                    ))
+                                }
+                                return Ok(AttributeArg {
+                                    ast: meta,
+                                    kind: AttributeArgKind::Requires(permission),
+                                })
+                            }
+                        }
+                        if name_value.path.is_ident("throttle") {
+                            if let syn::Lit::Str(lit_str) = &name_value.lit {
+                                let rate = ThrottleRate::parse(&lit_str.value())
+                                    .ok_or_else(|| {
+                                        format_err_spanned!(
+                                            meta,
+                                            "invalid throttle - expected a non-zero rate such as `throttle = \"1/block\"` or `throttle = \"3/10 blocks\"`",
+                                        )
+                                    })?;
+                                return Ok(AttributeArg {
+                                    ast: meta,
+                                    kind: AttributeArgKind::Throttle(rate),
+                                })
+                            }
+                        }
                         Err(format_err_spanned!(
                             meta,
                             "unknown ink! attribute argument (name = value)",
@@ -636,10 +858,28 @@ impl TryFrom<syn::NestedMeta> for AttributeArg {
                                     "storage" => Some(AttributeArgKind::Storage),
                                     "message" => Some(AttributeArgKind::Message),
                                     "constructor" => Some(AttributeArgKind::Constructor),
+                                    "before_message" => {
+                                        Some(AttributeArgKind::BeforeMessage)
+                                    }
+                                    "after_message" => {
+                                        Some(AttributeArgKind::AfterMessage)
+                                    }
+                                    "continuation" => {
+                                        Some(AttributeArgKind::Continuation)
+                                    }
                                     "event" => Some(AttributeArgKind::Event),
                                     "anonymous" => Some(AttributeArgKind::Anonymous),
                                     "topic" => Some(AttributeArgKind::Topic),
+                                    "mixin" => Some(AttributeArgKind::Mixin),
                                     "payable" => Some(AttributeArgKind::Payable),
+                                    "non_reentrant" => {
+                                        Some(AttributeArgKind::NonReentrant)
+                                    }
+                                    "when_not_paused" => {
+                                        Some(AttributeArgKind::WhenNotPaused)
+                                    }
+                                    "only_owner" => Some(AttributeArgKind::OnlyOwner),
+                                    "default" => Some(AttributeArgKind::Default),
                                     "impl" => Some(AttributeArgKind::Implementation),
                                     _ => None,
                                 }
@@ -848,6 +1088,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn only_role_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(only_role = "ADMIN")]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArgKind::OnlyRole(
+                "ADMIN".to_string(),
+            )])),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(only_role = "")]
+            },
+            Err("encountered empty role name for ink! attribute argument"),
+        );
+    }
+
+    #[test]
+    fn requires_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(requires = "owner", requires = "kyc")]
+            },
+            Ok(test::Attribute::Ink(vec![
+                AttributeArgKind::Requires("owner".to_string()),
+                AttributeArgKind::Requires("kyc".to_string()),
+            ])),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(requires = "")]
+            },
+            Err("encountered empty permission for ink! attribute argument"),
+        );
+    }
+
+    #[test]
+    fn throttle_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(throttle = "1/block")]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArgKind::Throttle(
+                ThrottleRate {
+                    calls: 1,
+                    blocks: 1,
+                },
+            )])),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(throttle = "3/10 blocks")]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArgKind::Throttle(
+                ThrottleRate {
+                    calls: 3,
+                    blocks: 10,
+                },
+            )])),
+        );
+        for invalid in &["0/block", "1/0 blocks", "1/minute", "1", "x/block"] {
+            assert_attribute_try_from(
+                syn::parse_quote! {
+                    #[ink(throttle = #invalid)]
+                },
+                Err("invalid throttle - expected a non-zero rate such as `throttle = \"1/block\"` or `throttle = \"3/10 blocks\"`"),
+            );
+        }
+    }
+
     #[test]
     fn compound_mixed_works() {
         assert_attribute_try_from(
@@ -871,9 +1182,17 @@ mod tests {
                     storage,
                     message,
                     constructor,
+                    before_message,
+                    after_message,
+                    continuation,
                     event,
                     topic,
+                    mixin,
                     payable,
+                    non_reentrant,
+                    when_not_paused,
+                    only_owner,
+                    default,
                     impl,
                 )]
             },
@@ -881,9 +1200,17 @@ mod tests {
                 AttributeArgKind::Storage,
                 AttributeArgKind::Message,
                 AttributeArgKind::Constructor,
+                AttributeArgKind::BeforeMessage,
+                AttributeArgKind::AfterMessage,
+                AttributeArgKind::Continuation,
                 AttributeArgKind::Event,
                 AttributeArgKind::Topic,
+                AttributeArgKind::Mixin,
                 AttributeArgKind::Payable,
+                AttributeArgKind::NonReentrant,
+                AttributeArgKind::WhenNotPaused,
+                AttributeArgKind::OnlyOwner,
+                AttributeArgKind::Default,
                 AttributeArgKind::Implementation,
             ])),
         );
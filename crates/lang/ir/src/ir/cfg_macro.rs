@@ -0,0 +1,208 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Expansion of `ink::cfg!` invocations within an ink! smart contract.
+
+use super::Config;
+use proc_macro2::{
+    Delimiter,
+    Group,
+    Spacing,
+    TokenStream as TokenStream2,
+    TokenTree,
+};
+use quote::quote_spanned;
+use syn::spanned::Spanned as _;
+
+/// Replaces all `ink::cfg!(feature = "name")` invocations within the tokens.
+///
+/// Every invocation is replaced by `::core::cfg!(feature = "name")` which is
+/// `true` if the Cargo feature is enabled for the crate and `false` otherwise
+/// so that it can be used within `if` conditions as well as in constant
+/// expressions. This way contract features are selected the same way as the
+/// networks of `ink::addresses!`.
+/// The `ink_lang::cfg!` path is supported as well.
+///
+/// # Errors
+///
+/// - If an invocation does not have the form `ink::cfg!(feature = "name")`.
+/// - If the queried feature is not declared as contract feature in the ink!
+///   configuration.
+pub fn expand_cfg_macros(
+    input: TokenStream2,
+    config: &Config,
+) -> Result<TokenStream2, syn::Error> {
+    let tokens = input.into_iter().collect::<Vec<_>>();
+    let mut output = Vec::with_capacity(tokens.len());
+    let mut n = 0;
+    while n < tokens.len() {
+        if let Some(args) =
+            cfg_invocation_args(&tokens[n..]).filter(|_| !ends_within_path(&output))
+        {
+            if ends_with_path_sep(&output) {
+                output.truncate(output.len() - 2);
+            }
+            ensure_contract_feature(args, config)?;
+            output.extend(quote_spanned!(args.span()=> ::core::cfg!));
+            output.push(TokenTree::Group(args.clone()));
+            n += CFG_INVOCATION_LEN;
+            continue
+        }
+        match &tokens[n] {
+            TokenTree::Group(group) => {
+                let mut expanded = Group::new(
+                    group.delimiter(),
+                    expand_cfg_macros(group.stream(), config)?,
+                );
+                expanded.set_span(group.span());
+                output.push(TokenTree::Group(expanded))
+            }
+            token => output.push(token.clone()),
+        }
+        n += 1;
+    }
+    Ok(output.into_iter().collect())
+}
+
+/// The number of tokens of an `ink::cfg!(..)` invocation.
+const CFG_INVOCATION_LEN: usize = 6;
+
+/// Returns the arguments of the `ink::cfg!(..)` invocation at the start of the tokens.
+fn cfg_invocation_args(tokens: &[TokenTree]) -> Option<&Group> {
+    match tokens {
+        [TokenTree::Ident(krate), TokenTree::Punct(fst), TokenTree::Punct(snd), TokenTree::Ident(name), TokenTree::Punct(bang), TokenTree::Group(args), ..]
+            if (*krate == "ink" || *krate == "ink_lang")
+                && is_path_sep(fst, snd)
+                && *name == "cfg"
+                && bang.as_char() == '!'
+                && args.delimiter() == Delimiter::Parenthesis =>
+        {
+            Some(args)
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if both punctuations form a `::` path separator.
+fn is_path_sep(fst: &proc_macro2::Punct, snd: &proc_macro2::Punct) -> bool {
+    fst.as_char() == ':' && fst.spacing() == Spacing::Joint && snd.as_char() == ':'
+}
+
+/// Returns `true` if the tokens end with a `::` path separator.
+fn ends_with_path_sep(tokens: &[TokenTree]) -> bool {
+    match tokens {
+        [.., TokenTree::Punct(fst), TokenTree::Punct(snd)] => is_path_sep(fst, snd),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the tokens end with a `::` that continues a path, e.g. `foo::`.
+fn ends_within_path(tokens: &[TokenTree]) -> bool {
+    match tokens {
+        [.., TokenTree::Ident(_), TokenTree::Punct(_), TokenTree::Punct(_)] => {
+            ends_with_path_sep(tokens)
+        }
+        [.., TokenTree::Punct(angle), TokenTree::Punct(_), TokenTree::Punct(_)]
+            if angle.as_char() == '>' =>
+        {
+            ends_with_path_sep(tokens)
+        }
+        _ => false,
+    }
+}
+
+/// Ensures that the arguments query a contract feature of the ink! configuration.
+fn ensure_contract_feature(args: &Group, config: &Config) -> Result<(), syn::Error> {
+    let invalid_args_err = || {
+        format_err!(
+            args.span(),
+            "expected `feature = \"name\"` as the argument of `ink::cfg!`"
+        )
+    };
+    let meta = syn::parse2::<syn::MetaNameValue>(args.stream())
+        .map_err(|_| invalid_args_err())?;
+    match &meta.lit {
+        syn::Lit::Str(name) if meta.path.is_ident("feature") => {
+            if !config.is_contract_feature(&name.value()) {
+                return Err(format_err!(
+                    name.span(),
+                    "encountered undeclared contract feature `{}`, declare it via the `features` ink! config argument",
+                    name.value(),
+                ))
+            }
+            Ok(())
+        }
+        _ => Err(invalid_args_err()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast;
+    use core::convert::TryFrom;
+    use quote::quote;
+
+    fn expand(input: TokenStream2) -> Result<String, String> {
+        let args: ast::AttributeArgs =
+            syn::parse_quote! { features = "testnet, mainnet" };
+        let config = Config::try_from(args).unwrap();
+        expand_cfg_macros(input, &config)
+            .map(|tokens| tokens.to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn expand_works() {
+        assert_eq!(
+            expand(quote! {
+                const FEE: u32 = if ink::cfg!(feature = "testnet") { 0 } else { 10 };
+                fn debug() -> bool { ::ink_lang::cfg!(feature = "mainnet") }
+            }),
+            Ok(quote! {
+                const FEE: u32 = if ::core::cfg!(feature = "testnet") { 0 } else { 10 };
+                fn debug() -> bool { ::core::cfg!(feature = "mainnet") }
+            }
+            .to_string())
+        );
+    }
+
+    #[test]
+    fn other_paths_are_not_expanded() {
+        let input = quote! {
+            let a = cfg!(feature = "testnet");
+            let b = foo::ink::cfg!(feature = "testnet");
+            let c = <T>::ink::cfg!(feature = "testnet");
+        };
+        assert_eq!(expand(input.clone()), Ok(input.to_string()));
+    }
+
+    #[test]
+    fn invalid_args_fails() {
+        let expected = Err(
+            "expected `feature = \"name\"` as the argument of `ink::cfg!`".to_string(),
+        );
+        assert_eq!(expand(quote! { ink::cfg!(testnet) }), expected);
+        assert_eq!(expand(quote! { ink::cfg!(network = "testnet") }), expected);
+        assert_eq!(expand(quote! { ink::cfg!(feature = 1) }), expected);
+    }
+
+    #[test]
+    fn undeclared_feature_fails() {
+        assert_eq!(
+            expand(quote! { ink::cfg!(feature = "devnet") }),
+            Err("encountered undeclared contract feature `devnet`, declare it via the `features` ink! config argument".to_string()),
+        );
+    }
+}
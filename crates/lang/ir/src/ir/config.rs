@@ -19,6 +19,11 @@ use crate::{
 use core::convert::TryFrom;
 use syn::spanned::Spanned;
 
+/// The largest accepted value of the `max_input_len` ink! config argument.
+///
+/// Equals the capacity of the environmental buffer into which the input is read.
+const MAX_INPUT_LEN_LIMIT: usize = 16 * 1024;
+
 /// The ink! configuration.
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct Config {
@@ -38,6 +43,71 @@ pub struct Config {
     /// be used to change the underlying environmental types of an ink! smart
     /// contract.
     env_types: Option<Environment>,
+    /// The BLAKE2 256-bit hash of the metadata of the ink! smart contract.
+    ///
+    /// If provided the hash is embedded into a Wasm custom section named
+    /// `ink_metadata_hash` so that the on-chain code can be matched with
+    /// its off-chain metadata.
+    metadata_hash: Option<[u8; 32]>,
+    /// If `true` the ink! smart contract is provided with an owner and
+    /// messages to transfer and renounce the ownership. The default is `false`.
+    ownable: Option<bool>,
+    /// If `true` all constructor and message inputs are required to have
+    /// a bounded SCALE encoded length. The default is `false`.
+    bounded_inputs: Option<bool>,
+    /// The maximum length of the input of a contract execution in bytes.
+    ///
+    /// Larger inputs are rejected by the dispatch before they are decoded.
+    /// The default is to not limit the input length.
+    max_input_len: Option<usize>,
+    /// The Cargo features of the crate that are contract features.
+    ///
+    /// Queried within the ink! smart contract via `ink::cfg!(feature = "name")`.
+    /// The enabled ones are recorded in the contract metadata.
+    features: Option<Vec<String>>,
+    /// If `true` the ink! smart contract is provided with a `supports_interface`
+    /// message reporting the ink! trait definitions it implements. The default
+    /// is `false`.
+    supports_interface: Option<bool>,
+    /// If `true` the ink! smart contract counts the dispatches of its `&mut self`
+    /// ink! messages and is provided with a `telemetry` message returning the
+    /// counters. The default is `false`.
+    telemetry: Option<bool>,
+}
+
+/// Decodes the given `0x` prefixed hex string into 32 bytes.
+///
+/// Returns `None` if the string is not a valid hex encoding of 32 bytes.
+fn decode_hash(hex: &str) -> Option<[u8; 32]> {
+    let hex = hex.strip_prefix("0x")?;
+    // Checked up front since `u8::from_str_radix` accepts a leading `+`.
+    if hex.len() != 64 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None
+    }
+    let mut hash = [0x00; 32];
+    for (n, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * n..2 * n + 2], 16).ok()?;
+    }
+    Some(hash)
+}
+
+/// Parses the given comma separated list of contract feature names.
+///
+/// Returns `None` if one of the names is empty, contains characters other than
+/// ASCII alphanumerics, `_` and `-` or if a name is given more than once.
+fn parse_features(features: &str) -> Option<Vec<String>> {
+    let mut parsed = Vec::new();
+    for feature in features.split(',').map(str::trim) {
+        let is_valid = !feature.is_empty()
+            && feature
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !is_valid || parsed.iter().any(|parsed| parsed == feature) {
+            return None
+        }
+        parsed.push(feature.to_string());
+    }
+    Some(parsed)
 }
 
 /// Return an error to notify about duplicate ink! config arguments.
@@ -65,6 +135,13 @@ impl TryFrom<ast::AttributeArgs> for Config {
         let mut dynamic_storage_allocator: Option<(bool, ast::MetaNameValue)> = None;
         let mut as_dependency: Option<(bool, ast::MetaNameValue)> = None;
         let mut env_types: Option<(Environment, ast::MetaNameValue)> = None;
+        let mut metadata_hash: Option<([u8; 32], ast::MetaNameValue)> = None;
+        let mut ownable: Option<(bool, ast::MetaNameValue)> = None;
+        let mut bounded_inputs: Option<(bool, ast::MetaNameValue)> = None;
+        let mut max_input_len: Option<(usize, ast::MetaNameValue)> = None;
+        let mut features: Option<(Vec<String>, ast::MetaNameValue)> = None;
+        let mut supports_interface: Option<(bool, ast::MetaNameValue)> = None;
+        let mut telemetry: Option<(bool, ast::MetaNameValue)> = None;
         for arg in args.into_iter() {
             if arg.name.is_ident("dynamic_storage_allocator") {
                 if let Some((_, ast)) = dynamic_storage_allocator {
@@ -78,7 +155,7 @@ impl TryFrom<ast::AttributeArgs> for Config {
                     dynamic_storage_allocator = Some((lit_bool.value, arg))
                 } else {
                     return Err(format_err_spanned!(
-                        arg,
+                        arg.value,
                         "expected a bool literal for `dynamic_storage_allocator` ink! config argument",
                     ))
                 }
@@ -90,7 +167,7 @@ impl TryFrom<ast::AttributeArgs> for Config {
                     as_dependency = Some((lit_bool.value, arg))
                 } else {
                     return Err(format_err_spanned!(
-                        arg,
+                        arg.value,
                         "expected a bool literal for `compile_as_dependency` ink! config argument",
                     ))
                 }
@@ -102,10 +179,119 @@ impl TryFrom<ast::AttributeArgs> for Config {
                     env_types = Some((Environment { path: path.clone() }, arg))
                 } else {
                     return Err(format_err_spanned!(
-                        arg,
+                        arg.value,
                         "expected a path for `env_types` ink! config argument",
                     ))
                 }
+            } else if arg.name.is_ident("metadata_hash") {
+                if let Some((_, ast)) = metadata_hash {
+                    return Err(duplicate_config_err(ast, arg, "metadata_hash"))
+                }
+                let hash = match &arg.value {
+                    ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) => {
+                        decode_hash(&lit_str.value())
+                    }
+                    _ => None,
+                };
+                if let Some(hash) = hash {
+                    metadata_hash = Some((hash, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg.value,
+                        "expected a 0x prefixed hex encoded 32 bytes string for `metadata_hash` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("ownable") {
+                if let Some((_, ast)) = ownable {
+                    return Err(duplicate_config_err(ast, arg, "ownable"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Bool(lit_bool)) = &arg.value {
+                    ownable = Some((lit_bool.value, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg.value,
+                        "expected a bool literal for `ownable` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("bounded_inputs") {
+                if let Some((_, ast)) = bounded_inputs {
+                    return Err(duplicate_config_err(ast, arg, "bounded_inputs"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Bool(lit_bool)) = &arg.value {
+                    bounded_inputs = Some((lit_bool.value, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg.value,
+                        "expected a bool literal for `bounded_inputs` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("max_input_len") {
+                if let Some((_, ast)) = max_input_len {
+                    return Err(duplicate_config_err(ast, arg, "max_input_len"))
+                }
+                let len = match &arg.value {
+                    ast::PathOrLit::Lit(syn::Lit::Int(lit_int)) => {
+                        lit_int.base10_parse::<usize>().ok().filter(|len| *len > 0)
+                    }
+                    _ => None,
+                };
+                if let Some(len) = len {
+                    if len > MAX_INPUT_LEN_LIMIT {
+                        return Err(format_err_spanned!(
+                            arg.value,
+                            "expected `max_input_len` ink! config argument to not exceed {} bytes",
+                            MAX_INPUT_LEN_LIMIT,
+                        ))
+                    }
+                    max_input_len = Some((len, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg.value,
+                        "expected a non-zero integer literal for `max_input_len` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("features") {
+                if let Some((_, ast)) = features {
+                    return Err(duplicate_config_err(ast, arg, "features"))
+                }
+                let parsed = match &arg.value {
+                    ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) => {
+                        parse_features(&lit_str.value())
+                    }
+                    _ => None,
+                };
+                if let Some(parsed) = parsed {
+                    features = Some((parsed, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg.value,
+                        "expected a string literal of comma separated unique feature names for `features` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("supports_interface") {
+                if let Some((_, ast)) = supports_interface {
+                    return Err(duplicate_config_err(ast, arg, "supports_interface"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Bool(lit_bool)) = &arg.value {
+                    supports_interface = Some((lit_bool.value, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg.value,
+                        "expected a bool literal for `supports_interface` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("telemetry") {
+                if let Some((_, ast)) = telemetry {
+                    return Err(duplicate_config_err(ast, arg, "telemetry"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Bool(lit_bool)) = &arg.value {
+                    telemetry = Some((lit_bool.value, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg.value,
+                        "expected a bool literal for `telemetry` ink! config argument",
+                    ))
+                }
             } else {
                 return Err(format_err_spanned!(
                     arg,
@@ -117,6 +303,13 @@ impl TryFrom<ast::AttributeArgs> for Config {
             dynamic_storage_allocator: dynamic_storage_allocator.map(|(value, _)| value),
             as_dependency: as_dependency.map(|(value, _)| value),
             env_types: env_types.map(|(value, _)| value),
+            metadata_hash: metadata_hash.map(|(value, _)| value),
+            ownable: ownable.map(|(value, _)| value),
+            bounded_inputs: bounded_inputs.map(|(value, _)| value),
+            max_input_len: max_input_len.map(|(value, _)| value),
+            features: features.map(|(value, _)| value),
+            supports_interface: supports_interface.map(|(value, _)| value),
+            telemetry: telemetry.map(|(value, _)| value),
         })
     }
 }
@@ -149,6 +342,62 @@ impl Config {
     pub fn is_compile_as_dependency_enabled(&self) -> bool {
         self.as_dependency.unwrap_or(false)
     }
+
+    /// Returns the hash of the contract metadata if specified.
+    ///
+    /// The hash is to be embedded into the Wasm custom section `ink_metadata_hash`.
+    pub fn metadata_hash(&self) -> Option<&[u8; 32]> {
+        self.metadata_hash.as_ref()
+    }
+
+    /// Returns `true` if the ink! smart contract is ownable.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_ownable(&self) -> bool {
+        self.ownable.unwrap_or(false)
+    }
+
+    /// Returns `true` if all constructor and message inputs must have a
+    /// bounded SCALE encoded length.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_bounded_inputs_enabled(&self) -> bool {
+        self.bounded_inputs.unwrap_or(false)
+    }
+
+    /// Returns the maximum length of the input of a contract execution in bytes
+    /// if specified.
+    ///
+    /// Larger inputs are rejected by the dispatch before they are decoded.
+    pub fn max_input_len(&self) -> Option<usize> {
+        self.max_input_len
+    }
+
+    /// Returns the Cargo features of the crate that are contract features.
+    pub fn features(&self) -> &[String] {
+        self.features.as_deref().unwrap_or(&[])
+    }
+
+    /// Returns `true` if the Cargo feature with the given name is a contract feature.
+    pub fn is_contract_feature(&self, name: &str) -> bool {
+        self.features().iter().any(|feature| feature == name)
+    }
+
+    /// Returns `true` if the ink! smart contract reports the ink! trait
+    /// definitions it implements via a `supports_interface` message.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_supports_interface_enabled(&self) -> bool {
+        self.supports_interface.unwrap_or(false)
+    }
+
+    /// Returns `true` if the ink! smart contract counts the dispatches of its
+    /// ink! messages.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_telemetry_enabled(&self) -> bool {
+        self.telemetry.unwrap_or(false)
+    }
 }
 
 /// The environmental types definition.
@@ -198,6 +447,13 @@ mod tests {
                 dynamic_storage_allocator: Some(true),
                 as_dependency: None,
                 env_types: None,
+                metadata_hash: None,
+                ownable: None,
+                bounded_inputs: None,
+                max_input_len: None,
+                features: None,
+                supports_interface: None,
+                telemetry: None,
             }),
         )
     }
@@ -220,6 +476,13 @@ mod tests {
                 dynamic_storage_allocator: None,
                 as_dependency: Some(false),
                 env_types: None,
+                metadata_hash: None,
+                ownable: None,
+                bounded_inputs: None,
+                max_input_len: None,
+                features: None,
+                supports_interface: None,
+                telemetry: None,
             }),
         )
     }
@@ -246,6 +509,13 @@ mod tests {
                 env_types: Some(Environment {
                     path: syn::parse_quote! { ::my::env::Types },
                 }),
+                metadata_hash: None,
+                ownable: None,
+                bounded_inputs: None,
+                max_input_len: None,
+                features: None,
+                supports_interface: None,
+                telemetry: None,
             }),
         )
     }
@@ -258,6 +528,235 @@ mod tests {
         );
     }
 
+    #[test]
+    fn metadata_hash_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                metadata_hash = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env_types: None,
+                metadata_hash: Some([0x01; 32]),
+                ownable: None,
+                bounded_inputs: None,
+                max_input_len: None,
+                features: None,
+                supports_interface: None,
+                telemetry: None,
+            }),
+        )
+    }
+
+    #[test]
+    fn metadata_hash_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { metadata_hash = "0x0101" },
+            Err("expected a 0x prefixed hex encoded 32 bytes string for `metadata_hash` ink! config argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! {
+                metadata_hash = "0x+101010101010101010101010101010101010101010101010101010101010101"
+            },
+            Err("expected a 0x prefixed hex encoded 32 bytes string for `metadata_hash` ink! config argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! { metadata_hash = true },
+            Err("expected a 0x prefixed hex encoded 32 bytes string for `metadata_hash` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn ownable_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                ownable = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env_types: None,
+                metadata_hash: None,
+                ownable: Some(true),
+                bounded_inputs: None,
+                max_input_len: None,
+                features: None,
+                supports_interface: None,
+                telemetry: None,
+            }),
+        )
+    }
+
+    #[test]
+    fn ownable_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { ownable = "invalid" },
+            Err("expected a bool literal for `ownable` ink! config argument"),
+        )
+    }
+
+    #[test]
+    fn bounded_inputs_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                bounded_inputs = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env_types: None,
+                metadata_hash: None,
+                ownable: None,
+                bounded_inputs: Some(true),
+                max_input_len: None,
+                features: None,
+                supports_interface: None,
+                telemetry: None,
+            }),
+        )
+    }
+
+    #[test]
+    fn bounded_inputs_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { bounded_inputs = 42 },
+            Err("expected a bool literal for `bounded_inputs` ink! config argument"),
+        )
+    }
+
+    #[test]
+    fn max_input_len_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                max_input_len = 1024
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env_types: None,
+                metadata_hash: None,
+                ownable: None,
+                bounded_inputs: None,
+                max_input_len: Some(1024),
+                features: None,
+                supports_interface: None,
+                telemetry: None,
+            }),
+        )
+    }
+
+    #[test]
+    fn max_input_len_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { max_input_len = 0 },
+            Err("expected a non-zero integer literal for `max_input_len` ink! config argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! { max_input_len = "1024" },
+            Err("expected a non-zero integer literal for `max_input_len` ink! config argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! { max_input_len = 16385 },
+            Err("expected `max_input_len` ink! config argument to not exceed 16384 bytes"),
+        );
+    }
+
+    #[test]
+    fn features_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                features = "testnet, debug-messages"
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env_types: None,
+                metadata_hash: None,
+                ownable: None,
+                bounded_inputs: None,
+                max_input_len: None,
+                features: Some(vec!["testnet".to_string(), "debug-messages".to_string()]),
+                supports_interface: None,
+                telemetry: None,
+            }),
+        )
+    }
+
+    #[test]
+    fn features_invalid_value_fails() {
+        for invalid in &[
+            quote::quote! { features = testnet },
+            quote::quote! { features = "" },
+            quote::quote! { features = "testnet,,debug" },
+            quote::quote! { features = "main net" },
+            quote::quote! { features = "testnet, testnet" },
+        ] {
+            assert_try_from(
+                syn::parse2(invalid.clone()).unwrap(),
+                Err("expected a string literal of comma separated unique feature names for `features` ink! config argument"),
+            );
+        }
+    }
+
+    #[test]
+    fn supports_interface_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                supports_interface = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env_types: None,
+                metadata_hash: None,
+                ownable: None,
+                bounded_inputs: None,
+                max_input_len: None,
+                features: None,
+                supports_interface: Some(true),
+                telemetry: None,
+            }),
+        )
+    }
+
+    #[test]
+    fn supports_interface_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { supports_interface = "invalid" },
+            Err("expected a bool literal for `supports_interface` ink! config argument"),
+        )
+    }
+
+    #[test]
+    fn telemetry_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                telemetry = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env_types: None,
+                metadata_hash: None,
+                ownable: None,
+                bounded_inputs: None,
+                max_input_len: None,
+                features: None,
+                supports_interface: None,
+                telemetry: Some(true),
+            }),
+        )
+    }
+
+    #[test]
+    fn telemetry_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { telemetry = "invalid" },
+            Err("expected a bool literal for `telemetry` ink! config argument"),
+        )
+    }
+
     #[test]
     fn unknown_arg_fails() {
         assert_try_from(
@@ -64,9 +64,21 @@ impl Contract {
         ink_module: TokenStream2,
     ) -> Result<Self, syn::Error> {
         let config = syn::parse2::<ast::AttributeArgs>(ink_config)?;
-        let module = syn::parse2::<syn::ItemMod>(ink_module)?;
         let ink_config = ir::Config::try_from(config)?;
-        let ink_module = ir::ItemMod::try_from(module)?;
+        let ink_module = ir::expand_cfg_macros(ink_module, &ink_config)?;
+        let module = syn::parse2::<syn::ItemMod>(ink_module)?;
+        let mut ink_module = ir::ItemMod::try_from(module)?;
+        if ink_config.is_ownable() {
+            ink_module.add_ownable_messages()?;
+        } else {
+            ink_module.ensure_no_only_owner_messages()?;
+        }
+        if ink_config.is_supports_interface_enabled() {
+            ink_module.add_supports_interface_message()?;
+        }
+        if ink_config.is_telemetry_enabled() {
+            ink_module.add_telemetry_message()?;
+        }
         Ok(Self {
             item: ink_module,
             config: ink_config,
@@ -108,6 +120,15 @@ impl Contract {
     ///                    if it was a dependency of another smart contract.
     ///                    This configuration is mainly needed for testing and
     ///                    the default is `false`.
+    /// - `ownable`: If `true` the ink! smart contract is owned by its instantiator
+    ///              and provided with messages to transfer its ownership.
+    ///              The default is `false`.
+    /// - `supports_interface`: If `true` the ink! smart contract is provided with
+    ///                         a `supports_interface` message reporting the ink!
+    ///                         trait definitions it implements. The default is `false`.
+    /// - `telemetry`: If `true` the ink! smart contract counts the dispatches of its
+    ///                ink! messages and is provided with a `telemetry` message
+    ///                returning the counters. The default is `false`.
     ///
     /// Note that we might add more configuration fields in the future if
     /// necessary.
@@ -0,0 +1,133 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ir;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    Token,
+};
+
+/// The input of the `contract_ref!` macro.
+///
+/// Refers to an ink! trait definition and an optional environment,
+/// e.g. `contract_ref!(my_traits::Erc20, MyEnvironment)`.
+pub struct ContractRef {
+    /// The path to the ink! trait definition.
+    trait_path: syn::Path,
+    /// The environment of the called contract.
+    ///
+    /// If this is `None` the default environment is used.
+    env: Option<syn::Type>,
+}
+
+impl Parse for ContractRef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let trait_path = input.parse::<syn::Path>()?;
+        let mut env = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            env = Some(input.parse::<syn::Type>()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+        if !input.is_empty() {
+            return Err(
+                input.error("expected an ink! trait path and an optional environment")
+            )
+        }
+        Ok(Self { trait_path, env })
+    }
+}
+
+impl ContractRef {
+    /// Returns `Ok` if the input refers to an ink! trait definition.
+    ///
+    /// # Errors
+    ///
+    /// - If the input is not an ink! trait path followed by an optional environment.
+    /// - If the ink! trait path has generic arguments.
+    pub fn new(input: TokenStream2) -> Result<Self, syn::Error> {
+        let contract_ref = syn::parse2::<ContractRef>(input)?;
+        let last = contract_ref
+            .trait_path
+            .segments
+            .last()
+            .expect("encountered empty ink! trait path");
+        if !last.arguments.is_empty() {
+            return Err(format_err_spanned!(
+                last.arguments,
+                "ink! trait definitions cannot have generic arguments"
+            ))
+        }
+        Ok(contract_ref)
+    }
+
+    /// Returns the path to the ink! trait definition.
+    pub fn trait_path(&self) -> &syn::Path {
+        &self.trait_path
+    }
+
+    /// Returns the path to the contract reference of the ink! trait definition.
+    pub fn contract_ref_path(&self) -> syn::Path {
+        let mut path = self.trait_path.clone();
+        let last = path
+            .segments
+            .last_mut()
+            .expect("encountered empty ink! trait path");
+        last.ident = ir::InkTrait::contract_ref_ident(&last.ident);
+        path
+    }
+
+    /// Returns the environment of the called contract if any.
+    pub fn env(&self) -> Option<&syn::Type> {
+        self.env.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract_ref_path_of(input: TokenStream2) -> String {
+        let path = ContractRef::new(input).unwrap().contract_ref_path();
+        let mut path = quote::quote! { #path }.to_string();
+        path.retain(|c| !c.is_whitespace());
+        path
+    }
+
+    #[test]
+    fn contract_ref_works() {
+        assert_eq!(
+            contract_ref_path_of(quote::quote! { Erc20 }),
+            "__ink_Erc20ContractRef",
+        );
+        assert_eq!(
+            contract_ref_path_of(quote::quote! { ::my_traits::Erc20, MyEnvironment }),
+            "::my_traits::__ink_Erc20ContractRef",
+        );
+        assert_eq!(
+            contract_ref_path_of(quote::quote! { my_traits::Erc20, MyEnvironment, }),
+            "my_traits::__ink_Erc20ContractRef",
+        );
+    }
+
+    #[test]
+    fn contract_ref_invalid_input_fails() {
+        assert!(ContractRef::new(quote::quote! {}).is_err());
+        assert!(ContractRef::new(quote::quote! { Erc20<u8> }).is_err());
+        assert!(ContractRef::new(quote::quote! { Erc20, MyEnvironment, Other }).is_err());
+    }
+}
@@ -40,6 +40,7 @@ mod private {
     impl Sealed for syn::ItemMod {}
     impl Sealed for syn::ItemTrait {}
     impl Sealed for syn::ItemFn {}
+    impl Sealed for syn::ItemStruct {}
 
     impl VisitBy for syn::ItemMod {
         fn visit_by(&self, visitor: &mut IdentVisitor) {
@@ -59,6 +60,12 @@ mod private {
         }
     }
 
+    impl VisitBy for syn::ItemStruct {
+        fn visit_by(&self, visitor: &mut IdentVisitor) {
+            syn::visit::visit_item_struct(visitor, self);
+        }
+    }
+
     /// Visitor to ensure that there are no identifiers starting with `__ink_` as prefix.
     ///
     /// # Errors
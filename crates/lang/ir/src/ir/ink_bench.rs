@@ -0,0 +1,156 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ast,
+    ir::idents_lint,
+};
+use proc_macro2::TokenStream as TokenStream2;
+
+/// The ink! benchmark with all required information.
+pub struct InkBench {
+    /// The function which was annotated.
+    pub item_fn: syn::ItemFn,
+    /// The number of times every benchmarked message is executed.
+    iterations: u32,
+}
+
+impl InkBench {
+    /// The default number of times every benchmarked message is executed.
+    pub const DEFAULT_ITERATIONS: u32 = 100;
+
+    /// Returns `Ok` if the function matches all requirements for an ink! benchmark.
+    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self, syn::Error> {
+        let args = syn::parse2::<ast::AttributeArgs>(attr)?;
+        let mut iterations = None;
+        for arg in args {
+            if !arg.name.is_ident("iterations") {
+                return Err(format_err_spanned!(
+                    arg,
+                    "encountered unknown or unsupported ink! benchmark argument",
+                ))
+            }
+            if iterations.is_some() {
+                return Err(format_err_spanned!(
+                    arg,
+                    "encountered duplicate ink! benchmark `iterations` argument",
+                ))
+            }
+            match &arg.value {
+                ast::PathOrLit::Lit(syn::Lit::Int(lit_int)) => {
+                    iterations = Some(lit_int.base10_parse::<u32>()?)
+                }
+                _ => {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected an integer literal for the ink! benchmark `iterations` argument",
+                    ))
+                }
+            }
+        }
+        let item_fn = syn::parse2::<syn::ItemFn>(input)?;
+        idents_lint::ensure_no_ink_identifiers(&item_fn)?;
+        if item_fn.sig.inputs.len() != 1 {
+            return Err(format_err_spanned!(
+                item_fn.sig,
+                "ink! benchmarks must have exactly one `&mut ink_env::test::Bencher` input",
+            ))
+        }
+        if let Some(syn::FnArg::Receiver(receiver)) = item_fn.sig.inputs.first() {
+            return Err(format_err_spanned!(
+                receiver,
+                "ink! benchmarks must not have a `self` receiver",
+            ))
+        }
+        Ok(Self {
+            item_fn,
+            iterations: iterations.unwrap_or(Self::DEFAULT_ITERATIONS),
+        })
+    }
+
+    /// Returns the number of times every benchmarked message is executed.
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Returns the `&mut ink_env::test::Bencher` input of the benchmark.
+    pub fn bencher_input(&self) -> &syn::PatType {
+        match self.item_fn.sig.inputs.first() {
+            Some(syn::FnArg::Typed(pat_type)) => pat_type,
+            _ => unreachable!("ink! benchmarks have been checked to have a typed input"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    fn assert_bench_fails(attr: TokenStream2, input: TokenStream2, expected: &str) {
+        assert_eq!(
+            InkBench::new(attr, input)
+                .map(|_| ())
+                .map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn iterations_works() {
+        let input = quote! {
+            fn bench(b: &mut ink_env::test::Bencher) {}
+        };
+        let bench = InkBench::new(quote! {}, input.clone()).unwrap();
+        assert_eq!(bench.iterations(), InkBench::DEFAULT_ITERATIONS);
+        let bench = InkBench::new(quote! { iterations = 42 }, input).unwrap();
+        assert_eq!(bench.iterations(), 42);
+    }
+
+    #[test]
+    fn invalid_args_fail() {
+        let input = quote! {
+            fn bench(b: &mut ink_env::test::Bencher) {}
+        };
+        assert_bench_fails(
+            quote! { iterations = "many" },
+            input.clone(),
+            "expected an integer literal for the ink! benchmark `iterations` argument",
+        );
+        assert_bench_fails(
+            quote! { iterations = 1, iterations = 2 },
+            input.clone(),
+            "encountered duplicate ink! benchmark `iterations` argument",
+        );
+        assert_bench_fails(
+            quote! { warmup = 1 },
+            input,
+            "encountered unknown or unsupported ink! benchmark argument",
+        );
+    }
+
+    #[test]
+    fn invalid_inputs_fail() {
+        assert_bench_fails(
+            quote! {},
+            quote! { fn bench() {} },
+            "ink! benchmarks must have exactly one `&mut ink_env::test::Bencher` input",
+        );
+        assert_bench_fails(
+            quote! {},
+            quote! { fn bench(a: u32, b: &mut ink_env::test::Bencher) {} },
+            "ink! benchmarks must have exactly one `&mut ink_env::test::Bencher` input",
+        );
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ast,
+    ir,
+    ir::idents_lint,
+};
+use core::convert::TryFrom;
+use proc_macro2::TokenStream as TokenStream2;
+
+/// An ink! event defined outside of an ink! smart contract via `#[ink::event]`.
+///
+/// Such events are usually defined alongside an ink! trait definition so that
+/// its default implemented methods can emit them on behalf of the implementing
+/// ink! smart contract.
+///
+/// # Example
+///
+/// ```
+/// # use quote::quote;
+/// # let event = ink_lang_ir::InkEvent::new(
+/// #     quote! { namespace = "PSP22" },
+/// #     quote! {
+/// pub struct Transfer {
+///     #[ink(topic)]
+///     from: Option<AccountId>,
+///     #[ink(topic)]
+///     to: Option<AccountId>,
+///     value: Balance,
+/// }
+/// #     },
+/// # ).unwrap();
+/// # assert_eq!(event.signature(), "PSP22::Transfer");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct InkEvent {
+    event: ir::Event,
+    /// The namespace prefixed to the signature topic of the event.
+    namespace: Option<String>,
+}
+
+impl InkEvent {
+    /// Returns `Ok` if the struct matches all requirements for an ink! event.
+    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self, syn::Error> {
+        let args = syn::parse2::<ast::AttributeArgs>(attr)?;
+        let mut namespace = None;
+        for arg in args {
+            if !arg.name.is_ident("namespace") {
+                return Err(format_err_spanned!(
+                    arg,
+                    "encountered unknown or unsupported ink! event argument",
+                ))
+            }
+            if namespace.is_some() {
+                return Err(format_err_spanned!(
+                    arg,
+                    "encountered duplicate ink! event `namespace` argument",
+                ))
+            }
+            match &arg.value {
+                ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) => {
+                    namespace = Some(lit_str.value())
+                }
+                _ => {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a string literal for the ink! event `namespace` argument",
+                    ))
+                }
+            }
+        }
+        let mut item_struct = syn::parse2::<syn::ItemStruct>(input)?;
+        idents_lint::ensure_no_ink_identifiers(&item_struct)?;
+        item_struct
+            .attrs
+            .insert(0, syn::parse_quote! { #[ink(event)] });
+        let event = <ir::Event as TryFrom<syn::ItemStruct>>::try_from(item_struct)?;
+        Ok(Self { event, namespace })
+    }
+
+    /// Returns the checked event struct.
+    pub fn event(&self) -> &ir::Event {
+        &self.event
+    }
+
+    /// Returns the signature of the event.
+    ///
+    /// This is the identifier of the event prefixed with its namespace if any,
+    /// e.g. `PSP22::Transfer`, and is used for the signature topic of the event.
+    pub fn signature(&self) -> String {
+        match &self.namespace {
+            Some(namespace) => format!("{}::{}", namespace, self.event.ident()),
+            None => self.event.ident().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    fn assert_event_fails(attr: TokenStream2, input: TokenStream2, expected: &str) {
+        assert_eq!(
+            InkEvent::new(attr, input)
+                .map(|_| ())
+                .map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn signature_works() {
+        let input = quote! {
+            pub struct Transfer {
+                #[ink(topic)]
+                from: AccountId,
+                value: Balance,
+            }
+        };
+        let event = InkEvent::new(quote! {}, input.clone()).unwrap();
+        assert_eq!(event.signature(), "Transfer");
+        assert_eq!(
+            event
+                .event()
+                .fields()
+                .filter(|field| field.is_topic)
+                .count(),
+            1
+        );
+        let event = InkEvent::new(quote! { namespace = "PSP22" }, input).unwrap();
+        assert_eq!(event.signature(), "PSP22::Transfer");
+    }
+
+    #[test]
+    fn anonymous_works() {
+        let event = InkEvent::new(
+            quote! {},
+            quote! {
+                #[ink(anonymous)]
+                pub struct Transfer {}
+            },
+        )
+        .unwrap();
+        assert!(event.event().anonymous);
+    }
+
+    #[test]
+    fn invalid_args_fail() {
+        let input = quote! {
+            pub struct Transfer {}
+        };
+        assert_event_fails(
+            quote! { namespace = 42 },
+            input.clone(),
+            "expected a string literal for the ink! event `namespace` argument",
+        );
+        assert_event_fails(
+            quote! { namespace = "A", namespace = "B" },
+            input.clone(),
+            "encountered duplicate ink! event `namespace` argument",
+        );
+        assert_event_fails(
+            quote! { anonymous = true },
+            input,
+            "encountered unknown or unsupported ink! event argument",
+        );
+    }
+
+    #[test]
+    fn invalid_struct_fails() {
+        assert_event_fails(
+            quote! {},
+            quote! {
+                pub struct Transfer<T> {
+                    value: T,
+                }
+            },
+            "generic ink! event structs are not supported",
+        );
+    }
+}
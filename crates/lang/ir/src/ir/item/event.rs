@@ -112,16 +112,21 @@ impl TryFrom<syn::ItemStruct> for Event {
                 })?;
             if !matches!(normalized.first().kind(), ir::AttributeArgKind::Topic) {
                 return Err(format_err!(
-                    field_span,
+                    normalized.first().span(),
                     "first optional ink! attribute of an event field must be #[ink(topic)]",
-                ))
+                )
+                .into_combine(format_err!(field_span, "at this event field")))
             }
             for arg in normalized.args() {
                 if !matches!(arg.kind(), ir::AttributeArgKind::Topic) {
                     return Err(format_err!(
                         arg.span(),
                         "encountered conflicting ink! attribute for event field",
-                    ))
+                    )
+                    .into_combine(format_err!(
+                        normalized.first().span(),
+                        "conflicts with this ink! attribute argument",
+                    )))
                 }
             }
         }
@@ -141,6 +146,11 @@ impl Event {
         &self.item.ident
     }
 
+    /// Returns the visibility of the event struct.
+    pub fn vis(&self) -> &syn::Visibility {
+        &self.item.vis
+    }
+
     /// Returns an iterator yielding all the `#[ink(topic)]` annotated fields
     /// of the event struct.
     pub fn fields(&self) -> EventFieldsIter {
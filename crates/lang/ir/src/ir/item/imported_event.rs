@@ -0,0 +1,152 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ir;
+use core::convert::TryFrom;
+use proc_macro2::Ident;
+use syn::spanned::Spanned as _;
+
+/// An ink! event defined via `#[ink::event]` outside of the ink! module and
+/// imported into it.
+///
+/// Imported events are variants of the event enum of the ink! smart contract and
+/// listed in its metadata after the ink! events defined by the ink! module. This
+/// allows default methods of ink! trait definitions to emit them on behalf of the
+/// ink! smart contract.
+///
+/// # Example
+///
+/// ```
+/// # use core::convert::TryFrom;
+/// # let event = <ink_lang_ir::ImportedEvent as TryFrom<syn::ItemUse>>::try_from(syn::parse_quote! {
+/// #[ink(event)]
+/// use psp22::Transfer as Psp22Transfer;
+/// # }).unwrap();
+/// # assert_eq!(event.ident(), "Psp22Transfer");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ImportedEvent {
+    item: syn::ItemUse,
+    /// The identifier under which the event is imported.
+    ident: Ident,
+}
+
+impl quote::ToTokens for ImportedEvent {
+    /// We mainly implement this trait for this ink! type to have a derived
+    /// [`Spanned`](`syn::spanned::Spanned`) implementation for it.
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.item.to_tokens(tokens)
+    }
+}
+
+impl TryFrom<syn::ItemUse> for ImportedEvent {
+    type Error = syn::Error;
+
+    fn try_from(item_use: syn::ItemUse) -> Result<Self, Self::Error> {
+        let use_span = item_use.span();
+        let (_, other_attrs) = ir::sanitize_attributes(
+            use_span,
+            item_use.attrs,
+            &ir::AttributeArgKind::Event,
+            |kind| !matches!(kind, ir::AttributeArgKind::Event),
+        )?;
+        let mut tree = &item_use.tree;
+        let ident = loop {
+            match tree {
+                syn::UseTree::Path(use_path) => tree = &use_path.tree,
+                syn::UseTree::Name(use_name) => break use_name.ident.clone(),
+                syn::UseTree::Rename(use_rename) => break use_rename.rename.clone(),
+                syn::UseTree::Glob(_) | syn::UseTree::Group(_) => {
+                    return Err(format_err_spanned!(
+                        tree,
+                        "ink! event imports must import exactly one event",
+                    ))
+                }
+            }
+        };
+        Ok(Self {
+            item: syn::ItemUse {
+                attrs: other_attrs,
+                ..item_use
+            },
+            ident,
+        })
+    }
+}
+
+impl ImportedEvent {
+    /// Returns the identifier under which the event is imported.
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_try_from_fails(item_use: syn::ItemUse, expected: &str) {
+        assert_eq!(
+            <ImportedEvent as TryFrom<_>>::try_from(item_use)
+                .map_err(|err| err.to_string()),
+            Err(expected.to_string()),
+        )
+    }
+
+    #[test]
+    fn try_from_works() {
+        let event =
+            <ImportedEvent as TryFrom<syn::ItemUse>>::try_from(syn::parse_quote! {
+                /// Emitted by the PSP22 standard.
+                #[ink(event)]
+                pub use ::psp22::events::Transfer;
+            })
+            .unwrap();
+        assert_eq!(event.ident(), "Transfer");
+        let expected: syn::ItemUse = syn::parse_quote! {
+            /// Emitted by the PSP22 standard.
+            pub use ::psp22::events::Transfer;
+        };
+        assert_eq!(event.item, expected);
+    }
+
+    #[test]
+    fn multiple_imports_fail() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(event)]
+                use psp22::{Approval, Transfer};
+            },
+            "ink! event imports must import exactly one event",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(event)]
+                use psp22::*;
+            },
+            "ink! event imports must import exactly one event",
+        );
+    }
+
+    #[test]
+    fn conflicting_attributes_fail() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(event, anonymous)]
+                use psp22::Transfer;
+            },
+            "encountered conflicting ink! attribute argument",
+        );
+    }
+}
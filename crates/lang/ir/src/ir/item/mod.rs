@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod event;
+mod imported_event;
 mod storage;
 
 #[cfg(test)]
@@ -20,6 +21,7 @@ mod tests;
 
 pub use self::{
     event::Event,
+    imported_event::ImportedEvent,
     storage::Storage,
 };
 
@@ -96,6 +98,16 @@ impl TryFrom<syn::Item> for Item {
                     .map(Into::into)
                     .map(Self::Ink)
             }
+            syn::Item::Use(item_use) => {
+                if !ir::contains_ink_attributes(&item_use.attrs) {
+                    return Ok(Self::Rust(item_use.into()))
+                }
+                // At this point we know that there must be at least one ink!
+                // attribute which must be `#[ink(event)]` for imported events.
+                <ir::ImportedEvent as TryFrom<_>>::try_from(item_use)
+                    .map(Into::into)
+                    .map(Self::Ink)
+            }
             item => {
                 // This is an error if the item contains any unexpected
                 // ink! attributes. Otherwise it is a normal Rust item.
@@ -156,6 +168,8 @@ pub enum InkItem {
     Storage(ir::Storage),
     /// An ink! event definition.
     Event(ir::Event),
+    /// An ink! event defined via `#[ink::event]` and imported into the ink! module.
+    ImportedEvent(ir::ImportedEvent),
     /// An ink! implementation block.
     ImplBlock(ir::ItemImpl),
 }
@@ -167,6 +181,7 @@ impl quote::ToTokens for InkItem {
         match self {
             Self::Storage(storage) => storage.to_tokens(tokens),
             Self::Event(event) => event.to_tokens(tokens),
+            Self::ImportedEvent(event) => event.to_tokens(tokens),
             Self::ImplBlock(impl_block) => impl_block.to_tokens(tokens),
         }
     }
@@ -190,6 +205,9 @@ impl InkItem {
             syn::Item::Impl(item_impl) => {
                 return ir::ItemImpl::is_ink_impl_block(item_impl)
             }
+            syn::Item::Use(item_use) => {
+                return Ok(ir::contains_ink_attributes(&item_use.attrs))
+            }
             _ => (),
         }
         Ok(false)
@@ -208,6 +226,12 @@ impl From<ir::Event> for InkItem {
     }
 }
 
+impl From<ir::ImportedEvent> for InkItem {
+    fn from(event: ir::ImportedEvent) -> Self {
+        Self::ImportedEvent(event)
+    }
+}
+
 impl From<ir::ItemImpl> for InkItem {
     fn from(impl_block: ir::ItemImpl) -> Self {
         Self::ImplBlock(impl_block)
@@ -245,6 +269,16 @@ impl InkItem {
         self.filter_map_event_item().is_some()
     }
 
+    /// Returns `Some` if `self` is an imported ink! event.
+    ///
+    /// Otherwise, returns `None`.
+    pub fn filter_map_imported_event(&self) -> Option<&ir::ImportedEvent> {
+        match self {
+            InkItem::ImportedEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
     /// Returns `Some` if `self` is an ink! implementation block.
     ///
     /// Otherwise, returns `None`.
@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use crate::{
+    error::ExtError as _,
     ir,
     ir::utils,
 };
 use core::convert::TryFrom;
 use proc_macro2::Ident;
+use quote::ToTokens as _;
 use syn::spanned::Spanned as _;
 
 /// An ink! storage struct definition.
@@ -41,10 +43,15 @@ use syn::spanned::Spanned as _;
 /// }
 /// # }).unwrap();
 /// ```
+///
+/// Fields of the storage struct flagged with `#[ink(mixin)]` embed the
+/// ink! mixin of the field type into the contract.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Storage {
     /// The underlying `struct` Rust item.
     ast: syn::ItemStruct,
+    /// The indices of the fields flagged with `#[ink(mixin)]`.
+    mixins: Vec<usize>,
 }
 
 impl quote::ToTokens for Storage {
@@ -95,15 +102,75 @@ impl TryFrom<syn::ItemStruct> for Storage {
             ))
         }
         utils::ensure_pub_visibility("storage structs", struct_span, &item_struct.vis)?;
+        let mut item_struct = item_struct;
+        let mut mixins = Vec::new();
+        for (index, field) in item_struct.fields.iter_mut().enumerate() {
+            let field_span = field.span();
+            let (ink_attrs, other_attrs) = ir::partition_attributes(field.attrs.clone())?;
+            if ink_attrs.is_empty() {
+                continue
+            }
+            let normalized =
+                ir::InkAttribute::from_expanded(ink_attrs).map_err(|err| {
+                    err.into_combine(format_err!(field_span, "at this invocation",))
+                })?;
+            normalized
+                .ensure_first(&ir::AttributeArgKind::Mixin)
+                .map_err(|err| {
+                    err.into_combine(format_err!(field_span, "at this storage field"))
+                })?;
+            normalized.ensure_no_conflicts(|arg| {
+                !matches!(arg.kind(), ir::AttributeArgKind::Mixin)
+            })?;
+            field.attrs = other_attrs;
+            mixins.push(index);
+        }
+        Self::ensure_no_duplicate_mixins(&item_struct.fields, &mixins)?;
         Ok(Self {
             ast: syn::ItemStruct {
                 attrs: other_attrs,
                 ..item_struct
             },
+            mixins,
         })
     }
 }
 
+impl Storage {
+    /// Ensures that no two fields flagged with `#[ink(mixin)]` embed the same
+    /// ink! mixin since the selectors of their messages would be identical.
+    ///
+    /// # Note
+    ///
+    /// The types are compared by their tokens, i.e. different paths to the same
+    /// mixin are not detected here but by the selector overlap check of the codegen.
+    fn ensure_no_duplicate_mixins(
+        fields: &syn::Fields,
+        mixins: &[usize],
+    ) -> Result<(), syn::Error> {
+        let mixin_fields = fields
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| mixins.contains(index))
+            .map(|(_, field)| field)
+            .collect::<Vec<_>>();
+        for (n, field) in mixin_fields.iter().enumerate() {
+            let ty = field.ty.to_token_stream().to_string();
+            if let Some(first) = mixin_fields[..n]
+                .iter()
+                .find(|first| first.ty.to_token_stream().to_string() == ty)
+            {
+                return Err(format_err!(
+                    field.span(),
+                    "encountered multiple ink! mixins of the same type",
+                )
+                .into_combine(format_err!(first.span(), "first ink! mixin here")))
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Storage {
     /// Returns the non-ink! attributes of the ink! storage struct.
     pub fn attrs(&self) -> &[syn::Attribute] {
@@ -119,6 +186,21 @@ impl Storage {
     pub fn fields(&self) -> syn::punctuated::Iter<syn::Field> {
         self.ast.fields.iter()
     }
+
+    /// Returns an iter yielding the fields flagged with `#[ink(mixin)]` together
+    /// with the members to access them.
+    pub fn mixin_fields(&self) -> impl Iterator<Item = (syn::Member, &syn::Field)> + '_ {
+        self.fields()
+            .enumerate()
+            .filter(move |(index, _)| self.mixins.contains(index))
+            .map(|(index, field)| {
+                let member = match &field.ident {
+                    Some(ident) => syn::Member::Named(ident.clone()),
+                    None => syn::Member::Unnamed(index.into()),
+                };
+                (member, field)
+            })
+    }
 }
 
 #[cfg(test)]
@@ -215,6 +297,54 @@ mod tests {
         )
     }
 
+    #[test]
+    fn mixin_fields_works() {
+        let storage = Storage::try_from(syn::parse_quote! {
+            #[ink(storage)]
+            pub struct MyStorage {
+                field_1: i32,
+                #[ink(mixin)]
+                field_2: Escrow,
+            }
+        })
+        .unwrap();
+        let mixins = storage
+            .mixin_fields()
+            .map(|(member, field)| (member, field.attrs.is_empty()))
+            .collect::<Vec<_>>();
+        assert_eq!(mixins, vec![(syn::parse_quote! { field_2 }, true)]);
+    }
+
+    #[test]
+    fn duplicate_mixin_type_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    #[ink(mixin)]
+                    escrow_1: escrow::Escrow,
+                    #[ink(mixin)]
+                    escrow_2: escrow::Escrow,
+                }
+            },
+            "encountered multiple ink! mixins of the same type",
+        )
+    }
+
+    #[test]
+    fn invalid_field_attribute_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    #[ink(topic)]
+                    field_1: i32,
+                }
+            },
+            "unexpected first ink! attribute argument",
+        )
+    }
+
     #[test]
     fn non_pub_storage_struct() {
         assert_try_from_fails(
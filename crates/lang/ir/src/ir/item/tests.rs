@@ -47,6 +47,19 @@ fn simple_event_works() {
     ))
 }
 
+#[test]
+fn simple_imported_event_works() {
+    let event_use: syn::Item = syn::parse_quote! {
+        #[ink(event)]
+        use psp22::Transfer;
+    };
+    assert!(matches!(
+        <ir::Item as TryFrom<_>>::try_from(event_use.clone())
+            .map_err(|err| err.to_string()),
+        Ok(ir::Item::Ink(ir::InkItem::ImportedEvent(_)))
+    ))
+}
+
 #[test]
 fn simple_rust_item_works() {
     let rust_items: Vec<syn::Item> = vec![
@@ -48,6 +48,11 @@ pub struct CallableWithSelector<'a, C> {
     /// The composed selector computed by the associated implementation block
     /// and the given callable.
     composed_selector: ir::Selector,
+    /// The identifier of the callable that is unique within the ink! smart contract.
+    ///
+    /// This differs from the composed selector only for trait implementation
+    /// blocks using a relative trait path with more than a single segment.
+    composed_id: ir::Selector,
     /// The parent implementation block.
     item_impl: &'a ir::ItemImpl,
     /// The actual callable.
@@ -59,6 +64,7 @@ impl<C> Clone for CallableWithSelector<'_, C> {
     fn clone(&self) -> Self {
         Self {
             composed_selector: self.composed_selector,
+            composed_id: self.composed_id,
             item_impl: self.item_impl,
             callable: self.callable,
         }
@@ -73,6 +79,7 @@ where
     pub(super) fn new(item_impl: &'a ir::ItemImpl, callable: &'a C) -> Self {
         Self {
             composed_selector: compose_selector(item_impl, callable),
+            composed_id: compose_id(item_impl, callable),
             item_impl,
             callable,
         }
@@ -85,6 +92,18 @@ impl<'a, C> CallableWithSelector<'a, C> {
         self.composed_selector
     }
 
+    /// Returns the identifier of the ink! callable that is unique within the
+    /// ink! smart contract.
+    ///
+    /// # Note
+    ///
+    /// Use this instead of the composed selector to identify the callable,
+    /// e.g. for generated items. Relative trait paths such as `v1::Trait` and
+    /// `v2::Trait` yield the same composed selector but different identifiers.
+    pub fn composed_id(&self) -> ir::Selector {
+        self.composed_id
+    }
+
     /// Returns a shared reference to the underlying callable.
     pub fn callable(&self) -> &'a C {
         self.callable
@@ -96,6 +115,24 @@ impl<'a, C> CallableWithSelector<'a, C> {
     }
 }
 
+impl<'a> CallableWithSelector<'a, ir::Message> {
+    /// Returns the path of the implemented ink! trait definition if the ink! message
+    /// inherits the selector that the trait definition might have pinned for it.
+    ///
+    /// This is the case for ink! messages of trait implementation blocks
+    /// for which the ink! smart contract does not provide its own selector.
+    /// Trait implementation blocks with an `#[ink(namespace = "..")]` compose
+    /// the selectors of their messages from the namespace instead.
+    pub fn inherited_selector_trait(&self) -> Option<&'a syn::Path> {
+        if self.callable.user_provided_selector().is_some()
+            || self.item_impl.namespace().is_some()
+        {
+            return None
+        }
+        self.item_impl.trait_path()
+    }
+}
+
 impl<'a, C> Callable for CallableWithSelector<'a, C>
 where
     C: Callable,
@@ -298,6 +335,32 @@ pub trait Callable {
 ///   e.g. in case of backwards compatibility.
 /// - Do not use the namespace unless required to disambiguate.
 pub fn compose_selector<C>(item_impl: &ir::ItemImpl, callable: &C) -> ir::Selector
+where
+    C: Callable,
+{
+    compose_selector_or_id(item_impl, callable, false)
+}
+
+/// Returns the identifier of the callable that is unique within the ink! smart contract.
+///
+/// This is computed the same way as [`compose_selector`] with the exception
+/// that the full trait path is used for all trait implementation blocks.
+///
+/// E.g. `impl v1::MyTrait` and `impl v2::MyTrait` both compose the selector
+/// from `MyTrait::my_message` whereas their identifiers are composed from
+/// `v1::MyTrait::my_message` and `v2::MyTrait::my_message` respectively.
+pub fn compose_id<C>(item_impl: &ir::ItemImpl, callable: &C) -> ir::Selector
+where
+    C: Callable,
+{
+    compose_selector_or_id(item_impl, callable, true)
+}
+
+fn compose_selector_or_id<C>(
+    item_impl: &ir::ItemImpl,
+    callable: &C,
+    full_path: bool,
+) -> ir::Selector
 where
     C: Callable,
 {
@@ -324,7 +387,7 @@ where
             //
             // We need to separate between full-path, e.g. `::my::full::Path`
             // starting with `::` and relative paths for the composition.
-            let path_bytes = if path.leading_colon.is_some() {
+            let path_bytes = if path.leading_colon.is_some() || full_path {
                 let mut str_repr = path.to_token_stream().to_string();
                 str_repr.retain(|c| !c.is_whitespace());
                 str_repr.into_bytes()
@@ -421,6 +484,39 @@ pub(super) fn ensure_callable_invariants(
     Ok(())
 }
 
+/// Removes the documentation attributes from all inputs of the method and returns them.
+///
+/// The returned vector has one entry per non-receiver input in the order in
+/// which the inputs are declared.
+///
+/// # Note
+///
+/// Rust does not allow documentation on function parameters. ink! supports
+/// them for ink! messages and constructors in order to include them into the
+/// contract metadata, so they have to be removed before the method is expanded.
+pub(super) fn extract_input_docs(
+    method_item: &mut syn::ImplItemMethod,
+) -> Vec<Vec<syn::Attribute>> {
+    method_item
+        .sig
+        .inputs
+        .iter_mut()
+        .filter_map(|fn_arg| {
+            match fn_arg {
+                syn::FnArg::Typed(pat_type) => Some(pat_type),
+                syn::FnArg::Receiver(_) => None,
+            }
+        })
+        .map(|pat_type| {
+            let (docs, others) = core::mem::take(&mut pat_type.attrs)
+                .into_iter()
+                .partition(|attr| attr.path.is_ident("doc"));
+            pat_type.attrs = others;
+            docs
+        })
+        .collect()
+}
+
 /// The visibility of an ink! message or constructor.
 #[derive(Debug, Clone)]
 pub enum Visibility {
@@ -619,4 +715,106 @@ mod tests {
             b"MyTrait::my_message".to_vec(),
         );
     }
+
+    #[test]
+    fn compose_id_works() {
+        let compose = |item_impl: syn::ItemImpl| {
+            compose_id(
+                &<ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(item_impl).unwrap(),
+                &<ir::Message as TryFrom<syn::ImplItemMethod>>::try_from(
+                    syn::parse_quote! {
+                        #[ink(message)]
+                        fn my_message(&self) {}
+                    },
+                )
+                .unwrap(),
+            )
+        };
+        let v1 = compose(syn::parse_quote! {
+            #[ink(impl)]
+            impl v1::MyTrait for MyStorage {}
+        });
+        let v2 = compose(syn::parse_quote! {
+            #[ink(impl)]
+            impl v2::MyTrait for MyStorage {}
+        });
+        assert_ne!(v1, v2);
+        assert_eq!(
+            v1,
+            ExpectedSelector::from(b"v1::MyTrait::my_message".to_vec())
+                .expected_selector(),
+        );
+        assert_eq!(
+            compose(syn::parse_quote! {
+                #[ink(impl)]
+                impl MyTrait for MyStorage {}
+            }),
+            ExpectedSelector::from(b"MyTrait::my_message".to_vec()).expected_selector(),
+        );
+    }
+
+    #[test]
+    fn inherited_selector_trait_works() {
+        let inherited = |item_impl: syn::ItemImpl, message: syn::ImplItemMethod| {
+            let item_impl =
+                <ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(item_impl).unwrap();
+            let message =
+                <ir::Message as TryFrom<syn::ImplItemMethod>>::try_from(message).unwrap();
+            CallableWithSelector::new(&item_impl, &message)
+                .inherited_selector_trait()
+                .map(|path| path.to_token_stream().to_string())
+        };
+        assert_eq!(
+            inherited(
+                syn::parse_quote! {
+                    #[ink(impl)]
+                    impl my::MyTrait for MyStorage {}
+                },
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            Some("my :: MyTrait".to_string()),
+        );
+        assert_eq!(
+            inherited(
+                syn::parse_quote! {
+                    #[ink(impl)]
+                    impl MyStorage {}
+                },
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            None,
+        );
+        assert_eq!(
+            inherited(
+                syn::parse_quote! {
+                    #[ink(impl)]
+                    impl MyTrait for MyStorage {}
+                },
+                syn::parse_quote! {
+                    #[ink(message, selector = "0xDEADBEEF")]
+                    fn my_message(&self) {}
+                },
+            ),
+            None,
+        );
+        assert_eq!(
+            inherited(
+                syn::parse_quote! {
+                    #[ink(impl, namespace = "my_namespace")]
+                    impl MyTrait for MyStorage {}
+                },
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            None,
+        );
+    }
 }
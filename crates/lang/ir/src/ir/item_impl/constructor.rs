@@ -14,6 +14,7 @@
 
 use super::{
     ensure_callable_invariants,
+    extract_input_docs,
     Callable,
     CallableKind,
     InputsIter,
@@ -71,6 +72,10 @@ pub struct Constructor {
     /// This overrides the computed selector, even when using a manual namespace
     /// for the parent implementation block.
     selector: Option<ir::Selector>,
+    /// The documentation attributes of every input of the ink! constructor.
+    input_docs: Vec<Vec<syn::Attribute>>,
+    /// If the constructor is flagged as the default constructor.
+    is_default: bool,
 }
 
 impl quote::ToTokens for Constructor {
@@ -156,7 +161,9 @@ impl Constructor {
             |kind| {
                 !matches!(
                     kind,
-                    ir::AttributeArgKind::Constructor | ir::AttributeArgKind::Selector(_)
+                    ir::AttributeArgKind::Constructor
+                        | ir::AttributeArgKind::Default
+                        | ir::AttributeArgKind::Selector(_)
                 )
             },
         )
@@ -166,14 +173,18 @@ impl Constructor {
 impl TryFrom<syn::ImplItemMethod> for Constructor {
     type Error = syn::Error;
 
-    fn try_from(method_item: syn::ImplItemMethod) -> Result<Self, Self::Error> {
+    fn try_from(mut method_item: syn::ImplItemMethod) -> Result<Self, Self::Error> {
         ensure_callable_invariants(&method_item, CallableKind::Constructor)?;
         Self::ensure_valid_return_type(&method_item)?;
         Self::ensure_no_self_receiver(&method_item)?;
         let (ink_attrs, other_attrs) = Self::sanitize_attributes(&method_item)?;
+        let input_docs = extract_input_docs(&mut method_item);
         let selector = ink_attrs.selector();
+        let is_default = ink_attrs.is_default();
         Ok(Constructor {
             selector,
+            is_default,
+            input_docs,
             item: syn::ImplItemMethod {
                 attrs: other_attrs,
                 ..method_item
@@ -225,6 +236,18 @@ impl Constructor {
     pub fn attrs(&self) -> &[syn::Attribute] {
         &self.item.attrs
     }
+
+    /// Returns `true` if the ink! constructor is flagged as the default constructor.
+    pub fn is_default(&self) -> bool {
+        self.is_default
+    }
+
+    /// Returns the documentation attributes of every input of the ink! constructor.
+    ///
+    /// Yields one entry per input in the order of [`Callable::inputs`].
+    pub fn input_docs(&self) -> &[Vec<syn::Attribute>] {
+        &self.input_docs
+    }
 }
 
 #[cfg(test)]
@@ -281,6 +304,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn is_default_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
+            // Not default.
+            (
+                false,
+                syn::parse_quote! {
+                    #[ink(constructor)]
+                    fn my_constructor() -> Self {}
+                },
+            ),
+            // Normalized ink! attribute.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(constructor, default)]
+                    pub fn my_constructor() -> Self {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(constructor)]
+                    #[ink(default)]
+                    pub fn my_constructor() -> Self {}
+                },
+            ),
+            // Another ink! attribute, separate and normalized attribute.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(constructor)]
+                    #[ink(selector = "0xDEADBEEF", default)]
+                    pub fn my_constructor() -> Self {}
+                },
+            ),
+        ];
+        for (expect_default, item_method) in test_inputs {
+            let is_default = <ir::Constructor as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .is_default();
+            assert_eq!(is_default, expect_default);
+        }
+    }
+
     #[test]
     fn visibility_works() {
         let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
@@ -0,0 +1,261 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ir;
+use core::convert::TryFrom;
+use proc_macro2::{
+    Ident,
+    Span,
+};
+use syn::spanned::Spanned as _;
+
+/// An ink! continuation resuming a suspended multi-step workflow of the contract.
+///
+/// # Note
+///
+/// Continuations are not exported as ink! messages. Instead they are invoked
+/// through `ink_lang::continuation::Continuations::resume_continuation` with
+/// the SCALE encoded input of the continuation. The continuation is identified
+/// by its selector which is computed the same way as for inherent ink! messages
+/// unless it has been provided via `#[ink(continuation, selector = "0xDEADBEEF")]`.
+///
+/// # Example
+///
+/// ```
+/// # use core::convert::TryFrom;
+/// # <ink_lang_ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(syn::parse_quote! {
+/// impl MyStorage {
+///     #[ink(continuation)]
+///     fn on_price_received(&mut self, price: u128) {
+///         /* continuation implementation goes here */
+/// #       unimplemented!()
+///     }
+/// }
+/// # }).unwrap();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct Continuation {
+    /// The underlying Rust method item.
+    pub(super) item: syn::ImplItemMethod,
+    /// The selector identifying the continuation.
+    selector: ir::Selector,
+}
+
+impl quote::ToTokens for Continuation {
+    /// We mainly implement this trait for this ink! type to have a derived
+    /// [`Spanned`](`syn::spanned::Spanned`) implementation for it.
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.item.to_tokens(tokens)
+    }
+}
+
+impl Continuation {
+    /// Ensures that the signature of the continuation is `fn(&mut self, T)`
+    /// without return type.
+    fn ensure_valid_signature(
+        method_item: &syn::ImplItemMethod,
+    ) -> Result<(), syn::Error> {
+        let sig = &method_item.sig;
+        let bail = |span: Span| {
+            format_err!(
+                span,
+                "ink! continuations must have a `&mut self` receiver and a single input",
+            )
+        };
+        let mut fn_args = sig.inputs.iter();
+        match fn_args.next() {
+            Some(syn::FnArg::Receiver(receiver))
+                if receiver.reference.is_some() && receiver.mutability.is_some() => {}
+            Some(arg) => return Err(bail(arg.span())),
+            None => return Err(bail(sig.span())),
+        }
+        match (fn_args.next(), fn_args.next()) {
+            (Some(syn::FnArg::Typed(_)), None) => (),
+            (_, Some(arg)) => return Err(bail(arg.span())),
+            _ => return Err(bail(sig.inputs.span())),
+        }
+        if let syn::ReturnType::Type(_, ret_type) = &sig.output {
+            return Err(format_err!(
+                ret_type,
+                "ink! continuations must not have a return type",
+            ))
+        }
+        if !sig.generics.params.is_empty() {
+            return Err(format_err_spanned!(
+                sig.generics.params,
+                "ink! continuations must not be generic",
+            ))
+        }
+        if let Some(asyncness) = sig.asyncness {
+            return Err(format_err_spanned!(
+                asyncness,
+                "ink! continuations must not be async",
+            ))
+        }
+        if let Some(unsafety) = sig.unsafety {
+            return Err(format_err_spanned!(
+                unsafety,
+                "ink! continuations must not be unsafe",
+            ))
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<syn::ImplItemMethod> for Continuation {
+    type Error = syn::Error;
+
+    fn try_from(method_item: syn::ImplItemMethod) -> Result<Self, Self::Error> {
+        let (ink_attrs, other_attrs) = ir::sanitize_attributes(
+            method_item.span(),
+            method_item.attrs.clone(),
+            &ir::AttributeArgKind::Continuation,
+            |arg| {
+                !matches!(
+                    arg,
+                    ir::AttributeArgKind::Continuation
+                        | ir::AttributeArgKind::Selector(_)
+                )
+            },
+        )?;
+        Self::ensure_valid_signature(&method_item)?;
+        let selector = match ink_attrs.selector() {
+            Some(selector) => selector,
+            None => {
+                let ident = method_item.sig.ident.to_string();
+                let hash = <blake2::Blake2b as blake2::Digest>::digest(ident.as_bytes());
+                ir::Selector::new([hash[0], hash[1], hash[2], hash[3]])
+            }
+        };
+        Ok(Self {
+            item: syn::ImplItemMethod {
+                attrs: other_attrs,
+                ..method_item
+            },
+            selector,
+        })
+    }
+}
+
+impl Continuation {
+    /// Returns the identifier of the continuation.
+    pub fn ident(&self) -> &Ident {
+        &self.item.sig.ident
+    }
+
+    /// Returns the selector identifying the continuation.
+    pub fn selector(&self) -> ir::Selector {
+        self.selector
+    }
+
+    /// Returns the type of the input of the continuation.
+    pub fn input_type(&self) -> &syn::Type {
+        match self.item.sig.inputs.iter().nth(1) {
+            Some(syn::FnArg::Typed(pat_type)) => &pat_type.ty,
+            _ => unreachable!("encountered invalid input for ink! continuation"),
+        }
+    }
+
+    /// Returns the span of the continuation.
+    pub fn span(&self) -> Span {
+        self.item.span()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn try_from_method(item_method: syn::ImplItemMethod) -> Result<Continuation, String> {
+        <Continuation as TryFrom<_>>::try_from(item_method).map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn try_from_works() {
+        let continuation = try_from_method(syn::parse_quote! {
+            #[ink(continuation)]
+            fn on_price(&mut self, price: u128) {}
+        })
+        .unwrap();
+        let hash = <blake2::Blake2b as blake2::Digest>::digest(b"on_price");
+        assert_eq!(
+            continuation.selector(),
+            ir::Selector::new([hash[0], hash[1], hash[2], hash[3]])
+        );
+        let expected_type: syn::Type = syn::parse_quote! { u128 };
+        assert_eq!(continuation.input_type(), &expected_type);
+        assert!(continuation.item.attrs.is_empty());
+        let continuation = try_from_method(syn::parse_quote! {
+            #[ink(continuation, selector = "0xDEADBEEF")]
+            fn on_price(&mut self, price: u128) {}
+        })
+        .unwrap();
+        assert_eq!(
+            continuation.selector(),
+            ir::Selector::new([0xDE, 0xAD, 0xBE, 0xEF])
+        );
+    }
+
+    #[test]
+    fn try_from_invalid_signature_fails() {
+        let expected =
+            "ink! continuations must have a `&mut self` receiver and a single input";
+        let item_methods: Vec<syn::ImplItemMethod> = vec![
+            syn::parse_quote! {
+                #[ink(continuation)]
+                fn on_price(&mut self) {}
+            },
+            syn::parse_quote! {
+                #[ink(continuation)]
+                fn on_price(&self, price: u128) {}
+            },
+            syn::parse_quote! {
+                #[ink(continuation)]
+                fn on_price(price: u128) {}
+            },
+            syn::parse_quote! {
+                #[ink(continuation)]
+                fn on_price(&mut self, price: u128, other: bool) {}
+            },
+        ];
+        for item_method in item_methods {
+            assert_eq!(
+                try_from_method(item_method).map(|_| ()),
+                Err(expected.into())
+            )
+        }
+        assert_eq!(
+            try_from_method(syn::parse_quote! {
+                #[ink(continuation)]
+                fn on_price(&mut self, price: u128) -> bool {}
+            })
+            .map(|_| ()),
+            Err("ink! continuations must not have a return type".into())
+        );
+    }
+
+    #[test]
+    fn try_from_conflicting_attributes_fails() {
+        assert!(try_from_method(syn::parse_quote! {
+            #[ink(continuation, payable)]
+            fn on_price(&mut self, price: u128) {}
+        })
+        .is_err());
+        assert!(try_from_method(syn::parse_quote! {
+            #[ink(continuation, message)]
+            fn on_price(&mut self, price: u128) {}
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,294 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::Receiver;
+use crate::ir;
+use core::convert::TryFrom;
+use proc_macro2::{
+    Ident,
+    Span,
+};
+use syn::spanned::Spanned as _;
+
+/// The point in time at which an ink! message hook is invoked.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MessageHookKind {
+    /// A `#[ink(before_message)]` hook invoked before every ink! message.
+    Before,
+    /// A `#[ink(after_message)]` hook invoked after every ink! message.
+    After,
+}
+
+impl MessageHookKind {
+    /// Returns the ink! attribute argument kind of the hook kind.
+    fn attribute_kind(self) -> ir::AttributeArgKind {
+        match self {
+            Self::Before => ir::AttributeArgKind::BeforeMessage,
+            Self::After => ir::AttributeArgKind::AfterMessage,
+        }
+    }
+}
+
+impl core::fmt::Display for MessageHookKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.attribute_kind())
+    }
+}
+
+/// An ink! message hook invoked by the dispatch around every ink! message.
+///
+/// # Note
+///
+/// The hook receives the selector of the dispatched ink! message. Hooks with a
+/// `&mut self` receiver are only invoked around ink! messages with a `&mut self`
+/// receiver since only those write back the contract storage.
+///
+/// # Example
+///
+/// ```
+/// # use core::convert::TryFrom;
+/// # <ink_lang_ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(syn::parse_quote! {
+/// impl MyStorage {
+///     #[ink(before_message)]
+///     fn count_calls(&mut self, selector: [u8; 4]) {
+///         /* hook implementation goes here */
+/// #       unimplemented!()
+///     }
+/// }
+/// # }).unwrap();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct MessageHook {
+    /// The underlying Rust method item.
+    pub(super) item: syn::ImplItemMethod,
+    /// When the hook is invoked.
+    kind: MessageHookKind,
+}
+
+impl quote::ToTokens for MessageHook {
+    /// We mainly implement this trait for this ink! type to have a derived
+    /// [`Spanned`](`syn::spanned::Spanned`) implementation for it.
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.item.to_tokens(tokens)
+    }
+}
+
+impl MessageHook {
+    /// Ensures that the signature of the hook is `fn(&self, [u8; 4])` or
+    /// `fn(&mut self, [u8; 4])` without return type.
+    ///
+    /// # Note
+    ///
+    /// The type of the selector input is checked by the Rust compiler.
+    fn ensure_valid_signature(
+        method_item: &syn::ImplItemMethod,
+        kind: MessageHookKind,
+    ) -> Result<(), syn::Error> {
+        let sig = &method_item.sig;
+        let bail = |span: Span| {
+            format_err!(
+                span,
+                "ink! {} hooks must have a `&self` or `&mut self` receiver and a \
+                 single selector input",
+                kind,
+            )
+        };
+        let mut fn_args = sig.inputs.iter();
+        match fn_args.next() {
+            Some(syn::FnArg::Receiver(receiver)) if receiver.reference.is_some() => (),
+            Some(arg) => return Err(bail(arg.span())),
+            None => return Err(bail(sig.span())),
+        }
+        match (fn_args.next(), fn_args.next()) {
+            (Some(syn::FnArg::Typed(_)), None) => (),
+            (_, Some(arg)) => return Err(bail(arg.span())),
+            _ => return Err(bail(sig.inputs.span())),
+        }
+        if let syn::ReturnType::Type(_, ret_type) = &sig.output {
+            return Err(format_err!(
+                ret_type,
+                "ink! {} hooks must not have a return type",
+                kind,
+            ))
+        }
+        if !sig.generics.params.is_empty() {
+            return Err(format_err_spanned!(
+                sig.generics.params,
+                "ink! {} hooks must not be generic",
+                kind,
+            ))
+        }
+        if let Some(asyncness) = sig.asyncness {
+            return Err(format_err_spanned!(
+                asyncness,
+                "ink! {} hooks must not be async",
+                kind,
+            ))
+        }
+        if let Some(unsafety) = sig.unsafety {
+            return Err(format_err_spanned!(
+                unsafety,
+                "ink! {} hooks must not be unsafe",
+                kind,
+            ))
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<syn::ImplItemMethod> for MessageHook {
+    type Error = syn::Error;
+
+    fn try_from(method_item: syn::ImplItemMethod) -> Result<Self, Self::Error> {
+        let attr = ir::first_ink_attribute(&method_item.attrs)?
+            .expect("missing expected ink! attribute for message hook");
+        let kind = match attr.first().kind() {
+            ir::AttributeArgKind::BeforeMessage => MessageHookKind::Before,
+            ir::AttributeArgKind::AfterMessage => MessageHookKind::After,
+            _ => {
+                return Err(format_err_spanned!(
+                    method_item,
+                    "expected #[ink(before_message)] or #[ink(after_message)] attribute",
+                ))
+            }
+        };
+        let attribute_kind = kind.attribute_kind();
+        let (_, other_attrs) = ir::sanitize_attributes(
+            method_item.span(),
+            method_item.attrs.clone(),
+            &attribute_kind,
+            |arg| arg != &attribute_kind,
+        )?;
+        Self::ensure_valid_signature(&method_item, kind)?;
+        Ok(Self {
+            item: syn::ImplItemMethod {
+                attrs: other_attrs,
+                ..method_item
+            },
+            kind,
+        })
+    }
+}
+
+impl MessageHook {
+    /// Returns when the hook is invoked.
+    pub fn kind(&self) -> MessageHookKind {
+        self.kind
+    }
+
+    /// Returns the identifier of the hook.
+    pub fn ident(&self) -> &Ident {
+        &self.item.sig.ident
+    }
+
+    /// Returns the receiver of the hook.
+    pub fn receiver(&self) -> Receiver {
+        match self.item.sig.inputs.iter().next() {
+            Some(syn::FnArg::Receiver(receiver)) => {
+                debug_assert!(receiver.reference.is_some());
+                if receiver.mutability.is_some() {
+                    Receiver::RefMut
+                } else {
+                    Receiver::Ref
+                }
+            }
+            _ => unreachable!("encountered invalid receiver for ink! message hook"),
+        }
+    }
+
+    /// Returns the span of the hook.
+    pub fn span(&self) -> Span {
+        self.item.span()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn try_from_method(item_method: syn::ImplItemMethod) -> Result<MessageHook, String> {
+        <MessageHook as TryFrom<_>>::try_from(item_method).map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn try_from_works() {
+        let hook = try_from_method(syn::parse_quote! {
+            #[ink(before_message)]
+            fn before(&self, selector: [u8; 4]) {}
+        })
+        .unwrap();
+        assert_eq!(hook.kind(), MessageHookKind::Before);
+        assert_eq!(hook.receiver(), Receiver::Ref);
+        assert!(hook.item.attrs.is_empty());
+        let hook = try_from_method(syn::parse_quote! {
+            #[ink(after_message)]
+            fn after(&mut self, _: [u8; 4]) {}
+        })
+        .unwrap();
+        assert_eq!(hook.kind(), MessageHookKind::After);
+        assert_eq!(hook.receiver(), Receiver::RefMut);
+    }
+
+    #[test]
+    fn try_from_invalid_signature_fails() {
+        let expected = "ink! before_message hooks must have a `&self` or `&mut self` \
+                        receiver and a single selector input";
+        let item_methods: Vec<syn::ImplItemMethod> = vec![
+            syn::parse_quote! {
+                #[ink(before_message)]
+                fn before(&self) {}
+            },
+            syn::parse_quote! {
+                #[ink(before_message)]
+                fn before(selector: [u8; 4]) {}
+            },
+            syn::parse_quote! {
+                #[ink(before_message)]
+                fn before(self, selector: [u8; 4]) {}
+            },
+            syn::parse_quote! {
+                #[ink(before_message)]
+                fn before(&self, selector: [u8; 4], other: bool) {}
+            },
+        ];
+        for item_method in item_methods {
+            assert_eq!(
+                try_from_method(item_method).map(|_| ()),
+                Err(expected.into())
+            )
+        }
+        assert_eq!(
+            try_from_method(syn::parse_quote! {
+                #[ink(after_message)]
+                fn after(&self, selector: [u8; 4]) -> bool {}
+            })
+            .map(|_| ()),
+            Err("ink! after_message hooks must not have a return type".into())
+        );
+    }
+
+    #[test]
+    fn try_from_conflicting_attributes_fails() {
+        assert!(try_from_method(syn::parse_quote! {
+            #[ink(before_message, payable)]
+            fn before(&self, selector: [u8; 4]) {}
+        })
+        .is_err());
+        assert!(try_from_method(syn::parse_quote! {
+            #[ink(before_message, after_message)]
+            fn hook(&self, selector: [u8; 4]) {}
+        })
+        .is_err());
+    }
+}
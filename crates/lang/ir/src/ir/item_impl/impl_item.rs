@@ -14,7 +14,9 @@
 
 use super::{
     Constructor,
+    Continuation,
     Message,
+    MessageHook,
 };
 use crate::{
     error::ExtError as _,
@@ -29,6 +31,8 @@ use syn::spanned::Spanned as _;
 /// Can be either
 /// - an ink! [`ir::Constructor`](`crate::ir::Constructor`)
 /// - an ink! [`ir::Message`](`crate::ir::Message`)
+/// - an ink! [`ir::MessageHook`](`crate::ir::MessageHook`)
+/// - an ink! [`ir::Continuation`](`crate::ir::Continuation`)
 /// - or any other non-ink! item.
 ///
 /// # Note
@@ -41,6 +45,10 @@ pub enum ImplItem {
     Constructor(Constructor),
     /// A `#[ink(message)]` marked method.
     Message(Message),
+    /// A `#[ink(before_message)]` or `#[ink(after_message)]` marked method.
+    Hook(MessageHook),
+    /// A `#[ink(continuation)]` marked method.
+    Continuation(Continuation),
     /// Any other implementation block item.
     Other(syn::ImplItem),
 }
@@ -52,6 +60,8 @@ impl quote::ToTokens for ImplItem {
         match self {
             Self::Constructor(constructor) => constructor.to_tokens(tokens),
             Self::Message(message) => message.to_tokens(tokens),
+            Self::Hook(hook) => hook.to_tokens(tokens),
+            Self::Continuation(continuation) => continuation.to_tokens(tokens),
             Self::Other(other) => other.to_tokens(tokens),
         }
     }
@@ -79,6 +89,14 @@ impl TryFrom<syn::ImplItem> for ImplItem {
                             .map(Into::into)
                             .map(Self::Constructor)
                     }
+                    ir::AttributeArgKind::BeforeMessage
+                    | ir::AttributeArgKind::AfterMessage => {
+                        <MessageHook as TryFrom<_>>::try_from(method_item).map(Self::Hook)
+                    }
+                    ir::AttributeArgKind::Continuation => {
+                        <Continuation as TryFrom<_>>::try_from(method_item)
+                            .map(Self::Continuation)
+                    }
                     _ => Err(format_err_spanned!(
                         method_item,
                         "encountered invalid ink! attribute at this point, expected either \
@@ -138,6 +156,26 @@ impl ImplItem {
         }
     }
 
+    /// Returns `Some` if `self` is an ink! message hook.
+    ///
+    /// Otherwise, returns `None`.
+    pub fn filter_map_hook(&self) -> Option<&MessageHook> {
+        match self {
+            ImplItem::Hook(hook) => Some(hook),
+            _ => None,
+        }
+    }
+
+    /// Returns `Some` if `self` is an ink! continuation.
+    ///
+    /// Otherwise, returns `None`.
+    pub fn filter_map_continuation(&self) -> Option<&Continuation> {
+        match self {
+            ImplItem::Continuation(continuation) => Some(continuation),
+            _ => None,
+        }
+    }
+
     /// Returns `true` if the impl block item is a non ink! specific item.
     pub fn is_other_item(&self) -> bool {
         self.filter_map_other_item().is_some()
@@ -14,6 +14,7 @@
 
 use super::{
     ensure_callable_invariants,
+    extract_input_docs,
     Callable,
     CallableKind,
     InputsIter,
@@ -97,6 +98,12 @@ pub struct Message {
     pub(super) item: syn::ImplItemMethod,
     /// If the ink! message can receive funds.
     is_payable: bool,
+    /// If the ink! message rejects nested calls to non-reentrant messages.
+    is_non_reentrant: bool,
+    /// If the ink! message rejects calls while the contract is paused.
+    is_when_not_paused: bool,
+    /// If only the owner of the contract is allowed to call the ink! message.
+    is_only_owner: bool,
     /// An optional user provided selector.
     ///
     /// # Note
@@ -104,6 +111,14 @@ pub struct Message {
     /// This overrides the computed selector, even when using a manual namespace
     /// for the parent implementation block.
     selector: Option<ir::Selector>,
+    /// The name of the role required to call the ink! message if any.
+    only_role: Option<String>,
+    /// The rate at which every caller may call the ink! message if any.
+    throttle: Option<ir::ThrottleRate>,
+    /// The permissions documented via `#[ink(requires = "..")]`.
+    requires: Vec<String>,
+    /// The documentation attributes of every input of the ink! message.
+    input_docs: Vec<Vec<syn::Attribute>>,
 }
 
 impl quote::ToTokens for Message {
@@ -185,7 +200,13 @@ impl Message {
                     kind,
                     ir::AttributeArgKind::Message
                         | ir::AttributeArgKind::Payable
+                        | ir::AttributeArgKind::NonReentrant
+                        | ir::AttributeArgKind::WhenNotPaused
+                        | ir::AttributeArgKind::OnlyOwner
                         | ir::AttributeArgKind::Selector(_)
+                        | ir::AttributeArgKind::OnlyRole(_)
+                        | ir::AttributeArgKind::Throttle(_)
+                        | ir::AttributeArgKind::Requires(_)
                 )
             },
         )
@@ -195,16 +216,30 @@ impl Message {
 impl TryFrom<syn::ImplItemMethod> for Message {
     type Error = syn::Error;
 
-    fn try_from(method_item: syn::ImplItemMethod) -> Result<Self, Self::Error> {
+    fn try_from(mut method_item: syn::ImplItemMethod) -> Result<Self, Self::Error> {
         ensure_callable_invariants(&method_item, CallableKind::Message)?;
         Self::ensure_receiver_is_self_ref(&method_item)?;
         Self::ensure_not_return_self(&method_item)?;
         let (ink_attrs, other_attrs) = Self::sanitize_attributes(&method_item)?;
+        let input_docs = extract_input_docs(&mut method_item);
         let is_payable = ink_attrs.is_payable();
+        let is_non_reentrant = ink_attrs.is_non_reentrant();
+        let is_when_not_paused = ink_attrs.is_when_not_paused();
+        let is_only_owner = ink_attrs.is_only_owner();
         let selector = ink_attrs.selector();
+        let only_role = ink_attrs.only_role();
+        let throttle = ink_attrs.throttle();
+        let requires = ink_attrs.requires();
         Ok(Self {
             is_payable,
+            is_non_reentrant,
+            is_when_not_paused,
+            is_only_owner,
             selector,
+            only_role,
+            throttle,
+            requires,
+            input_docs,
             item: syn::ImplItemMethod {
                 attrs: other_attrs,
                 ..method_item
@@ -257,6 +292,13 @@ impl Message {
         &self.item.attrs
     }
 
+    /// Returns the documentation attributes of every input of the ink! message.
+    ///
+    /// Yields one entry per input in the order of [`Callable::inputs`].
+    pub fn input_docs(&self) -> &[Vec<syn::Attribute>] {
+        &self.input_docs
+    }
+
     /// Returns the `self` receiver of the ink! message.
     pub fn receiver(&self) -> Receiver {
         match self.item.sig.inputs.iter().next() {
@@ -279,6 +321,73 @@ impl Message {
             syn::ReturnType::Type(_, return_type) => Some(return_type),
         }
     }
+
+    /// Returns the name of the role required to call the ink! message if any.
+    ///
+    /// # Note
+    ///
+    /// The role is required using `#[ink(message, only_role = "NAME")]`.
+    pub fn only_role(&self) -> Option<&str> {
+        self.only_role.as_deref()
+    }
+
+    /// Returns the rate at which every caller may call the ink! message if any.
+    ///
+    /// # Note
+    ///
+    /// The rate is set using `#[ink(message, throttle = "1/block")]`.
+    pub fn throttle(&self) -> Option<ir::ThrottleRate> {
+        self.throttle
+    }
+
+    /// Returns `true` if the ink! message rejects reentrant calls.
+    ///
+    /// # Note
+    ///
+    /// A call to such an ink! message fails while another call to any
+    /// `#[ink(message, non_reentrant)]` message of the same contract is in progress.
+    pub fn is_non_reentrant(&self) -> bool {
+        self.is_non_reentrant
+    }
+
+    /// Returns `true` if the ink! message rejects calls while the contract is paused.
+    ///
+    /// # Note
+    ///
+    /// Contracts with such ink! messages are paused and unpaused using the
+    /// generated `pause` and `unpause` messages.
+    pub fn is_when_not_paused(&self) -> bool {
+        self.is_when_not_paused
+    }
+
+    /// Returns the permissions required by callers of the ink! message.
+    ///
+    /// # Note
+    ///
+    /// These are the permissions documented via `#[ink(message, requires = "..")]`
+    /// followed by `owner` for `only_owner` messages and `role:NAME` for messages
+    /// requiring the `NAME` role.
+    pub fn permissions(&self) -> Vec<String> {
+        let mut permissions = self.requires.clone();
+        if self.is_only_owner {
+            permissions.push("owner".to_string());
+        }
+        if let Some(role) = &self.only_role {
+            permissions.push(format!("role:{}", role));
+        }
+        let mut seen = std::collections::HashSet::new();
+        permissions.retain(|permission| seen.insert(permission.clone()));
+        permissions
+    }
+
+    /// Returns `true` if only the owner of the contract may call the ink! message.
+    ///
+    /// # Note
+    ///
+    /// This requires the contract to be ownable via `#[ink::contract(ownable = true)]`.
+    pub fn is_only_owner(&self) -> bool {
+        self.is_only_owner
+    }
 }
 
 #[cfg(test)]
@@ -372,6 +481,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn input_docs_works() {
+        let item_method: syn::ImplItemMethod = syn::parse_quote! {
+            #[ink(message)]
+            fn my_message(
+                &self,
+                /// The first input.
+                a: i32,
+                #[allow(unused)]
+                b: u64,
+            ) {}
+        };
+        let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+        let expected_docs: Vec<Vec<syn::Attribute>> = vec![
+            vec![syn::parse_quote! { #[doc = " The first input."] }],
+            vec![],
+        ];
+        assert_eq!(message.input_docs(), expected_docs.as_slice());
+        let expected_inputs: Vec<syn::PatType> = vec![
+            syn::parse_quote! { a: i32 },
+            syn::parse_quote! { #[allow(unused)] b: u64 },
+        ];
+        assert_eq!(
+            message.inputs().cloned().collect::<Vec<_>>(),
+            expected_inputs
+        );
+    }
+
     #[test]
     fn is_payable_works() {
         let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
@@ -418,6 +555,224 @@ mod tests {
         }
     }
 
+    #[test]
+    fn is_non_reentrant_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
+            // Reentrant.
+            (
+                false,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+            // Normalized ink! attribute.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message, non_reentrant)]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message, payable)]
+                    #[ink(non_reentrant)]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expect_non_reentrant, item_method) in test_inputs {
+            let is_non_reentrant = <ir::Message as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .is_non_reentrant();
+            assert_eq!(is_non_reentrant, expect_non_reentrant);
+        }
+    }
+
+    #[test]
+    fn is_when_not_paused_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
+            // Callable while paused.
+            (
+                false,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+            // Normalized ink! attribute.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message, when_not_paused)]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    #[ink(when_not_paused, non_reentrant)]
+                    pub fn my_message(&self) {}
+                },
+            ),
+        ];
+        for (expect_when_not_paused, item_method) in test_inputs {
+            let is_when_not_paused = <ir::Message as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .is_when_not_paused();
+            assert_eq!(is_when_not_paused, expect_when_not_paused);
+        }
+    }
+
+    #[test]
+    fn is_only_owner_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
+            // Callable by everyone.
+            (
+                false,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+            // Normalized ink! attribute.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message, only_owner)]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message, payable)]
+                    #[ink(only_owner)]
+                    pub fn my_message(&self) {}
+                },
+            ),
+        ];
+        for (expect_only_owner, item_method) in test_inputs {
+            let is_only_owner = <ir::Message as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .is_only_owner();
+            assert_eq!(is_only_owner, expect_only_owner);
+        }
+    }
+
+    #[test]
+    fn only_role_works() {
+        let test_inputs: Vec<(Option<&str>, syn::ImplItemMethod)> = vec![
+            // No required role.
+            (
+                None,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            // Normalized ink! attribute.
+            (
+                Some("ADMIN"),
+                syn::parse_quote! {
+                    #[ink(message, only_role = "ADMIN")]
+                    pub fn my_message(&self) {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                Some("MINTER"),
+                syn::parse_quote! {
+                    #[ink(message, payable)]
+                    #[ink(only_role = "MINTER")]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expected_role, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(message.only_role(), expected_role);
+        }
+    }
+
+    #[test]
+    fn throttle_works() {
+        let test_inputs: Vec<(Option<(u32, u32)>, syn::ImplItemMethod)> = vec![
+            // No throttle.
+            (
+                None,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            // Normalized ink! attribute.
+            (
+                Some((1, 1)),
+                syn::parse_quote! {
+                    #[ink(message, throttle = "1/block")]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                Some((2, 100)),
+                syn::parse_quote! {
+                    #[ink(message, payable)]
+                    #[ink(throttle = "2/100 blocks")]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expected_rate, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            let rate = message.throttle().map(|rate| (rate.calls(), rate.blocks()));
+            assert_eq!(rate, expected_rate);
+        }
+    }
+
+    #[test]
+    fn permissions_works() {
+        let test_inputs: Vec<(Vec<&str>, syn::ImplItemMethod)> = vec![
+            // No permissions.
+            (
+                vec![],
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            // Documented permissions.
+            (
+                vec!["owner", "kyc"],
+                syn::parse_quote! {
+                    #[ink(message, requires = "owner")]
+                    #[ink(requires = "kyc")]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+            // Permissions checked by the ink! dispatch.
+            (
+                vec!["kyc", "owner", "role:MINTER"],
+                syn::parse_quote! {
+                    #[ink(message, requires = "kyc", only_owner, requires = "owner")]
+                    #[ink(only_role = "MINTER")]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expected_permissions, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(message.permissions(), expected_permissions);
+        }
+    }
+
     #[test]
     fn receiver_works() {
         let test_inputs: Vec<(Receiver, syn::ImplItemMethod)> = vec![
@@ -25,6 +25,8 @@ use proc_macro2::{
 
 mod callable;
 mod constructor;
+mod continuation;
+mod hook;
 mod impl_item;
 mod iter;
 mod message;
@@ -32,7 +34,10 @@ mod message;
 #[cfg(test)]
 mod tests;
 
-use self::callable::ensure_callable_invariants;
+use self::callable::{
+    ensure_callable_invariants,
+    extract_input_docs,
+};
 pub use self::{
     callable::{
         Callable,
@@ -42,6 +47,11 @@ pub use self::{
         Visibility,
     },
     constructor::Constructor,
+    continuation::Continuation,
+    hook::{
+        MessageHook,
+        MessageHookKind,
+    },
     impl_item::ImplItem,
     iter::{
         IterConstructors,
@@ -198,7 +208,10 @@ impl ItemImpl {
                         .expect("missing expected ink! attribute for struct");
                     match attr.first().kind() {
                         ir::AttributeArgKind::Constructor
-                        | ir::AttributeArgKind::Message => return Ok(true),
+                        | ir::AttributeArgKind::Message
+                        | ir::AttributeArgKind::BeforeMessage
+                        | ir::AttributeArgKind::AfterMessage
+                        | ir::AttributeArgKind::Continuation => return Ok(true),
                         _ => continue 'repeat,
                     }
                 }
@@ -244,6 +257,7 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
             .map(<ImplItem as TryFrom<_>>::try_from)
             .collect::<Result<Vec<_>, syn::Error>>()?;
         let is_trait_impl = item_impl.trait_.is_some();
+        let impl_token_span = item_impl.impl_token.span;
         for impl_item in &impl_items {
             /// Ensures that visibility of ink! messages and constructors is
             /// valid in dependency of the containing ink! impl block.
@@ -252,21 +266,31 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
             ///
             /// Trait implementation blocks expect inherited visibility
             /// while inherent implementation block expect public visibility.
+            ///
+            /// The error points at the offending visibility if any and
+            /// at the `impl` token of the containing impl block.
             fn ensure_valid_visibility(
                 vis: ir::Visibility,
                 span: Span,
+                impl_token_span: Span,
                 what: &str,
                 is_trait_impl: bool,
             ) -> Result<(), syn::Error> {
                 let requires_pub = !is_trait_impl;
+                let impl_kind = if is_trait_impl { "trait" } else { "inherent" };
                 if requires_pub != vis.is_pub() {
                     return Err(format_err!(
-                        span,
+                        vis.span().unwrap_or(span),
                         "ink! {} in {} impl blocks must have {} visibility",
                         what,
-                        if is_trait_impl { "trait" } else { "inherent" },
+                        impl_kind,
                         if requires_pub { "public" } else { "inherited" },
-                    ))
+                    )
+                    .into_combine(format_err!(
+                        impl_token_span,
+                        "in this {} impl block",
+                        impl_kind,
+                    )))
                 }
                 Ok(())
             }
@@ -275,6 +299,7 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
                     ensure_valid_visibility(
                         message.visibility(),
                         message.item.span(),
+                        impl_token_span,
                         "message",
                         is_trait_impl,
                     )?;
@@ -283,10 +308,32 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
                     ensure_valid_visibility(
                         constructor.visibility(),
                         constructor.item.span(),
+                        impl_token_span,
                         "constructor",
                         is_trait_impl,
                     )?;
                 }
+                ir::ImplItem::Hook(hook) if is_trait_impl => {
+                    return Err(format_err!(
+                        hook.span(),
+                        "ink! {} hooks must be defined in inherent impl blocks",
+                        hook.kind(),
+                    )
+                    .into_combine(format_err!(
+                        impl_token_span,
+                        "in this trait impl block"
+                    )))
+                }
+                ir::ImplItem::Continuation(continuation) if is_trait_impl => {
+                    return Err(format_err!(
+                        continuation.span(),
+                        "ink! continuations must be defined in inherent impl blocks",
+                    )
+                    .into_combine(format_err!(
+                        impl_token_span,
+                        "in this trait impl block"
+                    )))
+                }
                 _ => (),
             }
         }
@@ -349,6 +396,24 @@ impl ItemImpl {
             .map(|segment| &segment.ident)
     }
 
+    /// Returns the identifier of the trait implementation block that is unique
+    /// within the ink! smart contract.
+    ///
+    /// This is derived from the full trait path so that implementations of
+    /// different traits with the same identifier, e.g. `v1::MyTrait` and
+    /// `v2::MyTrait`, can be told apart.
+    ///
+    /// Returns `None` if this is an inherent implementation block.
+    pub fn trait_impl_id(&self) -> Option<ir::Selector> {
+        self.trait_path().map(|trait_path| {
+            let mut path = quote::ToTokens::to_token_stream(trait_path).to_string();
+            path.retain(|c| !c.is_whitespace());
+            let joined = format!("__ink_trait_impl::{}", path);
+            let hash = <blake2::Blake2b as blake2::Digest>::digest(joined.as_bytes());
+            ir::Selector::new([hash[0], hash[1], hash[2], hash[3]])
+        })
+    }
+
     /// Returns the namespace of the implementation block if any has been provided.
     pub fn namespace(&self) -> Option<&ir::Namespace> {
         self.namespace.as_ref()
@@ -364,6 +429,18 @@ impl ItemImpl {
         IterConstructors::new(self)
     }
 
+    /// Returns an iterator yielding the ink! message hooks of the implementation block.
+    pub fn iter_hooks(&self) -> impl Iterator<Item = &ir::MessageHook> {
+        self.items.iter().filter_map(ImplItem::filter_map_hook)
+    }
+
+    /// Returns an iterator yielding the ink! continuations of the implementation block.
+    pub fn iter_continuations(&self) -> impl Iterator<Item = &ir::Continuation> {
+        self.items
+            .iter()
+            .filter_map(ImplItem::filter_map_continuation)
+    }
+
     /// Returns a slice over the shared references of the items of the impl.
     pub fn items(&self) -> &[ir::ImplItem] {
         &self.items
@@ -254,3 +254,27 @@ fn namespace_works() {
         ))
     )
 }
+
+#[test]
+fn trait_impl_id_works() {
+    let trait_impl_id = |item_impl: syn::ItemImpl| {
+        <ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(item_impl)
+            .unwrap()
+            .trait_impl_id()
+    };
+    let inherent = trait_impl_id(syn::parse_quote! {
+        #[ink(impl)]
+        impl MyStorage {}
+    });
+    let v1 = trait_impl_id(syn::parse_quote! {
+        #[ink(impl)]
+        impl v1::MyTrait for MyStorage {}
+    });
+    let v2 = trait_impl_id(syn::parse_quote! {
+        #[ink(impl)]
+        impl v2::MyTrait for MyStorage {}
+    });
+    assert_eq!(inherent, None);
+    assert!(v1.is_some());
+    assert_ne!(v1, v2);
+}
@@ -98,7 +98,7 @@ pub struct ItemMod {
 impl ItemMod {
     /// Ensures that the ink! storage struct is not missing and that there are
     /// not multiple ink! storage struct definitions for the given slice of items.
-    fn ensure_storage_struct_quantity(
+    pub(super) fn ensure_storage_struct_quantity(
         module_span: Span,
         items: &[ir::Item],
     ) -> Result<(), syn::Error> {
@@ -122,7 +122,7 @@ impl ItemMod {
     }
 
     /// Ensures that the given slice of items contains at least one ink! message.
-    fn ensure_contains_message(
+    pub(super) fn ensure_contains_message(
         module_span: Span,
         items: &[ir::Item],
     ) -> Result<(), syn::Error> {
@@ -165,6 +165,511 @@ impl ItemMod {
         Ok(())
     }
 
+    /// Ensures that the given slice of items contains at most one default
+    /// ink! constructor.
+    fn ensure_unique_default_constructor(items: &[ir::Item]) -> Result<(), syn::Error> {
+        use crate::error::ExtError as _;
+        let mut defaults = items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .filter_map(ir::InkItem::filter_map_impl_block)
+            .flat_map(ir::ItemImpl::iter_constructors)
+            .map(|constructor| constructor.callable())
+            .filter(|constructor| constructor.is_default());
+        if let Some(first) = defaults.next() {
+            if let Some(second) = defaults.next() {
+                return Err(format_err!(
+                    second.span(),
+                    "encountered multiple default ink! constructors",
+                )
+                .into_combine(format_err!(
+                    first.span(),
+                    "first default ink! constructor here",
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that the given slice of items contains at most one ink! message hook
+    /// of every kind.
+    fn ensure_unique_message_hooks(items: &[ir::Item]) -> Result<(), syn::Error> {
+        use crate::error::ExtError as _;
+        let mut before: Option<&ir::MessageHook> = None;
+        let mut after: Option<&ir::MessageHook> = None;
+        let hooks = items
+            .iter()
+            .filter_map(|item| {
+                match item {
+                    ir::Item::Ink(ir::InkItem::ImplBlock(item_impl)) => {
+                        Some(item_impl.iter_hooks())
+                    }
+                    _ => None,
+                }
+            })
+            .flatten();
+        for hook in hooks {
+            let found = match hook.kind() {
+                ir::MessageHookKind::Before => &mut before,
+                ir::MessageHookKind::After => &mut after,
+            };
+            if let Some(first) = found {
+                return Err(format_err!(
+                    hook.span(),
+                    "encountered multiple ink! {} hooks",
+                    hook.kind(),
+                )
+                .into_combine(format_err!(first.span(), "first ink! hook here")))
+            }
+            *found = Some(hook);
+        }
+        Ok(())
+    }
+
+    /// Ensures that the ink! continuations of the given slice of items have
+    /// unique selectors.
+    fn ensure_unique_continuation_selectors(
+        items: &[ir::Item],
+    ) -> Result<(), syn::Error> {
+        use crate::error::ExtError as _;
+        let mut seen: HashMap<ir::Selector, &ir::Continuation> = HashMap::new();
+        let continuations = items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .filter_map(ir::InkItem::filter_map_impl_block)
+            .flat_map(ir::ItemImpl::iter_continuations);
+        for continuation in continuations {
+            if let Some(first) = seen.insert(continuation.selector(), continuation) {
+                return Err(format_err!(
+                    continuation.span(),
+                    "encountered ink! continuations with overlapping selectors (= {:02X?})",
+                    continuation.selector().as_bytes(),
+                )
+                .into_combine(format_err!(
+                    first.span(),
+                    "first ink! continuation with overlapping selector here",
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if any ink! message of the items satisfies the predicate.
+    fn any_message<P>(items: &[ir::Item], mut predicate: P) -> bool
+    where
+        P: FnMut(&ir::Message) -> bool,
+    {
+        items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .filter_map(ir::InkItem::filter_map_impl_block)
+            .flat_map(ir::ItemImpl::iter_messages)
+            .any(|message| predicate(message.callable()))
+    }
+
+    /// Returns the identifier of the ink! storage struct of the items.
+    ///
+    /// # Panics
+    ///
+    /// If there is no ink! storage struct among the items.
+    fn storage_ident(items: &[ir::Item]) -> Ident {
+        items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .find_map(ir::InkItem::filter_map_storage_item)
+            .expect("encountered ink! module without a storage struct")
+            .ident()
+            .clone()
+    }
+
+    /// Returns the identifiers of all methods implemented for the ink! storage
+    /// struct, including the ones of non-ink! implementation blocks.
+    fn storage_methods(items: &[ir::Item]) -> Vec<&Ident> {
+        let storage_ident = Self::storage_ident(items);
+        let mut methods = Vec::new();
+        for item in items {
+            match item {
+                ir::Item::Ink(ir::InkItem::ImplBlock(item_impl)) => methods.extend(
+                    item_impl.items().iter().filter_map(|item| match item {
+                        ir::ImplItem::Constructor(constructor) => {
+                            Some(ir::Callable::ident(constructor))
+                        }
+                        ir::ImplItem::Message(message) => {
+                            Some(ir::Callable::ident(message))
+                        }
+                        ir::ImplItem::Hook(hook) => Some(hook.ident()),
+                        ir::ImplItem::Continuation(continuation) => {
+                            Some(continuation.ident())
+                        }
+                        ir::ImplItem::Other(syn::ImplItem::Method(method)) => {
+                            Some(&method.sig.ident)
+                        }
+                        ir::ImplItem::Other(_) => None,
+                    }),
+                ),
+                ir::Item::Rust(syn::Item::Impl(item_impl))
+                    if item_impl.trait_.is_none()
+                        && matches!(
+                            &*item_impl.self_ty,
+                            syn::Type::Path(self_ty) if self_ty.path.is_ident(&storage_ident)
+                        ) =>
+                {
+                    methods.extend(item_impl.items.iter().filter_map(|item| match item {
+                        syn::ImplItem::Method(method) => Some(&method.sig.ident),
+                        _ => None,
+                    }))
+                }
+                _ => (),
+            }
+        }
+        methods
+    }
+
+    /// Ensures that the user provided items do not use the names of the items
+    /// that ink! generates for the given feature.
+    ///
+    /// # Errors
+    ///
+    /// If a method of the ink! storage struct has one of the `methods` names or
+    /// an item of the ink! module has one of the `types` names.
+    fn ensure_no_reserved_names(
+        items: &[ir::Item],
+        methods: &[&str],
+        types: &[&str],
+        feature: &str,
+    ) -> Result<(), syn::Error> {
+        let collision = |ident: &Ident| {
+            format_err!(
+                ident.span(),
+                "encountered `{}` which collides with an item generated by ink! for {}",
+                ident,
+                feature,
+            )
+        };
+        if let Some(method) = Self::storage_methods(items)
+            .into_iter()
+            .find(|ident| methods.iter().any(|name| ident == name))
+        {
+            return Err(collision(method))
+        }
+        let type_idents = items.iter().filter_map(|item| match item {
+            ir::Item::Ink(ir::InkItem::Event(event)) => Some(event.ident()),
+            ir::Item::Ink(ir::InkItem::ImportedEvent(event)) => Some(event.ident()),
+            ir::Item::Rust(syn::Item::Struct(item)) => Some(&item.ident),
+            ir::Item::Rust(syn::Item::Enum(item)) => Some(&item.ident),
+            ir::Item::Rust(syn::Item::Type(item)) => Some(&item.ident),
+            ir::Item::Rust(syn::Item::Union(item)) => Some(&item.ident),
+            _ => None,
+        });
+        for ident in type_idents {
+            if types.iter().any(|name| ident == name) {
+                return Err(collision(ident))
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the `Paused` event and the messages to pause the contract if any
+    /// ink! message can only be called while the contract is not paused.
+    ///
+    /// # Note
+    ///
+    /// The added `pause` and `unpause` messages require the admin role of
+    /// `ink_lang::access_control`.
+    fn add_pausable_items(items: &mut Vec<ir::Item>) -> Result<(), syn::Error> {
+        if !Self::any_message(items, ir::Message::is_when_not_paused) {
+            return Ok(())
+        }
+        Self::ensure_no_reserved_names(
+            items,
+            &["pause", "unpause", "paused"],
+            &["Paused"],
+            "`#[ink(message, when_not_paused)]` messages",
+        )?;
+        let storage_ident = Self::storage_ident(items);
+        let event: syn::ItemStruct = syn::parse_quote! {
+            /// Emitted whenever the contract has been paused or unpaused.
+            #[ink(event)]
+            pub struct Paused {
+                /// `true` if the contract has been paused.
+                paused: bool,
+                /// The account that paused or unpaused the contract.
+                #[ink(topic)]
+                account: AccountId,
+            }
+        };
+        let item_impl: syn::ItemImpl = syn::parse_quote! {
+            impl #storage_ident {
+                /// Pauses the contract.
+                ///
+                /// Requires the caller to have the admin role.
+                #[ink(message, only_role = "ADMIN")]
+                pub fn pause(&mut self) {
+                    ::ink_lang::pausable::set_paused(true);
+                    self.env().emit_event(Paused {
+                        paused: true,
+                        account: self.env().caller(),
+                    })
+                }
+
+                /// Unpauses the contract.
+                ///
+                /// Requires the caller to have the admin role.
+                #[ink(message, only_role = "ADMIN")]
+                pub fn unpause(&mut self) {
+                    ::ink_lang::pausable::set_paused(false);
+                    self.env().emit_event(Paused {
+                        paused: false,
+                        account: self.env().caller(),
+                    })
+                }
+
+                /// Returns `true` if the contract is paused.
+                #[ink(message)]
+                pub fn paused(&self) -> bool {
+                    ::ink_lang::pausable::is_paused()
+                }
+            }
+        };
+        items.push(<ir::Item as TryFrom<syn::Item>>::try_from(
+            syn::Item::Struct(event),
+        )?);
+        items.push(<ir::Item as TryFrom<syn::Item>>::try_from(
+            syn::Item::Impl(item_impl),
+        )?);
+        Ok(())
+    }
+
+    /// Adds the messages to manage the ownership of an ownable contract.
+    ///
+    /// # Errors
+    ///
+    /// If the added messages overlap with the selectors of other ink! messages.
+    pub(crate) fn add_ownable_messages(&mut self) -> Result<(), syn::Error> {
+        Self::ensure_no_reserved_names(
+            &self.items,
+            &[
+                "owner",
+                "pending_owner",
+                "transfer_ownership",
+                "accept_ownership",
+                "renounce_ownership",
+            ],
+            &[],
+            "ownable contracts",
+        )?;
+        let storage_ident = Self::storage_ident(&self.items);
+        let item_impl: syn::ItemImpl = syn::parse_quote! {
+            impl #storage_ident {
+                /// Returns the owner of the contract if any.
+                #[ink(message)]
+                pub fn owner(&self) -> Option<AccountId> {
+                    ::ink_lang::ownable::owner::<Environment>()
+                }
+
+                /// Returns the account that has been proposed as new owner if any.
+                #[ink(message)]
+                pub fn pending_owner(&self) -> Option<AccountId> {
+                    ::ink_lang::ownable::pending_owner::<Environment>()
+                }
+
+                /// Proposes the account as the new owner of the contract.
+                ///
+                /// The ownership is transferred once the account accepts it.
+                #[ink(message, only_owner)]
+                pub fn transfer_ownership(&mut self, new_owner: AccountId) {
+                    ::ink_lang::ownable::set_pending_owner::<Environment>(Some(&new_owner))
+                }
+
+                /// Accepts the ownership of the contract.
+                ///
+                /// # Errors
+                ///
+                /// If the caller has not been proposed as the new owner.
+                #[ink(message)]
+                pub fn accept_ownership(
+                    &mut self,
+                ) -> Result<(), ::ink_lang::ownable::OwnershipError> {
+                    ::ink_lang::ownable::accept_ownership::<Environment>()
+                }
+
+                /// Renounces the ownership of the contract.
+                ///
+                /// Afterwards no account is able to call owner only messages.
+                #[ink(message, only_owner)]
+                pub fn renounce_ownership(&mut self) {
+                    ::ink_lang::ownable::renounce_ownership::<Environment>()
+                }
+            }
+        };
+        self.items.push(<ir::Item as TryFrom<syn::Item>>::try_from(
+            syn::Item::Impl(item_impl),
+        )?);
+        Self::ensure_no_overlapping_selectors(&self.items)
+    }
+
+    /// Adds the message reporting the ink! trait definitions implemented
+    /// by the contract.
+    ///
+    /// Trait implementation blocks with a namespace are not reported since
+    /// their messages do not have the selectors of the trait definition.
+    ///
+    /// # Errors
+    ///
+    /// If the added message overlaps with the selector of another ink! message.
+    pub(crate) fn add_supports_interface_message(&mut self) -> Result<(), syn::Error> {
+        Self::ensure_no_reserved_names(
+            &self.items,
+            &["supports_interface"],
+            &[],
+            "`supports_interface = true` contracts",
+        )?;
+        let storage_ident = Self::storage_ident(&self.items);
+        let interface_id_ident = ir::InkTrait::interface_id_ident();
+        let trait_paths = self
+            .items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .filter_map(ir::InkItem::filter_map_impl_block)
+            .filter(|item_impl| item_impl.namespace().is_none())
+            .filter_map(ir::ItemImpl::trait_path)
+            .cloned()
+            .collect::<Vec<_>>();
+        let item_impl: syn::ItemImpl = syn::parse_quote! {
+            impl #storage_ident {
+                /// Returns `true` if the contract implements the interface with the
+                /// given identifier.
+                ///
+                /// Interface identifiers are derived from the ink! trait definitions
+                /// implemented by the contract, see `ink_lang::interface`.
+                #[ink(message)]
+                pub fn supports_interface(&self, interface_id: [u8; 4]) -> bool {
+                    [
+                        ::ink_lang::interface::SUPPORTS_INTERFACE_ID,
+                        #( <#storage_ident as #trait_paths>::#interface_id_ident, )*
+                    ]
+                    .contains(&interface_id)
+                }
+            }
+        };
+        self.items.push(<ir::Item as TryFrom<syn::Item>>::try_from(
+            syn::Item::Impl(item_impl),
+        )?);
+        Self::ensure_no_overlapping_selectors(&self.items)
+    }
+
+    /// Adds the message returning the dispatch counters of the ink! messages.
+    ///
+    /// # Errors
+    ///
+    /// If the added message overlaps with the selector of another ink! message.
+    pub(crate) fn add_telemetry_message(&mut self) -> Result<(), syn::Error> {
+        Self::ensure_no_reserved_names(
+            &self.items,
+            &["telemetry"],
+            &[],
+            "`telemetry = true` contracts",
+        )?;
+        let storage_ident = Self::storage_ident(&self.items);
+        let item_impl: syn::ItemImpl = syn::parse_quote! {
+            impl #storage_ident {
+                /// Returns how often the `&mut self` ink! messages have been dispatched.
+                ///
+                /// The messages are identified by their selectors and ordered by
+                /// their first dispatch. Messages that have never been dispatched
+                /// are omitted.
+                #[ink(message)]
+                pub fn telemetry(&self) -> ::ink_lang::telemetry::CallCounts {
+                    ::ink_lang::telemetry::call_counts()
+                }
+            }
+        };
+        self.items.push(<ir::Item as TryFrom<syn::Item>>::try_from(
+            syn::Item::Impl(item_impl),
+        )?);
+        Self::ensure_no_overlapping_selectors(&self.items)
+    }
+
+    /// Ensures that no ink! message may only be called by the owner.
+    ///
+    /// # Errors
+    ///
+    /// If the contract is not ownable but has `#[ink(only_owner)]` messages.
+    pub(crate) fn ensure_no_only_owner_messages(&self) -> Result<(), syn::Error> {
+        let only_owner_message = self
+            .impls()
+            .flat_map(ir::ItemImpl::iter_messages)
+            .find(|message| message.callable().is_only_owner());
+        if let Some(message) = only_owner_message {
+            return Err(format_err!(
+                message.callable().span(),
+                "encountered `#[ink(only_owner)]` message in a contract that is not ownable\n\
+                 hint: use #[ink::contract(ownable = true)] to make the contract ownable",
+            ))
+        }
+        Ok(())
+    }
+
+    /// Adds the messages to manage roles if any ink! message requires a role.
+    ///
+    /// # Note
+    ///
+    /// The added `grant_role` and `revoke_role` messages themselves require
+    /// the admin role of `ink_lang::access_control`.
+    fn add_access_control_messages(items: &mut Vec<ir::Item>) -> Result<(), syn::Error> {
+        if !Self::any_message(items, |message| message.only_role().is_some()) {
+            return Ok(())
+        }
+        Self::ensure_no_reserved_names(
+            items,
+            &["grant_role", "revoke_role", "has_role"],
+            &[],
+            "`#[ink(message, only_role = \"...\")]` messages",
+        )?;
+        let storage_ident = Self::storage_ident(items);
+        let item_impl: syn::ItemImpl = syn::parse_quote! {
+            impl #storage_ident {
+                /// Grants the role to the account.
+                ///
+                /// Requires the caller to have the admin role.
+                #[ink(message, only_role = "ADMIN")]
+                pub fn grant_role(
+                    &mut self,
+                    role: ::ink_lang::access_control::RoleId,
+                    account: AccountId,
+                ) {
+                    ::ink_lang::access_control::grant_role::<Environment>(role, &account)
+                }
+
+                /// Revokes the role from the account.
+                ///
+                /// Requires the caller to have the admin role.
+                #[ink(message, only_role = "ADMIN")]
+                pub fn revoke_role(
+                    &mut self,
+                    role: ::ink_lang::access_control::RoleId,
+                    account: AccountId,
+                ) {
+                    ::ink_lang::access_control::revoke_role::<Environment>(role, &account)
+                }
+
+                /// Returns `true` if the account has been granted the role.
+                #[ink(message)]
+                pub fn has_role(
+                    &self,
+                    role: ::ink_lang::access_control::RoleId,
+                    account: AccountId,
+                ) -> bool {
+                    ::ink_lang::access_control::has_role::<Environment>(role, &account)
+                }
+            }
+        };
+        items.push(<ir::Item as TryFrom<syn::Item>>::try_from(
+            syn::Item::Impl(item_impl),
+        )?);
+        Ok(())
+    }
+
     /// Ensures that no ink! message or constructor selectors are overlapping.
     ///
     /// # Note
@@ -172,7 +677,13 @@ impl ItemMod {
     /// We differentiate between ink! message and ink! constructor selectors
     /// since they are dispatched independently from each other and thus are
     /// allowed to have overlapping selectors.
-    fn ensure_no_overlapping_selectors(items: &[ir::Item]) -> Result<(), syn::Error> {
+    ///
+    /// Callables are compared by their composed identifiers so that messages of
+    /// different versions of the same ink! trait definition, e.g. implemented
+    /// via `v1::MyTrait` and `v2::MyTrait`, are not reported as overlapping.
+    pub(super) fn ensure_no_overlapping_selectors(
+        items: &[ir::Item],
+    ) -> Result<(), syn::Error> {
         let mut messages = <HashMap<ir::Selector, &ir::Message>>::new();
         let mut constructors = <HashMap<ir::Selector, &ir::Constructor>>::new();
         for item_impl in items
@@ -205,7 +716,7 @@ impl ItemMod {
                 ))
             }
             for message in item_impl.iter_messages() {
-                let selector = message.composed_selector();
+                let selector = message.composed_id();
                 match messages.entry(selector) {
                     Entry::Occupied(overlap) => {
                         return Err(compose_error(
@@ -221,7 +732,7 @@ impl ItemMod {
                 }
             }
             for constructor in item_impl.iter_constructors() {
-                let selector = constructor.composed_selector();
+                let selector = constructor.composed_id();
                 match constructors.entry(selector) {
                     Entry::Occupied(overlap) => {
                         return Err(compose_error(
@@ -270,14 +781,19 @@ impl TryFrom<syn::ItemMod> for ItemMod {
             }
             return Err(error)
         }
-        let items = items
+        let mut items = items
             .into_iter()
             .map(<ir::Item as TryFrom<syn::Item>>::try_from)
             .collect::<Result<Vec<_>, syn::Error>>()?;
         Self::ensure_storage_struct_quantity(module_span, &items)?;
+        Self::add_pausable_items(&mut items)?;
+        Self::add_access_control_messages(&mut items)?;
         Self::ensure_contains_message(module_span, &items)?;
         Self::ensure_contains_constructor(module_span, &items)?;
         Self::ensure_no_overlapping_selectors(&items)?;
+        Self::ensure_unique_default_constructor(&items)?;
+        Self::ensure_unique_message_hooks(&items)?;
+        Self::ensure_unique_continuation_selectors(&items)?;
         Ok(Self {
             attrs: other_attrs,
             vis: module.vis,
@@ -426,6 +942,35 @@ impl ItemMod {
         IterEvents::new(self)
     }
 
+    /// Returns an iterator yielding all events imported into this ink! module via
+    /// `#[ink(event)] use path::to::Event;`.
+    ///
+    /// # Note
+    ///
+    /// Imported events follow the event definitions of the ink! module in the
+    /// event enum and the metadata of the ink! smart contract.
+    pub fn imported_events(&self) -> impl Iterator<Item = &ir::ImportedEvent> {
+        IterInkItems::new(self).filter_map(ir::InkItem::filter_map_imported_event)
+    }
+
+    /// Returns `true` if the ink! module defines or imports any ink! events.
+    pub fn has_events(&self) -> bool {
+        self.events().next().is_some() || self.imported_events().next().is_some()
+    }
+
+    /// Returns the ink! message hook of the given kind if any.
+    pub fn message_hook(&self, kind: ir::MessageHookKind) -> Option<&ir::MessageHook> {
+        self.impls()
+            .map(ir::ItemImpl::iter_hooks)
+            .flatten()
+            .find(|hook| hook.kind() == kind)
+    }
+
+    /// Returns an iterator yielding all ink! continuations of the ink! module.
+    pub fn continuations(&self) -> impl Iterator<Item = &ir::Continuation> {
+        self.impls().flat_map(ir::ItemImpl::iter_continuations)
+    }
+
     /// Returns all non-ink! attributes of the ink! module.
     pub fn attrs(&self) -> &[syn::Attribute] {
         &self.attrs
@@ -730,6 +1275,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn multiple_default_constructors_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor, default)]
+                        pub fn my_constructor_1() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor, default)]
+                        pub fn my_constructor_2() -> Self {}
+                    }
+                }
+            },
+            "encountered multiple default ink! constructors",
+        );
+    }
+
+    #[test]
+    fn multiple_message_hooks_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+
+                        #[ink(before_message)]
+                        fn before_1(&self, selector: [u8; 4]) {}
+                    }
+
+                    impl MyStorage {
+                        #[ink(after_message)]
+                        fn after(&self, selector: [u8; 4]) {}
+
+                        #[ink(before_message)]
+                        fn before_2(&mut self, selector: [u8; 4]) {}
+                    }
+                }
+            },
+            "encountered multiple ink! before_message hooks",
+        );
+    }
+
+    #[test]
+    fn overlapping_continuations_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+
+                        #[ink(continuation, selector = "0xDEADBEEF")]
+                        fn on_price(&mut self, price: u128) {}
+
+                        #[ink(continuation, selector = "0xDEADBEEF")]
+                        fn on_confirm(&mut self, confirmed: bool) {}
+                    }
+                }
+            },
+            "encountered ink! continuations with overlapping selectors (= [DE, AD, BE, EF])",
+        );
+    }
+
     #[test]
     fn overlapping_constructors_fails() {
         assert_fail(
@@ -835,4 +1465,136 @@ mod tests {
             .is_ok()
         );
     }
+
+    #[test]
+    fn pausable_name_collision_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, when_not_paused)]
+                        pub fn my_message(&self) {}
+                    }
+
+                    impl MyStorage {
+                        fn pause(&mut self) {}
+                    }
+                }
+            },
+            "encountered `pause` which collides with an item generated by ink! for \
+             `#[ink(message, when_not_paused)]` messages",
+        );
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    #[ink(event)]
+                    pub struct Paused {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, when_not_paused)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "encountered `Paused` which collides with an item generated by ink! for \
+             `#[ink(message, when_not_paused)]` messages",
+        );
+    }
+
+    #[test]
+    fn access_control_name_collision_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, only_role = "MINTER")]
+                        pub fn mint(&mut self) {}
+
+                        #[ink(message)]
+                        pub fn grant_role(&mut self) {}
+                    }
+                }
+            },
+            "encountered `grant_role` which collides with an item generated by ink! for \
+             `#[ink(message, only_role = \"...\")]` messages",
+        );
+    }
+
+    #[test]
+    fn reserved_names_without_feature_works() {
+        assert!(
+            <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    pub struct Paused;
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn pause(&mut self) {}
+
+                        #[ink(message)]
+                        pub fn grant_role(&mut self) {}
+                    }
+                }
+            })
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn imported_events_works() {
+        let item_mod =
+            <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    #[ink(event)]
+                    use psp22::Transfer;
+
+                    use psp22::Approval;
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            })
+            .unwrap();
+        assert_eq!(item_mod.events().count(), 0);
+        assert!(item_mod.has_events());
+        assert_eq!(
+            item_mod
+                .imported_events()
+                .map(|event| event.ident().to_string())
+                .collect::<Vec<_>>(),
+            vec!["Transfer".to_string()],
+        );
+    }
 }
@@ -0,0 +1,409 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    error::ExtError as _,
+    ir,
+    ir::idents_lint,
+};
+use core::convert::TryFrom;
+use proc_macro2::{
+    Ident,
+    TokenStream as TokenStream2,
+};
+use syn::{
+    spanned::Spanned as _,
+    Result,
+};
+
+/// A checked ink! mixin definition.
+///
+/// Noticed by ink! through the `#[ink::mixin]` annotation on an inline module.
+///
+/// An ink! mixin consists of exactly one `#[ink(storage)]` struct and inherent
+/// implementation blocks with ink! messages for it. The selectors of the messages
+/// are namespaced by the name of the storage struct unless the implementation
+/// block provides its own namespace.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang_ir as ir;
+/// # ir::InkMixin::new(quote::quote! {}, quote::quote! {
+/// mod escrow {
+///     #[ink(storage)]
+///     pub struct Escrow {
+///         /* storage fields */
+///     }
+///
+///     impl Escrow {
+///         #[ink(message)]
+///         pub fn release(&mut self) {
+///             /* message statements */
+///         }
+///     }
+/// }
+/// # }).unwrap();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct InkMixin {
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    ident: Ident,
+    items: Vec<ir::Item>,
+}
+
+impl InkMixin {
+    /// Creates a new ink! mixin definition from the given attribute arguments
+    /// and inline module.
+    pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self> {
+        if !attr.is_empty() {
+            return Err(format_err_spanned!(
+                attr,
+                "unexpected ink! mixin configuration argument",
+            ))
+        }
+        let module = syn::parse2::<syn::ItemMod>(input)?;
+        <Self as TryFrom<syn::ItemMod>>::try_from(module)
+    }
+}
+
+impl TryFrom<syn::ItemMod> for InkMixin {
+    type Error = syn::Error;
+
+    fn try_from(module: syn::ItemMod) -> core::result::Result<Self, Self::Error> {
+        let module_span = module.span();
+        idents_lint::ensure_no_ink_identifiers(&module)?;
+        let items = match module.content {
+            Some((_, items)) => items,
+            None => {
+                return Err(format_err_spanned!(
+                    module,
+                    "out-of-line ink! mixins are not supported, use `#[ink::mixin] mod name {{ ... }}`",
+                ))
+            }
+        };
+        let (ink_attrs, other_attrs) = ir::partition_attributes(module.attrs)?;
+        if let Some(ink_attr) = ink_attrs.first() {
+            return Err(format_err!(
+                ink_attr.span(),
+                "encountered invalid ink! attribute on ink! mixin"
+            ))
+        }
+        let namespace = Self::storage_ident(&items).map(ToString::to_string);
+        let items = items
+            .into_iter()
+            .map(|item| Self::namespace_item(item, namespace.as_deref()))
+            .map(|item| <ir::Item as TryFrom<syn::Item>>::try_from(item?))
+            .collect::<Result<Vec<_>>>()?;
+        ir::ItemMod::ensure_storage_struct_quantity(module_span, &items)?;
+        ir::ItemMod::ensure_contains_message(module_span, &items)?;
+        for item in items.iter().filter_map(ir::Item::map_ink_item) {
+            Self::ensure_valid_item(item, namespace.as_deref().unwrap_or_default())?;
+        }
+        ir::ItemMod::ensure_no_overlapping_selectors(&items)?;
+        Ok(Self {
+            attrs: other_attrs,
+            vis: module.vis,
+            ident: module.ident,
+            items,
+        })
+    }
+}
+
+impl InkMixin {
+    /// Returns the identifier of the first `#[ink(storage)]` struct if any.
+    fn storage_ident(items: &[syn::Item]) -> Option<&Ident> {
+        items.iter().find_map(|item| {
+            match item {
+                syn::Item::Struct(item_struct) => {
+                    let attr = ir::first_ink_attribute(&item_struct.attrs).ok()??;
+                    match attr.first().kind() {
+                        ir::AttributeArgKind::Storage => Some(&item_struct.ident),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Applies the namespace of the mixin to the ink! implementation block
+    /// unless it provides its own namespace.
+    fn namespace_item(item: syn::Item, namespace: Option<&str>) -> Result<syn::Item> {
+        let (mut item_impl, namespace) = match (item, namespace) {
+            (syn::Item::Impl(item_impl), Some(namespace)) => (item_impl, namespace),
+            (item, _) => return Ok(item),
+        };
+        if ir::ItemImpl::is_ink_impl_block(&item_impl)? {
+            let (ink_attrs, _) = ir::partition_attributes(item_impl.attrs.clone())?;
+            let has_namespace = !ink_attrs.is_empty()
+                && ir::InkAttribute::from_expanded(ink_attrs)?
+                    .namespace()
+                    .is_some();
+            if !has_namespace {
+                item_impl
+                    .attrs
+                    .push(syn::parse_quote! { #[ink(namespace = #namespace)] });
+            }
+        }
+        Ok(syn::Item::Impl(item_impl))
+    }
+
+    /// Ensures that the ink! item is supported by ink! mixins.
+    fn ensure_valid_item(item: &ir::InkItem, storage_ident: &str) -> Result<()> {
+        let item_impl = match item {
+            ir::InkItem::Storage(storage) => {
+                if let Some((_, field)) = storage.mixin_fields().next() {
+                    return Err(format_err!(
+                        field.span(),
+                        "nested ink! mixins are not supported",
+                    ))
+                }
+                return Ok(())
+            }
+            ir::InkItem::Event(event) => {
+                return Err(format_err_spanned!(
+                    event,
+                    "ink! events are not supported in ink! mixins",
+                ))
+            }
+            ir::InkItem::ImportedEvent(event) => {
+                return Err(format_err_spanned!(
+                    event,
+                    "ink! events are not supported in ink! mixins",
+                ))
+            }
+            ir::InkItem::ImplBlock(item_impl) => item_impl,
+        };
+        if let Some(trait_path) = item_impl.trait_path() {
+            return Err(format_err_spanned!(
+                trait_path,
+                "trait implementations are not supported in ink! mixins",
+            ))
+        }
+        let self_type = item_impl.self_type();
+        let implements_storage = match self_type {
+            syn::Type::Path(type_path) => type_path.path.is_ident(storage_ident),
+            _ => false,
+        };
+        if !implements_storage {
+            return Err(format_err_spanned!(
+                self_type,
+                "ink! mixin implementation blocks must implement the ink! storage struct",
+            ))
+        }
+        if let Some(constructor) = item_impl.iter_constructors().next() {
+            return Err(format_err!(
+                constructor.callable().span(),
+                "ink! constructors are not supported in ink! mixins",
+            ))
+        }
+        if let Some(hook) = item_impl.iter_hooks().next() {
+            return Err(format_err!(
+                hook.span(),
+                "ink! message hooks are not supported in ink! mixins",
+            ))
+        }
+        if let Some(continuation) = item_impl.iter_continuations().next() {
+            return Err(format_err!(
+                continuation.span(),
+                "ink! continuations are not supported in ink! mixins",
+            ))
+        }
+        Ok(())
+    }
+}
+
+impl InkMixin {
+    /// Returns the non-ink! attributes of the ink! mixin module.
+    pub fn attrs(&self) -> &[syn::Attribute] {
+        &self.attrs
+    }
+
+    /// Returns the visibility of the ink! mixin module.
+    pub fn vis(&self) -> &syn::Visibility {
+        &self.vis
+    }
+
+    /// Returns the identifier of the ink! mixin module.
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Returns the storage struct of the ink! mixin.
+    pub fn storage(&self) -> &ir::Storage {
+        self.items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .find_map(ir::InkItem::filter_map_storage_item)
+            .expect("encountered ink! mixin without a storage struct")
+    }
+
+    /// Returns an iterator yielding the ink! implementation blocks of the ink! mixin.
+    pub fn impls(&self) -> impl Iterator<Item = &ir::ItemImpl> {
+        self.items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .filter_map(ir::InkItem::filter_map_impl_block)
+    }
+
+    /// Returns an iterator yielding the non-ink! items of the ink! mixin module.
+    pub fn rust_items(&self) -> impl Iterator<Item = &syn::Item> {
+        self.items.iter().filter_map(ir::Item::map_rust_item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selector(input: &[u8]) -> ir::Selector {
+        let hash = <blake2::Blake2b as blake2::Digest>::digest(input);
+        ir::Selector::new([hash[0], hash[1], hash[2], hash[3]])
+    }
+
+    fn assert_mixin_fails(input: TokenStream2, expected: &str) {
+        assert_eq!(
+            InkMixin::new(TokenStream2::new(), input).map_err(|err| err.to_string()),
+            Err(expected.to_string())
+        )
+    }
+
+    #[test]
+    fn mixin_works() {
+        let mixin = InkMixin::new(
+            TokenStream2::new(),
+            quote::quote! {
+                mod escrow {
+                    #[ink(storage)]
+                    pub struct Escrow {
+                        amount: u128,
+                    }
+
+                    impl Escrow {
+                        #[ink(message)]
+                        pub fn amount(&self) -> u128 {
+                            self.amount
+                        }
+                    }
+
+                    #[ink(namespace = "Custom")]
+                    impl Escrow {
+                        #[ink(message)]
+                        pub fn release(&mut self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        assert_eq!(mixin.storage().ident(), "Escrow");
+        let selectors = mixin
+            .impls()
+            .flat_map(ir::ItemImpl::iter_messages)
+            .map(|message| message.composed_selector())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            selectors,
+            vec![selector(b"Escrow::amount"), selector(b"Custom::release")]
+        );
+    }
+
+    #[test]
+    fn config_argument_fails() {
+        assert_eq!(
+            InkMixin::new(quote::quote! { version = 2 }, quote::quote! { mod m {} })
+                .map_err(|err| err.to_string()),
+            Err("unexpected ink! mixin configuration argument".to_string())
+        )
+    }
+
+    #[test]
+    fn constructor_fails() {
+        assert_mixin_fails(
+            quote::quote! {
+                mod escrow {
+                    #[ink(storage)]
+                    pub struct Escrow {}
+
+                    impl Escrow {
+                        #[ink(constructor)]
+                        pub fn new() -> Self {
+                            Self {}
+                        }
+
+                        #[ink(message)]
+                        pub fn release(&mut self) {}
+                    }
+                }
+            },
+            "ink! constructors are not supported in ink! mixins",
+        )
+    }
+
+    #[test]
+    fn event_fails() {
+        assert_mixin_fails(
+            quote::quote! {
+                mod escrow {
+                    #[ink(storage)]
+                    pub struct Escrow {}
+
+                    #[ink(event)]
+                    pub struct Released {}
+
+                    impl Escrow {
+                        #[ink(message)]
+                        pub fn release(&mut self) {}
+                    }
+                }
+            },
+            "ink! events are not supported in ink! mixins",
+        )
+    }
+
+    #[test]
+    fn guarded_messages_work() {
+        let mixin = InkMixin::new(
+            TokenStream2::new(),
+            quote::quote! {
+                mod escrow {
+                    #[ink(storage)]
+                    pub struct Escrow {}
+
+                    impl Escrow {
+                        #[ink(message, only_owner, non_reentrant)]
+                        pub fn release(&mut self) {}
+
+                        #[ink(message, only_role = "refunder", when_not_paused)]
+                        pub fn refund(&mut self) {}
+
+                        #[ink(message, throttle = "1/block")]
+                        pub fn claim(&mut self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let messages = mixin
+            .impls()
+            .flat_map(ir::ItemImpl::iter_messages)
+            .map(|message| message.callable())
+            .collect::<Vec<_>>();
+        assert!(messages[0].is_only_owner() && messages[0].is_non_reentrant());
+        assert_eq!(messages[1].only_role(), Some("refunder"));
+        assert!(messages[1].is_when_not_paused());
+        assert!(messages[2].throttle().is_some());
+    }
+}
@@ -14,15 +14,24 @@
 
 #![allow(dead_code)]
 
+mod address_book;
+mod assert_storage_footprint;
+mod assert_trait_impl;
 mod attrs;
+mod cfg_macro;
 mod config;
 mod contract;
+mod contract_ref;
 mod idents_lint;
+mod ink_bench;
+mod ink_event;
 mod ink_test;
 mod item;
 mod item_impl;
 mod item_mod;
+mod mixin;
 mod selector;
+mod sign_payload;
 mod trait_def;
 pub mod utils;
 
@@ -38,13 +47,28 @@ use self::attrs::{
     AttributeArgKind,
     InkAttribute,
 };
+use self::cfg_macro::expand_cfg_macros;
 pub use self::{
-    attrs::Namespace,
+    address_book::{
+        Address,
+        AddressBook,
+        Network,
+    },
+    assert_storage_footprint::AssertStorageFootprint,
+    assert_trait_impl::AssertTraitImpl,
+    attrs::{
+        Namespace,
+        ThrottleRate,
+    },
     config::Config,
     contract::Contract,
+    contract_ref::ContractRef,
+    ink_bench::InkBench,
+    ink_event::InkEvent,
     ink_test::InkTest,
     item::{
         Event,
+        ImportedEvent,
         InkItem,
         Item,
         Storage,
@@ -54,12 +78,15 @@ pub use self::{
         CallableKind,
         CallableWithSelector,
         Constructor,
+        Continuation,
         ImplItem,
         InputsIter,
         ItemImpl,
         IterConstructors,
         IterMessages,
         Message,
+        MessageHook,
+        MessageHookKind,
         Receiver,
         Visibility,
     },
@@ -68,7 +95,9 @@ pub use self::{
         IterEvents,
         IterItemImpls,
     },
+    mixin::InkMixin,
     selector::Selector,
+    sign_payload::SignPayload,
     trait_def::{
         InkTrait,
         InkTraitConstructor,
@@ -0,0 +1,107 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens as _;
+
+/// The input of the `#[derive(SignPayload)]` macro.
+///
+/// Refers to a struct whose values are signed as typed structured data,
+/// e.g. the payload of a meta-transaction.
+pub struct SignPayload {
+    /// The struct deriving the trait.
+    item: syn::ItemStruct,
+}
+
+impl SignPayload {
+    /// Returns `Ok` if the input is a struct.
+    ///
+    /// # Errors
+    ///
+    /// If the input is not a struct.
+    pub fn new(input: TokenStream2) -> Result<Self, syn::Error> {
+        let item = syn::parse2::<syn::ItemStruct>(input).map_err(|err| {
+            format_err!(err.span(), "expected a struct for `#[derive(SignPayload)]`")
+        })?;
+        Ok(Self { item })
+    }
+
+    /// Returns the identifier of the struct.
+    pub fn ident(&self) -> &syn::Ident {
+        &self.item.ident
+    }
+
+    /// Returns the generics of the struct.
+    pub fn generics(&self) -> &syn::Generics {
+        &self.item.generics
+    }
+
+    /// Returns the type string of the struct.
+    ///
+    /// The type string lists the name of the struct followed by the names and
+    /// types of all of its fields, e.g. `Transfer(to:AccountId,value:Balance)`.
+    /// Fields of tuple structs are named by their index.
+    pub fn type_string(&self) -> String {
+        let fields = self
+            .item
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let name = match &field.ident {
+                    Some(ident) => ident.to_string(),
+                    None => index.to_string(),
+                };
+                let ty = field.ty.to_token_stream().to_string();
+                let ty = ty.split_whitespace().collect::<String>();
+                format!("{}:{}", name, ty)
+            })
+            .collect::<Vec<_>>();
+        format!("{}({})", self.ident(), fields.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_string(input: TokenStream2) -> String {
+        SignPayload::new(input).unwrap().type_string()
+    }
+
+    #[test]
+    fn type_string_works() {
+        assert_eq!(
+            type_string(quote::quote! {
+                pub struct Transfer {
+                    to: AccountId,
+                    value: Balance,
+                    memo: Option<Vec<u8> >,
+                }
+            }),
+            "Transfer(to:AccountId,value:Balance,memo:Option<Vec<u8>>)",
+        );
+        assert_eq!(
+            type_string(quote::quote! { struct Nonce(u64, [u8; 4]); }),
+            "Nonce(0:u64,1:[u8;4])",
+        );
+        assert_eq!(type_string(quote::quote! { struct Ping; }), "Ping()");
+    }
+
+    #[test]
+    fn non_struct_fails() {
+        assert!(SignPayload::new(quote::quote! { enum Call { A, B } }).is_err());
+        assert!(SignPayload::new(quote::quote! { fn transfer() {} }).is_err());
+    }
+}
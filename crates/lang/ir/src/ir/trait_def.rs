@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use crate::{
+    ast,
+    error::ExtError as _,
     ir,
     ir::idents_lint,
 };
@@ -22,6 +24,7 @@ use proc_macro2::{
     Span,
     TokenStream as TokenStream2,
 };
+use quote::format_ident;
 use syn::{
     spanned::Spanned as _,
     Result,
@@ -31,16 +34,18 @@ use syn::{
 #[derive(Debug, PartialEq, Eq)]
 pub struct InkTrait {
     item: syn::ItemTrait,
+    /// The version of the trait definition.
+    ///
+    /// Versions other than the default version `1` are folded into the
+    /// selectors of the trait messages.
+    version: u32,
 }
 
 impl TryFrom<syn::ItemTrait> for InkTrait {
     type Error = syn::Error;
 
     fn try_from(item_trait: syn::ItemTrait) -> core::result::Result<Self, Self::Error> {
-        idents_lint::ensure_no_ink_identifiers(&item_trait)?;
-        Self::analyse_properties(&item_trait)?;
-        Self::analyse_items(&item_trait)?;
-        Ok(Self { item: item_trait })
+        Self::from_item(item_trait, 1)
     }
 }
 
@@ -140,13 +145,13 @@ impl<'a> Iterator for IterInkTraitItems<'a> {
             match self.iter.next() {
                 None => return None,
                 Some(syn::TraitItem::Method(method)) => {
-                    let first_attr = ir::first_ink_attribute(&method.attrs)
-                        .ok()
-                        .flatten()
-                        .expect("unexpected missing ink! attribute for trait method")
-                        .first()
-                        .kind()
-                        .clone();
+                    let first_attr = match ir::first_ink_attribute(&method.attrs)
+                        .expect("encountered unexpected invalid ink! attributes")
+                    {
+                        Some(ink_attr) => ink_attr.first().kind().clone(),
+                        // Default implemented methods are no ink! trait items.
+                        None => continue 'outer,
+                    };
                     match first_attr {
                         ir::AttributeArgKind::Constructor => {
                             return Some(InkTraitItem::Constructor(InkTraitConstructor {
@@ -248,6 +253,16 @@ impl<'a> InkTraitMessage<'a> {
         self.item.span()
     }
 
+    /// Returns the selector of the ink! message if it has been pinned
+    /// by the ink! trait definition using `#[ink(selector = ..)]`.
+    pub fn user_provided_selector(&self) -> Option<ir::Selector> {
+        let (ink_attrs, _rust_attrs) = ir::partition_attributes(self.item.attrs.clone())
+            .expect("encountered unexpected invalid ink! attributes");
+        ir::InkAttribute::from_expanded(ink_attrs)
+            .expect("encountered unexpected invalid ink! attributes")
+            .selector()
+    }
+
     /// Returns `true` if the ink! message may mutate the contract storage.
     pub fn mutates(&self) -> bool {
         self.sig()
@@ -277,14 +292,69 @@ impl<'a> InkTraitMessage<'a> {
 impl InkTrait {
     /// Returns `Ok` if the trait matches all requirements for an ink! trait definition.
     pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self> {
-        if !attr.is_empty() {
-            return Err(format_err_spanned!(
-                attr,
-                "unexpected attribute input for ink! trait definition"
-            ))
-        }
+        let args = syn::parse2::<ast::AttributeArgs>(attr)?;
+        let version = Self::analyse_args(args)?;
         let item_trait = syn::parse2::<syn::ItemTrait>(input)?;
-        InkTrait::try_from(item_trait)
+        InkTrait::from_item(item_trait, version)
+    }
+
+    /// Checks the trait and creates the ink! trait definition of the given version.
+    fn from_item(item_trait: syn::ItemTrait, version: u32) -> Result<Self> {
+        idents_lint::ensure_no_ink_identifiers(&item_trait)?;
+        Self::analyse_properties(&item_trait)?;
+        Self::analyse_items(&item_trait)?;
+        let ink_trait = Self {
+            item: item_trait,
+            version,
+        };
+        ink_trait.ensure_no_overlapping_selectors()?;
+        Ok(ink_trait)
+    }
+
+    /// Analyses the arguments of the `#[ink::trait_definition]` attribute
+    /// and returns the version of the trait definition.
+    ///
+    /// # Errors
+    ///
+    /// - If an argument other than `version` is provided.
+    /// - If the `version` argument is provided more than once.
+    /// - If the version is not an integer literal greater than zero.
+    fn analyse_args(args: ast::AttributeArgs) -> Result<u32> {
+        let mut version: Option<(u32, ast::MetaNameValue)> = None;
+        for arg in args.into_iter() {
+            if !arg.name.is_ident("version") {
+                return Err(format_err_spanned!(
+                    arg,
+                    "encountered unknown or unsupported ink! trait definition argument",
+                ))
+            }
+            if let Some((_, fst)) = version {
+                return Err(format_err_spanned!(
+                    arg,
+                    "encountered duplicate ink! `version` trait definition argument",
+                )
+                .into_combine(format_err_spanned!(
+                    fst,
+                    "first `version` trait definition argument here",
+                )))
+            }
+            let value = match &arg.value {
+                ast::PathOrLit::Lit(syn::Lit::Int(lit_int)) => {
+                    lit_int.base10_parse::<u32>().ok().filter(|value| *value > 0)
+                }
+                _ => None,
+            };
+            match value {
+                Some(value) => version = Some((value, arg)),
+                None => {
+                    return Err(format_err_spanned!(
+                        arg.value,
+                        "expected an integer literal greater than zero for `version` ink! trait definition argument",
+                    ))
+                }
+            }
+        }
+        Ok(version.map(|(value, _)| value).unwrap_or(1))
     }
 
     /// Returns span of the ink! trait definition.
@@ -302,11 +372,191 @@ impl InkTrait {
         &self.item.ident
     }
 
+    /// Returns the version of the ink! trait definition.
+    ///
+    /// This is `1` unless specified otherwise via `#[ink::trait_definition(version = N)]`.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
     /// Returns an iterator yielding the ink! specific items of the ink! trait definition.
     pub fn iter_items(&self) -> IterInkTraitItems {
         IterInkTraitItems::new(self)
     }
 
+    /// Returns an iterator yielding the default implemented methods of the
+    /// ink! trait definition.
+    ///
+    /// Default implemented methods are no ink! messages and therefore cannot be
+    /// called from outside of the contract. They are implemented for all ink!
+    /// smart contracts implementing the trait definition and may emit ink! events
+    /// defined via `#[ink::event]` on behalf of them.
+    pub fn iter_default_methods(&self) -> impl Iterator<Item = &syn::TraitItemMethod> {
+        self.item
+            .items
+            .iter()
+            .filter_map(|trait_item| {
+                match trait_item {
+                    syn::TraitItem::Method(method)
+                        if !ir::contains_ink_attributes(&method.attrs) =>
+                    {
+                        Some(method)
+                    }
+                    _ => None,
+                }
+            })
+    }
+
+    /// Returns the selector of the ink! trait message.
+    ///
+    /// This is the selector pinned by the ink! trait definition if any.
+    /// Otherwise this is the selector an ink! smart contract composes for the
+    /// message if it implements the trait definition without an additional namespace.
+    ///
+    /// Versioned ink! trait definitions compose their selectors with the version
+    /// and pin them for all messages, see [`InkTrait::compose_versioned_message_selector`].
+    pub fn message_selector(&self, message: &InkTraitMessage) -> ir::Selector {
+        message.user_provided_selector().unwrap_or_else(|| {
+            Self::compose_versioned_message_selector(
+                self.ident(),
+                self.version,
+                &message.sig().ident,
+            )
+        })
+    }
+
+    /// Returns the selector pinned by the ink! trait definition for the ink! trait message.
+    ///
+    /// This is the selector provided by the user if any. For versioned ink! trait
+    /// definitions this is the selector composed with the version otherwise so that
+    /// implementing ink! smart contracts inherit it.
+    pub fn pinned_message_selector(
+        &self,
+        message: &InkTraitMessage,
+    ) -> Option<ir::Selector> {
+        if self.version == 1 {
+            return message.user_provided_selector()
+        }
+        Some(self.message_selector(message))
+    }
+
+    /// Returns the selector of the ink! trait message with the given identifier
+    /// of the ink! trait definition with the given identifier and version.
+    ///
+    /// Version `1` composes the same selectors as [`InkTrait::compose_message_selector`].
+    /// Other versions are folded into the hashed input as in `MyTrait::v2::message`.
+    /// This allows ink! smart contracts to implement multiple versions of the same
+    /// trait definition without overlapping selectors.
+    pub fn compose_versioned_message_selector(
+        trait_ident: &Ident,
+        version: u32,
+        message_ident: &Ident,
+    ) -> ir::Selector {
+        if version == 1 {
+            return Self::compose_message_selector(trait_ident, message_ident)
+        }
+        let joined = format!("{}::v{}::{}", trait_ident, version, message_ident);
+        let hash = <blake2::Blake2b as blake2::Digest>::digest(joined.as_bytes());
+        ir::Selector::new([hash[0], hash[1], hash[2], hash[3]])
+    }
+
+    /// Returns the selector of the ink! trait message with the given identifier
+    /// of the ink! trait definition with the given identifier.
+    ///
+    /// See [`InkTrait::message_selector`] for more information.
+    pub fn compose_message_selector(
+        trait_ident: &Ident,
+        message_ident: &Ident,
+    ) -> ir::Selector {
+        let joined = format!("{}::{}", trait_ident, message_ident);
+        let hash = <blake2::Blake2b as blake2::Digest>::digest(joined.as_bytes());
+        ir::Selector::new([hash[0], hash[1], hash[2], hash[3]])
+    }
+
+    /// Returns the interface identifier of the ink! trait definition.
+    ///
+    /// See [`InkTrait::compose_interface_id`] for more information.
+    pub fn interface_id(&self) -> [u8; 4] {
+        Self::compose_interface_id(
+            self.iter_items()
+                .flat_map(InkTraitItem::filter_map_message)
+                .map(|message| self.message_selector(&message)),
+        )
+    }
+
+    /// Returns the interface identifier of an ink! trait definition with the
+    /// given message selectors.
+    ///
+    /// This is the first four bytes of the BLAKE2 hash of the sorted and
+    /// concatenated selectors. It therefore does not depend on the order in
+    /// which the messages have been defined but changes whenever a message is
+    /// added, removed or renamed or when the version of the trait definition changes.
+    pub fn compose_interface_id<I>(selectors: I) -> [u8; 4]
+    where
+        I: IntoIterator<Item = ir::Selector>,
+    {
+        let mut selectors = selectors.into_iter().collect::<Vec<_>>();
+        selectors.sort_unstable_by_key(|selector| *selector.as_bytes());
+        let joined = selectors
+            .iter()
+            .flat_map(|selector| selector.as_bytes().iter().copied())
+            .collect::<Vec<_>>();
+        let hash = <blake2::Blake2b as blake2::Digest>::digest(&joined);
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+
+    /// Returns the identifier of the type asserting that an ink! smart contract
+    /// implements the ink! trait definition with the given identifier.
+    ///
+    /// The assertion is used through the `assert_trait_impl!` macro.
+    pub fn assert_impl_ident(trait_ident: &Ident) -> Ident {
+        format_ident!("__ink_{}AssertImpl", trait_ident)
+    }
+
+    /// Returns the identifier of the contract reference generated for the
+    /// ink! trait definition with the given identifier.
+    ///
+    /// The contract reference allows to call ink! smart contracts implementing
+    /// the trait definition through its messages.
+    pub fn contract_ref_ident(trait_ident: &Ident) -> Ident {
+        format_ident!("__ink_{}ContractRef", trait_ident)
+    }
+
+    /// Returns the identifier of the mock contract generated for the
+    /// ink! trait definition with the given identifier.
+    ///
+    /// The mock contract allows to program the responses to the messages
+    /// of the trait definition for off-chain tests.
+    pub fn mock_ident(trait_ident: &Ident) -> Ident {
+        format_ident!("Mock{}", trait_ident)
+    }
+
+    /// Returns the identifier of the associated constant holding the selector
+    /// pinned by the ink! trait definition for the message with the given identifier.
+    ///
+    /// ink! smart contracts implementing the trait definition inherit the selector
+    /// unless they provide their own selector for the message.
+    pub fn pinned_selector_ident(message_ident: &Ident) -> Ident {
+        format_ident!("__ink_pinned_selector_{}", message_ident)
+    }
+
+    /// Returns the identifier of the associated constant holding the interface
+    /// identifier of the ink! trait definition.
+    ///
+    /// The interface identifiers are reported by the `supports_interface` message of
+    /// implementing ink! smart contracts.
+    pub fn interface_id_ident() -> Ident {
+        format_ident!("__ink_interface_id")
+    }
+
+    /// Returns the identifier of the associated constant holding the version
+    /// of the ink! trait definition.
+    ///
+    /// The version is part of the metadata of implementing ink! smart contracts.
+    pub fn version_ident() -> Ident {
+        format_ident!("__ink_trait_version")
+    }
+
     /// Analyses the properties of the ink! trait definition.
     ///
     /// # Errors
@@ -349,6 +599,42 @@ impl InkTrait {
         Ok(())
     }
 
+    /// Returns `Ok` if no two ink! trait messages share the same selector.
+    ///
+    /// # Errors
+    ///
+    /// If a message pins the selector of another message of the trait definition.
+    fn ensure_no_overlapping_selectors(&self) -> Result<()> {
+        use std::collections::hash_map::{
+            Entry,
+            HashMap,
+        };
+        let mut seen = <HashMap<ir::Selector, Span>>::new();
+        for message in self
+            .iter_items()
+            .filter_map(InkTraitItem::filter_map_message)
+        {
+            let selector = self.message_selector(&message);
+            match seen.entry(selector) {
+                Entry::Occupied(overlap) => {
+                    return Err(format_err!(
+                        message.span(),
+                        "encountered ink! trait messages with overlapping selectors (= {:02X?})",
+                        selector.as_bytes(),
+                    )
+                    .into_combine(format_err!(
+                        *overlap.get(),
+                        "first ink! trait message with overlapping selector here",
+                    )))
+                }
+                Entry::Vacant(vacant) => {
+                    vacant.insert(message.span());
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Returns `Ok` if all trait items respects the requirements for an ink! trait definition.
     ///
     /// # Errors
@@ -358,10 +644,11 @@ impl InkTrait {
     ///     - associated types (`type`)
     ///     - macros definitions or usages
     ///     - unknown token sequences (verbatims)
-    ///     - methods with default implementations
+    ///     - ink! messages or ink! constructors with default implementations
     /// - If the trait contains methods which do not respect the ink! trait definition requirements:
-    ///     - All trait methods need to be declared as either `#[ink(message)]` or `#[ink(constructor)]`
-    ///       and need to respect their respective rules.
+    ///     - All trait methods without default implementation need to be declared as
+    ///       either `#[ink(message)]` or `#[ink(constructor)]` and need to respect
+    ///       their respective rules.
     ///
     /// # Note
     ///
@@ -417,6 +704,11 @@ impl InkTrait {
     /// - If the method does not respect the properties of either an
     ///   ink! message or ink! constructor.
     fn analyse_methods(method: &syn::TraitItemMethod) -> Result<()> {
+        if method.default.is_some() && !ir::contains_ink_attributes(&method.attrs) {
+            // Default implemented methods without ink! attributes are plain Rust
+            // methods that are not exposed as ink! messages.
+            return Ok(())
+        }
         if let Some(default_impl) = &method.default {
             return Err(format_err_spanned!(
                 default_impl,
@@ -469,10 +761,14 @@ impl InkTrait {
                         Self::analyse_constructor(method)?;
                     }
                     _unsupported => {
-                        return Err(format_err_spanned!(
-                            method,
+                        return Err(format_err!(
+                            ink_attr.first().span(),
                             "encountered unsupported ink! attribute for ink! trait method",
-                        ))
+                        )
+                        .into_combine(format_err!(
+                            method.sig.ident,
+                            "at this ink! trait method",
+                        )))
                     }
                 }
             }
@@ -545,7 +841,12 @@ impl InkTrait {
             message.span(),
             message.attrs.clone(),
             &ir::AttributeArgKind::Message,
-            |c| !matches!(c, ir::AttributeArgKind::Message),
+            |c| {
+                !matches!(
+                    c,
+                    ir::AttributeArgKind::Message | ir::AttributeArgKind::Selector(_)
+                )
+            },
         )?;
         match message.sig.receiver() {
             None | Some(syn::FnArg::Typed(_)) => {
@@ -570,6 +871,7 @@ impl InkTrait {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use quote::quote;
 
     /// Checks if the token stream in `$trait_def` results in the expected error message.
     macro_rules! assert_ink_trait_eq_err {
@@ -700,6 +1002,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn trait_def_containing_default_methods_works() {
+        let ink_trait =
+            <InkTrait as TryFrom<syn::ItemTrait>>::try_from(syn::parse_quote! {
+                pub trait MyTrait {
+                    #[ink(message)]
+                    fn message(&self);
+
+                    fn default_method(&self, value: i32) -> i32 {
+                        value
+                    }
+                }
+            })
+            .unwrap();
+        assert_eq!(ink_trait.iter_items().count(), 1);
+        assert_eq!(
+            ink_trait
+                .iter_default_methods()
+                .map(|method| method.sig.ident.to_string())
+                .collect::<Vec<_>>(),
+            vec!["default_method".to_string()],
+        );
+    }
+
     #[test]
     fn trait_def_containing_const_methods_is_denied() {
         assert_ink_trait_eq_err!(
@@ -1103,4 +1429,242 @@ mod tests {
             "__ink_trait::MyTrait::constructor_1:0,constructor_2:2",
         );
     }
+
+    #[test]
+    fn message_selector_works() {
+        let ink_trait = ink_trait! {
+            pub trait MyTrait {
+                #[ink(message)]
+                fn message_1(&self);
+                #[ink(message)]
+                fn message_2(&mut self, a: i32, b: i32) -> i32;
+            }
+        };
+        let selectors = ink_trait
+            .iter_items()
+            .flat_map(InkTraitItem::filter_map_message)
+            .map(|message| ink_trait.message_selector(&message))
+            .collect::<Vec<_>>();
+        let expected = ["MyTrait::message_1", "MyTrait::message_2"]
+            .iter()
+            .map(|joined| {
+                let hash = <blake2::Blake2b as blake2::Digest>::digest(joined.as_bytes());
+                ir::Selector::new([hash[0], hash[1], hash[2], hash[3]])
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(selectors, expected);
+    }
+
+    #[test]
+    fn pinned_message_selector_works() {
+        let ink_trait = ink_trait! {
+            pub trait MyTrait {
+                #[ink(message, selector = "0xDEADBEEF")]
+                fn message_1(&self);
+                #[ink(message)]
+                fn message_2(&self);
+            }
+        };
+        let selectors = ink_trait
+            .iter_items()
+            .flat_map(InkTraitItem::filter_map_message)
+            .map(|message| {
+                (
+                    message.user_provided_selector(),
+                    ink_trait.message_selector(&message),
+                )
+            })
+            .collect::<Vec<_>>();
+        let hash = <blake2::Blake2b as blake2::Digest>::digest(b"MyTrait::message_2");
+        assert_eq!(
+            selectors,
+            vec![
+                (
+                    Some(ir::Selector::new([0xDE, 0xAD, 0xBE, 0xEF])),
+                    ir::Selector::new([0xDE, 0xAD, 0xBE, 0xEF]),
+                ),
+                (
+                    None,
+                    ir::Selector::new([hash[0], hash[1], hash[2], hash[3]])
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn versioned_message_selector_works() {
+        let ink_trait = InkTrait::new(
+            quote! { version = 2 },
+            quote! {
+                pub trait MyTrait {
+                    #[ink(message, selector = "0xDEADBEEF")]
+                    fn message_1(&self);
+                    #[ink(message)]
+                    fn message_2(&self);
+                }
+            },
+        )
+        .unwrap();
+        assert_eq!(ink_trait.version(), 2);
+        let selectors = ink_trait
+            .iter_items()
+            .flat_map(InkTraitItem::filter_map_message)
+            .map(|message| ink_trait.pinned_message_selector(&message))
+            .collect::<Vec<_>>();
+        let hash = <blake2::Blake2b as blake2::Digest>::digest(b"MyTrait::v2::message_2");
+        assert_eq!(
+            selectors,
+            vec![
+                Some(ir::Selector::new([0xDE, 0xAD, 0xBE, 0xEF])),
+                Some(ir::Selector::new([hash[0], hash[1], hash[2], hash[3]])),
+            ]
+        );
+    }
+
+    #[test]
+    fn version_1_message_selector_works() {
+        let ink_trait = InkTrait::new(
+            quote! { version = 1 },
+            quote! {
+                pub trait MyTrait {
+                    #[ink(message)]
+                    fn message(&self);
+                }
+            },
+        )
+        .unwrap();
+        let message = ink_trait
+            .iter_items()
+            .flat_map(InkTraitItem::filter_map_message)
+            .next()
+            .unwrap();
+        let hash = <blake2::Blake2b as blake2::Digest>::digest(b"MyTrait::message");
+        assert_eq!(ink_trait.pinned_message_selector(&message), None);
+        assert_eq!(
+            ink_trait.message_selector(&message),
+            ir::Selector::new([hash[0], hash[1], hash[2], hash[3]])
+        );
+    }
+
+    #[test]
+    fn interface_id_works() {
+        let interface_id_of = |args: TokenStream2, trait_def: TokenStream2| {
+            InkTrait::new(args, trait_def).unwrap().interface_id()
+        };
+        let interface_id = interface_id_of(
+            quote! {},
+            quote! {
+                pub trait MyTrait {
+                    #[ink(message)]
+                    fn message_1(&self);
+                    #[ink(message, selector = "0x00000001")]
+                    fn message_2(&self);
+                }
+            },
+        );
+        let selector = InkTrait::compose_message_selector(
+            &format_ident!("MyTrait"),
+            &format_ident!("message_1"),
+        );
+        let mut joined = vec![0x00, 0x00, 0x00, 0x01];
+        joined.extend_from_slice(selector.as_bytes());
+        let hash = <blake2::Blake2b as blake2::Digest>::digest(&joined);
+        assert_eq!(interface_id, [hash[0], hash[1], hash[2], hash[3]]);
+        // The order of the messages does not matter.
+        assert_eq!(
+            interface_id_of(
+                quote! {},
+                quote! {
+                    pub trait MyTrait {
+                        #[ink(message, selector = "0x00000001")]
+                        fn message_2(&self);
+                        #[ink(message)]
+                        fn message_1(&self);
+                    }
+                },
+            ),
+            interface_id,
+        );
+        // Other versions yield other interface identifiers.
+        assert_ne!(
+            interface_id_of(
+                quote! { version = 2 },
+                quote! {
+                    pub trait MyTrait {
+                        #[ink(message)]
+                        fn message_1(&self);
+                        #[ink(message, selector = "0x00000001")]
+                        fn message_2(&self);
+                    }
+                },
+            ),
+            interface_id,
+        );
+    }
+
+    #[test]
+    fn invalid_trait_definition_args_are_denied() {
+        let trait_def = quote! {
+            pub trait MyTrait {
+                #[ink(message)]
+                fn message(&self);
+            }
+        };
+        let assert_args_err = |args: TokenStream2, expected: &str| {
+            assert_eq!(
+                InkTrait::new(args, trait_def.clone()).map_err(|err| err.to_string()),
+                Err(expected.to_string()),
+            )
+        };
+        assert_args_err(
+            quote! { version = 0 },
+            "expected an integer literal greater than zero for `version` ink! trait definition argument",
+        );
+        assert_args_err(
+            quote! { version = "2" },
+            "expected an integer literal greater than zero for `version` ink! trait definition argument",
+        );
+        assert_args_err(
+            quote! { version = 2, version = 3 },
+            "encountered duplicate ink! `version` trait definition argument",
+        );
+        assert_args_err(
+            quote! { revision = 2 },
+            "encountered unknown or unsupported ink! trait definition argument",
+        );
+    }
+
+    #[test]
+    fn overlapping_message_selectors_are_denied() {
+        assert_ink_trait_eq_err!(
+            error: "encountered ink! trait messages with overlapping selectors (= [DE, AD, BE, EF])",
+            pub trait MyTrait {
+                #[ink(message, selector = "0xDEADBEEF")]
+                fn message_1(&self);
+                #[ink(message, selector = "0xDEADBEEF")]
+                fn message_2(&self);
+            }
+        );
+    }
+
+    #[test]
+    fn conflicting_attribute_errors_point_at_both_arguments() {
+        let error = <InkTrait as TryFrom<syn::ItemTrait>>::try_from(syn::parse_quote! {
+            pub trait MyTrait {
+                #[ink(message, payable)]
+                fn message(&self);
+            }
+        })
+        .expect_err("encountered unexpected valid ink! trait definition");
+        assert_eq!(
+            error
+                .into_iter()
+                .map(|err| err.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "encountered conflicting ink! attribute argument",
+                "conflicts with this ink! attribute argument",
+            ]
+        );
+    }
 }
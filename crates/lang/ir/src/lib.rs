@@ -34,15 +34,25 @@ mod ast;
 mod ir;
 
 pub use self::ir::{
+    Address,
+    AddressBook,
+    AssertStorageFootprint,
+    AssertTraitImpl,
     Callable,
     CallableKind,
     CallableWithSelector,
     Config,
     Constructor,
+    Continuation,
     Contract,
+    ContractRef,
     Event,
     ImplItem,
+    ImportedEvent,
+    InkBench,
+    InkEvent,
     InkItem,
+    InkMixin,
     InkTest,
     InkTrait,
     InkTraitConstructor,
@@ -58,9 +68,14 @@ pub use self::ir::{
     IterItemImpls,
     IterMessages,
     Message,
+    MessageHook,
+    MessageHookKind,
     Namespace,
+    Network,
     Receiver,
     Selector,
+    SignPayload,
     Storage,
+    ThrottleRate,
     Visibility,
 };
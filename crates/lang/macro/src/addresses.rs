@@ -12,7 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[alloc_error_handler]
-fn oom(_: core::alloc::Layout) -> ! {
-    core::intrinsics::abort()
+use ink_lang_codegen::generate_code;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::Result;
+
+pub fn generate(input: TokenStream2) -> TokenStream2 {
+    match generate_or_err(input) {
+        Ok(tokens) => tokens,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+pub fn generate_or_err(input: TokenStream2) -> Result<TokenStream2> {
+    let address_book = ink_lang_ir::AddressBook::new(input)?;
+    Ok(generate_code(&address_book))
 }
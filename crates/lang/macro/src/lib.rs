@@ -14,8 +14,16 @@
 
 extern crate proc_macro;
 
+mod addresses;
+mod assert_storage_footprint;
+mod assert_trait_impl;
 mod contract;
+mod contract_ref;
+mod ink_bench;
+mod ink_event;
 mod ink_test;
+mod mixin;
+mod sign_payload;
 mod trait_def;
 
 use proc_macro::TokenStream;
@@ -151,6 +159,7 @@ use proc_macro::TokenStream;
 ///         type Hash = [u8; 32];
 ///         type Timestamp = u64;
 ///         type BlockNumber = u32;
+///         type TopicHasher = ink_env::hash::Keccak256;
 ///     }
 ///     ```
 ///     A user might implement their ink! smart contract using the above custom `Environment`
@@ -168,6 +177,7 @@ use proc_macro::TokenStream;
 ///         #     type Hash = [u8; 32];
 ///         #     type Timestamp = u64;
 ///         #     type BlockNumber = u32;
+///         #     type TopicHasher = ink_env::hash::Keccak256;
 ///         # }
 ///         #
 ///         # #[ink(storage)]
@@ -184,6 +194,219 @@ use proc_macro::TokenStream;
 ///
 ///     **Default value:** `DefaultEnvironment` defined in `ink_env` crate.
 ///
+/// - `metadata_hash: &str`
+///
+///     Tells the ink! code generator to embed the given BLAKE2 256-bit hash of the
+///     contract's metadata JSON into the `ink_metadata_hash` custom section of the
+///     compiled Wasm blob. This allows explorers and other tooling to cryptographically
+///     match the on-chain code of a smart contract with its off-chain metadata.
+///
+///     The hash must be provided as `0x` prefixed hex string and can be computed
+///     from the generated metadata using `ink_metadata::metadata_hash`.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(
+///         metadata_hash = "0x0101010101010101010101010101010101010101010101010101010101010101"
+///     )]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** No metadata hash is embedded.
+///
+/// - `ownable: bool`
+///
+///     Tells the ink! code generator to make the instantiator of the smart contract its
+///     owner. The contract is provided with the `owner`, `pending_owner`,
+///     `transfer_ownership`, `accept_ownership` and `renounce_ownership` messages.
+///     Ownership is transferred in two steps: the owner proposes a new owner using
+///     `transfer_ownership` which then has to call `accept_ownership`.
+///
+///     ink! messages flagged with `#[ink(message, only_owner)]` can only be called by
+///     the owner of the smart contract.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(ownable = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         #[ink(message, only_owner)]
+///         pub fn withdraw(&mut self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `bounded_inputs: bool`
+///
+///     Tells the ink! code generator to reject constructor and message inputs whose
+///     SCALE encoded length is unbounded, e.g. `Vec<u8>` or `String`, at compile time.
+///     All inputs are required to implement `ink_storage::traits::MaxEncodedLen` which
+///     can be derived for custom types. This helps to keep the worst-case gas costs
+///     of decoding the inputs bounded.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(bounded_inputs = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #[ink(message)]
+///         pub fn set(&mut self, values: [u8; 32]) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `max_input_len: usize`
+///
+///     Tells the ink! code generator to reject contract executions whose input is
+///     longer than the given number of bytes before the input is decoded. The
+///     execution is then reverted with the `InputTooLarge` language level error.
+///     This protects against inputs that are expensive to decode, e.g. deeply
+///     nested collections, without requiring bounded input types. The limit must
+///     not exceed the 16kB environmental buffer since larger inputs trap the
+///     execution before they can be rejected.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(max_input_len = 1024)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #[ink(message)]
+///         pub fn set(&mut self, values: Vec<Vec<u8>>) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** No limit
+///
+/// - `features: String`
+///
+///     Declares the given comma separated Cargo features of the crate as contract
+///     features. Within the contract module `ink::cfg!(feature = "name")` is `true` if
+///     the Cargo feature is enabled, e.g. via `--features testnet`, and `false` otherwise
+///     so that a single codebase can emit different variants, e.g. for a test network
+///     and the main network. This is the same mechanism that selects the network of
+///     [`macro@addresses`]. Querying undeclared features fails to compile. The enabled
+///     contract features are recorded in the contract metadata.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(features = "testnet, mainnet")]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         const FEE: Balance = if ink::cfg!(feature = "testnet") { 0 } else { 100 };
+///
+///         #[ink(message)]
+///         pub fn fee(&self) -> Balance {
+///             if ink::cfg!(feature = "testnet") {
+///                 ink_env::debug_println("no fees on the test network");
+///             }
+///             Self::FEE
+///         }
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** No contract features are declared
+///
+/// - `supports_interface: bool`
+///
+///     Tells the ink! code generator to provide the smart contract with a
+///     `supports_interface(interface_id: [u8; 4]) -> bool` message that reports the
+///     ink! trait definitions implemented by the smart contract, similar to ERC-165.
+///     The interface identifiers are derived from the selectors of the messages of the
+///     trait definitions. Other contracts query them using the
+///     `ink_lang::interface::implements` and `ink_lang::interface::supports_interface`
+///     helpers.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(supports_interface = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `telemetry: bool`
+///
+///     Tells the ink! code generator to count the dispatches of every `&mut self`
+///     ink! message of the smart contract and to provide it with a `telemetry` message
+///     returning the counters identified by the message selectors. This allows to
+///     measure the real-world usage of messages, e.g. before deprecating them.
+///     Counting costs an additional storage read and write for every dispatched
+///     `&mut self` message. `&self` messages are not counted so that they do not
+///     write to the contract storage.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(telemetry = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
 /// ## Anaylsis
 ///
 /// The `#[ink::contract]` macro fully analyses its input smart contract
@@ -356,17 +579,277 @@ use proc_macro::TokenStream;
 ///             Flipper { value: false }
 ///         }
 ///
-///         # /// Flips the current value.
-///         # #[ink(message)]
-///         # #[ink(selector = "0xCAFEBABE")] // You can either specify selector out-of-line.
-///         # pub fn flip(&mut self) {
-///         #     self.value = !self.value;
-///         # }
-///         #
-///         /// Returns the current value.
-///         #[ink(message, selector = "0xFEEDBEEF")] // ... or specify selector inline.
-///         pub fn get(&self) -> bool {
-///             self.value
+///         # /// Flips the current value.
+///         # #[ink(message)]
+///         # #[ink(selector = "0xCAFEBABE")] // You can either specify selector out-of-line.
+///         # pub fn flip(&mut self) {
+///         #     self.value = !self.value;
+///         # }
+///         #
+///         /// Returns the current value.
+///         #[ink(message, selector = "0xFEEDBEEF")] // ... or specify selector inline.
+///         pub fn get(&self) -> bool {
+///             self.value
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Role guarded messages:**
+///
+///     An ink! message can be restricted to callers that have been granted a role
+///     using the `only_role` flag. Calls from other callers are rejected before the
+///     message is executed.
+///
+///     If any message is guarded this way the contract additionally provides the
+///     `grant_role`, `revoke_role` and `has_role` messages. The instantiator of the
+///     contract is granted the `"ADMIN"` role that is required to grant and revoke roles.
+///     Role identifiers are computed via `ink_lang::access_control::role_id`.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new(initial_value: bool) -> Self {
+///         #     Flipper { value: false }
+///         # }
+///         /// Flips the current value.
+///         #[ink(message, only_role = "FLIPPER")]
+///         pub fn flip(&mut self) {
+///             self.value = !self.value;
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Documented permissions:**
+///
+///     The permissions required by callers of an ink! message are listed in the
+///     `permissions` field of the message in the contract metadata. This allows
+///     wallets to warn users about calls that would likely fail due to missing
+///     authorization without executing a dry run. Messages flagged as `only_owner`
+///     require the `"owner"` permission and messages guarded by a role `NAME`
+///     require the `"role:NAME"` permission. Permissions checked by the contract
+///     itself are documented using the `requires` flag which is not enforced by the
+///     ink! dispatch.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         #     operator: AccountId,
+///         # }
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new(initial_value: bool) -> Self {
+///         #     Flipper { value: false, operator: Self::env().caller() }
+///         # }
+///         /// Flips the current value.
+///         #[ink(message, requires = "operator")]
+///         pub fn flip(&mut self) {
+///             assert_eq!(self.env().caller(), self.operator, "caller is not the operator");
+///             self.value = !self.value;
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Non-reentrant messages:**
+///
+///     An ink! message flagged as `non_reentrant` rejects calls while another call to a
+///     non-reentrant message of the same contract is in progress, e.g. if a called
+///     contract calls back into the contract. Such calls fail with
+///     `ink_lang::DispatchError::ReentrantCall`.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new(initial_value: bool) -> Self {
+///         #     Flipper { value: false }
+///         # }
+///         /// Flips the current value.
+///         #[ink(message, non_reentrant)]
+///         pub fn flip(&mut self) {
+///             self.value = !self.value;
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Pausable messages:**
+///
+///     An ink! message flagged as `when_not_paused` rejects calls while the contract
+///     is paused. If any message is flagged this way the contract additionally provides
+///     a `Paused` event as well as the `pause`, `unpause` and `paused` messages.
+///     Pausing and unpausing requires the `"ADMIN"` role, see role guarded messages.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new(initial_value: bool) -> Self {
+///         #     Flipper { value: false }
+///         # }
+///         /// Flips the current value.
+///         #[ink(message, when_not_paused)]
+///         pub fn flip(&mut self) {
+///             self.value = !self.value;
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Throttled messages:**
+///
+///     An ink! message flagged with `throttle = "N/block"` or `throttle = "N/M blocks"`
+///     can only be called `N` times by every caller within one or `M` blocks. Further
+///     calls fail with `ink_lang::DispatchError::Throttled` until the period has elapsed.
+///     See `ink_lang::throttle::Throttle` for rate limiting within message bodies.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod faucet {
+///         # #[ink(storage)]
+///         # pub struct Faucet {
+///         #     claims: u32,
+///         # }
+///     impl Faucet {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Faucet { claims: 0 }
+///         # }
+///         /// Claims from the faucet at most once every 100 blocks per caller.
+///         #[ink(message, throttle = "1/100 blocks")]
+///         pub fn claim(&mut self) {
+///             self.claims += 1;
+///         }
+///     }
+///     # }
+///     ```
+///
+/// - Methods of the `#[ink(storage)]` struct flagged with `#[ink(before_message)]`
+///   or `#[ink(after_message)]` are hooks invoked by the dispatch before or after
+///   every ink! message of the contract.
+///
+///     Hooks receive the selector of the dispatched message and allow to handle
+///     cross-cutting concerns such as accounting or logging in one place. A contract
+///     can have at most one hook of every kind. Hooks with a `&mut self` receiver are
+///     only invoked around messages with a `&mut self` receiver since the storage of
+///     other messages is not written back. Hooks are invoked for the messages of
+///     embedded ink! mixins as well. A hook rejects a call by panicking.
+///
+///     **Example:**
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod counter {
+///         # #[ink(storage)]
+///         # pub struct Counter {
+///         #     calls: u32,
+///         #     value: bool,
+///         # }
+///     impl Counter {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Counter { calls: 0, value: false }
+///         # }
+///         # #[ink(message)]
+///         # pub fn flip(&mut self) {
+///         #     self.value = !self.value;
+///         # }
+///         /// Counts the calls to all mutating messages of the contract.
+///         #[ink(before_message)]
+///         fn count_calls(&mut self, _selector: [u8; 4]) {
+///             self.calls += 1;
+///         }
+///     }
+///     # }
+///     ```
+///
+/// - Methods of the `#[ink(storage)]` struct flagged with `#[ink(continuation)]` are
+///   steps of multi-step workflows that span several contract executions.
+///
+///     A workflow is suspended by handing out the `ContinuationToken` of the step
+///     that resumes it and is resumed via `Continuations::resume_continuation` of the
+///     `ink_lang::continuation` module with the SCALE encoded input of the step.
+///     Continuations take `&mut self` and a single input. For every continuation a
+///     `Step` associated constant named after it in upper snake case is generated.
+///     The contract resumes tokens from an ink! message of its own and is
+///     responsible for checking that the token and its caller are expected.
+///
+///     **Example:**
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod oracle_client {
+///     use ink_lang::continuation::{
+///         ContinuationError,
+///         ContinuationToken,
+///         Continuations as _,
+///     };
+///
+///     # #[ink(storage)]
+///     # pub struct OracleClient {
+///     #     pending: Option<ContinuationToken>,
+///     #     nonce: u64,
+///     #     price: Balance,
+///     # }
+///     impl OracleClient {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     OracleClient { pending: None, nonce: 0, price: 0 }
+///         # }
+///         /// Suspends until the returned token is resumed with the price.
+///         #[ink(message)]
+///         pub fn request_price(&mut self) -> ContinuationToken {
+///             self.nonce += 1;
+///             let token = Self::ON_PRICE.token(self.nonce);
+///             self.pending = Some(token);
+///             token
+///         }
+///
+///         /// Resumes the pending token with the SCALE encoded input.
+///         #[ink(message)]
+///         pub fn resume(
+///             &mut self,
+///             token: ContinuationToken,
+///             input: Vec<u8>,
+///         ) -> Result<(), ContinuationError> {
+///             if self.pending != Some(token) {
+///                 return Err(ContinuationError::UnexpectedToken)
+///             }
+///             self.pending = None;
+///             self.resume_continuation(token, &input)
+///         }
+///
+///         #[ink(continuation)]
+///         fn on_price(&mut self, price: Balance) {
+///             self.price = price;
 ///         }
 ///     }
 ///     # }
@@ -465,6 +948,14 @@ use proc_macro::TokenStream;
 /// }
 /// ```
 ///
+/// Emitting an event traps the contract execution if the encoded event topics or data
+/// do not fit into the environmental buffer. Use `try_emit_event` instead of `emit_event`
+/// in order to receive an `ink_env::EventError` and drop the event without aborting
+/// the whole call.
+///
+/// Events defined outside of the ink! module via [`macro@event`] are imported via
+/// `#[ink(event)] use path::to::Event;` and become events of the ink! smart contract.
+///
 /// ## Example: Flipper
 ///
 /// The below code shows the complete implementation of the so-called Flipper
@@ -582,11 +1073,497 @@ pub fn contract(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// # Pinned Selectors
+///
+/// Messages of ink! trait definitions may pin their selector using
+/// `#[ink(message, selector = "0xDEADBEEF")]`. ink! smart contracts implementing
+/// the trait definition inherit the pinned selectors unless they provide their
+/// own selectors for the messages. This allows standards to define canonical
+/// selectors that stay stable even if the trait or its messages are renamed.
+///
+/// # Versions
+///
+/// Standards may evolve in incompatible ways while contracts keep supporting older
+/// versions of them. `#[ink::trait_definition(version = 2)]` folds the version into
+/// the selectors of all messages without pinned selectors, e.g. the selector of
+/// `total_supply` is composed from `Erc20::v2::total_supply` instead of
+/// `Erc20::total_supply`. Implementing ink! smart contracts inherit these selectors
+/// and list the version of the trait definition in their metadata.
+///
+/// This way a contract can implement version 1 and version 2 of a standard trait
+/// defined in different modules at the same time without overlapping selectors.
+/// Version 1 is the default and composes the same selectors as unversioned trait
+/// definitions. Note that two versions of a trait definition must differ in their
+/// constructors or messages to be implemented by the same contract.
+///
+/// ```
+/// # use ink_lang as ink;
+/// # type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
+/// #[ink::trait_definition(version = 2)]
+/// pub trait Erc20 {
+///     /// Returns the total supply of the ERC-20 smart contract.
+///     #[ink(message)]
+///     fn total_supply(&self) -> Balance;
+///
+///     /// Returns the number of decimals of the token.
+///     #[ink(message)]
+///     fn decimals(&self) -> u8;
+/// }
+/// ```
+///
+/// # Mocking
+///
+/// For the unit tests of the crate defining the trait definition a mock contract
+/// named after the trait definition is generated, e.g. `MockErc20` for the above
+/// trait definition. It allows to unit test ink! smart contracts that call other
+/// smart contracts implementing the trait definition without deploying them.
+///
+/// For every message the mock contract provides an `on_` prefixed method to register
+/// its response, e.g. `on_total_supply`, and a `revert_` prefixed method to make it
+/// revert with the given data. Once registered under an account ID in the off-chain
+/// environment calls to the account ID are answered by the mock contract.
+///
+/// ```text
+/// MockErc20::new()
+///     .on_total_supply(|| 1000)
+///     .register::<ink_env::DefaultEnvironment>(account_id);
+/// ```
+///
+/// # Default Methods
+///
+/// Methods without ink! attributes may be defined in ink! trait definitions if
+/// they provide a default implementation. They are no ink! messages but allow
+/// standards to ship reusable logic for the implementing ink! smart contracts.
+/// Default methods may emit events defined via [`macro@event`] through
+/// `self.emit_trait_event(..)` which emits the event on behalf of the implementing
+/// ink! smart contract. Such default methods have to be bounded by
+/// `Self: ink_lang::EmitTraitEvent<Event>` for every emitted event.
 #[proc_macro_attribute]
 pub fn trait_definition(attr: TokenStream, item: TokenStream) -> TokenStream {
     trait_def::analyze(attr.into(), item.into()).into()
 }
 
+/// Defines a reusable ink! mixin of storage fields and ink! messages.
+///
+/// Applied on an inline module with exactly one `#[ink(storage)]` struct and
+/// inherent implementation blocks with ink! messages for it. Host ink! smart
+/// contracts embed the mixin through a field of their storage struct flagged
+/// with `#[ink(mixin)]` and dispatch to the messages of the mixin in addition
+/// to their own messages. This allows to share building blocks such as an escrow
+/// or a vesting schedule between ink! smart contracts.
+///
+/// The selectors of mixin messages are namespaced by the name of the mixin storage
+/// struct, e.g. the selector of `deposit` below is composed from `Escrow::deposit`.
+/// Implementation blocks may provide their own namespace via
+/// `#[ink(namespace = "...")]` and messages may provide their own selectors.
+/// Host contracts whose selectors overlap with the selectors of their mixins
+/// fail to compile, and so do host contracts embedding the same mixin twice.
+/// The mixin messages are listed in the metadata of the host contract.
+///
+/// Mixin messages support the same guards as the messages of the host contract,
+/// e.g. `only_owner` or `when_not_paused`, and are subject to its telemetry and
+/// message hooks. The guards operate on the state of the host contract, so the
+/// host contract has to provide the respective infrastructure: `only_owner` mixin
+/// messages deny every call unless the host contract is ownable and `only_role`
+/// mixin messages deny every call unless the host contract grants the role.
+///
+/// **Note:** Mixins do not support ink! constructors, ink! events or trait
+///           implementation blocks. Mixin messages access the environment
+///           through the functions of `ink_env`.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// #
+/// #[ink::contract]
+/// mod shop {
+///     #[ink::mixin]
+///     pub mod escrow {
+///         use ink_env::DefaultEnvironment as Env;
+///         type Balance = <Env as ink_env::Environment>::Balance;
+///
+///         #[ink(storage)]
+///         pub struct Escrow {
+///             deposited: Balance,
+///         }
+///
+///         impl Escrow {
+///             pub fn new() -> Self {
+///                 Self { deposited: 0 }
+///             }
+///
+///             /// Deposits the transferred value into the escrow.
+///             #[ink(message, payable)]
+///             pub fn deposit(&mut self) {
+///                 let value = ink_env::transferred_balance::<Env>().unwrap_or_default();
+///                 self.deposited += value;
+///             }
+///
+///             /// Returns the value deposited into the escrow.
+///             #[ink(message)]
+///             pub fn deposited(&self) -> Balance {
+///                 self.deposited
+///             }
+///         }
+///     }
+///
+///     #[ink(storage)]
+///     pub struct Shop {
+///         #[ink(mixin)]
+///         escrow: escrow::Escrow,
+///     }
+///
+///     impl Shop {
+///         #[ink(constructor)]
+///         pub fn new() -> Self {
+///             Self { escrow: escrow::Escrow::new() }
+///         }
+///
+///         /// Returns `true` if any value has been deposited into the escrow.
+///         #[ink(message)]
+///         pub fn is_funded(&self) -> bool {
+///             self.escrow.deposited() > 0
+///         }
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn mixin(attr: TokenStream, item: TokenStream) -> TokenStream {
+    mixin::generate(attr.into(), item.into()).into()
+}
+
+/// Defines an ink! event outside of an ink! smart contract.
+///
+/// The event is usually defined alongside an ink! trait definition and emitted
+/// by its default methods through `self.emit_trait_event(..)`. This way standards
+/// can ship the events they require together with their trait definitions instead
+/// of every implementing ink! smart contract having to define them.
+///
+/// Fields flagged with `#[ink(topic)]` are emitted as topics of the event and
+/// events flagged with `#[ink(anonymous)]` do not emit their signature as topic.
+///
+/// # Arguments
+///
+/// - `namespace = "..."`: prefixes the signature of the event, e.g. the signature
+///   of the event below is `Counter::Incremented`. The signature is the identifier
+///   of the event if no namespace is given.
+///
+/// # Emitting
+///
+/// ink! smart contracts emitting the event import it into their ink! module via
+/// `#[ink(event)] use path::to::Event;`. Imported events are variants of the event
+/// enum of the ink! smart contract following its own ink! events. Therefore they
+/// are encoded with their variant index like all other events of the ink! smart
+/// contract and listed in its metadata.
+///
+/// Default methods of ink! trait definitions emitting the event are bounded by
+/// `Self: ink_lang::EmitTraitEvent<Event>` and are only available to ink! smart
+/// contracts importing the event.
+///
+/// **Note:** Crates defining events via `#[ink::event]` have to depend on
+///           `scale-info` and `ink_metadata` with their `std` crate feature for
+///           the metadata of the event, just like ink! smart contracts.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// #
+/// #[ink::event(namespace = "Counter")]
+/// pub struct Incremented {
+///     #[ink(topic)]
+///     by: u32,
+/// }
+///
+/// #[ink::trait_definition]
+/// pub trait Counter {
+///     /// Increments the counter by the given value.
+///     #[ink(message)]
+///     fn increment(&mut self, by: u32);
+///
+///     /// Emits the event every counter has to emit upon increments.
+///     fn notify_incremented(&self, by: u32)
+///     where
+///         Self: ink_lang::EmitTraitEvent<Incremented>,
+///     {
+///         self.emit_trait_event(Incremented { by })
+///     }
+/// }
+///
+/// #[ink::contract]
+/// mod counter {
+///     use super::Counter;
+///
+///     #[ink(event)]
+///     use super::Incremented;
+///
+///     #[ink(storage)]
+///     pub struct MyCounter {
+///         value: u32,
+///     }
+///
+///     impl MyCounter {
+///         #[ink(constructor)]
+///         pub fn new() -> Self {
+///             Self { value: 0 }
+///         }
+///     }
+///
+///     impl Counter for MyCounter {
+///         #[ink(message)]
+///         fn increment(&mut self, by: u32) {
+///             self.value += by;
+///             self.notify_incremented(by);
+///         }
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn event(attr: TokenStream, item: TokenStream) -> TokenStream {
+    ink_event::generate(attr.into(), item.into()).into()
+}
+
+/// Refers to a callable handle of an ink! smart contract implementing an ink! trait definition.
+///
+/// Expands to the type of a contract reference that is generated for every
+/// ink! trait definition. Given only the account identifier of an ink! smart
+/// contract implementing the trait definition it allows to call all of its ink!
+/// trait messages. This way integrators only need to depend on the crate
+/// providing the ink! trait definition and not on the crate of the called contract.
+///
+/// The first argument is the path to the ink! trait definition. The second and
+/// optional argument is the environment of the called contract. If it is omitted
+/// `ink_env::DefaultEnvironment` is used.
+///
+/// **Note:** The selectors of the called trait messages are composed the same way as
+///           for ink! smart contracts implementing the trait definition through a
+///           trait implementation block without an explicit namespace.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// #
+/// #[ink::contract]
+/// mod proxy {
+/// #    #[ink_lang::trait_definition]
+/// #    pub trait Erc20 {
+/// #        /// Returns the total supply of the ERC-20 smart contract.
+/// #        #[ink(message)]
+/// #        fn total_supply(&self) -> Balance;
+/// #    }
+/// #
+///     use ink_env::call::FromAccountId as _;
+///
+///     #[ink(storage)]
+///     pub struct Proxy {
+///         token: AccountId,
+///     }
+///
+///     impl Proxy {
+///         #[ink(constructor)]
+///         pub fn new(token: AccountId) -> Self {
+///             Self { token }
+///         }
+///
+///         /// Returns the total supply of the referred ERC-20 smart contract.
+///         #[ink(message)]
+///         pub fn total_supply(&self) -> Balance {
+///             let token = <ink_lang::contract_ref!(Erc20, Environment)>::from_account_id(
+///                 self.token,
+///             );
+///             token.total_supply()
+///         }
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn contract_ref(input: TokenStream) -> TokenStream {
+    contract_ref::generate(input.into()).into()
+}
+
+/// Asserts at compile time that an ink! smart contract implements an ink! trait definition.
+///
+/// The first argument is the storage type of the ink! smart contract and the second
+/// argument is the path to the ink! trait definition.
+///
+/// Compilation fails if the ink! smart contract does not implement the trait definition
+/// or if any of its implemented ink! messages is dispatched with a selector that differs
+/// from the selector of the trait definition, e.g. because of a `namespace` or because
+/// the trait is referred to by its full path within the implementation block.
+/// The compile errors mention the affected ink! messages as `__ink_Trait_message`.
+///
+/// This allows to catch drift between ink! smart contracts and evolving standards
+/// before deployment.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// #
+/// #[ink::contract]
+/// mod base_erc20 {
+/// #    #[ink_lang::trait_definition]
+/// #    pub trait Erc20 {
+/// #        /// Returns the total supply of the ERC-20 smart contract.
+/// #        #[ink(message)]
+/// #        fn total_supply(&self) -> Balance;
+/// #    }
+/// #
+///     #[ink(storage)]
+///     pub struct BaseErc20 {
+///         total_supply: Balance,
+///     }
+///
+///     impl BaseErc20 {
+///         #[ink(constructor)]
+///         pub fn new(initial_supply: Balance) -> Self {
+///             Self { total_supply: initial_supply }
+///         }
+///     }
+///
+///     impl Erc20 for BaseErc20 {
+///         #[ink(message)]
+///         fn total_supply(&self) -> Balance {
+///             self.total_supply
+///         }
+///     }
+/// }
+///
+/// ink::assert_trait_impl!(base_erc20::BaseErc20, base_erc20::Erc20);
+/// ```
+#[proc_macro]
+pub fn assert_trait_impl(input: TokenStream) -> TokenStream {
+    assert_trait_impl::generate(input.into()).into()
+}
+
+/// Asserts at compile time that an ink! storage struct fits into a budget of storage cells.
+///
+/// The root storage struct of an ink! smart contract is loaded from the contract storage
+/// upon every contract execution. Keeping it small keeps the costs of all
+/// executions low.
+///
+/// Compilation fails if the spread footprint of the storage struct, as defined by
+/// its `SpreadLayout::FOOTPRINT`, exceeds the given number of storage cells.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// #
+/// #[ink::contract]
+/// mod flipper {
+///     #[ink(storage)]
+///     pub struct Flipper {
+///         value: bool,
+///         owner: AccountId,
+///     }
+///
+///     impl Flipper {
+///         #[ink(constructor)]
+///         pub fn new() -> Self {
+///             Self { value: false, owner: Self::env().caller() }
+///         }
+///
+///         #[ink(message)]
+///         pub fn flip(&mut self) {
+///             self.value = !self.value;
+///         }
+///     }
+/// }
+///
+/// ink::assert_storage_footprint!(flipper::Flipper, max_cells = 2);
+/// ```
+#[proc_macro]
+pub fn assert_storage_footprint(input: TokenStream) -> TokenStream {
+    assert_storage_footprint::generate(input.into()).into()
+}
+
+/// Defines `AccountId` constants of well known contracts per network.
+///
+/// Every network of the address book names a crate feature. The constants of a
+/// network are only defined if its feature is enabled so that the same contract
+/// code refers to the correct accounts for each network it is built for.
+///
+/// The addresses are given in their SS58 encoding and are decoded and checked at
+/// compile time.
+///
+/// **Note:** All networks must define the same set of constants and enabling the
+///           features of more than one network at the same time fails to compile.
+///
+/// # Example
+///
+/// With a crate that defines the `mainnet` and `testnet` features:
+///
+/// ```
+/// mod addresses {
+///     ink_lang::addresses! {
+///         mainnet: {
+///             /// The decentralized exchange.
+///             DEX: "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+///         },
+///         testnet: {
+///             /// The decentralized exchange.
+///             DEX: "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty",
+///         },
+///     }
+/// }
+/// ```
+///
+/// Building with `--features mainnet` then defines `addresses::DEX` as the
+/// account of the exchange on the main network.
+#[proc_macro]
+pub fn addresses(input: TokenStream) -> TokenStream {
+    addresses::generate(input.into()).into()
+}
+
+/// Derives `ink_lang::typed_data::SignPayload` for a struct.
+///
+/// The derived type string lists the name of the struct followed by the names and
+/// types of all of its fields as written in the struct definition. Renaming the struct
+/// or any of its fields or changing their types therefore invalidates all signatures
+/// of prior payloads.
+///
+/// The struct additionally has to implement `scale::Encode`.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// # type AccountId = [u8; 32];
+/// use ink_lang::typed_data::{
+///     Domain,
+///     SignPayload as _,
+///     TypedData,
+/// };
+///
+/// /// Transfers tokens on behalf of the signer.
+/// #[derive(scale::Encode, ink::SignPayload)]
+/// pub struct Transfer {
+///     to: AccountId,
+///     value: u128,
+///     nonce: u64,
+/// }
+///
+/// assert_eq!(Transfer::TYPE_STRING, "Transfer(to:AccountId,value:u128,nonce:u64)");
+/// # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|accounts| {
+/// let domain = Domain::<ink_env::DefaultEnvironment>::new("MyToken", 1, accounts.alice);
+/// let digest = TypedData::<ink_env::hash::Keccak256>::new(&domain).digest(&Transfer {
+///     to: [0x01; 32],
+///     value: 100,
+///     nonce: 0,
+/// });
+/// # let _ = digest;
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+#[proc_macro_derive(SignPayload)]
+pub fn sign_payload(input: TokenStream) -> TokenStream {
+    sign_payload::generate(input.into()).into()
+}
+
 /// Defines a unit test that makes use of ink!'s off-chain testing capabilities.
 ///
 /// If your unit test does not require the existence of an off-chain environment
@@ -632,5 +1609,45 @@ pub fn test(attr: TokenStream, item: TokenStream) -> TokenStream {
     ink_test::generate(attr.into(), item.into()).into()
 }
 
+/// Defines a benchmark that estimates the weight of ink! messages using ink!'s
+/// off-chain testing capabilities.
+///
+/// The benchmark is run as a unit test and receives an `ink_env::test::Bencher`
+/// that executes the benchmarked messages repeatedly. The storage reads, storage
+/// writes and emitted events of the most expensive execution are weighted by an
+/// `ink_env::test::GasModel` to estimate the weight of every message.
+///
+/// The results are reported as JSON lines that are appended to the file denoted
+/// by the `INK_BENCH_OUTPUT` environment variable. Nothing is reported if it is
+/// not set. This allows to track the weight of messages in CI.
+///
+/// **Note:** The default weights of the gas model are illustrative only and not
+///           the weights charged on-chain. The estimates are meant to be compared
+///           with each other, e.g. across the commits of a contract.
+///
+/// # Arguments
+///
+/// - `iterations = N`: the number of times every message is executed. The default is `100`.
+///
+/// # Example
+///
+/// ```
+/// use ink_lang as ink;
+///
+/// #[cfg(test)]
+/// mod benches {
+///     #[ink::bench(iterations = 10)]
+///     fn bench_store(b: &mut ink_env::test::Bencher) {
+///         let key = ink_primitives::Key::from([0x42; 32]);
+///         b.iter("store", || ink_env::set_contract_storage(&key, &42_u32))
+///             .expect("the off-chain environment has an executing contract");
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn bench(attr: TokenStream, item: TokenStream) -> TokenStream {
+    ink_bench::generate(attr.into(), item.into()).into()
+}
+
 #[cfg(test)]
 pub use contract::generate_or_err;
@@ -25,6 +25,13 @@ fn compile_tests() {
     t.pass("tests/ui/pass/08-static-env.rs");
     t.pass("tests/ui/pass/09-derive-for-storage.rs");
     t.pass("tests/ui/pass/10-alias-storage-struct-impl.rs");
+    t.pass("tests/ui/pass/11-trait-versions.rs");
+    t.pass("tests/ui/pass/12-mixin-guards.rs");
+    t.pass("tests/ui/pass/13-message-hooks.rs");
+    t.pass("tests/ui/pass/14-guard-attributes.rs");
+    t.pass("tests/ui/pass/15-trait-events.rs");
+    t.pass("tests/ui/pass/16-continuations.rs");
+    t.pass("tests/ui/pass/17-contract-ref.rs");
 
     t.compile_fail("tests/ui/fail/C-00-constructor-self-ref.rs");
     t.compile_fail("tests/ui/fail/C-01-constructor-self-mut.rs");
@@ -35,6 +42,7 @@ fn compile_tests() {
     t.compile_fail("tests/ui/fail/C-11-unsafe-constructor.rs");
     t.compile_fail("tests/ui/fail/C-12-const-constructor.rs");
     t.compile_fail("tests/ui/fail/C-13-abi-constructor.rs");
+    t.compile_fail("tests/ui/fail/C-14-multiple-default-constructors.rs");
 
     t.compile_fail("tests/ui/fail/H-01-invalid-dyn-alloc.rs");
     t.compile_fail("tests/ui/fail/H-02-invalid-as-dependency.rs");
@@ -46,6 +54,9 @@ fn compile_tests() {
     t.compile_fail("tests/ui/fail/M-04-message-returns-non-codec.rs");
     t.compile_fail("tests/ui/fail/M-05-message-invalid-selector.rs");
     t.compile_fail("tests/ui/fail/M-10-method-unknown-ink-marker.rs");
+    t.compile_fail("tests/ui/fail/M-11-message-reserved-name.rs");
+    t.compile_fail("tests/ui/fail/M-12-only-owner-not-ownable.rs");
+    t.compile_fail("tests/ui/fail/M-13-pinned-selector-overlap.rs");
 
     t.compile_fail("tests/ui/fail/S-01-missing-storage-struct.rs");
     t.compile_fail("tests/ui/fail/S-02-multiple-storage-structs.rs");
@@ -53,4 +64,5 @@ fn compile_tests() {
     t.compile_fail("tests/ui/fail/S-04-non-storage-ink-impls.rs");
     t.compile_fail("tests/ui/fail/S-05-storage-as-event.rs");
     t.compile_fail("tests/ui/fail/S-06-event-as-storage.rs");
+    t.compile_fail("tests/ui/fail/S-07-duplicate-mixin.rs");
 }
@@ -0,0 +1,20 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod noop {
+    #[ink(storage)]
+    pub struct Noop {}
+
+    impl Noop {
+        #[ink(constructor, default)]
+        pub fn new() -> Self { Self {} }
+
+        #[ink(constructor, default)]
+        pub fn default() -> Self { Self {} }
+
+        #[ink(message)]
+        pub fn noop(&self) {}
+    }
+}
+
+fn main() {}
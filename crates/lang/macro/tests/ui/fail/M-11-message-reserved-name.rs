@@ -0,0 +1,23 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod message_reserved_name {
+    #[ink(storage)]
+    pub struct MessageReservedName {}
+
+    impl MessageReservedName {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message, when_not_paused)]
+        pub fn message(&self) {}
+    }
+
+    impl MessageReservedName {
+        pub fn pause(&mut self) {}
+    }
+}
+
+fn main() {}
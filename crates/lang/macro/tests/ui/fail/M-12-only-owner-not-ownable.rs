@@ -0,0 +1,19 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod only_owner_not_ownable {
+    #[ink(storage)]
+    pub struct OnlyOwnerNotOwnable {}
+
+    impl OnlyOwnerNotOwnable {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message, only_owner)]
+        pub fn message(&self) {}
+    }
+}
+
+fn main() {}
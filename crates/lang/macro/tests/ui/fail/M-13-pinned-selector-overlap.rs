@@ -0,0 +1,30 @@
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait Pinned {
+    #[ink(message, selector = "0xCAFEBABE")]
+    fn pinned(&self);
+}
+
+#[ink::contract]
+mod pinned_selector_overlap {
+    #[ink(storage)]
+    pub struct PinnedSelectorOverlap {}
+
+    impl PinnedSelectorOverlap {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {}
+        }
+
+        #[ink(message, selector = "0xCAFEBABE")]
+        pub fn inherent(&self) {}
+    }
+
+    impl super::Pinned for PinnedSelectorOverlap {
+        #[ink(message)]
+        fn pinned(&self) {}
+    }
+}
+
+fn main() {}
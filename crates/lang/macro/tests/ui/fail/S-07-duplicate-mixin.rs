@@ -0,0 +1,46 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod duplicate_mixin {
+    #[ink::mixin]
+    pub mod escrow {
+        #[ink(storage)]
+        pub struct Escrow {
+            released: bool,
+        }
+
+        impl Escrow {
+            pub fn new() -> Self {
+                Self { released: false }
+            }
+
+            #[ink(message)]
+            pub fn release(&mut self) {
+                self.released = true;
+            }
+        }
+    }
+
+    #[ink(storage)]
+    pub struct DuplicateMixin {
+        #[ink(mixin)]
+        first: escrow::Escrow,
+        #[ink(mixin)]
+        second: escrow::Escrow,
+    }
+
+    impl DuplicateMixin {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self {
+                first: escrow::Escrow::new(),
+                second: escrow::Escrow::new(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn message(&self) {}
+    }
+}
+
+fn main() {}
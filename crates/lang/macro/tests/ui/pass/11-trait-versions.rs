@@ -0,0 +1,71 @@
+use ink_lang as ink;
+
+pub mod v1 {
+    use super::ink;
+
+    #[ink::trait_definition]
+    pub trait Flip {
+        #[ink(message)]
+        fn flip(&mut self);
+
+        #[ink(message)]
+        fn get(&self) -> bool;
+    }
+}
+
+pub mod v2 {
+    use super::ink;
+
+    #[ink::trait_definition(version = 2)]
+    pub trait Flip {
+        #[ink(message)]
+        fn flip(&mut self);
+
+        #[ink(message)]
+        fn get(&self) -> bool;
+    }
+}
+
+#[ink::contract]
+mod trait_versions {
+    #[ink(storage)]
+    pub struct Flipper {
+        value: bool,
+    }
+
+    impl Flipper {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: false }
+        }
+    }
+
+    impl super::v1::Flip for Flipper {
+        #[ink(message)]
+        fn flip(&mut self) {
+            self.value = !self.value;
+        }
+
+        #[ink(message)]
+        fn get(&self) -> bool {
+            self.value
+        }
+    }
+
+    impl super::v2::Flip for Flipper {
+        #[ink(message)]
+        fn flip(&mut self) {
+            self.value = !self.value;
+        }
+
+        #[ink(message)]
+        fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+ink::assert_trait_impl!(trait_versions::Flipper, v1::Flip);
+ink::assert_trait_impl!(trait_versions::Flipper, v2::Flip);
+
+fn main() {}
@@ -0,0 +1,71 @@
+use ink_lang as ink;
+
+#[ink::contract(ownable = true, telemetry = true)]
+mod shop {
+    #[ink::mixin]
+    pub mod escrow {
+        use ink_env::DefaultEnvironment as Env;
+        type Balance = <Env as ink_env::Environment>::Balance;
+
+        #[ink(storage)]
+        pub struct Escrow {
+            deposited: Balance,
+        }
+
+        impl Escrow {
+            pub fn new() -> Self {
+                Self { deposited: 0 }
+            }
+
+            #[ink(message, payable, when_not_paused, throttle = "1/block")]
+            pub fn deposit(&mut self) {
+                let value = ink_env::transferred_balance::<Env>().unwrap_or_default();
+                self.deposited += value;
+            }
+
+            #[ink(message, only_owner, non_reentrant)]
+            pub fn release(&mut self) {
+                self.deposited = 0;
+            }
+
+            #[ink(message)]
+            pub fn deposited(&self) -> Balance {
+                self.deposited
+            }
+        }
+    }
+
+    #[ink(storage)]
+    pub struct Shop {
+        calls: u32,
+        #[ink(mixin)]
+        escrow: escrow::Escrow,
+    }
+
+    impl Shop {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                calls: 0,
+                escrow: escrow::Escrow::new(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn calls(&self) -> u32 {
+            self.calls
+        }
+
+        #[ink(before_message)]
+        fn count_calls(&mut self, _selector: [u8; 4]) {
+            self.calls += 1;
+        }
+
+        #[ink(after_message)]
+        fn check_calls(&self, _selector: [u8; 4]) {
+            assert!(self.calls > 0);
+        }
+    }
+}
+
+fn main() {}
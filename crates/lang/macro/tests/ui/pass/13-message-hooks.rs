@@ -0,0 +1,94 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod hooks {
+    #[ink(storage)]
+    pub struct Hooks {
+        value: bool,
+        calls: u32,
+        last_selector: [u8; 4],
+        rejects: bool,
+    }
+
+    impl Hooks {
+        #[ink(constructor, selector = "0xC0000000")]
+        pub fn new() -> Self {
+            Self {
+                value: false,
+                calls: 0,
+                last_selector: [0x00; 4],
+                rejects: false,
+            }
+        }
+
+        #[ink(message, selector = "0x00000001")]
+        pub fn flip(&mut self) {
+            self.value = !self.value;
+        }
+
+        #[ink(message, selector = "0x00000002")]
+        pub fn calls(&self) -> u32 {
+            self.calls
+        }
+
+        #[ink(message, selector = "0x00000003")]
+        pub fn last_selector(&self) -> [u8; 4] {
+            self.last_selector
+        }
+
+        #[ink(message, selector = "0x00000004")]
+        pub fn reject(&mut self) {
+            self.rejects = true;
+        }
+
+        #[ink(before_message)]
+        fn count_calls(&mut self, selector: [u8; 4]) {
+            self.calls += 1;
+            self.last_selector = selector;
+        }
+
+        #[ink(after_message)]
+        fn check_rejects(&self, _selector: [u8; 4]) {
+            assert!(!self.rejects, "rejected by the hook");
+        }
+    }
+}
+
+fn main() {
+    use ink_env::DefaultEnvironment;
+    use ink_lang::replay::{
+        replay,
+        ExecutionOutcome,
+        RecordedCall,
+    };
+
+    ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+        let call = |input| {
+            RecordedCall::<DefaultEnvironment>::call_hex(accounts.alice, 0, input)
+                .unwrap()
+        };
+        let calls = vec![
+            RecordedCall::instantiate_hex(accounts.alice, 0, "0xC0000000").unwrap(),
+            call("0x00000001"),
+            call("0x00000001"),
+            call("0x00000002"),
+            call("0x00000003"),
+            call("0x00000004"),
+        ];
+        let outcomes = replay::<hooks::Hooks>(&calls);
+        let output = |outcome: &ExecutionOutcome| match outcome {
+            ExecutionOutcome::Returned {
+                reverted: false,
+                output,
+            } => output.clone(),
+            outcome => panic!("encountered unexpected outcome: {:?}", outcome),
+        };
+        // The `&mut self` hook is skipped around the `&self` messages.
+        assert_eq!(output(&outcomes[3]), 2_u32.to_le_bytes().to_vec());
+        assert_eq!(output(&outcomes[4]), vec![0x00, 0x00, 0x00, 0x01]);
+        // The `&self` hook runs after the message and rejects the call.
+        assert!(outcomes[5].is_reverted());
+        Ok(())
+    })
+    .unwrap()
+}
@@ -0,0 +1,38 @@
+use ink_lang as ink;
+
+#[ink::contract(ownable = true, telemetry = true, supports_interface = true)]
+mod faucet {
+    #[ink(storage)]
+    pub struct Faucet {
+        claimed: u32,
+    }
+
+    impl Faucet {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { claimed: 0 }
+        }
+
+        #[ink(message, when_not_paused, throttle = "1/block")]
+        pub fn claim(&mut self) {
+            self.claimed += 1;
+        }
+
+        #[ink(message, only_role = "REFILLER", non_reentrant)]
+        pub fn refill(&mut self) {
+            self.claimed = 0;
+        }
+
+        #[ink(message, only_owner)]
+        pub fn reset(&mut self) {
+            self.claimed = 0;
+        }
+
+        #[ink(message)]
+        pub fn claimed(&self) -> u32 {
+            self.claimed
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,76 @@
+use ink_lang as ink;
+
+#[ink::event(namespace = "Counter")]
+pub struct Incremented {
+    #[ink(topic)]
+    by: u32,
+}
+
+#[ink::trait_definition]
+pub trait Counter {
+    #[ink(message)]
+    fn increment(&mut self, by: u32);
+
+    fn notify_incremented(&self, by: u32)
+    where
+        Self: ink_lang::EmitTraitEvent<Incremented>,
+    {
+        self.emit_trait_event(Incremented { by })
+    }
+}
+
+#[ink::contract]
+mod counter {
+    use super::Counter;
+
+    #[ink(event)]
+    use super::Incremented;
+
+    #[ink(event)]
+    pub struct Reset {}
+
+    #[ink(storage)]
+    pub struct MyCounter {
+        value: u32,
+    }
+
+    impl MyCounter {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(message)]
+        pub fn reset(&mut self) {
+            self.value = 0;
+            self.env().emit_event(Reset {});
+        }
+    }
+
+    impl Counter for MyCounter {
+        #[ink(message)]
+        fn increment(&mut self, by: u32) {
+            self.value += by;
+            self.notify_incremented(by);
+        }
+    }
+}
+
+fn main() {
+    use ink_env::DefaultEnvironment;
+
+    ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+        let mut counter = counter::MyCounter::new();
+        counter.reset();
+        counter.increment(42);
+        let events = ink_env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(events.len(), 2);
+        // Imported events follow the events defined by the contract.
+        assert_eq!(events[0].data, vec![0]);
+        assert_eq!(events[1].data, vec![1, 42, 0, 0, 0]);
+        // The signature topic and the `by` topic.
+        assert_eq!(events[1].topics.len(), 2);
+        Ok(())
+    })
+    .unwrap()
+}
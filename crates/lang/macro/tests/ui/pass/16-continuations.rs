@@ -0,0 +1,63 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod oracle_client {
+    use ink_lang::continuation::{
+        ContinuationError,
+        ContinuationToken,
+        Continuations as _,
+    };
+
+    #[ink(storage)]
+    pub struct OracleClient {
+        price: Balance,
+    }
+
+    impl OracleClient {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { price: 0 }
+        }
+
+        #[ink(message)]
+        pub fn resume(
+            &mut self,
+            token: ContinuationToken,
+            input: Vec<u8>,
+        ) -> Result<(), ContinuationError> {
+            self.resume_continuation(token, &input)
+        }
+
+        #[ink(message)]
+        pub fn price(&self) -> Balance {
+            self.price
+        }
+
+        #[ink(continuation)]
+        fn on_price(&mut self, price: Balance) {
+            self.price = price;
+        }
+    }
+}
+
+fn main() {
+    use ink_lang::continuation::ContinuationError;
+    use oracle_client::OracleClient;
+
+    ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+        let mut client = OracleClient::new();
+        let token = OracleClient::ON_PRICE.token(1);
+        let mut input = scale::Encode::encode(&42u128);
+        input.push(0x00);
+        assert_eq!(
+            client.resume(token, input.clone()),
+            Err(ContinuationError::InvalidInput)
+        );
+        assert_eq!(client.price(), 0);
+        input.pop();
+        assert_eq!(client.resume(token, input), Ok(()));
+        assert_eq!(client.price(), 42);
+        Ok(())
+    })
+    .unwrap()
+}
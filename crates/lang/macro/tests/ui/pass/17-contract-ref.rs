@@ -0,0 +1,43 @@
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait Erc20 {
+    #[ink(message)]
+    fn total_supply(&self) -> u128;
+}
+
+#[ink::contract]
+mod proxy {
+    use ink_env::call::FromAccountId as _;
+
+    #[ink(storage)]
+    pub struct Proxy {
+        token: AccountId,
+    }
+
+    impl Proxy {
+        #[ink(constructor)]
+        pub fn new(token: AccountId) -> Self {
+            Self { token }
+        }
+
+        #[ink(message)]
+        pub fn total_supply(&self) -> Balance {
+            let token =
+                <ink_lang::contract_ref!(super::Erc20, Environment)>::from_account_id(
+                    self.token,
+                );
+            token.clone().total_supply()
+        }
+    }
+}
+
+fn assert_contract_ref<T>()
+where
+    T: core::fmt::Debug + Clone + scale::Encode + scale::Decode,
+{
+}
+
+fn main() {
+    assert_contract_ref::<ink::contract_ref!(Erc20)>();
+}
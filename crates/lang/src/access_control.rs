@@ -0,0 +1,130 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Role based access control for ink! messages.
+//!
+//! ink! messages flagged with `#[ink(message, only_role = "NAME")]` can only be
+//! called by accounts that have been granted the role with the given name.
+//! Contracts using this facility are provided with generated `grant_role`,
+//! `revoke_role` and `has_role` messages. The `grant_role` and `revoke_role`
+//! messages require the [`ADMIN_ROLE`] which is granted to the instantiator
+//! of the contract.
+//!
+//! The roles are stored under hashed keys outside of the contract storage struct
+//! and therefore do not interfere with its storage layout.
+
+use crate::DispatchError;
+use ink_env::{
+    hash::{
+        Blake2x256,
+        HashOutput,
+    },
+    Environment,
+};
+use ink_primitives::Key;
+
+/// The identifier of a role.
+///
+/// This is the BLAKE2 256-bit hash of the name of the role.
+pub type RoleId = [u8; 32];
+
+/// The name of the role that is allowed to grant and revoke roles.
+pub const ADMIN_ROLE: &str = "ADMIN";
+
+/// The prefix of the storage keys of granted roles.
+const ROLE_KEY_PREFIX: &[u8] = b"ink_lang::access_control::role";
+
+/// Returns the identifier of the role with the given name.
+pub fn role_id(name: &str) -> RoleId {
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_bytes::<Blake2x256>(name.as_bytes(), &mut output);
+    output
+}
+
+/// Returns the storage key under which the role of the account is stored.
+fn role_key<E>(role: &RoleId, account: &E::AccountId) -> Key
+where
+    E: Environment,
+{
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_encoded::<Blake2x256, _>(
+        &(ROLE_KEY_PREFIX, role, account),
+        &mut output,
+    );
+    Key::from(output)
+}
+
+/// Returns `true` if the account has been granted the role.
+pub fn has_role<E>(role: RoleId, account: &E::AccountId) -> bool
+where
+    E: Environment,
+{
+    matches!(
+        ink_env::get_contract_storage::<bool>(&role_key::<E>(&role, account)),
+        Ok(Some(true))
+    )
+}
+
+/// Grants the role to the account.
+///
+/// # Note
+///
+/// This does not check whether the caller is allowed to grant the role.
+pub fn grant_role<E>(role: RoleId, account: &E::AccountId)
+where
+    E: Environment,
+{
+    ink_env::set_contract_storage(&role_key::<E>(&role, account), &true)
+}
+
+/// Revokes the role from the account.
+///
+/// # Note
+///
+/// This does not check whether the caller is allowed to revoke the role.
+pub fn revoke_role<E>(role: RoleId, account: &E::AccountId)
+where
+    E: Environment,
+{
+    ink_env::clear_contract_storage(&role_key::<E>(&role, account))
+}
+
+/// Returns an error if the caller has not been granted the role with the given name.
+///
+/// Used by the ink! codegen before dispatching an ink! message flagged with
+/// `#[ink(message, only_role = "NAME")]`.
+#[doc(hidden)]
+pub fn ensure_caller_has_role<E>(name: &str) -> Result<(), DispatchError>
+where
+    E: Environment,
+{
+    let caller = ink_env::caller::<E>().map_err(|_| DispatchError::MissingRole)?;
+    if !has_role::<E>(role_id(name), &caller) {
+        return Err(DispatchError::MissingRole)
+    }
+    Ok(())
+}
+
+/// Grants the [`ADMIN_ROLE`] to the caller.
+///
+/// Used by the ink! codegen upon instantiation of ink! smart contracts
+/// using role based access control.
+#[doc(hidden)]
+pub fn grant_admin_role_to_caller<E>()
+where
+    E: Environment,
+{
+    let caller = ink_env::caller::<E>().expect("could not read the caller");
+    grant_role::<E>(role_id(ADMIN_ROLE), &caller)
+}
@@ -0,0 +1,217 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Multi-step workflows written as state machines of ink! continuations.
+//!
+//! Some workflows of a contract span several contract executions, e.g. when the
+//! contract waits for the answer of an oracle or for another contract to call back
+//! in a later block. Such a workflow is suspended by handing out a
+//! [`ContinuationToken`] and resumed once the token is passed back together with
+//! the SCALE encoded input of the next step.
+//!
+//! The steps are inherent methods of the storage struct flagged with
+//! `#[ink(continuation)]` that take `&mut self` and a single input. For every
+//! continuation the ink! codegen generates
+//!
+//! - a [`Step`] associated constant of the storage struct named after the
+//!   continuation in upper snake case, e.g. `ON_PRICE` for `on_price`, and
+//! - the routing of [`Continuations::resume_continuation`] to the continuation
+//!   identified by the selector of the token.
+//!
+//! The contract exposes the resumption through an ink! message of its own so that
+//! it stays in control of who is allowed to resume which token:
+//!
+//! ```ignore
+//! use ink_lang::continuation::{
+//!     ContinuationError,
+//!     ContinuationToken,
+//!     Continuations as _,
+//! };
+//!
+//! #[ink(storage)]
+//! pub struct Exchange {
+//!     oracle: AccountId,
+//!     pending: Option<ContinuationToken>,
+//!     nonce: u64,
+//!     price: Balance,
+//! }
+//!
+//! impl Exchange {
+//!     /// Suspends until the oracle resumes the returned token with the price.
+//!     #[ink(message)]
+//!     pub fn request_price(&mut self) -> ContinuationToken {
+//!         self.nonce += 1;
+//!         let token = Self::ON_PRICE.token(self.nonce);
+//!         self.pending = Some(token);
+//!         token
+//!     }
+//!
+//!     #[ink(message)]
+//!     pub fn resume(
+//!         &mut self,
+//!         token: ContinuationToken,
+//!         input: Vec<u8>,
+//!     ) -> Result<(), ContinuationError> {
+//!         if self.env().caller() != self.oracle || self.pending != Some(token) {
+//!             return Err(ContinuationError::UnexpectedToken)
+//!         }
+//!         self.pending = None;
+//!         self.resume_continuation(token, &input)
+//!     }
+//!
+//!     #[ink(continuation)]
+//!     fn on_price(&mut self, price: Balance) {
+//!         self.price = price;
+//!     }
+//! }
+//! ```
+
+use core::marker::PhantomData;
+use ink_prelude::vec::Vec;
+use ink_storage::traits::{
+    PackedLayout,
+    SpreadLayout,
+};
+
+/// Identifies a suspended step of a multi-step workflow.
+///
+/// The token consists of the selector of the ink! continuation that resumes the
+/// workflow and a contract defined nonce distinguishing multiple suspended
+/// workflows waiting for the same continuation.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    scale::Encode,
+    scale::Decode,
+    SpreadLayout,
+    PackedLayout,
+)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+)]
+pub struct ContinuationToken {
+    /// The selector of the resuming ink! continuation.
+    selector: [u8; 4],
+    /// The contract defined nonce of the suspended workflow.
+    nonce: u64,
+}
+
+impl ContinuationToken {
+    /// Creates a token resuming the ink! continuation with the selector.
+    pub fn new(selector: [u8; 4], nonce: u64) -> Self {
+        Self { selector, nonce }
+    }
+
+    /// Returns the selector of the ink! continuation resuming the workflow.
+    pub fn selector(&self) -> [u8; 4] {
+        self.selector
+    }
+
+    /// Returns the nonce of the suspended workflow.
+    pub fn nonce(&self) -> u64 {
+        self.nonce
+    }
+}
+
+/// Errors that can occur upon resuming a suspended workflow.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ContinuationError {
+    /// The token does not refer to an ink! continuation of the contract.
+    UnknownContinuation,
+    /// The input could not be decoded into the input of the ink! continuation
+    /// or has not been fully consumed by the decoding.
+    InvalidInput,
+    /// The token is not expected to be resumed by the caller.
+    ///
+    /// Not returned by [`Continuations::resume_continuation`] but by contracts
+    /// checking the tokens passed to them.
+    UnexpectedToken,
+}
+
+/// A typed reference to an ink! continuation of the contract.
+///
+/// Generated by the ink! codegen as associated constant of the storage struct
+/// for every ink! continuation with `T` being the input of the continuation.
+pub struct Step<T> {
+    /// The selector of the ink! continuation.
+    selector: [u8; 4],
+    /// The input type of the ink! continuation.
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Step<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Step<T> {}
+
+impl<T> Step<T> {
+    /// Creates the reference to the ink! continuation with the selector.
+    ///
+    /// Used by the ink! codegen.
+    #[doc(hidden)]
+    pub const fn from_selector(selector: [u8; 4]) -> Self {
+        Self {
+            selector,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the selector of the ink! continuation.
+    pub const fn selector(&self) -> [u8; 4] {
+        self.selector
+    }
+
+    /// Returns a token suspending a workflow until the ink! continuation resumes it.
+    pub fn token(&self, nonce: u64) -> ContinuationToken {
+        ContinuationToken::new(self.selector, nonce)
+    }
+}
+
+impl<T> Step<T>
+where
+    T: scale::Encode,
+{
+    /// Returns the SCALE encoded input for resuming the ink! continuation.
+    pub fn encode_input(&self, input: &T) -> Vec<u8> {
+        <T as scale::Encode>::encode(input)
+    }
+}
+
+/// Implemented by the ink! codegen for contracts defining ink! continuations.
+pub trait Continuations {
+    /// Decodes the input and invokes the ink! continuation identified by the token.
+    ///
+    /// # Note
+    ///
+    /// This does not check whether the token has been handed out by the contract
+    /// or whether the caller is allowed to resume it.
+    ///
+    /// # Errors
+    ///
+    /// - If the token does not refer to an ink! continuation of the contract.
+    /// - If the input cannot be decoded into the input of the ink! continuation.
+    fn resume_continuation(
+        &mut self,
+        token: ContinuationToken,
+        input: &[u8],
+    ) -> Result<(), ContinuationError>;
+}
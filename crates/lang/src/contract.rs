@@ -32,5 +32,10 @@ pub enum DispatchMode {
 /// calls to.
 #[doc(hidden)]
 pub trait DispatchUsingMode {
+    /// If all ink! messages of the contract deny payments.
+    ///
+    /// In this case payments are denied up front for all contract calls.
+    const ALL_MESSAGES_DENY_PAYMENT: bool = false;
+
     fn dispatch_using_mode(mode: DispatchMode) -> Result<(), DispatchError>;
 }
@@ -0,0 +1,328 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic sub-accounts of factory contracts.
+//!
+//! Factory contracts instantiate child contracts from the same code hash and
+//! often with the same constructor arguments. The [`contract_account!`] macro
+//! derives a distinct [`Salt`] for every key of the factory, instantiates the
+//! child contract under the predicted account ID and records the account ID in
+//! the [`Registry`] of the factory. A key can only be used once so that salts are
+//! never reused.
+//!
+//! [`contract_account!`]: crate::contract_account!
+
+use ink_env::{
+    call::{
+        utils::{
+            Set,
+            Unwrap,
+        },
+        CreateBuilder,
+        ExecutionInput,
+        FromAccountId,
+        Salt,
+    },
+    hash::{
+        CryptoHash,
+        HashOutput,
+    },
+    DefaultEnvironment,
+    Environment,
+};
+use ink_storage::{
+    collections::HashMap as StorageHashMap,
+    traits::{
+        KeyPtr,
+        PackedLayout,
+        SpreadLayout,
+    },
+};
+
+/// The registry that maps the keys of a factory contract to the account IDs of
+/// the contracts instantiated for them.
+///
+/// Used as field of the storage struct of the factory contract. Entries are only
+/// ever added by [`instantiate`] so that every key and thus every salt is used
+/// at most once.
+#[derive(Debug)]
+pub struct Registry<K, E = DefaultEnvironment>
+where
+    K: Ord + Clone + PackedLayout,
+    E: Environment,
+    E::AccountId: PackedLayout,
+{
+    /// The account IDs of the instantiated contracts by their keys.
+    accounts: StorageHashMap<K, E::AccountId>,
+}
+
+impl<K, E> Registry<K, E>
+where
+    K: Ord + Clone + PackedLayout,
+    E: Environment,
+    E::AccountId: PackedLayout,
+{
+    /// Creates a new empty registry.
+    pub fn new() -> Self {
+        Self {
+            accounts: StorageHashMap::new(),
+        }
+    }
+
+    /// Returns the account ID of the contract instantiated for the key if any.
+    pub fn get(&self, key: &K) -> Option<&E::AccountId> {
+        self.accounts.get(key)
+    }
+
+    /// Returns `true` if a contract has been instantiated for the key.
+    pub fn contains(&self, key: &K) -> bool {
+        self.accounts.contains_key(key)
+    }
+
+    /// Returns the number of contracts instantiated through the registry.
+    pub fn len(&self) -> u32 {
+        self.accounts.len()
+    }
+
+    /// Returns `true` if no contract has been instantiated through the registry.
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+}
+
+impl<K, E> Default for Registry<K, E>
+where
+    K: Ord + Clone + PackedLayout,
+    E: Environment,
+    E::AccountId: PackedLayout,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, E> SpreadLayout for Registry<K, E>
+where
+    K: Ord + Clone + PackedLayout,
+    E: Environment,
+    E::AccountId: PackedLayout,
+{
+    const FOOTPRINT: u64 = <StorageHashMap<K, E::AccountId> as SpreadLayout>::FOOTPRINT;
+    const REQUIRES_DEEP_CLEAN_UP: bool =
+        <StorageHashMap<K, E::AccountId> as SpreadLayout>::REQUIRES_DEEP_CLEAN_UP;
+
+    fn pull_spread(ptr: &mut KeyPtr) -> Self {
+        Self {
+            accounts: SpreadLayout::pull_spread(ptr),
+        }
+    }
+
+    fn push_spread(&self, ptr: &mut KeyPtr) {
+        SpreadLayout::push_spread(&self.accounts, ptr)
+    }
+
+    fn clear_spread(&self, ptr: &mut KeyPtr) {
+        SpreadLayout::clear_spread(&self.accounts, ptr)
+    }
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use ink_metadata::layout::Layout;
+    use ink_storage::traits::StorageLayout;
+    use scale_info::TypeInfo;
+
+    impl<K, E> StorageLayout for Registry<K, E>
+    where
+        K: TypeInfo + Ord + Clone + PackedLayout + 'static,
+        E: Environment,
+        E::AccountId: TypeInfo + PackedLayout + 'static,
+    {
+        fn layout(key_ptr: &mut KeyPtr) -> Layout {
+            <StorageHashMap<K, E::AccountId> as StorageLayout>::layout(key_ptr)
+        }
+    }
+};
+
+/// Errors that can occur upon instantiating a sub-account.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum ContractAccountError {
+    /// The registry already holds a sub-account for the key.
+    KeyInUse,
+    /// The instantiation of the contract failed.
+    InstantiationFailed,
+}
+
+/// Instantiates the contract for the key and records its account ID in the registry.
+///
+/// The salt of the instantiation is derived from the key via [`Salt::derive`].
+/// `H` must be the hasher of the runtime, see [`ink_env::call::contract_address`].
+///
+/// # Errors
+///
+/// - [`ContractAccountError::KeyInUse`] if the registry already holds the key.
+///   Nothing is instantiated in this case.
+/// - [`ContractAccountError::InstantiationFailed`] if the instantiation failed.
+///
+/// # Panics
+///
+/// If the instantiated contract has not been assigned the predicted account ID,
+/// see [`CreateBuilder::instantiate_salted`].
+///
+/// # Note
+///
+/// Usually used via the [`contract_account!`](crate::contract_account!) macro.
+pub fn instantiate<E, H, K, GasLimit, Args, R>(
+    registry: &mut Registry<K, E>,
+    key: K,
+    builder: CreateBuilder<
+        E,
+        Set<E::Hash>,
+        GasLimit,
+        Set<E::Balance>,
+        Set<ExecutionInput<Args>>,
+        R,
+    >,
+) -> Result<R, ContractAccountError>
+where
+    E: Environment,
+    E::AccountId: PackedLayout,
+    H: CryptoHash + HashOutput<Type = [u8; 32]>,
+    K: Ord + Clone + PackedLayout,
+    GasLimit: Unwrap<Output = u64>,
+    Args: scale::Encode,
+    R: FromAccountId<E>,
+{
+    if registry.contains(&key) {
+        return Err(ContractAccountError::KeyInUse)
+    }
+    let instantiated = builder
+        .instantiate_salted::<H>(Salt::derive(&key))
+        .map_err(|_| ContractAccountError::InstantiationFailed)?;
+    registry
+        .accounts
+        .insert(key, instantiated.account_id().clone());
+    Ok(instantiated.into_contract())
+}
+
+/// Instantiates a sub-account of a factory contract.
+///
+/// Takes the [`Registry`](crate::contract_account::Registry) of the factory,
+/// the key of the sub-account and the [`CreateBuilder`] of the instantiation
+/// with its code hash and endowment set. The salt of the instantiation is
+/// derived from the key and the account ID of the instantiated contract is
+/// checked against its prediction before it is recorded in the registry.
+///
+/// The account ID is predicted using [`Blake2x256`](ink_env::hash::Blake2x256)
+/// unless another hasher matching the runtime is provided as fourth argument.
+///
+/// Evaluates to a `Result` with the instantiated contract or a
+/// [`ContractAccountError`](crate::contract_account::ContractAccountError).
+///
+/// # Example
+///
+/// The builder is usually provided by the constructor of an ink! contract,
+/// e.g. `Vault::new(index).code_hash(code_hash).endowment(1_000)`.
+///
+/// ```no_run
+/// # use ink_lang as ink;
+/// # use ink_env::{
+/// #     call::{build_create, ExecutionInput, FromAccountId, Selector},
+/// #     AccountId,
+/// #     DefaultEnvironment,
+/// #     Hash,
+/// # };
+/// # struct Vault;
+/// # impl FromAccountId<DefaultEnvironment> for Vault {
+/// #     fn from_account_id(_account_id: AccountId) -> Self { Self }
+/// # }
+/// use ink_lang::contract_account::{ContractAccountError, Registry};
+///
+/// struct Factory {
+///     vault_code: Hash,
+///     vaults: Registry<u32>,
+/// }
+///
+/// impl Factory {
+///     fn create_vault(&mut self, index: u32) -> Result<Vault, ContractAccountError> {
+///         let builder = build_create::<DefaultEnvironment, Vault>()
+///             .code_hash(self.vault_code)
+///             .endowment(1_000)
+///             .exec_input(ExecutionInput::new(Selector::new([0xDE, 0xAD, 0xBE, 0xEF])));
+///         ink::contract_account!(self.vaults, index, builder)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! contract_account {
+    ( $registry:expr, $key:expr, $builder:expr $(,)? ) => {
+        $crate::contract_account!($registry, $key, $builder, ::ink_env::hash::Blake2x256)
+    };
+    ( $registry:expr, $key:expr, $builder:expr, $hasher:ty $(,)? ) => {
+        $crate::contract_account::instantiate::<_, $hasher, _, _, _, _>(
+            &mut $registry,
+            $key,
+            $builder,
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::{
+        call::{
+            build_create,
+            Selector,
+        },
+        hash::Blake2x256,
+        AccountId,
+        Hash,
+    };
+
+    struct Child;
+
+    impl FromAccountId<DefaultEnvironment> for Child {
+        fn from_account_id(_account_id: AccountId) -> Self {
+            Self
+        }
+    }
+
+    #[test]
+    fn registry_works() {
+        let mut registry = <Registry<u32>>::new();
+        assert!(registry.is_empty());
+        assert_eq!(registry.get(&1), None);
+        registry.accounts.insert(1, AccountId::from([0x01; 32]));
+        assert!(registry.contains(&1));
+        assert!(!registry.contains(&2));
+        assert_eq!(registry.get(&1), Some(&AccountId::from([0x01; 32])));
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn key_in_use_bails_out_before_instantiation() {
+        let mut registry = <Registry<u32>>::new();
+        registry.accounts.insert(1, AccountId::from([0x01; 32]));
+        let builder = build_create::<DefaultEnvironment, Child>()
+            .code_hash(Hash::from([0x02; 32]))
+            .endowment(1_000)
+            .exec_input(ExecutionInput::new(Selector::new([0xDE, 0xAD, 0xBE, 0xEF])));
+        // The off-chain environment would panic upon instantiating the child.
+        let result = instantiate::<_, Blake2x256, _, _, _, _>(&mut registry, 1, builder);
+        assert!(matches!(result, Err(ContractAccountError::KeyInUse)));
+        assert_eq!(registry.len(), 1);
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    trace,
     Constructor,
     DispatchError,
     FnOutput,
@@ -25,6 +26,10 @@ use core::{
     mem::ManuallyDrop,
 };
 use ink_env::{
+    hash::{
+        Blake2x256,
+        HashOutput,
+    },
     Environment,
     ReturnFlags,
 };
@@ -119,7 +124,9 @@ where
         alloc::initialize(ContractPhase::Call);
     }
     let root_key = Key::from([0x00; 32]);
-    let state = ManuallyDrop::new(pull_spread_root::<<M as FnState>::State>(&root_key));
+    let state = ManuallyDrop::new(trace::in_span(trace::STORAGE, "pull", || {
+        pull_spread_root::<<M as FnState>::State>(&root_key)
+    }));
     let result = f(&state);
     if enables_dynamic_storage_allocator {
         alloc::finalize();
@@ -149,12 +156,55 @@ where
     Ok(())
 }
 
+/// Returns the storage key of the reentrancy guard.
+fn reentrancy_guard_key() -> Key {
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_bytes::<Blake2x256>(
+        b"ink_lang::dispatcher::reentrancy_guard",
+        &mut output,
+    );
+    Key::from(output)
+}
+
+/// Enters a `#[ink(message, non_reentrant)]` message.
+///
+/// # Errors
+///
+/// If another non-reentrant message of the contract is already in progress.
+///
+/// # Note
+///
+/// The guard is released via [`leave_non_reentrant`] after the message
+/// has been executed. If the execution fails before that the contract
+/// execution is reverted and so is the guard.
+#[inline]
+#[doc(hidden)]
+pub fn enter_non_reentrant() -> Result<()> {
+    let key = reentrancy_guard_key();
+    if let Ok(Some(true)) = ink_env::get_contract_storage::<bool>(&key) {
+        return Err(DispatchError::ReentrantCall)
+    }
+    ink_env::set_contract_storage(&key, &true);
+    Ok(())
+}
+
+/// Leaves a `#[ink(message, non_reentrant)]` message.
+#[inline]
+#[doc(hidden)]
+pub fn leave_non_reentrant() {
+    ink_env::clear_contract_storage(&reentrancy_guard_key())
+}
+
 /// Executes the given `&mut self` message closure.
 ///
 /// # Note
 ///
 /// The closure is supposed to already contain all the arguments that the real
 /// message requires and forwards them.
+///
+/// Self-calls via `ink_env::call_self` are denied while the closure is executed
+/// since writing back the storage afterwards would overwrite the storage changes
+/// of the called message.
 #[inline]
 #[doc(hidden)]
 pub fn execute_message_mut<E, M, F>(
@@ -177,10 +227,15 @@ where
         alloc::initialize(ContractPhase::Call);
     }
     let root_key = Key::from([0x00; 32]);
-    let mut state =
-        ManuallyDrop::new(pull_spread_root::<<M as FnState>::State>(&root_key));
+    let mut state = ManuallyDrop::new(trace::in_span(trace::STORAGE, "pull", || {
+        pull_spread_root::<<M as FnState>::State>(&root_key)
+    }));
+    ink_env::set_self_calls_denied(true);
     let result = f(&mut state);
-    push_spread_root::<<M as FnState>::State>(&state, &root_key);
+    ink_env::set_self_calls_denied(false);
+    trace::in_span(trace::STORAGE, "push", || {
+        push_spread_root::<<M as FnState>::State>(&state, &root_key)
+    });
     if enables_dynamic_storage_allocator {
         alloc::finalize();
     }
@@ -213,9 +268,34 @@ where
     }
     let state = ManuallyDrop::new(f());
     let root_key = Key::from([0x00; 32]);
-    push_spread_root::<<C as FnState>::State>(&state, &root_key);
+    trace::in_span(trace::STORAGE, "push", || {
+        push_spread_root::<<C as FnState>::State>(&state, &root_key)
+    });
     if enables_dynamic_storage_allocator {
         alloc::finalize();
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::DefaultEnvironment;
+
+    #[test]
+    fn nested_non_reentrant_calls_fail() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+            assert!(enter_non_reentrant().is_ok());
+            // The guard is held in the contract storage and therefore also seen
+            // by the contract executions of self-calls.
+            assert!(matches!(
+                enter_non_reentrant(),
+                Err(DispatchError::ReentrantCall)
+            ));
+            leave_non_reentrant();
+            assert!(enter_non_reentrant().is_ok());
+            Ok(())
+        })
+        .unwrap()
+    }
+}
@@ -16,20 +16,90 @@
 #[doc(hidden)]
 pub type DispatchResult = core::result::Result<(), DispatchError>;
 
-/// A dispatch error.
-#[derive(Debug, Copy, Clone)]
-#[doc(hidden)]
-pub enum DispatchError {
-    UnknownSelector,
-    UnknownInstantiateSelector,
-    UnknownCallSelector,
+macro_rules! define_dispatch_errors {
+    (
+        $(
+            $( #[doc = $doc:literal] )*
+            $name:ident = $code:literal,
+        )*
+    ) => {
+        /// A dispatch error.
+        #[derive(Debug, Copy, Clone)]
+        #[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+        #[doc(hidden)]
+        pub enum DispatchError {
+            $(
+                $( #[doc = $doc] )*
+                $name = $code,
+            )*
+        }
 
-    InvalidParameters,
-    InvalidInstantiateParameters,
-    InvalidCallParameters,
+        /// Returns the specification of all language level dispatch errors.
+        ///
+        /// Used by the ink! codegen to include the dispatch errors into the metadata.
+        #[cfg(feature = "std")]
+        #[doc(hidden)]
+        pub fn lang_error_spec() -> ::ink_metadata::LangErrorSpec {
+            ::ink_metadata::LangErrorSpec::new(
+                ::ink_metadata::TypeSpec::with_name_str::<DispatchError>(
+                    "ink_lang::DispatchError",
+                ),
+            )
+            .variants(vec![
+                $(
+                    ::ink_metadata::LangErrorVariantSpec::new(stringify!($name), $code)
+                        .docs(vec![ $( $doc ),* ])
+                        .done(),
+                )*
+            ])
+            .done()
+        }
 
-    CouldNotReadInput,
-    PaidUnpayableMessage,
+        impl DispatchError {
+            /// Returns the dispatch error that corresponds to the language level error.
+            ///
+            /// Returns `None` if the error code is unknown.
+            #[inline]
+            pub fn from_lang_error(lang_error: ::ink_env::call::LangError) -> Option<Self> {
+                match lang_error.code() {
+                    $(
+                        $code => Some(Self::$name),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+define_dispatch_errors! {
+    /// The selector of the called constructor or message is unknown.
+    UnknownSelector = 0x01,
+    /// The selector of the called constructor is unknown.
+    UnknownInstantiateSelector = 0x02,
+    /// The selector of the called message is unknown.
+    UnknownCallSelector = 0x03,
+    /// The parameters of the called constructor or message could not be decoded.
+    InvalidParameters = 0x04,
+    /// The parameters of the called constructor could not be decoded.
+    InvalidInstantiateParameters = 0x05,
+    /// The parameters of the called message could not be decoded.
+    InvalidCallParameters = 0x06,
+    /// The input of the contract execution could not be read.
+    CouldNotReadInput = 0x07,
+    /// Value has been transferred to a message that is not payable.
+    PaidUnpayableMessage = 0x08,
+    /// The caller is missing the role required by the called message.
+    MissingRole = 0x09,
+    /// A non-reentrant message has been called while another one is in progress.
+    ReentrantCall = 0x0A,
+    /// A message that is unavailable while the contract is paused has been called.
+    ContractPaused = 0x0B,
+    /// A message that may only be called by the owner has been called by another account.
+    NotOwner = 0x0C,
+    /// The input of the contract execution exceeds the configured maximum length.
+    InputTooLarge = 0x0D,
+    /// A throttled message has been called more often than allowed for the caller.
+    Throttled = 0x0E,
 }
 
 impl DispatchError {
@@ -66,17 +136,36 @@ impl DispatchRetCode {
 impl From<DispatchError> for DispatchRetCode {
     #[inline]
     fn from(err: DispatchError) -> Self {
-        match err {
-            DispatchError::UnknownSelector => Self(0x01),
-            DispatchError::UnknownInstantiateSelector => Self(0x02),
-            DispatchError::UnknownCallSelector => Self(0x03),
-            DispatchError::InvalidParameters => Self(0x04),
-            DispatchError::InvalidInstantiateParameters => Self(0x05),
-            DispatchError::InvalidCallParameters => Self(0x06),
-            DispatchError::CouldNotReadInput => Self(0x07),
-            DispatchError::PaidUnpayableMessage => Self(0x08),
-        }
+        Self(err as u32)
+    }
+}
+
+impl From<DispatchError> for ::ink_env::call::LangError {
+    #[inline]
+    fn from(err: DispatchError) -> Self {
+        Self::from_code(err as u32)
+    }
+}
+
+/// Finalizes the dispatch of a contract execution.
+///
+/// Reverts the contract execution with the corresponding language level error
+/// if the dispatch has failed so that calling contracts are able to tell dispatch
+/// errors apart from other failures.
+///
+/// With the `heap-instrumentation` crate feature enabled the heap usage of the
+/// contract execution is reported to the environmental log before returning.
+#[doc(hidden)]
+pub fn finalize_dispatch(result: DispatchResult) -> u32 {
+    if let Err(err) = result {
+        ::ink_env::return_value(
+            ::ink_env::ReturnFlags::default().set_reverted(true),
+            &::ink_env::call::LangError::from(err),
+        )
     }
+    #[cfg(feature = "heap-instrumentation")]
+    ::ink_env::report_heap_usage();
+    DispatchRetCode::success().to_u32()
 }
 
 impl From<DispatchResult> for DispatchRetCode {
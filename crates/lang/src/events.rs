@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::ContractEnv;
+
 /// Implemented by contracts in order to override `env().emit_event(..)`
 /// syntax for emitting of ink! contract events.
 ///
@@ -28,6 +30,15 @@ where
     fn emit_event<E>(self, event: E)
     where
         E: Into<<C as BaseEvent>::Type>;
+
+    /// Emits an event that can be trivially converted into the base event.
+    ///
+    /// Returns an error instead of trapping the contract execution if the encoded
+    /// event does not fit into the environmental buffer so that contracts can
+    /// decide to drop the event.
+    fn try_emit_event<E>(self, event: E) -> Result<(), ink_env::EventError>
+    where
+        E: Into<<C as BaseEvent>::Type>;
 }
 
 /// Defines a base event type for the contract.
@@ -37,3 +48,51 @@ pub trait BaseEvent {
     /// The generated base event enum.
     type Type;
 }
+
+/// Allows default methods of ink! trait definitions to emit events defined via
+/// `#[ink::event]` on behalf of the implementing ink! smart contract.
+///
+/// The event is converted into the event enum of the ink! smart contract before
+/// it is emitted so that it is encoded with its variant index like every other
+/// event of the ink! smart contract. Therefore the ink! smart contract has to
+/// import the event via `#[ink(event)] use path::to::Event;` and default methods
+/// emitting the event have to be bounded by `Self: EmitTraitEvent<Event>`.
+pub trait EmitTraitEvent<E>: ContractEnv {
+    /// Emits the event on behalf of the ink! smart contract.
+    fn emit_trait_event(&self, event: E);
+
+    /// Emits the event on behalf of the ink! smart contract.
+    ///
+    /// Returns an error instead of trapping the contract execution if the encoded
+    /// event does not fit into the environmental buffer.
+    fn try_emit_trait_event(&self, event: E) -> Result<(), ink_env::EventError>;
+}
+
+impl<T, E> EmitTraitEvent<E> for T
+where
+    T: ContractEnv + BaseEvent,
+    E: Into<<T as BaseEvent>::Type>,
+    <T as BaseEvent>::Type: ink_env::Topics + scale::Encode,
+{
+    fn emit_trait_event(&self, event: E) {
+        ink_env::emit_event::<<T as ContractEnv>::Env, <T as BaseEvent>::Type>(
+            event.into(),
+        )
+    }
+
+    fn try_emit_trait_event(&self, event: E) -> Result<(), ink_env::EventError> {
+        ink_env::try_emit_event::<<T as ContractEnv>::Env, <T as BaseEvent>::Type>(
+            event.into(),
+        )
+    }
+}
+
+/// Provides the metadata of an event defined via `#[ink::event]`.
+///
+/// Used by the ink! codegen in order to list imported events in the metadata of
+/// the importing ink! smart contract.
+#[cfg(feature = "std")]
+pub trait EventMetadata {
+    /// Returns the metadata of the event.
+    fn event_spec() -> ink_metadata::EventSpec;
+}
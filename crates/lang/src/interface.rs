@@ -0,0 +1,89 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime discovery of the ink! trait definitions implemented by contracts.
+//!
+//! Contracts defined via `#[ink::contract(supports_interface = true)]` are
+//! provided with a generated `supports_interface` message that reports whether
+//! the contract implements the ink! trait definition with the given interface
+//! identifier, similar to ERC-165.
+//!
+//! The interface identifier of an ink! trait definition is the first four bytes
+//! of the BLAKE2 hash of the sorted and concatenated selectors of its messages.
+//! It is the same for all builds and available to callers through the
+//! [`InterfaceId`] implementation of the contract reference of the trait definition:
+//!
+//! ```no_compile
+//! let token: contract_ref!(Erc20) = FromAccountId::from_account_id(account_id);
+//! if ink_lang::interface::implements::<Environment, _>(&token) {
+//!     token.transfer(to, value);
+//! }
+//! ```
+
+use crate::ToAccountId;
+use ink_env::{
+    call::{
+        build_call,
+        utils::ReturnType,
+        ExecutionInput,
+        Selector,
+    },
+    Environment,
+};
+
+/// The selector of the generated `supports_interface` message.
+pub const SUPPORTS_INTERFACE_SELECTOR: [u8; 4] = [0xA3, 0x69, 0x49, 0x74];
+
+/// The interface identifier of the generated `supports_interface` message.
+///
+/// Contracts with a generated `supports_interface` message report
+/// that they support this interface.
+pub const SUPPORTS_INTERFACE_ID: [u8; 4] = [0x00, 0x73, 0x2C, 0x74];
+
+/// Implemented by the contract references of ink! trait definitions.
+pub trait InterfaceId {
+    /// The interface identifier of the ink! trait definition.
+    const INTERFACE_ID: [u8; 4];
+}
+
+/// Returns `true` if the contract at the account supports the interface.
+///
+/// Returns `false` if the contract does not have a `supports_interface`
+/// message or if the call to it fails otherwise.
+pub fn supports_interface<E>(account_id: E::AccountId, interface_id: [u8; 4]) -> bool
+where
+    E: Environment,
+{
+    build_call::<E>()
+        .callee(account_id)
+        .exec_input(
+            ExecutionInput::new(Selector::new(SUPPORTS_INTERFACE_SELECTOR))
+                .push_arg(interface_id),
+        )
+        .returns::<ReturnType<bool>>()
+        .fire()
+        .unwrap_or(false)
+}
+
+/// Returns `true` if the referenced contract implements the ink! trait definition
+/// of the contract reference.
+///
+/// See [`supports_interface`] for more information.
+pub fn implements<E, T>(contract: &T) -> bool
+where
+    E: Environment,
+    T: InterfaceId + ToAccountId<E>,
+{
+    supports_interface::<E>(contract.to_account_id(), T::INTERFACE_ID)
+}
@@ -14,14 +14,34 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod access_control;
 mod contract;
+pub mod contract_account;
+pub mod continuation;
 mod cross_calling;
 mod dispatcher;
 mod env_access;
 mod error;
 mod events;
+pub mod interface;
+pub mod mixin;
+pub mod ownable;
+pub mod pausable;
+pub mod reflect;
+#[cfg(feature = "std")]
+pub mod replay;
+mod revert;
+pub mod telemetry;
+pub mod throttle;
+pub mod trace;
 mod traits;
+pub mod typed_data;
 
+#[cfg(feature = "std")]
+pub use self::{
+    error::lang_error_spec,
+    events::EventMetadata,
+};
 pub use self::{
     contract::{
         DispatchMode,
@@ -38,6 +58,8 @@ pub use self::{
         execute_constructor,
         execute_message,
         execute_message_mut,
+        enter_non_reentrant,
+        leave_non_reentrant,
         AcceptsPayments,
         ConstructorDispatcher,
         EnablesDynamicStorageAllocator,
@@ -51,6 +73,7 @@ pub use self::{
         StaticEnv,
     },
     error::{
+        finalize_dispatch,
         DispatchError,
         DispatchResult,
         DispatchRetCode,
@@ -58,8 +81,11 @@ pub use self::{
     events::{
         BaseEvent,
         EmitEvent,
+        EmitTraitEvent,
     },
     traits::{
+        inherit_selector,
+        selector_id,
         CheckedInkTrait,
         Constructor,
         FnInput,
@@ -69,12 +95,21 @@ pub use self::{
         ImpliesReturn,
         MessageMut,
         MessageRef,
+        TraitMessageSelector,
         True,
     },
 };
 pub use ::static_assertions;
 pub use ink_lang_macro::{
+    addresses,
+    assert_storage_footprint,
+    assert_trait_impl,
+    bench,
     contract,
+    contract_ref,
+    event,
+    mixin,
     test,
     trait_definition,
+    SignPayload,
 };
@@ -0,0 +1,273 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Composition of ink! smart contracts from reusable mixins.
+//!
+//! A mixin defined via [`#[ink::mixin]`](`macro@crate::mixin`) bundles a struct of storage
+//! fields with the ink! messages operating on them, e.g. an escrow or a vesting
+//! schedule. Host contracts embed the mixin as a field of their storage struct
+//! flagged with `#[ink(mixin)]` and thereby dispatch to the messages of the mixin
+//! in addition to their own messages.
+//!
+//! The selectors of mixin messages are namespaced by the name of the mixin struct,
+//! e.g. the selector of `Escrow::deposit` is composed from `Escrow::deposit`, so that
+//! they do not overlap with the selectors of the host contract. If they do anyway,
+//! e.g. due to custom selectors, the host contract fails to compile.
+//!
+//! Mixin messages are subject to the same guards as the messages of the host
+//! contract, i.e. `only_owner`, `only_role`, `when_not_paused`, `throttle` and
+//! `non_reentrant`, as well as to its telemetry and message hooks. The guards
+//! operate on the state of the host contract, so for example `only_owner` mixin
+//! messages deny every call unless the host contract is ownable.
+
+use crate::{
+    deny_payment,
+    enter_non_reentrant,
+    leave_non_reentrant,
+    trace,
+    DispatchError,
+    EnablesDynamicStorageAllocator,
+};
+use core::mem::ManuallyDrop;
+use ink_env::{
+    Environment,
+    ReturnFlags,
+};
+use ink_prelude::vec::Vec;
+use ink_primitives::Key;
+use ink_storage::{
+    alloc,
+    alloc::ContractPhase,
+    traits::{
+        pull_spread_root,
+        push_spread_root,
+        SpreadLayout,
+    },
+};
+
+/// Implemented by the storage structs of ink! mixins.
+///
+/// # Note
+///
+/// This trait is implemented by `#[ink::mixin]` and is not meant to be
+/// implemented manually.
+pub trait Mixin: SpreadLayout {
+    /// The dispatch enum of the ink! messages of the mixin.
+    type Dispatch: MixinDispatch<Self>;
+
+    /// The selectors of all ink! messages of the mixin.
+    const SELECTORS: &'static [[u8; 4]];
+
+    /// Returns the metadata of all ink! messages of the mixin.
+    #[cfg(feature = "std")]
+    fn messages() -> Vec<ink_metadata::MessageSpec>;
+}
+
+/// The decoded call of an ink! message of the mixin `M`.
+#[doc(hidden)]
+pub trait MixinDispatch<M>: Sized {
+    /// Decodes the arguments of the ink! message with the given selector.
+    ///
+    /// Returns `Ok(None)` if the mixin has no ink! message with the selector.
+    fn decode_message<I: scale::Input>(
+        selector: [u8; 4],
+        input: &mut I,
+    ) -> Result<Option<Self>, scale::Error>;
+
+    /// Returns the selector of the ink! message.
+    fn selector(&self) -> [u8; 4];
+
+    /// Returns `true` if the ink! message accepts payments.
+    fn is_payable(&self) -> bool;
+
+    /// Returns `true` if the ink! message mutates the mixin.
+    fn mutates(&self) -> bool;
+
+    /// Returns `true` if the ink! message is guarded against reentrancy.
+    fn is_non_reentrant(&self) -> bool;
+
+    /// Checks the `only_role`, `only_owner`, `when_not_paused` and `throttle`
+    /// guards of the ink! message for the caller.
+    fn check_guards<E: Environment>(&self) -> Result<(), DispatchError>;
+
+    /// Executes the ink! message on the mixin.
+    ///
+    /// Returns the return value if the message has one.
+    fn execute(self, mixin: &mut M) -> Option<MixinOutput>;
+}
+
+/// Executes the decoded call of an ink! message of a mixin embedded in the
+/// contract storage `S`.
+///
+/// The given `field` closure selects the mixin field of the contract storage
+/// while the `before` and `after` closures invoke the message hooks of the
+/// contract storage around the message.
+///
+/// # Note
+///
+/// The guards of the message are checked by the caller via
+/// [`MixinDispatch::check_guards`] before, except for the reentrancy guard
+/// which is handled here.
+#[inline]
+#[doc(hidden)]
+pub fn execute_mixin_message<E, S, M, F, B, A>(
+    enables_dynamic_storage_allocator: EnablesDynamicStorageAllocator,
+    call: <M as Mixin>::Dispatch,
+    field: F,
+    before: B,
+    after: A,
+) -> Result<(), DispatchError>
+where
+    E: Environment,
+    S: SpreadLayout,
+    M: Mixin,
+    F: FnOnce(&mut S) -> &mut M,
+    B: FnOnce(&mut S),
+    A: FnOnce(&mut S),
+{
+    let enables_dynamic_storage_allocator: bool =
+        enables_dynamic_storage_allocator.into();
+    let is_non_reentrant = call.is_non_reentrant();
+    if is_non_reentrant {
+        enter_non_reentrant()?;
+    }
+    if !call.is_payable() {
+        deny_payment::<E>()?;
+    }
+    if enables_dynamic_storage_allocator {
+        alloc::initialize(ContractPhase::Call);
+    }
+    let root_key = Key::from([0x00; 32]);
+    let mut state = ManuallyDrop::new(trace::in_span(trace::STORAGE, "pull", || {
+        pull_spread_root::<S>(&root_key)
+    }));
+    let mutates = call.mutates();
+    // Storage changes of self-calls would be overwritten by the mutating message.
+    ink_env::set_self_calls_denied(mutates);
+    before(&mut state);
+    let output = call.execute(field(&mut state));
+    after(&mut state);
+    ink_env::set_self_calls_denied(false);
+    if mutates {
+        trace::in_span(trace::STORAGE, "push", || {
+            push_spread_root::<S>(&state, &root_key)
+        });
+    }
+    if enables_dynamic_storage_allocator {
+        alloc::finalize();
+    }
+    // The guard has to be released before returning the value since
+    // `return_value` does not return to the dispatcher.
+    if is_non_reentrant {
+        leave_non_reentrant();
+    }
+    if let Some(output) = output {
+        ink_env::return_value(ReturnFlags::default(), &output)
+    }
+    Ok(())
+}
+
+/// Returns the number of selectors that are shared by two ink! messages.
+///
+/// Used by the ink! codegen to reject contracts in which the selectors of
+/// the host contract and its mixins overlap at compile time. This includes
+/// the selectors that the host contract inherits from ink! trait definitions.
+#[doc(hidden)]
+pub const fn count_overlapping_selectors(
+    host: &[[u8; 4]],
+    mixins: &[&[[u8; 4]]],
+) -> usize {
+    let mut overlaps = 0;
+    let mut group = 0;
+    // The host selectors are treated as the group after all mixins.
+    while group <= mixins.len() {
+        let selectors = if group < mixins.len() {
+            mixins[group]
+        } else {
+            host
+        };
+        let mut index = 0;
+        while index < selectors.len() {
+            let selector = u32::from_le_bytes(selectors[index]);
+            let mut other_group = group;
+            while other_group <= mixins.len() {
+                let others = if other_group < mixins.len() {
+                    mixins[other_group]
+                } else {
+                    host
+                };
+                let mut other_index = if other_group == group { index + 1 } else { 0 };
+                while other_index < others.len() {
+                    if u32::from_le_bytes(others[other_index]) == selector {
+                        overlaps += 1;
+                    }
+                    other_index += 1;
+                }
+                other_group += 1;
+            }
+            index += 1;
+        }
+        group += 1;
+    }
+    overlaps
+}
+
+/// The SCALE encoded return value of an ink! mixin message.
+///
+/// Encodes to the return value as it is without a length prefix.
+#[doc(hidden)]
+pub struct MixinOutput(Vec<u8>);
+
+impl MixinOutput {
+    /// Creates the output from the return value of an ink! mixin message.
+    pub fn new<T>(value: &T) -> Self
+    where
+        T: scale::Encode,
+    {
+        Self(value.encode())
+    }
+}
+
+impl scale::Encode for MixinOutput {
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+
+    fn encode_to<T: scale::Output>(&self, dest: &mut T) {
+        dest.write(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_overlapping_selectors_works() {
+        let host = [[0x01; 4], [0x02; 4]];
+        let escrow = [[0x03; 4], [0x04; 4]];
+        let vesting = [[0x05; 4]];
+        assert_eq!(count_overlapping_selectors(&host, &[&escrow, &vesting]), 0);
+        assert_eq!(count_overlapping_selectors(&host, &[&[[0x02; 4]]]), 1);
+        assert_eq!(count_overlapping_selectors(&[], &[&escrow, &escrow]), 2);
+        assert_eq!(count_overlapping_selectors(&[[0x01; 4], [0x01; 4]], &[]), 1);
+    }
+
+    const OVERLAPS: usize = count_overlapping_selectors(&[[0x01; 4]], &[&[[0x01; 4]]]);
+
+    #[test]
+    fn count_overlapping_selectors_is_const() {
+        assert_eq!(OVERLAPS, 1);
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ownership of ink! smart contracts.
+//!
+//! Contracts defined via `#[ink::contract(ownable = true)]` are owned by their
+//! instantiator and provided with generated `owner`, `pending_owner`,
+//! `transfer_ownership`, `accept_ownership` and `renounce_ownership` messages.
+//! ink! messages flagged with `#[ink(message, only_owner)]` can only be called
+//! by the current owner.
+//!
+//! The ownership is transferred in two steps: the owner proposes a new owner
+//! which then has to accept the ownership. This prevents transferring the
+//! ownership to an account that is unable to use it.
+//!
+//! The owner and the proposed owner are stored under the hashed [`owner_key`]
+//! and [`pending_owner_key`] and therefore do not interfere with the storage
+//! layout of the contract.
+
+use crate::DispatchError;
+use ink_env::{
+    hash::{
+        Blake2x256,
+        HashOutput,
+    },
+    Environment,
+};
+use ink_primitives::Key;
+
+/// The preimage of the storage key of the owner.
+const OWNER_KEY_PREIMAGE: &[u8] = b"ink_lang::ownable::owner";
+
+/// The preimage of the storage key of the proposed new owner.
+const PENDING_OWNER_KEY_PREIMAGE: &[u8] = b"ink_lang::ownable::pending";
+
+/// Returns the BLAKE2 256-bit hash of the preimage as storage key.
+fn hashed_key(preimage: &[u8]) -> Key {
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_bytes::<Blake2x256>(preimage, &mut output);
+    Key::from(output)
+}
+
+/// Returns the storage key under which the owner is stored.
+///
+/// This is the BLAKE2 256-bit hash of `ink_lang::ownable::owner`.
+pub fn owner_key() -> Key {
+    hashed_key(OWNER_KEY_PREIMAGE)
+}
+
+/// Returns the storage key under which the proposed new owner is stored.
+///
+/// This is the BLAKE2 256-bit hash of `ink_lang::ownable::pending`.
+pub fn pending_owner_key() -> Key {
+    hashed_key(PENDING_OWNER_KEY_PREIMAGE)
+}
+
+/// Errors that can occur upon transferring the ownership of a contract.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum OwnershipError {
+    /// The caller has not been proposed as the new owner.
+    NotPendingOwner,
+}
+
+/// Reads the account stored under the key if any.
+fn get_account<E>(key: Key) -> Option<E::AccountId>
+where
+    E: Environment,
+{
+    ink_env::get_contract_storage::<E::AccountId>(&key)
+        .ok()
+        .flatten()
+}
+
+/// Stores the account under the key or clears the key for `None`.
+fn set_account<E>(key: Key, account: Option<&E::AccountId>)
+where
+    E: Environment,
+{
+    match account {
+        Some(account) => ink_env::set_contract_storage(&key, account),
+        None => ink_env::clear_contract_storage(&key),
+    }
+}
+
+/// Returns the owner of the contract if any.
+///
+/// Returns `None` if the ownership has been renounced.
+pub fn owner<E>() -> Option<E::AccountId>
+where
+    E: Environment,
+{
+    get_account::<E>(owner_key())
+}
+
+/// Returns the account that has been proposed as the new owner if any.
+pub fn pending_owner<E>() -> Option<E::AccountId>
+where
+    E: Environment,
+{
+    get_account::<E>(pending_owner_key())
+}
+
+/// Proposes the account as the new owner or withdraws the proposal for `None`.
+///
+/// # Note
+///
+/// This does not check whether the caller is the owner.
+pub fn set_pending_owner<E>(account: Option<&E::AccountId>)
+where
+    E: Environment,
+{
+    set_account::<E>(pending_owner_key(), account)
+}
+
+/// Transfers the ownership to the caller if it has been proposed as the new owner.
+///
+/// # Errors
+///
+/// If the caller has not been proposed as the new owner.
+pub fn accept_ownership<E>() -> Result<(), OwnershipError>
+where
+    E: Environment,
+{
+    let caller = ink_env::caller::<E>().expect("could not read the caller");
+    if pending_owner::<E>().as_ref() != Some(&caller) {
+        return Err(OwnershipError::NotPendingOwner)
+    }
+    set_account::<E>(owner_key(), Some(&caller));
+    set_pending_owner::<E>(None);
+    Ok(())
+}
+
+/// Renounces the ownership and withdraws a proposed new owner.
+///
+/// # Note
+///
+/// This does not check whether the caller is the owner.
+pub fn renounce_ownership<E>()
+where
+    E: Environment,
+{
+    set_account::<E>(owner_key(), None);
+    set_pending_owner::<E>(None);
+}
+
+/// Returns an error if the caller is not the owner of the contract.
+///
+/// Used by the ink! codegen before dispatching an ink! message flagged with
+/// `#[ink(message, only_owner)]`.
+#[doc(hidden)]
+pub fn ensure_caller_is_owner<E>() -> Result<(), DispatchError>
+where
+    E: Environment,
+{
+    let caller = ink_env::caller::<E>().map_err(|_| DispatchError::NotOwner)?;
+    if owner::<E>().as_ref() != Some(&caller) {
+        return Err(DispatchError::NotOwner)
+    }
+    Ok(())
+}
+
+/// Makes the caller the owner of the contract.
+///
+/// Used by the ink! codegen upon instantiation of ownable ink! smart contracts.
+#[doc(hidden)]
+pub fn init_owner_to_caller<E>()
+where
+    E: Environment,
+{
+    let caller = ink_env::caller::<E>().expect("could not read the caller");
+    set_account::<E>(owner_key(), Some(&caller))
+}
@@ -0,0 +1,119 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pausing of ink! smart contracts.
+//!
+//! ink! messages flagged with `#[ink(message, when_not_paused)]` can only be
+//! called while the contract is not paused. Contracts using this facility are
+//! provided with a generated `Paused` event as well as generated `pause`,
+//! `unpause` and `paused` messages. The `pause` and `unpause` messages require
+//! the admin role of the [`access_control`](crate::access_control) module.
+//!
+//! The pause flag is stored under the hashed [`paused_key`] so that its
+//! location neither depends on the storage layout of the contract nor changes
+//! with upgrades of the contract code.
+
+use crate::DispatchError;
+use ink_env::hash::{
+    Blake2x256,
+    HashOutput,
+};
+use ink_primitives::Key;
+
+/// The preimage of the storage key of the pause flag.
+const PAUSED_KEY_PREIMAGE: &[u8] = b"ink_lang::pausable::paused";
+
+/// Returns the storage key under which the pause flag is stored.
+///
+/// This is the BLAKE2 256-bit hash of `ink_lang::pausable::paused`.
+pub fn paused_key() -> Key {
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_bytes::<Blake2x256>(PAUSED_KEY_PREIMAGE, &mut output);
+    Key::from(output)
+}
+
+/// Returns `true` if the contract is paused.
+pub fn is_paused() -> bool {
+    matches!(
+        ink_env::get_contract_storage::<bool>(&paused_key()),
+        Ok(Some(true))
+    )
+}
+
+/// Pauses or unpauses the contract.
+///
+/// # Note
+///
+/// This does not check whether the caller is allowed to pause the contract.
+pub fn set_paused(paused: bool) {
+    let key = paused_key();
+    if paused {
+        ink_env::set_contract_storage(&key, &true)
+    } else {
+        ink_env::clear_contract_storage(&key)
+    }
+}
+
+/// Returns an error if the contract is paused.
+///
+/// Used by the ink! codegen before dispatching an ink! message flagged with
+/// `#[ink(message, when_not_paused)]`.
+#[doc(hidden)]
+pub fn ensure_not_paused() -> Result<(), DispatchError> {
+    if is_paused() {
+        return Err(DispatchError::ContractPaused)
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::DefaultEnvironment;
+
+    #[test]
+    fn paused_key_works() {
+        let mut expected = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_bytes::<Blake2x256>(b"ink_lang::pausable::paused", &mut expected);
+        assert_eq!(paused_key(), Key::from(expected));
+        assert_ne!(paused_key(), crate::ownable::owner_key());
+        assert_ne!(
+            crate::ownable::owner_key(),
+            crate::ownable::pending_owner_key()
+        );
+        assert_ne!(paused_key(), crate::telemetry::selectors_key());
+    }
+
+    #[test]
+    fn set_paused_works() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+            assert!(!is_paused());
+            assert!(ensure_not_paused().is_ok());
+            set_paused(true);
+            assert!(is_paused());
+            assert!(matches!(
+                ensure_not_paused(),
+                Err(DispatchError::ContractPaused)
+            ));
+            set_paused(false);
+            assert!(!is_paused());
+            assert_eq!(
+                ink_env::get_contract_storage::<bool>(&paused_key()),
+                Ok(None)
+            );
+            Ok(())
+        })
+        .unwrap()
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compile time information about ink! smart contracts.
+
+/// Implemented by ink! smart contracts to expose the fingerprint of their code.
+///
+/// The fingerprint is the BLAKE2 256-bit hash of the dispatch table of the
+/// contract which consists of the selectors of all constructors and messages
+/// as well as their argument and return types. It is computed at compile time
+/// and embedded into the `ink_code_fingerprint` Wasm custom section as well as
+/// into the metadata of the contract.
+///
+/// # Note
+///
+/// Types are hashed as they are written in the contract source, therefore
+/// renaming a type alias changes the fingerprint even if the ABI stays the same.
+pub trait ContractFingerprint {
+    /// The fingerprint of the contract code.
+    const CODE_FINGERPRINT: [u8; 32];
+}
+
+/// Returns the fingerprint of the code of the ink! smart contract.
+///
+/// A registry contract can compare the fingerprint with the one found in
+/// the Wasm custom section or the metadata of an uploaded contract code.
+pub fn code_fingerprint<C>() -> [u8; 32]
+where
+    C: ContractFingerprint,
+{
+    <C as ContractFingerprint>::CODE_FINGERPRINT
+}
@@ -0,0 +1,343 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic replay of recorded contract executions in the off-chain environment.
+//!
+//! Bug reports of contracts running in production usually come with the
+//! extrinsics that triggered the bug. The [`RecordedCall`] captures the payload
+//! of such an extrinsic, i.e. the SCALE encoded selector and arguments as well as
+//! its caller and transferred value, and [`replay`] runs the recorded calls against
+//! the dispatch of the contract code within the off-chain environment. Together
+//! with [`#[ink::test]`](`crate::test`) this reproduces the bug as a unit test:
+//!
+//! ```ignore
+//! #[ink::test]
+//! fn issue_42_is_fixed() {
+//!     let accounts = ink_env::test::default_accounts::<ink_env::DefaultEnvironment>()
+//!         .expect("cannot get accounts");
+//!     let calls = [
+//!         RecordedCall::instantiate_hex(accounts.alice, 0, "0x9bae9d5e2a000000")
+//!             .expect("invalid recorded payload"),
+//!         RecordedCall::call_hex(accounts.bob, 0, "0x633aa551")
+//!             .expect("invalid recorded payload"),
+//!     ];
+//!     let outcomes = ink_lang::replay::replay::<Flipper>(&calls);
+//!     assert!(outcomes.iter().all(|outcome| !outcome.is_reverted()));
+//! }
+//! ```
+//!
+//! The dispatch of contracts is not compiled for their unit tests unless the
+//! `ink-replay` crate feature of the contract is enabled, so contracts using the
+//! replay have to declare the feature in their `Cargo.toml` and enable it when
+//! running the tests, e.g. via `cargo test --features ink-replay`:
+//!
+//! ```toml
+//! [features]
+//! ink-replay = []
+//! ```
+//!
+//! All executions are dispatched to the current contract account of the
+//! off-chain environment. Executions that revert or trap have their state
+//! changes and emitted events discarded the same way as on-chain.
+//!
+//! # Note
+//!
+//! The transferred value is only reflected by the execution context of the
+//! replayed call and not moved from the caller to the contract account.
+
+pub use ink_env::test::ExecutionOutcome;
+
+use crate::{
+    ContractEnv,
+    DispatchMode,
+    DispatchUsingMode,
+};
+use ink_env::{
+    test::CallData,
+    Environment,
+};
+use ink_prelude::vec::Vec;
+
+/// The recorded payload of a contract instantiation or call extrinsic.
+pub struct RecordedCall<E>
+where
+    E: Environment,
+{
+    /// If the recorded extrinsic instantiated the contract.
+    mode: DispatchMode,
+    /// The account that submitted the extrinsic.
+    caller: E::AccountId,
+    /// The value transferred by the extrinsic.
+    value: E::Balance,
+    /// The SCALE encoded selector and arguments.
+    call_data: CallData,
+}
+
+impl<E> RecordedCall<E>
+where
+    E: Environment,
+{
+    /// Creates a recorded contract call from its SCALE encoded selector and arguments.
+    ///
+    /// Returns `None` if the input is too short to contain a selector.
+    pub fn call(caller: E::AccountId, value: E::Balance, input: &[u8]) -> Option<Self> {
+        Self::new(DispatchMode::Call, caller, value, input)
+    }
+
+    /// Creates a recorded contract instantiation from its SCALE encoded selector
+    /// and arguments.
+    ///
+    /// Returns `None` if the input is too short to contain a selector.
+    pub fn instantiate(
+        caller: E::AccountId,
+        value: E::Balance,
+        input: &[u8],
+    ) -> Option<Self> {
+        Self::new(DispatchMode::Instantiate, caller, value, input)
+    }
+
+    /// Creates a recorded contract call from its hex encoded selector and arguments
+    /// as displayed by block explorers, with an optional `0x` prefix.
+    ///
+    /// Returns `None` if the input is not valid hex or too short to contain a selector.
+    pub fn call_hex(
+        caller: E::AccountId,
+        value: E::Balance,
+        input: &str,
+    ) -> Option<Self> {
+        Self::call(caller, value, &decode_hex(input)?)
+    }
+
+    /// Creates a recorded contract instantiation from its hex encoded selector
+    /// and arguments as displayed by block explorers, with an optional `0x` prefix.
+    ///
+    /// Returns `None` if the input is not valid hex or too short to contain a selector.
+    pub fn instantiate_hex(
+        caller: E::AccountId,
+        value: E::Balance,
+        input: &str,
+    ) -> Option<Self> {
+        Self::instantiate(caller, value, &decode_hex(input)?)
+    }
+
+    fn new(
+        mode: DispatchMode,
+        caller: E::AccountId,
+        value: E::Balance,
+        input: &[u8],
+    ) -> Option<Self> {
+        let call_data = <CallData as scale::Decode>::decode(&mut &input[..]).ok()?;
+        Some(Self {
+            mode,
+            caller,
+            value,
+            call_data,
+        })
+    }
+
+    /// Returns the selector of the recorded extrinsic.
+    pub fn selector(&self) -> [u8; 4] {
+        self.call_data.selector().to_bytes()
+    }
+
+    /// Returns the account that submitted the recorded extrinsic.
+    pub fn caller(&self) -> &E::AccountId {
+        &self.caller
+    }
+
+    /// Returns the value transferred by the recorded extrinsic.
+    pub fn value(&self) -> &E::Balance {
+        &self.value
+    }
+}
+
+/// Replays the recorded executions in order against the dispatch of contract `C`.
+///
+/// Returns the outcome of every replayed execution.
+///
+/// # Panics
+///
+/// If there is no current contract account in the off-chain environment,
+/// e.g. if not run within an [`#[ink::test]`](`crate::test`).
+pub fn replay<C>(calls: &[RecordedCall<<C as ContractEnv>::Env>]) -> Vec<ExecutionOutcome>
+where
+    C: DispatchUsingMode + ContractEnv,
+{
+    calls.iter().map(replay_call::<C>).collect()
+}
+
+/// Replays a single recorded execution against the dispatch of contract `C`.
+///
+/// # Panics
+///
+/// If there is no current contract account in the off-chain environment,
+/// e.g. if not run within an [`#[ink::test]`](`crate::test`).
+pub fn replay_call<C>(call: &RecordedCall<<C as ContractEnv>::Env>) -> ExecutionOutcome
+where
+    C: DispatchUsingMode + ContractEnv,
+{
+    type Env<C> = <C as ContractEnv>::Env;
+    let callee = ink_env::test::get_current_contract_account_id::<Env<C>>()
+        .expect("encountered missing contract account");
+    let gas_limit =
+        ink_env::gas_left::<Env<C>>().expect("encountered missing execution context");
+    ink_env::test::push_execution_context::<Env<C>>(
+        call.caller.clone(),
+        callee,
+        gas_limit,
+        call.value,
+        call.call_data.clone(),
+    );
+    let mode = call.mode;
+    let outcome = ink_env::test::execute_contract(|| {
+        if mode == DispatchMode::Call
+            && <C as DispatchUsingMode>::ALL_MESSAGES_DENY_PAYMENT
+        {
+            crate::deny_payment::<Env<C>>().expect(
+                "caller transferred value even though all ink! message deny payments",
+            );
+        }
+        crate::finalize_dispatch(<C as DispatchUsingMode>::dispatch_using_mode(mode));
+    });
+    ink_env::test::pop_execution_context();
+    outcome
+}
+
+/// Decodes the hex string with an optional `0x` prefix.
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() % 2 != 0 {
+        return None
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DispatchError;
+    use ink_env::{
+        AccountId,
+        DefaultEnvironment,
+    };
+    use ink_primitives::Key;
+
+    const COUNT_KEY: [u8; 32] = [0x01; 32];
+    const CALLER_KEY: [u8; 32] = [0x02; 32];
+
+    /// A contract counting the values it is instantiated and called with.
+    struct Counter;
+
+    impl ContractEnv for Counter {
+        type Env = DefaultEnvironment;
+    }
+
+    impl DispatchUsingMode for Counter {
+        const ALL_MESSAGES_DENY_PAYMENT: bool = true;
+
+        fn dispatch_using_mode(mode: DispatchMode) -> Result<(), DispatchError> {
+            let (selector, value) = ink_env::decode_input::<([u8; 4], u32)>()
+                .map_err(|_| DispatchError::CouldNotReadInput)?;
+            let count_key = Key::from(COUNT_KEY);
+            let count = match (mode, selector) {
+                (DispatchMode::Instantiate, [1, 1, 1, 1]) => value,
+                (DispatchMode::Call, [2, 2, 2, 2]) => {
+                    let count = ink_env::get_contract_storage::<u32>(&count_key)
+                        .expect("encountered invalid count")
+                        .unwrap_or_default();
+                    count + value
+                }
+                (DispatchMode::Instantiate, _) => {
+                    return Err(DispatchError::UnknownInstantiateSelector)
+                }
+                (DispatchMode::Call, _) => {
+                    return Err(DispatchError::UnknownCallSelector)
+                }
+            };
+            let caller = ink_env::caller::<DefaultEnvironment>()
+                .expect("encountered missing caller");
+            ink_env::set_contract_storage(&count_key, &count);
+            ink_env::set_contract_storage(&Key::from(CALLER_KEY), &caller);
+            Ok(())
+        }
+    }
+
+    /// Returns the SCALE encoded selector and argument of a recorded extrinsic.
+    fn input(selector: [u8; 4], value: u32) -> Vec<u8> {
+        scale::Encode::encode(&(selector, value))
+    }
+
+    #[test]
+    fn recorded_call_works() {
+        let call = <RecordedCall<DefaultEnvironment>>::call_hex(
+            AccountId::from([0x01; 32]),
+            5,
+            "0x0202020202000000",
+        )
+        .expect("encountered invalid recorded payload");
+        assert_eq!(call.selector(), [0x02; 4]);
+        assert_eq!(call.caller(), &AccountId::from([0x01; 32]));
+        assert_eq!(call.value(), &5);
+        let caller = AccountId::from([0x01; 32]);
+        let call_hex = <RecordedCall<DefaultEnvironment>>::call_hex;
+        assert!(call_hex(caller, 0, "0x020202").is_none());
+        assert!(call_hex(caller, 0, "0x0202020").is_none());
+        assert!(call_hex(caller, 0, "0x0202020g").is_none());
+    }
+
+    #[test]
+    fn replay_works() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+            let calls = vec![
+                RecordedCall::instantiate(accounts.alice, 0, &input([0x01; 4], 40)),
+                RecordedCall::call_hex(accounts.bob, 0, "0x0202020202000000"),
+                // Unknown selectors revert without changing the state.
+                RecordedCall::call(accounts.charlie, 0, &input([0x03; 4], 1)),
+                // Payments are denied since all messages deny them.
+                RecordedCall::call(accounts.django, 10, &input([0x02; 4], 1)),
+            ]
+            .into_iter()
+            .map(|call| call.expect("encountered invalid recorded payload"))
+            .collect::<Vec<_>>();
+            let outcomes = replay::<Counter>(&calls);
+            assert_eq!(
+                outcomes
+                    .iter()
+                    .map(ExecutionOutcome::is_reverted)
+                    .collect::<Vec<_>>(),
+                vec![false, false, true, true]
+            );
+            assert_eq!(
+                ink_env::get_contract_storage::<u32>(&Key::from(COUNT_KEY)),
+                Ok(Some(42))
+            );
+            assert_eq!(
+                ink_env::get_contract_storage::<AccountId>(&Key::from(CALLER_KEY)),
+                Ok(Some(accounts.bob))
+            );
+            // Replayed executions continue from the state left by earlier ones.
+            let outcome = replay_call::<Counter>(&calls[1]);
+            assert!(!outcome.is_reverted());
+            assert_eq!(
+                ink_env::get_contract_storage::<u32>(&Key::from(COUNT_KEY)),
+                Ok(Some(44))
+            );
+            Ok(())
+        })
+        .unwrap()
+    }
+}
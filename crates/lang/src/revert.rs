@@ -0,0 +1,47 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Reverts the execution of the contract with a contract defined error code.
+///
+/// Takes the `u32` error code and optionally a SCALE encodable payload that is
+/// returned to the caller within an `ink_env::call::RevertEnvelope`. Callers
+/// decode the envelope via `ink_env::Error::revert_envelope` and frontends are
+/// provided with a machine readable error code beyond the indices of error enums.
+///
+/// # Note
+///
+/// The macro stops the execution of the contract immediately and reverts all
+/// of its state changes.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// /// Fails with code `1` and both amounts if the balance is insufficient.
+/// fn ensure_sufficient_balance(balance: u128, amount: u128) {
+///     if balance < amount {
+///         ink::revert!(1, (balance, amount));
+///     }
+/// }
+/// # let _ = ensure_sufficient_balance;
+/// ```
+#[macro_export]
+macro_rules! revert {
+    ( $code:expr $(,)? ) => {
+        $crate::revert!($code, ())
+    };
+    ( $code:expr, $data:expr $(,)? ) => {
+        ::ink_env::revert_with_code($code, &$data)
+    };
+}
@@ -0,0 +1,137 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-message execution counters of ink! smart contracts.
+//!
+//! Contracts defined via `#[ink::contract(telemetry = true)]` count how often
+//! each of their `&mut self` ink! messages has been dispatched and are provided
+//! with a generated `telemetry` message returning the counters. This allows to
+//! measure the real-world usage of messages, e.g. before deprecating them.
+//!
+//! # Costs
+//!
+//! Every counted dispatch reads and writes the counter of the message and the
+//! first dispatch of a message additionally writes the list of counted selectors.
+//! `&self` messages are not counted since otherwise every read-only call would
+//! write to the contract storage and pay for it.
+//!
+//! Only dispatches of successful contract executions are counted since the
+//! counters are reverted together with all other state changes otherwise.
+//! Off-chain queries via RPC dry-runs are not counted either.
+//!
+//! The counters are stored under hashed keys outside of the contract storage struct
+//! and therefore do not interfere with its storage layout.
+
+use ink_env::hash::{
+    Blake2x256,
+    HashOutput,
+};
+use ink_prelude::vec::Vec;
+use ink_primitives::Key;
+
+/// The prefix of the storage keys of the dispatch counters.
+const CALLS_KEY_PREFIX: &[u8] = b"ink_lang::telemetry::calls";
+
+/// The preimage of the storage key of the selectors of all counted messages.
+const SELECTORS_KEY_PREIMAGE: &[u8] = b"ink_lang::telemetry::selectors";
+
+/// The dispatch counters of the ink! messages identified by their selectors.
+pub type CallCounts = Vec<([u8; 4], u64)>;
+
+/// Returns the storage key under which the counter of the message is stored.
+fn calls_key(selector: [u8; 4]) -> Key {
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_encoded::<Blake2x256, _>(&(CALLS_KEY_PREFIX, selector), &mut output);
+    Key::from(output)
+}
+
+/// Returns the storage key under which the selectors of all counted messages
+/// are stored.
+///
+/// This is the BLAKE2 256-bit hash of `ink_lang::telemetry::selectors`.
+pub fn selectors_key() -> Key {
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_bytes::<Blake2x256>(SELECTORS_KEY_PREIMAGE, &mut output);
+    Key::from(output)
+}
+
+/// Returns the selectors of all messages that have been dispatched at least once.
+fn selectors() -> Vec<[u8; 4]> {
+    ink_env::get_contract_storage::<Vec<[u8; 4]>>(&selectors_key())
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Returns how often the message with the selector has been dispatched.
+pub fn call_count(selector: [u8; 4]) -> u64 {
+    ink_env::get_contract_storage::<u64>(&calls_key(selector))
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Returns the dispatch counters of all messages that have been dispatched.
+///
+/// The counters are ordered by the first dispatch of their messages.
+pub fn call_counts() -> CallCounts {
+    selectors()
+        .into_iter()
+        .map(|selector| (selector, call_count(selector)))
+        .collect()
+}
+
+/// Counts a dispatch of the message with the selector.
+///
+/// Used by the ink! codegen before dispatching a `&mut self` ink! message of a
+/// contract defined via `#[ink::contract(telemetry = true)]`.
+#[doc(hidden)]
+pub fn record_call(selector: [u8; 4]) {
+    let calls = call_count(selector);
+    if calls == 0 {
+        let mut selectors = selectors();
+        selectors.push(selector);
+        ink_env::set_contract_storage(&selectors_key(), &selectors);
+    }
+    ink_env::set_contract_storage(&calls_key(selector), &calls.saturating_add(1));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::DefaultEnvironment;
+
+    #[test]
+    fn record_call_works() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+            assert_eq!(call_counts(), vec![]);
+            record_call([0x02; 4]);
+            record_call([0x01; 4]);
+            record_call([0x02; 4]);
+            assert_eq!(call_count([0x01; 4]), 1);
+            assert_eq!(call_count([0x02; 4]), 2);
+            assert_eq!(call_count([0x03; 4]), 0);
+            // The counters are ordered by the first dispatch of their messages.
+            assert_eq!(call_counts(), vec![([0x02; 4], 2), ([0x01; 4], 1)]);
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn keys_are_distinct() {
+        assert_ne!(calls_key([0x00; 4]), calls_key([0x01; 4]));
+        assert_ne!(calls_key([0x00; 4]), selectors_key());
+    }
+}
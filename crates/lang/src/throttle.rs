@@ -0,0 +1,133 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rate limiting of ink! messages.
+//!
+//! ink! messages flagged with `#[ink(message, throttle = "N/block")]` or
+//! `#[ink(message, throttle = "N/M blocks")]` can only be called `N` times by
+//! every caller within a period of `1` or `M` blocks respectively. Further calls
+//! are rejected with [`DispatchError::Throttled`] until the period has elapsed.
+//!
+//! The same facility is available to contracts in the form of [`Throttle`],
+//! e.g. in order to rate limit only some code paths of a message.
+//!
+//! Call counts are stored under hashed keys outside of the contract storage struct
+//! and therefore do not interfere with its storage layout.
+
+use crate::DispatchError;
+use ink_env::{
+    hash::{
+        Blake2x256,
+        HashOutput,
+    },
+    Environment,
+};
+use ink_primitives::Key;
+
+/// The prefix of the storage keys of the call counts of throttled callers.
+const THROTTLE_KEY_PREFIX: &[u8] = b"ink_lang::throttle::calls";
+
+/// Limits how often every account is allowed to pass within a number of blocks.
+///
+/// # Example
+///
+/// ```
+/// use ink_lang::throttle::Throttle;
+///
+/// /// Every account may claim from the faucet once every 100 blocks.
+/// const FAUCET: Throttle = Throttle::new(b"faucet", 1, 100);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Throttle {
+    /// The identifier that separates the call counts of different throttles.
+    id: &'static [u8],
+    /// The number of calls allowed within the period.
+    calls: u32,
+    /// The length of the period in blocks.
+    blocks: u32,
+}
+
+impl Throttle {
+    /// Creates a throttle that allows `calls` calls per account within `blocks` blocks.
+    ///
+    /// # Note
+    ///
+    /// Throttles with the same `id` share their call counts.
+    /// A throttle allowing zero calls or using zero blocks rejects all calls.
+    pub const fn new(id: &'static [u8], calls: u32, blocks: u32) -> Self {
+        Self { id, calls, blocks }
+    }
+
+    /// Returns the storage key under which the call count of the account is stored.
+    fn calls_key<E>(&self, account: &E::AccountId) -> Key
+    where
+        E: Environment,
+    {
+        let mut output = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_encoded::<Blake2x256, _>(
+            &(THROTTLE_KEY_PREFIX, self.id, account),
+            &mut output,
+        );
+        Key::from(output)
+    }
+
+    /// Records a call of the account in the current block.
+    ///
+    /// Returns `false` and records nothing if the account has already used up
+    /// all of its calls within the current period.
+    ///
+    /// # Note
+    ///
+    /// A period starts with the first recorded call of the account and lasts
+    /// for the configured number of blocks.
+    pub fn try_acquire<E>(&self, account: &E::AccountId) -> bool
+    where
+        E: Environment,
+    {
+        if self.calls == 0 || self.blocks == 0 {
+            return false
+        }
+        let key = self.calls_key::<E>(account);
+        let now = ink_env::block_number::<E>().expect("could not read the block number");
+        let period = E::BlockNumber::from(self.blocks);
+        let (start, calls) =
+            match ink_env::get_contract_storage::<(E::BlockNumber, u32)>(&key) {
+                Ok(Some((start, calls))) if now.saturating_sub(start) < period => {
+                    (start, calls)
+                }
+                _ => (now, 0),
+            };
+        if calls >= self.calls {
+            return false
+        }
+        ink_env::set_contract_storage(&key, &(start, calls + 1));
+        true
+    }
+}
+
+/// Returns an error if the caller has used up all calls of the throttle.
+///
+/// Used by the ink! codegen before dispatching an ink! message flagged with
+/// `#[ink(message, throttle = "N/block")]`.
+#[doc(hidden)]
+pub fn ensure_caller_not_throttled<E>(throttle: Throttle) -> Result<(), DispatchError>
+where
+    E: Environment,
+{
+    let caller = ink_env::caller::<E>().map_err(|_| DispatchError::Throttled)?;
+    if !throttle.try_acquire::<E>(&caller) {
+        return Err(DispatchError::Throttled)
+    }
+    Ok(())
+}
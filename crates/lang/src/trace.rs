@@ -0,0 +1,60 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Structured tracing of the code generated for ink! smart contracts.
+//!
+//! With the `trace-codegen` crate feature enabled the generated code enters a
+//! [`tracing`](https://docs.rs/tracing) span around the dispatch of every ink!
+//! constructor and message, around every pull and push of the contract
+//! storage and around every cross-contract call issued through the generated
+//! call forwarders. Installing a `tracing` subscriber in a test then yields a
+//! span tree of the contract execution which helps to diagnose failures of
+//! complex off-chain tests.
+//!
+//! All spans are entered at the `TRACE` level under the `ink` name and carry
+//! a `kind` field as well as a `target` field, e.g. `kind = "dispatch"` and
+//! `target = "Flipper::flip"`.
+//!
+//! Without the `trace-codegen` crate feature the spans compile down to plain
+//! calls of the traced closures. The feature requires the `std` crate feature
+//! and therefore is usable off-chain only.
+
+/// The kind of the span entered around the dispatch of constructors and messages.
+pub const DISPATCH: &str = "dispatch";
+
+/// The kind of the span entered around storage pulls and pushes.
+pub const STORAGE: &str = "storage";
+
+/// The kind of the span entered around cross-contract calls.
+pub const CALL: &str = "call";
+
+/// Executes `f` within a span of the given kind and target.
+#[cfg(feature = "trace-codegen")]
+#[inline]
+pub fn in_span<F, R>(kind: &'static str, target: &str, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    tracing::trace_span!("ink", kind = kind, target = target).in_scope(f)
+}
+
+/// Executes `f` without any span since tracing is disabled.
+#[cfg(not(feature = "trace-codegen"))]
+#[inline(always)]
+pub fn in_span<F, R>(_kind: &'static str, _target: &str, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    f()
+}
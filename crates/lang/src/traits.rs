@@ -31,6 +31,40 @@ use ink_storage::traits::SpreadLayout;
 #[doc(hidden)]
 pub unsafe trait CheckedInkTrait<T> {}
 
+/// Implemented by ink! smart contracts for every ink! message of the ink! trait
+/// definitions they implement.
+///
+/// The `TraitId` parameter refers to the identifier of the trait implementation
+/// block and the `MessageId` parameter identifies the ink! message within it.
+/// The associated `Selector` type refers to the selector the ink! smart contract
+/// dispatches the ink! message with.
+///
+/// Used by `ink::assert_trait_impl!` to check that the selectors of an ink! smart
+/// contract match the selectors of the ink! trait definition.
+#[doc(hidden)]
+pub trait TraitMessageSelector<TraitId, MessageId> {
+    /// The selector of the ink! message encoded as `[(); N]` type.
+    type Selector;
+}
+
+/// Returns the selector an ink! smart contract dispatches an ink! trait message with.
+///
+/// This is the selector pinned by the ink! trait definition if any and
+/// the selector composed by the ink! smart contract otherwise.
+#[doc(hidden)]
+pub const fn inherit_selector(pinned: Option<[u8; 4]>, composed: [u8; 4]) -> [u8; 4] {
+    match pinned {
+        Some(pinned) => pinned,
+        None => composed,
+    }
+}
+
+/// Returns the unique identifier of the selector used to encode it as `[(); N]` type.
+#[doc(hidden)]
+pub const fn selector_id(selector: [u8; 4]) -> usize {
+    u32::from_le_bytes(selector) as usize
+}
+
 /// Trait used by `#[ink::trait_definition]` to ensure that the associated
 /// return type for each trait message is correct.
 #[doc(hidden)]
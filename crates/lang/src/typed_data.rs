@@ -0,0 +1,354 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed structured data signing for ink! smart contracts.
+//!
+//! Similar to EIP-712 the digest of a signed payload commits to the type of the
+//! payload as well as to a domain that identifies the contract. This prevents
+//! signatures from being replayed on other contracts or for other payload types,
+//! e.g. for meta-transactions that are relayed to a contract on behalf of the signer.
+//!
+//! The digest is computed as:
+//!
+//! ```text
+//! H(0x19 0x01 ++ domain_separator ++ H(H(TYPE_STRING) ++ SCALE(payload)))
+//! domain_separator = H(H(DOMAIN_TYPE_STRING) ++ SCALE(name, version, contract))
+//! ```
+//!
+//! where `H` is the chosen 256-bit crypto hash.
+//! Payloads implement [`SignPayload`] usually by `#[derive(ink::SignPayload)]`.
+//!
+//! # Verification
+//!
+//! The contracts pallet does not provide host functions to verify signatures.
+//! Therefore signatures over the digest are verified by an implementation of
+//! [`VerifySignature`] for the signature scheme of the signer, e.g. forwarding
+//! to a chain extension of the runtime, via [`TypedData::verify`].
+
+use core::marker::PhantomData;
+use ink_env::{
+    hash::{
+        Blake2x256,
+        CryptoHash,
+        HashOutput,
+    },
+    Environment,
+};
+
+/// The type string of the [`Domain`].
+pub const DOMAIN_TYPE_STRING: &str = "Domain(name:str,version:u32,contract:AccountId)";
+
+/// The prefix of all typed structured data digests.
+const DIGEST_PREFIX: [u8; 2] = [0x19, 0x01];
+
+/// Errors that can occur upon verifying a signed payload.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum SignatureError {
+    /// The signature has not been created by the signer over the digest.
+    InvalidSignature,
+}
+
+/// Verifies signatures over the digests of typed structured data.
+///
+/// Implemented for the signature schemes available to the contract, e.g. by
+/// forwarding the verification to a chain extension of the runtime.
+pub trait VerifySignature {
+    /// The environment of the contract verifying the signature.
+    type Env: Environment;
+    /// The signature of the signature scheme.
+    type Signature;
+
+    /// Returns `true` if the signature over the digest has been created by the signer.
+    fn verify(
+        digest: &[u8; 32],
+        signature: &Self::Signature,
+        signer: &<Self::Env as Environment>::AccountId,
+    ) -> bool;
+}
+
+/// Payloads that can be signed as typed structured data.
+///
+/// # Example
+///
+/// ```
+/// # use ink_lang as ink;
+/// use ink_lang::typed_data::SignPayload as _;
+///
+/// #[derive(scale::Encode, ink::SignPayload)]
+/// struct Transfer {
+///     to: [u8; 32],
+///     value: u128,
+///     nonce: u64,
+/// }
+///
+/// assert_eq!(Transfer::TYPE_STRING, "Transfer(to:[u8;32],value:u128,nonce:u64)");
+/// ```
+pub trait SignPayload: scale::Encode {
+    /// The name of the payload type followed by the names and types of its fields.
+    const TYPE_STRING: &'static str;
+}
+
+/// The domain that identifies the contract verifying signed payloads.
+pub struct Domain<'a, E>
+where
+    E: Environment,
+{
+    /// The human readable name of the signing domain, e.g. the name of the contract.
+    name: &'a str,
+    /// The version of the signing domain.
+    ///
+    /// Incrementing the version invalidates all signatures of prior versions.
+    version: u32,
+    /// The account of the verifying contract.
+    contract: E::AccountId,
+}
+
+impl<'a, E> Domain<'a, E>
+where
+    E: Environment,
+{
+    /// Creates a new signing domain for the given contract account.
+    pub fn new(name: &'a str, version: u32, contract: E::AccountId) -> Self {
+        Self {
+            name,
+            version,
+            contract,
+        }
+    }
+
+    /// Creates a new signing domain for the executed contract.
+    pub fn for_executed_contract(name: &'a str, version: u32) -> Self {
+        let contract =
+            ink_env::account_id::<E>().expect("could not read the contract account");
+        Self::new(name, version, contract)
+    }
+}
+
+/// Computes the digests of typed structured data for a single domain.
+///
+/// By default the BLAKE2 256-bit hash is used.
+/// Use e.g. `TypedData<Keccak256>` for signers that expect KECCAK digests.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TypedData<H = Blake2x256> {
+    /// The hash of the domain.
+    domain_separator: [u8; 32],
+    hasher: PhantomData<fn() -> H>,
+}
+
+impl<H> TypedData<H>
+where
+    H: CryptoHash + HashOutput<Type = [u8; 32]>,
+{
+    /// Creates the encoder for typed structured data of the given domain.
+    pub fn new<E>(domain: &Domain<E>) -> Self
+    where
+        E: Environment,
+    {
+        let domain_separator = hash_encoded::<H, _>(&(
+            hash_bytes::<H>(DOMAIN_TYPE_STRING.as_bytes()),
+            domain.name,
+            domain.version,
+            &domain.contract,
+        ));
+        Self {
+            domain_separator,
+            hasher: Default::default(),
+        }
+    }
+
+    /// Returns the hash of the domain.
+    pub fn domain_separator(&self) -> &[u8; 32] {
+        &self.domain_separator
+    }
+
+    /// Returns the hash of the payload including its type.
+    pub fn hash_payload<P>(&self, payload: &P) -> [u8; 32]
+    where
+        P: SignPayload,
+    {
+        hash_encoded::<H, _>(&(hash_bytes::<H>(P::TYPE_STRING.as_bytes()), payload))
+    }
+
+    /// Returns the digest of the payload that is to be signed.
+    pub fn digest<P>(&self, payload: &P) -> [u8; 32]
+    where
+        P: SignPayload,
+    {
+        hash_encoded::<H, _>(&(
+            DIGEST_PREFIX,
+            self.domain_separator,
+            self.hash_payload(payload),
+        ))
+    }
+
+    /// Verifies that the payload has been signed by the signer within the domain.
+    ///
+    /// # Errors
+    ///
+    /// If the signature has not been created by the signer over the digest of
+    /// the payload.
+    pub fn verify<V, P>(
+        &self,
+        payload: &P,
+        signature: &V::Signature,
+        signer: &<V::Env as Environment>::AccountId,
+    ) -> Result<(), SignatureError>
+    where
+        V: VerifySignature,
+        P: SignPayload,
+    {
+        if !V::verify(&self.digest(payload), signature, signer) {
+            return Err(SignatureError::InvalidSignature)
+        }
+        Ok(())
+    }
+}
+
+/// Returns the hash of the given bytes.
+fn hash_bytes<H>(input: &[u8]) -> [u8; 32]
+where
+    H: CryptoHash + HashOutput<Type = [u8; 32]>,
+{
+    let mut output = [0x00; 32];
+    ink_env::hash_bytes::<H>(input, &mut output);
+    output
+}
+
+/// Returns the hash of the SCALE encoding of the given value.
+fn hash_encoded<H, T>(input: &T) -> [u8; 32]
+where
+    H: CryptoHash + HashOutput<Type = [u8; 32]>,
+    T: scale::Encode,
+{
+    let mut output = [0x00; 32];
+    ink_env::hash_encoded::<H, T>(input, &mut output);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::{
+        hash::Keccak256,
+        AccountId,
+        DefaultEnvironment,
+    };
+
+    #[derive(scale::Encode)]
+    struct Transfer {
+        to: [u8; 32],
+        value: u128,
+        nonce: u64,
+    }
+
+    impl SignPayload for Transfer {
+        const TYPE_STRING: &'static str = "Transfer(to:[u8;32],value:u128,nonce:u64)";
+    }
+
+    /// Signs digests by hashing them together with the account of the signer.
+    enum MockScheme {}
+
+    impl MockScheme {
+        fn sign(digest: &[u8; 32], signer: &AccountId) -> [u8; 32] {
+            hash_encoded::<Blake2x256, _>(&(signer, digest))
+        }
+    }
+
+    impl VerifySignature for MockScheme {
+        type Env = DefaultEnvironment;
+        type Signature = [u8; 32];
+
+        fn verify(digest: &[u8; 32], signature: &[u8; 32], signer: &AccountId) -> bool {
+            &Self::sign(digest, signer) == signature
+        }
+    }
+
+    fn domain() -> Domain<'static, DefaultEnvironment> {
+        Domain::new("Token", 1, AccountId::from([0x01; 32]))
+    }
+
+    fn transfer(value: u128) -> Transfer {
+        Transfer {
+            to: [0x02; 32],
+            value,
+            nonce: 7,
+        }
+    }
+
+    #[test]
+    fn known_answers_work() {
+        let typed_data = TypedData::<Blake2x256>::new(&domain());
+        assert_eq!(
+            typed_data.domain_separator(),
+            &[
+                0x83, 0x31, 0x40, 0x1A, 0x52, 0x5E, 0x2A, 0x51, 0x29, 0x2B, 0xCF, 0x3E,
+                0x1E, 0xF6, 0xA7, 0xC0, 0x31, 0x7C, 0x9E, 0xF0, 0xBE, 0x80, 0xD9, 0x51,
+                0x0E, 0x64, 0x9E, 0x5A, 0xC8, 0x96, 0x3B, 0x60,
+            ]
+        );
+        assert_eq!(
+            typed_data.digest(&transfer(1000)),
+            [
+                0xE0, 0x20, 0x37, 0xFE, 0x24, 0xD7, 0xF9, 0x66, 0x53, 0x25, 0xFD, 0x9F,
+                0xA6, 0x44, 0x9D, 0x34, 0x43, 0xA5, 0x2C, 0xE1, 0x69, 0x4C, 0x2C, 0xAA,
+                0x1A, 0xA1, 0x47, 0xF5, 0x83, 0x42, 0x41, 0x79,
+            ]
+        );
+    }
+
+    #[test]
+    fn digest_commits_to_domain_and_hasher() {
+        let payload = transfer(1000);
+        let digest = TypedData::<Blake2x256>::new(&domain()).digest(&payload);
+        let other_version = Domain::new("Token", 2, AccountId::from([0x01; 32]));
+        let other_contract = Domain::new("Token", 1, AccountId::from([0x03; 32]));
+        assert_ne!(
+            TypedData::<Blake2x256>::new(&other_version).digest(&payload),
+            digest
+        );
+        assert_ne!(
+            TypedData::<Blake2x256>::new(&other_contract).digest(&payload),
+            digest
+        );
+        assert_ne!(
+            TypedData::<Keccak256>::new(&domain()).digest(&payload),
+            digest
+        );
+    }
+
+    #[test]
+    fn verify_works() {
+        let typed_data = TypedData::<Blake2x256>::new(&domain());
+        let signer = AccountId::from([0x04; 32]);
+        let signature = MockScheme::sign(&typed_data.digest(&transfer(1000)), &signer);
+        assert_eq!(
+            typed_data.verify::<MockScheme, _>(&transfer(1000), &signature, &signer),
+            Ok(())
+        );
+        // Tampered payloads and other signers are rejected.
+        assert_eq!(
+            typed_data.verify::<MockScheme, _>(&transfer(1001), &signature, &signer),
+            Err(SignatureError::InvalidSignature)
+        );
+        assert_eq!(
+            typed_data.verify::<MockScheme, _>(
+                &transfer(1000),
+                &signature,
+                &AccountId::from([0x05; 32])
+            ),
+            Err(SignatureError::InvalidSignature)
+        );
+    }
+}
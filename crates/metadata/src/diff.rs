@@ -0,0 +1,251 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stable message signature hashes and ABI diffing of ink! projects.
+//!
+//! The type identifiers of the type registry depend on the order in which
+//! types have been registered and therefore are not stable across builds.
+//! For this reason the signature of a message is hashed with all of its
+//! types fully resolved from the type registry.
+
+use crate::{
+    utils,
+    InkProject,
+    SignatureHash,
+};
+use serde_json::{
+    json,
+    Value,
+};
+use std::collections::BTreeMap;
+
+/// The differences between the messages of two ink! projects.
+///
+/// Messages are identified by their name including an optional trait prefix.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MessagesDiff {
+    /// Messages that only exist in the new project.
+    added: Vec<String>,
+    /// Messages that only exist in the old project.
+    removed: Vec<String>,
+    /// Messages with a different signature in the new project.
+    changed: Vec<String>,
+}
+
+impl MessagesDiff {
+    /// Returns the names of the messages that have been added.
+    pub fn added(&self) -> &[String] {
+        &self.added
+    }
+
+    /// Returns the names of the messages that have been removed.
+    pub fn removed(&self) -> &[String] {
+        &self.removed
+    }
+
+    /// Returns the names of the messages whose signature has changed.
+    pub fn changed(&self) -> &[String] {
+        &self.changed
+    }
+
+    /// Returns `true` if both projects have the same messages.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Returns `true` if callers of the old project can still call the new one.
+    ///
+    /// This is the case if messages have been added at most.
+    pub fn is_compatible(&self) -> bool {
+        self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl InkProject {
+    /// Computes the message differences from `self` to the `other` project.
+    ///
+    /// Signature hashes are recomputed so that projects decoded from metadata
+    /// without signature hashes can be compared as well.
+    pub fn diff(&self, other: &InkProject) -> MessagesDiff {
+        let old = named_signature_hashes(self);
+        let new = named_signature_hashes(other);
+        let mut diff = MessagesDiff::default();
+        for (name, hash) in &new {
+            match old.get(name) {
+                None => diff.added.push(name.clone()),
+                Some(old_hash) if old_hash != hash => diff.changed.push(name.clone()),
+                Some(_) => (),
+            }
+        }
+        diff.removed = old
+            .keys()
+            .filter(|name| !new.contains_key(*name))
+            .cloned()
+            .collect();
+        diff
+    }
+}
+
+/// Stores the signature hash of all messages of the project.
+pub(crate) fn hash_signatures(project: &mut InkProject) {
+    let hashes = signature_hashes(project);
+    for (message, hash) in project.spec.messages_mut().iter_mut().zip(hashes) {
+        message.set_signature_hash(hash);
+    }
+}
+
+/// Returns the signature hashes of all messages keyed by their names.
+fn named_signature_hashes(project: &InkProject) -> BTreeMap<String, SignatureHash> {
+    project
+        .spec()
+        .messages()
+        .iter()
+        .map(|message| message.name().join("::"))
+        .zip(signature_hashes(project))
+        .collect()
+}
+
+/// Returns the signature hashes of all messages in their order of definition.
+fn signature_hashes(project: &InkProject) -> Vec<SignatureHash> {
+    let registry = serde_json::to_value(project.registry())
+        .expect("the type registry is always serializable");
+    let resolver = TypeResolver::new(&registry);
+    project
+        .spec()
+        .messages()
+        .iter()
+        .map(|message| {
+            let message = serde_json::to_value(message)
+                .expect("message specifications are always serializable");
+            let args = message["args"]
+                .as_array()
+                .map(Vec::as_slice)
+                .unwrap_or(&[])
+                .iter()
+                .map(|arg| resolver.resolve_spec(&arg["type"]))
+                .collect::<Vec<_>>();
+            let selector = message["selector"].clone();
+            let return_type = resolver.resolve_spec(&message["returnType"]);
+            let signature = json!({
+                "selector": selector,
+                "args": args,
+                "returnType": return_type,
+            });
+            hash(&signature)
+        })
+        .collect()
+}
+
+/// Hashes the canonical JSON encoding of the value.
+///
+/// Object keys are always sorted in `serde_json` maps which makes
+/// the encoding canonical.
+fn hash(value: &Value) -> SignatureHash {
+    let encoded = serde_json::to_vec(value).expect("JSON values are always encodable");
+    utils::blake2b_256(&encoded).into()
+}
+
+/// Replaces type identifiers with the definitions of the types they refer to.
+pub(crate) struct TypeResolver<'a> {
+    /// The JSON encoded types of the type registry.
+    types: &'a [Value],
+}
+
+impl<'a> TypeResolver<'a> {
+    /// Creates a resolver for the types of the JSON encoded type registry.
+    pub(crate) fn new(registry: &'a Value) -> Self {
+        Self {
+            types: registry["types"]
+                .as_array()
+                .map(Vec::as_slice)
+                .unwrap_or(&[]),
+        }
+    }
+
+    /// Resolves the type of a type specification.
+    ///
+    /// Display names are not resolved since they are not part of the ABI.
+    fn resolve_spec(&self, spec: &Value) -> Value {
+        match spec["type"].as_u64() {
+            Some(id) => self.resolve(id, &mut Vec::new()),
+            None => Value::Null,
+        }
+    }
+
+    /// Resolves the type with the given identifier.
+    ///
+    /// Recursive types are resolved to a reference to the enclosing type
+    /// that has already been visited.
+    pub(crate) fn resolve(&self, id: u64, visiting: &mut Vec<u64>) -> Value {
+        if let Some(depth) = visiting.iter().position(|visited| *visited == id) {
+            return json!({ "recursive": depth })
+        }
+        let ty = match id
+            .checked_sub(1)
+            .and_then(|index| self.types.get(index as usize))
+        {
+            Some(ty) => ty,
+            None => return Value::Null,
+        };
+        visiting.push(id);
+        let resolved = self.substitute(None, ty, visiting);
+        visiting.pop();
+        resolved
+    }
+
+    /// Substitutes all type identifiers found within the value.
+    ///
+    /// Type identifiers are found in `type` fields as well as in the
+    /// `params` of a type and in the fields of tuple types.
+    fn substitute(
+        &self,
+        key: Option<&str>,
+        value: &Value,
+        visiting: &mut Vec<u64>,
+    ) -> Value {
+        match (key, value) {
+            (Some("type"), Value::Number(id)) => {
+                match id.as_u64() {
+                    Some(id) => self.resolve(id, visiting),
+                    None => value.clone(),
+                }
+            }
+            (Some("params"), Value::Array(ids)) | (Some("tuple"), Value::Array(ids)) => {
+                ids.iter()
+                    .map(|id| {
+                        match id.as_u64() {
+                            Some(id) => self.resolve(id, visiting),
+                            None => self.substitute(None, id, visiting),
+                        }
+                    })
+                    .collect()
+            }
+            (_, Value::Array(values)) => {
+                values
+                    .iter()
+                    .map(|value| self.substitute(None, value, visiting))
+                    .collect()
+            }
+            (_, Value::Object(fields)) => {
+                fields
+                    .iter()
+                    .map(|(name, value)| {
+                        (name.clone(), self.substitute(Some(name), value, visiting))
+                    })
+                    .collect()
+            }
+            _ => value.clone(),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Export of the events of ink! smart contracts for indexers.
+//!
+//! Indexers have to know how to decode the events of all contracts they track
+//! and how to compute the topics to filter them by. The registry collects this
+//! information from the metadata of any number of contracts into a single JSON
+//! document instead of requiring it to be maintained by hand.
+//!
+//! The registry has the following format:
+//!
+//! ```json
+//! {
+//!     "version": 1,
+//!     "contracts": [{
+//!         "name": "Erc20",
+//!         "events": [{
+//!             "index": 0,
+//!             "name": "Transfer",
+//!             "signatureTopic": "0x4572633230...",
+//!             "fields": [{
+//!                 "name": "from",
+//!                 "indexed": true,
+//!                 "topicPrefix": "0x45726332303a3a...",
+//!                 "displayName": ["Option"],
+//!                 "type": { "...": "..." }
+//!             }],
+//!             "docs": []
+//!         }]
+//!     }]
+//! }
+//! ```
+//!
+//! The `index` of an event is the first byte of its SCALE encoding and identifies
+//! the event when decoding the data of emitted events. The `signatureTopic` is
+//! `null` for anonymous events. The topic of an indexed
+//! field is derived from the concatenation of its `topicPrefix` and its SCALE
+//! encoded value. Types are fully resolved from the type registry of the contract.
+//!
+//! # Note
+//!
+//! Topics are computed for the default topic hasher of `ink_env`. Topics whose
+//! encoding exceeds 32 bytes are hashed with BLAKE2 256-bit, all others are
+//! padded with zeros.
+
+use crate::{
+    diff::TypeResolver,
+    serde_hex,
+    utils,
+    InkProject,
+};
+use serde_json::{
+    json,
+    Value,
+};
+
+/// The version of the event registry format.
+pub const EVENT_REGISTRY_VERSION: u32 = 1;
+
+/// The events of a set of ink! smart contracts.
+#[derive(Debug, Default, Clone)]
+pub struct EventRegistry {
+    /// The JSON encoded events of every registered contract.
+    contracts: Vec<Value>,
+}
+
+impl EventRegistry {
+    /// Creates an empty event registry.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Registers the events of the contract.
+    ///
+    /// The `contract_name` must be the name of the ink! storage struct of the
+    /// contract since it is part of the topics of its events. Events defined via
+    /// `#[ink::event]` carry their own signature in their spec instead.
+    pub fn register(&mut self, contract_name: &str, project: &InkProject) -> &mut Self {
+        let registry = serde_json::to_value(project.registry())
+            .expect("the type registry is always serializable");
+        let resolver = TypeResolver::new(&registry);
+        let events = project
+            .spec()
+            .events()
+            .iter()
+            .enumerate()
+            .map(|(index, event)| {
+                // Events defined via `#[ink::event]` provide their own signature.
+                let signature = match event.signature() {
+                    Some(signature) => signature.to_string(),
+                    None => format!("{}::{}", contract_name, event.name()),
+                };
+                let signature_topic = if event.is_anonymous() {
+                    Value::Null
+                } else {
+                    let topic = topic(signature.as_bytes());
+                    Value::String(serde_hex::to_hex(&topic))
+                };
+                let fields = event
+                    .args()
+                    .iter()
+                    .map(|arg| {
+                        let ty = serde_json::to_value(arg.ty())
+                            .expect("type specifications are always serializable");
+                        let topic_prefix = if arg.indexed() {
+                            let prefix = format!("{}::{}", signature, arg.name());
+                            Value::String(serde_hex::to_hex(prefix.as_bytes()))
+                        } else {
+                            Value::Null
+                        };
+                        json!({
+                            "name": arg.name(),
+                            "indexed": arg.indexed(),
+                            "topicPrefix": topic_prefix,
+                            "displayName": ty["displayName"],
+                            "type": ty["type"]
+                                .as_u64()
+                                .map(|id| resolver.resolve(id, &mut Vec::new()))
+                                .unwrap_or(Value::Null),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                json!({
+                    "index": index,
+                    "name": event.name(),
+                    "signatureTopic": signature_topic,
+                    "fields": fields,
+                    "docs": event.docs(),
+                })
+            })
+            .collect::<Vec<_>>();
+        self.contracts.push(json!({
+            "name": contract_name,
+            "events": events,
+        }));
+        self
+    }
+
+    /// Returns the JSON representation of the registry.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "version": EVENT_REGISTRY_VERSION,
+            "contracts": self.contracts,
+        })
+    }
+}
+
+/// Returns the topic of the given encoded topic value.
+fn topic(encoded: &[u8]) -> [u8; 32] {
+    if encoded.len() > 32 {
+        return utils::blake2b_256(encoded)
+    }
+    let mut topic = [0x00; 32];
+    topic[..encoded.len()].copy_from_slice(encoded);
+    topic
+}
@@ -23,7 +23,15 @@ use crate::{
     },
 };
 use derive_more::From;
-use ink_prelude::collections::btree_map::BTreeMap;
+use ink_prelude::{
+    boxed::Box,
+    collections::btree_map::BTreeMap,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
 use ink_primitives::Key;
 use scale_info::{
     form::{
@@ -72,7 +80,7 @@ pub enum Layout<F: Form = MetaForm> {
 }
 
 /// A pointer into some storage region.
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, From)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, From)]
 pub struct LayoutKey {
     key: [u8; 32],
 }
@@ -92,7 +100,7 @@ impl<'de> serde::Deserialize<'de> for LayoutKey {
         D: serde::Deserializer<'de>,
     {
         let mut arr = [0; 32];
-        serde_hex::deserialize_check_len(d, serde_hex::ExpectedLen::Exact(&mut arr[..]))?;
+        serde_hex::deserialize_exact(d, &mut arr)?;
         Ok(arr.into())
     }
 }
@@ -131,6 +139,12 @@ pub struct CellLayout<F: Form = MetaForm> {
     key: LayoutKey,
     /// The type of the encoded entity.
     ty: <F as Form>::Type,
+    /// The human readable path of the cell within the storage layout.
+    ///
+    /// Consists of the names of the fields leading to the cell and is only
+    /// resolved for the storage layout of an entire ink! project.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    path: Vec<F::String>,
 }
 
 impl CellLayout {
@@ -142,6 +156,7 @@ impl CellLayout {
         Self {
             key,
             ty: meta_type::<T>(),
+            path: Vec::new(),
         }
     }
 }
@@ -153,6 +168,58 @@ impl IntoCompact for CellLayout {
         CellLayout {
             key: self.key,
             ty: registry.register_type(&self.ty),
+            path: registry.map_into_compact(self.path),
+        }
+    }
+}
+
+impl<F> Layout<F>
+where
+    F: Form,
+{
+    /// Returns the first storage key that is occupied by the layout.
+    ///
+    /// Returns `None` for layouts that do not occupy any storage cells,
+    /// e.g. structs without any fields.
+    pub fn root_key(&self) -> Option<&LayoutKey> {
+        match self {
+            Layout::Cell(cell_layout) => Some(cell_layout.key()),
+            Layout::Hash(hash_layout) => Some(hash_layout.offset()),
+            Layout::Array(array_layout) => Some(array_layout.offset()),
+            Layout::Struct(struct_layout) => {
+                struct_layout
+                    .fields()
+                    .iter()
+                    .find_map(|field| field.layout().root_key())
+            }
+            Layout::Enum(enum_layout) => Some(enum_layout.dispatch_key()),
+        }
+    }
+}
+
+impl Layout<CompactForm> {
+    /// Resolves the paths of all cells and the root keys of all fields.
+    ///
+    /// Paths are composed of the field names leading to a cell. Unnamed
+    /// fields are represented by their index and enum variants by their
+    /// discriminant.
+    pub(crate) fn resolve_paths(&mut self) {
+        self.resolve_paths_at(&mut Vec::new())
+    }
+
+    fn resolve_paths_at(&mut self, path: &mut Vec<String>) {
+        match self {
+            Layout::Cell(cell_layout) => cell_layout.path = path.clone(),
+            Layout::Hash(hash_layout) => hash_layout.layout.resolve_paths_at(path),
+            Layout::Array(array_layout) => array_layout.layout.resolve_paths_at(path),
+            Layout::Struct(struct_layout) => struct_layout.resolve_paths_at(path),
+            Layout::Enum(enum_layout) => {
+                for (discriminant, variant) in enum_layout.variants.iter_mut() {
+                    path.push(discriminant.value().to_string());
+                    variant.resolve_paths_at(path);
+                    path.pop();
+                }
+            }
         }
     }
 }
@@ -190,6 +257,13 @@ where
     pub fn ty(&self) -> &F::Type {
         &self.ty
     }
+
+    /// Returns the human readable path of the cell within the storage layout.
+    ///
+    /// The path might be empty if it has not been resolved.
+    pub fn path(&self) -> &[F::String] {
+        &self.path
+    }
 }
 
 /// A hashing layout potentially hitting all cells of the storage.
@@ -426,6 +500,18 @@ where
     }
 }
 
+impl StructLayout<CompactForm> {
+    fn resolve_paths_at(&mut self, path: &mut Vec<String>) {
+        for (n, field) in self.fields.iter_mut().enumerate() {
+            let segment = field.name.clone().unwrap_or_else(|| n.to_string());
+            path.push(segment);
+            field.layout.resolve_paths_at(path);
+            field.key = field.layout.root_key().copied();
+            path.pop();
+        }
+    }
+}
+
 impl IntoCompact for StructLayout {
     type Output = StructLayout<CompactForm>;
 
@@ -456,6 +542,11 @@ pub struct FieldLayout<F: Form = MetaForm> {
     /// This is either a direct layout bound
     /// or another recursive layout sub-struct.
     layout: Layout<F>,
+    /// The first storage key occupied by the field.
+    ///
+    /// Only resolved for the storage layout of an entire ink! project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    key: Option<LayoutKey>,
 }
 
 impl FieldLayout {
@@ -468,6 +559,7 @@ impl FieldLayout {
         Self {
             name: name.into(),
             layout: layout.into(),
+            key: None,
         }
     }
 }
@@ -490,6 +582,11 @@ where
     pub fn layout(&self) -> &Layout<F> {
         &self.layout
     }
+
+    /// Returns the first storage key occupied by the field if resolved.
+    pub fn key(&self) -> Option<&LayoutKey> {
+        self.key.as_ref()
+    }
 }
 
 impl IntoCompact for FieldLayout {
@@ -499,6 +596,7 @@ impl IntoCompact for FieldLayout {
         FieldLayout {
             name: self.name.map(|name| name.into_compact(registry)),
             layout: self.layout.into_compact(registry),
+            key: self.key,
         }
     }
 }
@@ -366,3 +366,65 @@ fn unbounded_layout_works() {
     };
     assert_eq!(json, expected);
 }
+
+#[test]
+fn resolve_paths_works() {
+    let layout: Layout = StructLayout::new(vec![
+        FieldLayout::new(
+            "named",
+            named_fields_struct_layout(&mut KeyPtr::from(Key::from([0x00; 32]))),
+        ),
+        FieldLayout::new(
+            "mixed",
+            mixed_enum_layout(&mut KeyPtr::from(Key::from([0x02; 32]))),
+        ),
+    ])
+    .into();
+    let mut registry = Registry::new();
+    let mut compacted = layout.into_compact(&mut registry);
+    compacted.resolve_paths();
+
+    fn collect_paths(layout: &Layout<CompactForm>, paths: &mut Vec<Vec<String>>) {
+        match layout {
+            Layout::Cell(cell) => paths.push(cell.path().to_vec()),
+            Layout::Hash(hash) => collect_paths(hash.layout(), paths),
+            Layout::Array(array) => collect_paths(array.layout(), paths),
+            Layout::Struct(struct_layout) => {
+                for field in struct_layout.fields() {
+                    collect_paths(field.layout(), paths)
+                }
+            }
+            Layout::Enum(enum_layout) => {
+                for variant in enum_layout.variants().values() {
+                    for field in variant.fields() {
+                        collect_paths(field.layout(), paths)
+                    }
+                }
+            }
+        }
+    }
+    let mut paths = Vec::new();
+    collect_paths(&compacted, &mut paths);
+    assert_eq!(
+        paths,
+        vec![
+            vec!["named", "a"],
+            vec!["named", "b"],
+            vec!["mixed", "1", "0"],
+            vec!["mixed", "1", "1"],
+            vec!["mixed", "2", "a"],
+            vec!["mixed", "2", "b"],
+        ]
+    );
+    let field_keys = match &compacted {
+        Layout::Struct(struct_layout) => {
+            struct_layout
+                .fields()
+                .iter()
+                .map(|field| field.key().map(LayoutKey::to_bytes).map(|key| key[0]))
+                .collect::<Vec<_>>()
+        }
+        _ => unreachable!("encountered unexpected root layout"),
+    };
+    assert_eq!(field_keys, vec![Some(0x00), Some(0x02)]);
+}
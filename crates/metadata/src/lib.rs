@@ -20,11 +20,26 @@ extern crate alloc;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "std")]
+mod diff;
+#[cfg(feature = "std")]
+mod event_registry;
 pub mod layout;
+mod read;
+#[cfg(feature = "rpc-client")]
+mod rpc_client;
+mod serde_hex;
 mod specs;
+#[cfg(feature = "typescript")]
+mod typescript;
+#[cfg(feature = "std")]
+mod upgrade;
 mod utils;
+#[cfg(feature = "std")]
+mod validate;
 
 pub use self::specs::{
+    CodeFingerprint,
     ConstructorSpec,
     ConstructorSpecBuilder,
     ContractSpec,
@@ -34,16 +49,59 @@ pub use self::specs::{
     EventParamSpecBuilder,
     EventSpec,
     EventSpecBuilder,
+    LangErrorSpec,
+    LangErrorSpecBuilder,
+    LangErrorVariantSpec,
+    LangErrorVariantSpecBuilder,
     MessageParamSpec,
     MessageParamSpecBuilder,
     MessageSpec,
     MessageSpecBuilder,
     ReturnTypeSpec,
     Selector,
+    SignatureHash,
+    TraitSpec,
+    TraitSpecBuilder,
     TypeSpec,
 };
-
-use impl_serde::serialize as serde_hex;
+pub use self::read::{
+    read_contract,
+    CallableInfo,
+    ContractInfo,
+    ReadError,
+};
+#[cfg(feature = "std")]
+pub use self::diff::MessagesDiff;
+#[cfg(feature = "std")]
+pub use self::event_registry::{
+    EventRegistry,
+    EVENT_REGISTRY_VERSION,
+};
+#[cfg(feature = "rpc-client")]
+pub use self::rpc_client::{
+    CallOutcome,
+    ContractClient,
+    HttpTransport,
+    InstantiateCode,
+    InstantiateOutcome,
+    RpcClientError,
+    Transport,
+    DEFAULT_GAS_LIMIT,
+};
+#[cfg(feature = "typescript")]
+pub use self::typescript::generate_typescript;
+#[cfg(feature = "std")]
+pub use self::upgrade::{
+    check_upgrade,
+    UpgradeCompatibility,
+    UpgradeReport,
+};
+#[cfg(feature = "std")]
+pub use self::validate::{
+    validate,
+    ValidationError,
+    METADATA_SCHEMA,
+};
 
 #[cfg(feature = "derive")]
 use scale_info::{
@@ -57,6 +115,28 @@ use serde::{
     Serialize,
 };
 
+/// The name of the Wasm custom section that holds the metadata hash.
+///
+/// Contracts embed the hash of their metadata into this custom section
+/// if they have been configured with the `metadata_hash` ink! config argument.
+pub const METADATA_HASH_SECTION: &str = "ink_metadata_hash";
+
+/// The name of the Wasm custom section that holds the code fingerprint.
+///
+/// Every contract embeds the fingerprint of its dispatch table into this
+/// custom section. The same fingerprint is part of the contract specification.
+pub const CODE_FINGERPRINT_SECTION: &str = "ink_code_fingerprint";
+
+/// Returns the hash of the given JSON encoded metadata.
+///
+/// This is the BLAKE2 256-bit hash that is to be provided via
+/// the `metadata_hash` ink! config argument so that the Wasm blob
+/// of a contract can be matched with its off-chain metadata.
+#[cfg(feature = "std")]
+pub fn metadata_hash(json: &str) -> [u8; 32] {
+    utils::blake2b_256(json.as_bytes())
+}
+
 /// An entire ink! project for metadata file generation purposes.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct InkProject {
@@ -75,12 +155,18 @@ impl InkProject {
         S: Into<ContractSpec>,
     {
         let mut registry = Registry::new();
+        let mut layout = layout.into().into_compact(&mut registry);
+        layout.resolve_paths();
 
-        Self {
-            layout: layout.into().into_compact(&mut registry),
+        #[allow(unused_mut)]
+        let mut project = Self {
+            layout,
             spec: spec.into().into_compact(&mut registry),
             registry: registry.into(),
-        }
+        };
+        #[cfg(feature = "std")]
+        diff::hash_signatures(&mut project);
+        project
     }
 }
 
@@ -0,0 +1,155 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Read-only access to selected parts of ink! metadata.
+//!
+//! In contrast to `InkProject` this does not require
+//! the type registry and works in `no_std` environments, e.g. for on-chain or
+//! light-client components that need to know the selectors or the storage
+//! layout of another contract. All other parts of the metadata are skipped.
+
+use crate::{
+    layout::Layout,
+    Selector,
+};
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+use scale_info::form::CompactForm;
+use serde::Deserialize;
+
+/// An error encountered upon reading ink! metadata.
+#[derive(Debug)]
+pub struct ReadError {
+    /// The underlying decoding error.
+    error: serde_json::Error,
+}
+
+impl From<serde_json::Error> for ReadError {
+    fn from(error: serde_json::Error) -> Self {
+        Self { error }
+    }
+}
+
+impl core::fmt::Display for ReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "could not read ink! metadata: {}", self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReadError {}
+
+/// Reads the selectors and the storage layout from the given ink! metadata JSON.
+///
+/// # Errors
+///
+/// If the input is not valid JSON or if the read parts are malformed.
+pub fn read_contract(json: &str) -> Result<ContractInfo, ReadError> {
+    serde_json::from_str(json).map_err(Into::into)
+}
+
+/// The selected parts of the metadata of a contract.
+#[derive(Debug, Deserialize)]
+pub struct ContractInfo {
+    /// The specification of the contract.
+    spec: SpecInfo,
+    /// The storage layout of the contract.
+    storage: Layout<CompactForm>,
+}
+
+/// The selected parts of the specification of a contract.
+#[derive(Debug, Deserialize)]
+struct SpecInfo {
+    /// The constructors of the contract.
+    constructors: Vec<CallableInfo>,
+    /// The messages of the contract.
+    messages: Vec<CallableInfo>,
+}
+
+impl ContractInfo {
+    /// Returns the constructors of the contract.
+    pub fn constructors(&self) -> &[CallableInfo] {
+        &self.spec.constructors
+    }
+
+    /// Returns the messages of the contract.
+    pub fn messages(&self) -> &[CallableInfo] {
+        &self.spec.messages
+    }
+
+    /// Returns the message with the given name if any.
+    ///
+    /// Trait provided messages are named by their trait name and
+    /// their message name separated by `::`, e.g. `Flip::flip`.
+    pub fn message(&self, name: &str) -> Option<&CallableInfo> {
+        self.spec
+            .messages
+            .iter()
+            .find(|message| message.is_named(name))
+    }
+
+    /// Returns the storage layout of the contract.
+    pub fn layout(&self) -> &Layout<CompactForm> {
+        &self.storage
+    }
+}
+
+/// The selected parts of a constructor or message of a contract.
+#[derive(Debug, Deserialize)]
+pub struct CallableInfo {
+    /// The name of the constructor or message and some optional prefixes.
+    name: Vec<String>,
+    /// The selector of the constructor or message.
+    selector: Selector,
+    /// If the constructor or message is payable by the caller.
+    payable: bool,
+    /// If the message is allowed to mutate the contract state.
+    ///
+    /// Always `false` for constructors.
+    #[serde(default)]
+    mutates: bool,
+}
+
+impl CallableInfo {
+    /// Returns the name of the constructor or message and some optional prefixes.
+    pub fn name(&self) -> &[String] {
+        &self.name
+    }
+
+    /// Returns the selector of the constructor or message.
+    pub fn selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    /// Returns `true` if the constructor or message is payable by the caller.
+    pub fn payable(&self) -> bool {
+        self.payable
+    }
+
+    /// Returns `true` if the message is allowed to mutate the contract state.
+    pub fn mutates(&self) -> bool {
+        self.mutates
+    }
+
+    /// Returns `true` if the `::` separated name matches the name segments.
+    fn is_named(&self, name: &str) -> bool {
+        let mut segments = self.name.iter();
+        let matches = name
+            .split("::")
+            .all(|segment| segments.next().map(String::as_str) == Some(segment));
+        matches && segments.next().is_none()
+    }
+}
@@ -0,0 +1,701 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A client for querying deployed contracts via dry-run RPCs of a node.
+//!
+//! The client wraps the `contracts_call` and `contracts_instantiate` RPCs of
+//! the contracts pallet. Constructors and messages are looked up by their name
+//! in the metadata of the contract and their return values are decoded into
+//! JSON using the type registry of the metadata, so that backend services do
+//! not have to hand-roll JSON-RPC requests for their queries:
+//!
+//! ```no_run
+//! # use ink_metadata::{ContractClient, HttpTransport};
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let metadata = std::fs::read_to_string("metadata.json")?;
+//! # let (contract, alice) = ("5FnL...", "5Grw...");
+//! let transport = HttpTransport::new("http://localhost:9933");
+//! let client = ContractClient::new(transport, &metadata, contract)?;
+//! let total_supply = client.call(alice, "total_supply", &[])?.value();
+//! # Ok(()) }
+//! ```
+//!
+//! Arguments are expected to be SCALE encoded already. Decoded values use the
+//! same JSON representation as `@polkadot/api`: integers of up to 64 bits
+//! become numbers, larger integers become strings, byte arrays and sequences
+//! become hex encoded strings, `Option<T>` becomes `T` or `null`, fieldless
+//! enum variants become their name and all other variants become an object
+//! with the variant name as the only key.
+
+use crate::serde_hex;
+use serde_json::{
+    json,
+    Map,
+    Value,
+};
+use std::{
+    convert::TryFrom,
+    fmt,
+};
+
+/// The gas limit used for dry-runs unless configured otherwise.
+pub const DEFAULT_GAS_LIMIT: u64 = 50_000_000_000;
+
+/// The flag set by contracts in their return flags if they reverted.
+const REVERT_FLAG: u64 = 1;
+
+/// An error encountered by a [`ContractClient`].
+#[derive(Debug)]
+pub enum RpcClientError {
+    /// The transport failed to deliver the request or its response.
+    Transport(String),
+    /// The node answered with a JSON-RPC error.
+    Rpc { code: i64, message: String },
+    /// The contract metadata is malformed.
+    Metadata(String),
+    /// The metadata has no constructor or message with the name.
+    UnknownCallable(String),
+    /// The node answered with a response of an unexpected shape.
+    MalformedResponse(Value),
+    /// The dry-run failed, e.g. because the contract trapped.
+    ExecutionFailed(Value),
+    /// The returned data could not be decoded with the metadata.
+    Decode(String),
+}
+
+impl fmt::Display for RpcClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Transport(error) => write!(f, "transport failed: {}", error),
+            Self::Rpc { code, message } => {
+                write!(f, "node returned error {}: {}", code, message)
+            }
+            Self::Metadata(error) => write!(f, "malformed contract metadata: {}", error),
+            Self::UnknownCallable(name) => {
+                write!(f, "encountered unknown constructor or message `{}`", name)
+            }
+            Self::MalformedResponse(response) => {
+                write!(f, "encountered malformed response: {}", response)
+            }
+            Self::ExecutionFailed(error) => write!(f, "dry-run failed: {}", error),
+            Self::Decode(error) => write!(f, "could not decode returned data: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for RpcClientError {}
+
+/// A transport for JSON-RPC requests to a node.
+pub trait Transport {
+    /// Sends the request and returns the `result` of its response.
+    fn request(&self, method: &str, params: Value) -> Result<Value, RpcClientError>;
+}
+
+/// A transport sending JSON-RPC requests via HTTP.
+#[derive(Debug, Clone)]
+pub struct HttpTransport {
+    /// The HTTP endpoint of the node.
+    url: String,
+}
+
+impl HttpTransport {
+    /// Creates a transport for the HTTP endpoint, e.g. `http://localhost:9933`.
+    pub fn new<U>(url: U) -> Self
+    where
+        U: Into<String>,
+    {
+        Self { url: url.into() }
+    }
+}
+
+impl Transport for HttpTransport {
+    fn request(&self, method: &str, params: Value) -> Result<Value, RpcClientError> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let mut response: Value = ureq::post(&self.url)
+            .send_json(body)
+            .map_err(|error| RpcClientError::Transport(error.to_string()))?
+            .into_json()
+            .map_err(|error| RpcClientError::Transport(error.to_string()))?;
+        if let Some(error) = response.get("error") {
+            return Err(RpcClientError::Rpc {
+                code: error["code"].as_i64().unwrap_or_default(),
+                message: error["message"].as_str().unwrap_or_default().to_string(),
+            })
+        }
+        match response.get_mut("result") {
+            Some(result) => Ok(result.take()),
+            None => Err(RpcClientError::MalformedResponse(response)),
+        }
+    }
+}
+
+/// The code of a contract to be instantiated by a dry-run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstantiateCode {
+    /// The Wasm blob of the contract code that has not been uploaded yet.
+    Upload(Vec<u8>),
+    /// The hash of contract code that has already been uploaded.
+    Existing([u8; 32]),
+}
+
+/// The outcome of a dry-run call of a message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallOutcome {
+    /// If the contract reverted its state changes.
+    reverted: bool,
+    /// The SCALE encoded data returned by the contract.
+    data: Vec<u8>,
+    /// The decoded return value of the message.
+    value: Value,
+    /// The gas consumed by the dry-run if reported by the node.
+    gas_consumed: Option<u64>,
+}
+
+impl CallOutcome {
+    /// Returns `true` if the contract reverted its state changes.
+    pub fn reverted(&self) -> bool {
+        self.reverted
+    }
+
+    /// Returns the SCALE encoded data returned by the contract.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the decoded return value of the message.
+    ///
+    /// This is `null` if the message has no return value or if the
+    /// contract reverted.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Returns the gas consumed by the dry-run if reported by the node.
+    pub fn gas_consumed(&self) -> Option<u64> {
+        self.gas_consumed
+    }
+}
+
+/// The outcome of a dry-run instantiation of a contract.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstantiateOutcome {
+    /// If the constructor reverted.
+    reverted: bool,
+    /// The SCALE encoded data returned by the constructor.
+    data: Vec<u8>,
+    /// The address the contract would be instantiated at.
+    account_id: Option<String>,
+    /// The gas consumed by the dry-run if reported by the node.
+    gas_consumed: Option<u64>,
+}
+
+impl InstantiateOutcome {
+    /// Returns `true` if the constructor reverted.
+    pub fn reverted(&self) -> bool {
+        self.reverted
+    }
+
+    /// Returns the SCALE encoded data returned by the constructor.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the address the contract would be instantiated at if reported.
+    pub fn account_id(&self) -> Option<&str> {
+        self.account_id.as_deref()
+    }
+
+    /// Returns the gas consumed by the dry-run if reported by the node.
+    pub fn gas_consumed(&self) -> Option<u64> {
+        self.gas_consumed
+    }
+}
+
+/// A client for dry-run queries of a deployed contract.
+#[derive(Debug)]
+pub struct ContractClient<T> {
+    /// The transport to the node.
+    transport: T,
+    /// The type registry of the contract metadata.
+    types: Vec<Value>,
+    /// The contract specification of the contract metadata.
+    spec: Value,
+    /// The address of the deployed contract.
+    address: String,
+    /// The gas limit of the dry-runs.
+    gas_limit: u64,
+}
+
+impl<T> ContractClient<T>
+where
+    T: Transport,
+{
+    /// Creates a client for the contract at the address with the JSON metadata.
+    ///
+    /// # Errors
+    ///
+    /// If the metadata is not valid JSON or has no contract specification.
+    pub fn new<A>(
+        transport: T,
+        metadata: &str,
+        address: A,
+    ) -> Result<Self, RpcClientError>
+    where
+        A: Into<String>,
+    {
+        let mut metadata: Value = serde_json::from_str(metadata)
+            .map_err(|error| RpcClientError::Metadata(error.to_string()))?;
+        let types = match metadata["types"].take() {
+            Value::Array(types) => types,
+            _ => return Err(RpcClientError::Metadata("missing `types`".to_string())),
+        };
+        let spec = metadata["spec"].take();
+        if !spec.is_object() {
+            return Err(RpcClientError::Metadata("missing `spec`".to_string()))
+        }
+        Ok(Self {
+            transport,
+            types,
+            spec,
+            address: address.into(),
+            gas_limit: DEFAULT_GAS_LIMIT,
+        })
+    }
+
+    /// Sets the gas limit of subsequent dry-runs.
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Returns the address of the deployed contract.
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Returns the transport to the node.
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Dry-runs the message with the SCALE encoded arguments as `origin`.
+    ///
+    /// Trait provided messages are named by their trait name and
+    /// their message name separated by `::`, e.g. `Flip::flip`.
+    pub fn call(
+        &self,
+        origin: &str,
+        message: &str,
+        args: &[u8],
+    ) -> Result<CallOutcome, RpcClientError> {
+        self.call_with_value(origin, message, args, 0)
+    }
+
+    /// Dry-runs the message as `origin` transferring `value` to the contract.
+    pub fn call_with_value(
+        &self,
+        origin: &str,
+        message: &str,
+        args: &[u8],
+        value: u128,
+    ) -> Result<CallOutcome, RpcClientError> {
+        let spec = self.callable("messages", message)?;
+        let request = json!({
+            "origin": origin,
+            "dest": self.address,
+            "value": format!("0x{:x}", value),
+            "gasLimit": self.gas_limit,
+            "inputData": input_data(spec, args)?,
+        });
+        let response = self.transport.request("contracts_call", json!([request]))?;
+        let exec = exec_result(&response)?;
+        let (reverted, data) = return_data(exec, &response)?;
+        let value = match &spec["returnType"] {
+            Value::Null => Value::Null,
+            _ if reverted => Value::Null,
+            return_type => {
+                let mut input = &data[..];
+                let value = decode_value(&self.types, &return_type["type"], &mut input)?;
+                if !input.is_empty() {
+                    return Err(RpcClientError::Decode(format!(
+                        "{} trailing bytes",
+                        input.len()
+                    )))
+                }
+                value
+            }
+        };
+        Ok(CallOutcome {
+            reverted,
+            data,
+            value,
+            gas_consumed: gas_consumed(exec, &response),
+        })
+    }
+
+    /// Dry-runs the instantiation of the contract code as `origin`.
+    ///
+    /// The constructor is called with the SCALE encoded arguments and
+    /// `endowment` is transferred to the new contract.
+    pub fn instantiate(
+        &self,
+        origin: &str,
+        constructor: &str,
+        args: &[u8],
+        code: InstantiateCode,
+        endowment: u128,
+        salt: &[u8],
+    ) -> Result<InstantiateOutcome, RpcClientError> {
+        let spec = self.callable("constructors", constructor)?;
+        let code = match code {
+            InstantiateCode::Upload(code) => {
+                json!({ "upload": serde_hex::to_hex(&code) })
+            }
+            InstantiateCode::Existing(hash) => {
+                json!({ "existing": serde_hex::to_hex(&hash) })
+            }
+        };
+        let request = json!({
+            "origin": origin,
+            "endowment": format!("0x{:x}", endowment),
+            "gasLimit": self.gas_limit,
+            "code": code,
+            "data": input_data(spec, args)?,
+            "salt": serde_hex::to_hex(salt),
+        });
+        let response = self
+            .transport
+            .request("contracts_instantiate", json!([request]))?;
+        let exec = exec_result(&response)?;
+        let (reverted, data) = return_data(&exec["result"], &response)?;
+        Ok(InstantiateOutcome {
+            reverted,
+            data,
+            account_id: exec["accountId"].as_str().map(ToString::to_string),
+            gas_consumed: gas_consumed(exec, &response),
+        })
+    }
+
+    /// Returns the constructor or message specification with the name.
+    fn callable(&self, kind: &str, name: &str) -> Result<&Value, RpcClientError> {
+        self.spec[kind]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|spec| is_named(spec, name))
+            .ok_or_else(|| RpcClientError::UnknownCallable(name.to_string()))
+    }
+}
+
+/// Returns `true` if the `::` separated name matches the name of the specification.
+fn is_named(spec: &Value, name: &str) -> bool {
+    let segments = spec["name"].as_array().map(Vec::as_slice).unwrap_or(&[]);
+    let mut segments = segments.iter();
+    let matches = name
+        .split("::")
+        .all(|segment| segments.next().and_then(Value::as_str) == Some(segment));
+    matches && segments.next().is_none()
+}
+
+/// Returns the hex encoded selector of the specification followed by the arguments.
+fn input_data(spec: &Value, args: &[u8]) -> Result<String, RpcClientError> {
+    let mut data = spec["selector"]
+        .as_str()
+        .and_then(|selector| serde_hex::from_hex(selector).ok())
+        .ok_or_else(|| RpcClientError::Metadata("malformed `selector`".to_string()))?;
+    data.extend_from_slice(args);
+    Ok(serde_hex::to_hex(&data))
+}
+
+/// Returns the successful execution result of the dry-run response.
+///
+/// Supports both the `result: { Ok | Err }` responses of current nodes
+/// and the `success | error` responses of older nodes.
+fn exec_result(response: &Value) -> Result<&Value, RpcClientError> {
+    if let Some(result) = response.get("result") {
+        if let Some(error) = result.get("Err") {
+            return Err(RpcClientError::ExecutionFailed(error.clone()))
+        }
+        if let Some(ok) = result.get("Ok") {
+            return Ok(ok)
+        }
+    }
+    if let Some(success) = response.get("success") {
+        return Ok(success)
+    }
+    if let Some(error) = response.get("error") {
+        return Err(RpcClientError::ExecutionFailed(error.clone()))
+    }
+    Err(RpcClientError::MalformedResponse(response.clone()))
+}
+
+/// Returns if the execution reverted and the data it returned.
+fn return_data(
+    exec: &Value,
+    response: &Value,
+) -> Result<(bool, Vec<u8>), RpcClientError> {
+    let malformed = || RpcClientError::MalformedResponse(response.clone());
+    let flags = exec["flags"].as_u64().ok_or_else(malformed)?;
+    let data = exec["data"]
+        .as_str()
+        .and_then(|data| serde_hex::from_hex(data).ok())
+        .ok_or_else(malformed)?;
+    Ok((flags & REVERT_FLAG != 0, data))
+}
+
+/// Returns the gas consumed by the dry-run if reported by the node.
+fn gas_consumed(exec: &Value, response: &Value) -> Option<u64> {
+    response["gasConsumed"]
+        .as_u64()
+        .or_else(|| exec["gasConsumed"].as_u64())
+}
+
+/// Decodes the SCALE encoded value of the type with the identifier into JSON.
+fn decode_value(
+    types: &[Value],
+    id: &Value,
+    input: &mut &[u8],
+) -> Result<Value, RpcClientError> {
+    let ty = id
+        .as_u64()
+        .and_then(|id| id.checked_sub(1))
+        .and_then(|index| types.get(index as usize))
+        .ok_or_else(|| RpcClientError::Metadata(format!("unknown type {}", id)))?;
+    let def = &ty["def"];
+    if let Some(primitive) = def["primitive"].as_str() {
+        return decode_primitive(primitive, input)
+    }
+    if let Some(composite) = def.get("composite") {
+        return decode_fields(types, &composite["fields"], input)
+    }
+    if let Some(variant) = def.get("variant") {
+        let index = u64::from(take(input, 1)?[0]);
+        let variants = variant["variants"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        let variant = variants
+            .iter()
+            .enumerate()
+            .find(|(position, variant)| {
+                variant["discriminant"].as_u64().unwrap_or(*position as u64) == index
+            })
+            .map(|(_, variant)| variant)
+            .ok_or_else(|| {
+                RpcClientError::Decode(format!("unknown variant {}", index))
+            })?;
+        let name = variant["name"].as_str().unwrap_or("_");
+        let is_option = ty["path"]
+            .as_array()
+            .and_then(|path| path.last())
+            .map(|name| name == "Option")
+            .unwrap_or(false);
+        let fields = &variant["fields"];
+        let is_fieldless = fields.as_array().map(Vec::is_empty).unwrap_or(true);
+        return match (is_option, is_fieldless) {
+            (true, true) => Ok(Value::Null),
+            (true, false) => decode_fields(types, fields, input),
+            (false, true) => Ok(Value::String(name.to_string())),
+            (false, false) => {
+                let mut object = Map::new();
+                object.insert(name.to_string(), decode_fields(types, fields, input)?);
+                Ok(Value::Object(object))
+            }
+        }
+    }
+    if let Some(sequence) = def.get("sequence") {
+        let len = decode_compact(input)?;
+        return decode_elems(types, &sequence["type"], len, input)
+    }
+    if let Some(array) = def.get("array") {
+        let len = array["len"].as_u64().unwrap_or_default();
+        return decode_elems(types, &array["type"], u128::from(len), input)
+    }
+    if let Some(tuple) = def["tuple"].as_array() {
+        if tuple.is_empty() {
+            return Ok(Value::Null)
+        }
+        return tuple
+            .iter()
+            .map(|id| decode_value(types, id, input))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array)
+    }
+    if def.get("compact").is_some() {
+        return decode_compact(input).map(number)
+    }
+    Err(RpcClientError::Metadata(format!("unsupported type {}", id)))
+}
+
+/// Decodes the fields of a composite or variant.
+///
+/// Named fields are decoded into an object, a single unnamed field into its
+/// value and several unnamed fields into an array.
+fn decode_fields(
+    types: &[Value],
+    fields: &Value,
+    input: &mut &[u8],
+) -> Result<Value, RpcClientError> {
+    let fields = fields.as_array().map(Vec::as_slice).unwrap_or(&[]);
+    match fields {
+        [] => Ok(Value::Null),
+        [field] if !field["name"].is_string() => {
+            decode_value(types, &field["type"], input)
+        }
+        _ if fields.iter().all(|field| field["name"].is_string()) => {
+            let mut object = Map::new();
+            for field in fields {
+                let name = field["name"].as_str().unwrap_or_default().to_string();
+                object.insert(name, decode_value(types, &field["type"], input)?);
+            }
+            Ok(Value::Object(object))
+        }
+        _ => fields
+            .iter()
+            .map(|field| decode_value(types, &field["type"], input))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+    }
+}
+
+/// Decodes `len` elements of the type with the identifier.
+///
+/// Byte elements are decoded into a single hex encoded string.
+fn decode_elems(
+    types: &[Value],
+    id: &Value,
+    len: u128,
+    input: &mut &[u8],
+) -> Result<Value, RpcClientError> {
+    let is_byte = id
+        .as_u64()
+        .and_then(|id| id.checked_sub(1))
+        .and_then(|index| types.get(index as usize))
+        .map(|ty| ty["def"]["primitive"] == "u8")
+        .unwrap_or(false);
+    let len = usize::try_from(len)
+        .map_err(|_| RpcClientError::Decode("sequence too long".to_string()))?;
+    if is_byte {
+        return take(input, len).map(|bytes| Value::String(serde_hex::to_hex(bytes)))
+    }
+    if len > input.len() {
+        return Err(RpcClientError::Decode("sequence too long".to_string()))
+    }
+    (0..len)
+        .map(|_| decode_value(types, id, input))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Value::Array)
+}
+
+/// Decodes the primitive with the name.
+fn decode_primitive(primitive: &str, input: &mut &[u8]) -> Result<Value, RpcClientError> {
+    let (len, signed) = match primitive {
+        "bool" => {
+            return match take(input, 1)?[0] {
+                0 => Ok(Value::Bool(false)),
+                1 => Ok(Value::Bool(true)),
+                byte => Err(RpcClientError::Decode(format!("invalid bool {}", byte))),
+            }
+        }
+        "char" => {
+            let bytes = take(input, 4)?;
+            let code = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            return std::char::from_u32(code)
+                .map(|c| Value::String(c.to_string()))
+                .ok_or_else(|| RpcClientError::Decode(format!("invalid char {}", code)))
+        }
+        "str" => {
+            let len = usize::try_from(decode_compact(input)?)
+                .map_err(|_| RpcClientError::Decode("string too long".to_string()))?;
+            let bytes = take(input, len)?;
+            return String::from_utf8(bytes.to_vec())
+                .map(Value::String)
+                .map_err(|error| RpcClientError::Decode(error.to_string()))
+        }
+        "u8" => (1, false),
+        "u16" => (2, false),
+        "u32" => (4, false),
+        "u64" => (8, false),
+        "u128" => (16, false),
+        "i8" => (1, true),
+        "i16" => (2, true),
+        "i32" => (4, true),
+        "i64" => (8, true),
+        "i128" => (16, true),
+        _ => {
+            return Err(RpcClientError::Metadata(format!(
+                "unsupported primitive `{}`",
+                primitive
+            )))
+        }
+    };
+    let bytes = take(input, len)?;
+    let negative = signed && bytes[len - 1] & 0x80 != 0;
+    let mut buffer = if negative { [0xFF; 16] } else { [0x00; 16] };
+    buffer[..len].copy_from_slice(bytes);
+    if signed {
+        let value = i128::from_le_bytes(buffer);
+        return Ok(match i64::try_from(value) {
+            Ok(value) => json!(value),
+            Err(_) => Value::String(value.to_string()),
+        })
+    }
+    Ok(number(u128::from_le_bytes(buffer)))
+}
+
+/// Decodes a SCALE compact encoded integer.
+fn decode_compact(input: &mut &[u8]) -> Result<u128, RpcClientError> {
+    let prefix = take(input, 1)?[0];
+    let len = match prefix & 0b11 {
+        0b00 => return Ok(u128::from(prefix >> 2)),
+        0b01 => 1,
+        0b10 => 3,
+        _ => usize::from(prefix >> 2) + 4,
+    };
+    if len > 16 {
+        return Err(RpcClientError::Decode(
+            "compact integer too large".to_string(),
+        ))
+    }
+    let mut buffer = [0x00; 16];
+    buffer[..len].copy_from_slice(take(input, len)?);
+    let value = u128::from_le_bytes(buffer);
+    Ok(match prefix & 0b11 {
+        0b11 => value,
+        _ => (value << 6) | u128::from(prefix >> 2),
+    })
+}
+
+/// Returns the integer as JSON number or as string if it exceeds 64 bits.
+fn number(value: u128) -> Value {
+    match u64::try_from(value) {
+        Ok(value) => json!(value),
+        Err(_) => Value::String(value.to_string()),
+    }
+}
+
+/// Takes the next `len` bytes from the input.
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], RpcClientError> {
+    if input.len() < len {
+        return Err(RpcClientError::Decode("unexpected end of data".to_string()))
+    }
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+    Ok(bytes)
+}
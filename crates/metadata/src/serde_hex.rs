@@ -0,0 +1,137 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hex encoding of the byte strings of ink! metadata.
+//!
+//! Provides the subset of `impl_serde::serialize` used by this crate without
+//! requiring the standard library so that metadata can be read in `no_std`
+//! environments.
+
+use core::fmt;
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+
+/// The lower case hex digits.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// An error encountered upon decoding a hex string.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FromHexError {
+    /// The hex string has an odd number of digits.
+    InvalidLength,
+    /// The hex string contains a character that is not a hex digit.
+    InvalidCharacter {
+        /// The invalid character.
+        character: char,
+        /// The index of the invalid character without the `0x` prefix.
+        index: usize,
+    },
+}
+
+impl fmt::Display for FromHexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "invalid hex string length"),
+            Self::InvalidCharacter { character, index } => {
+                write!(
+                    f,
+                    "invalid hex character {:?} at index {}",
+                    character, index
+                )
+            }
+        }
+    }
+}
+
+/// Encodes the given bytes as lower case hex string with `0x` prefix.
+pub fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 + 2 * bytes.len());
+    hex.push_str("0x");
+    for byte in bytes {
+        hex.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+        hex.push(char::from(HEX_DIGITS[usize::from(byte & 0x0F)]));
+    }
+    hex
+}
+
+/// Decodes the given hex string with optional `0x` prefix.
+///
+/// # Errors
+///
+/// If the hex string has an odd number of digits or contains non hex digits.
+pub fn from_hex(hex: &str) -> Result<Vec<u8>, FromHexError> {
+    let digits = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+    if digits.len() % 2 != 0 {
+        return Err(FromHexError::InvalidLength)
+    }
+    let digit = |index: usize| {
+        let character = char::from(digits[index]);
+        character
+            .to_digit(16)
+            .map(|digit| digit as u8)
+            .ok_or(FromHexError::InvalidCharacter { character, index })
+    };
+    (0..digits.len())
+        .step_by(2)
+        .map(|index| Ok((digit(index)? << 4) | digit(index + 1)?))
+        .collect()
+}
+
+/// Serializes the given bytes as lower case hex string with `0x` prefix.
+pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&to_hex(bytes))
+}
+
+/// Deserializes a hex string with optional `0x` prefix into the given bytes.
+///
+/// # Errors
+///
+/// If the hex string is malformed or does not decode to exactly as many
+/// bytes as given.
+pub fn deserialize_exact<'de, D>(
+    deserializer: D,
+    bytes: &mut [u8],
+) -> Result<(), D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct Visitor<'a> {
+        bytes: &'a mut [u8],
+    }
+
+    impl<'a, 'b> serde::de::Visitor<'b> for Visitor<'a> {
+        type Value = ();
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "hex string of {} bytes", self.bytes.len())
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            let decoded = from_hex(v).map_err(E::custom)?;
+            if decoded.len() != self.bytes.len() {
+                return Err(E::invalid_length(decoded.len(), &self))
+            }
+            let Self { bytes } = self;
+            bytes.copy_from_slice(&decoded);
+            Ok(())
+        }
+    }
+
+    deserializer.deserialize_str(Visitor { bytes })
+}
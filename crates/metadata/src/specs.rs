@@ -54,6 +54,22 @@ pub struct ContractSpec<F: Form = MetaForm> {
     events: Vec<EventSpec<F>>,
     /// The contract documentation.
     docs: Vec<F::String>,
+    /// The language level errors that can occur upon dispatch.
+    #[serde(rename = "langError", default, skip_serializing_if = "Option::is_none")]
+    lang_error: Option<LangErrorSpec<F>>,
+    /// The trait definitions implemented by the contract.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    traits: Vec<TraitSpec<F>>,
+    /// The fingerprint of the dispatch table of the contract code.
+    #[serde(
+        rename = "codeFingerprint",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    code_fingerprint: Option<CodeFingerprint>,
+    /// The contract features that were enabled for the compiled contract.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    features: Vec<F::String>,
 }
 
 impl IntoCompact for ContractSpec {
@@ -77,6 +93,16 @@ impl IntoCompact for ContractSpec {
                 .map(|event| event.into_compact(registry))
                 .collect::<Vec<_>>(),
             docs: registry.map_into_compact(self.docs),
+            lang_error: self
+                .lang_error
+                .map(|lang_error| lang_error.into_compact(registry)),
+            traits: self
+                .traits
+                .into_iter()
+                .map(|trait_spec| trait_spec.into_compact(registry))
+                .collect::<Vec<_>>(),
+            code_fingerprint: self.code_fingerprint,
+            features: registry.map_into_compact(self.features),
         }
     }
 }
@@ -95,6 +121,11 @@ where
         &self.messages
     }
 
+    /// Returns the external messages of the contract mutably.
+    pub(crate) fn messages_mut(&mut self) -> &mut [MessageSpec<F>] {
+        &mut self.messages
+    }
+
     /// Returns the events of the contract.
     pub fn events(&self) -> &[EventSpec<F>] {
         &self.events
@@ -104,6 +135,26 @@ where
     pub fn docs(&self) -> &[F::String] {
         &self.docs
     }
+
+    /// Returns the language level errors that can occur upon dispatch.
+    pub fn lang_error(&self) -> Option<&LangErrorSpec<F>> {
+        self.lang_error.as_ref()
+    }
+
+    /// Returns the trait definitions implemented by the contract.
+    pub fn traits(&self) -> &[TraitSpec<F>] {
+        &self.traits
+    }
+
+    /// Returns the fingerprint of the dispatch table of the contract code if any.
+    pub fn code_fingerprint(&self) -> Option<&CodeFingerprint> {
+        self.code_fingerprint.as_ref()
+    }
+
+    /// Returns the contract features that were enabled for the compiled contract.
+    pub fn features(&self) -> &[F::String] {
+        &self.features
+    }
 }
 
 /// The message builder is ready to finalize construction.
@@ -181,6 +232,60 @@ impl<S> ContractSpecBuilder<S> {
             ..self
         }
     }
+
+    /// Sets the language level errors of the contract specification.
+    pub fn lang_error(self, lang_error: LangErrorSpec) -> Self {
+        debug_assert!(self.spec.lang_error.is_none());
+        Self {
+            spec: ContractSpec {
+                lang_error: Some(lang_error),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the implemented trait definitions of the contract specification.
+    pub fn traits<T>(self, traits: T) -> Self
+    where
+        T: IntoIterator<Item = TraitSpec>,
+    {
+        debug_assert!(self.spec.traits.is_empty());
+        Self {
+            spec: ContractSpec {
+                traits: traits.into_iter().collect::<Vec<_>>(),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the fingerprint of the dispatch table of the contract code.
+    pub fn code_fingerprint(self, fingerprint: [u8; 32]) -> Self {
+        debug_assert!(self.spec.code_fingerprint.is_none());
+        Self {
+            spec: ContractSpec {
+                code_fingerprint: Some(fingerprint.into()),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the enabled contract features of the contract specification.
+    pub fn features<I>(self, features: I) -> Self
+    where
+        I: IntoIterator<Item = &'static str>,
+    {
+        debug_assert!(self.spec.features.is_empty());
+        Self {
+            spec: ContractSpec {
+                features: features.into_iter().collect::<Vec<_>>(),
+                ..self.spec
+            },
+            ..self
+        }
+    }
 }
 
 impl ContractSpecBuilder<Valid> {
@@ -207,6 +312,10 @@ impl ContractSpec {
                 messages: Vec::new(),
                 events: Vec::new(),
                 docs: Vec::new(),
+                lang_error: None,
+                traits: Vec::new(),
+                code_fingerprint: None,
+                features: Vec::new(),
             },
             marker: PhantomData,
         }
@@ -214,6 +323,13 @@ impl ContractSpec {
 }
 
 /// Describes a constructor of a contract.
+///
+/// # Note
+///
+/// Unlike [`MessageSpec`] a constructor spec carries no `mutates` flag.
+/// Every ink! constructor writes the initial contract storage and thus
+/// always mutates it, so the flag would carry no information for off-chain
+/// consumers. Deployment UIs should treat every constructor as mutating.
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(bound(
     serialize = "F::Type: Serialize, F::String: Serialize",
@@ -226,6 +342,10 @@ pub struct ConstructorSpec<F: Form = MetaForm> {
     pub name: Vec<F::String>,
     /// The selector hash of the message.
     pub selector: Selector,
+    /// If the constructor accepts any `value` from the caller.
+    pub payable: bool,
+    /// If the constructor is the default for off-chain consumers (e.g UIs).
+    pub default: bool,
     /// The parameters of the deploy handler.
     pub args: Vec<MessageParamSpec<F>>,
     /// The deploy handler documentation.
@@ -239,6 +359,8 @@ impl IntoCompact for ConstructorSpec {
         ConstructorSpec {
             name: registry.map_into_compact(self.name),
             selector: self.selector,
+            payable: self.payable,
+            default: self.default,
             args: self
                 .args
                 .into_iter()
@@ -265,6 +387,16 @@ where
         &self.selector
     }
 
+    /// Returns true if the constructor accepts any `value` from the caller.
+    pub fn payable(&self) -> bool {
+        self.payable
+    }
+
+    /// Returns true if the constructor is marked as the default constructor.
+    pub fn default(&self) -> bool {
+        self.default
+    }
+
     /// Returns the parameters of the deploy handler.
     pub fn args(&self) -> &[MessageParamSpec<F>] {
         &self.args
@@ -283,20 +415,22 @@ where
 /// Some of the fields are guarded by a type-state pattern to
 /// fail at compile-time instead of at run-time. This is useful
 /// to better debug code-gen macros.
-pub struct ConstructorSpecBuilder<Selector> {
+pub struct ConstructorSpecBuilder<Selector, IsPayable> {
     spec: ConstructorSpec,
-    marker: PhantomData<fn() -> Selector>,
+    marker: PhantomData<fn() -> (Selector, IsPayable)>,
 }
 
 impl ConstructorSpec {
     /// Creates a new constructor spec builder.
     fn from_name_segments(
         segments: Vec<&'static str>,
-    ) -> ConstructorSpecBuilder<Missing<state::Selector>> {
+    ) -> ConstructorSpecBuilder<Missing<state::Selector>, Missing<state::IsPayable>> {
         ConstructorSpecBuilder {
             spec: Self {
                 name: segments,
                 selector: Selector::default(),
+                payable: false,
+                default: false,
                 args: Vec::new(),
                 docs: Vec::new(),
             },
@@ -307,7 +441,7 @@ impl ConstructorSpec {
     /// Creates a new constructor spec builder.
     pub fn from_name(
         name: &'static str,
-    ) -> ConstructorSpecBuilder<Missing<state::Selector>> {
+    ) -> ConstructorSpecBuilder<Missing<state::Selector>, Missing<state::IsPayable>> {
         Self::from_name_segments(vec![name])
     }
 
@@ -315,14 +449,17 @@ impl ConstructorSpec {
     pub fn from_trait_and_name(
         trait_name: &'static str,
         constructor_name: &'static str,
-    ) -> ConstructorSpecBuilder<Missing<state::Selector>> {
+    ) -> ConstructorSpecBuilder<Missing<state::Selector>, Missing<state::IsPayable>> {
         Self::from_name_segments(vec![trait_name, constructor_name])
     }
 }
 
-impl ConstructorSpecBuilder<Missing<state::Selector>> {
+impl<P> ConstructorSpecBuilder<Missing<state::Selector>, P> {
     /// Sets the function selector of the message.
-    pub fn selector(self, selector: [u8; 4]) -> ConstructorSpecBuilder<state::Selector> {
+    pub fn selector(
+        self,
+        selector: [u8; 4],
+    ) -> ConstructorSpecBuilder<state::Selector, P> {
         ConstructorSpecBuilder {
             spec: ConstructorSpec {
                 selector: selector.into(),
@@ -333,7 +470,30 @@ impl ConstructorSpecBuilder<Missing<state::Selector>> {
     }
 }
 
-impl<S> ConstructorSpecBuilder<S> {
+impl<S> ConstructorSpecBuilder<S, Missing<state::IsPayable>> {
+    /// Sets if the constructor is payable, thus accepting value for the caller.
+    pub fn payable(
+        self,
+        is_payable: bool,
+    ) -> ConstructorSpecBuilder<S, state::IsPayable> {
+        ConstructorSpecBuilder {
+            spec: ConstructorSpec {
+                payable: is_payable,
+                ..self.spec
+            },
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S, P> ConstructorSpecBuilder<S, P> {
+    /// Sets if the constructor is marked as the default constructor.
+    pub fn default(self, is_default: bool) -> Self {
+        let mut this = self;
+        this.spec.default = is_default;
+        this
+    }
+
     /// Sets the input arguments of the message specification.
     pub fn args<A>(self, args: A) -> Self
     where
@@ -357,7 +517,7 @@ impl<S> ConstructorSpecBuilder<S> {
     }
 }
 
-impl ConstructorSpecBuilder<state::Selector> {
+impl ConstructorSpecBuilder<state::Selector, state::IsPayable> {
     /// Finishes construction of the constructor.
     pub fn done(self) -> ConstructorSpec {
         self.spec
@@ -389,6 +549,17 @@ pub struct MessageSpec<F: Form = MetaForm> {
     return_type: ReturnTypeSpec<F>,
     /// The message documentation.
     docs: Vec<F::String>,
+    /// The hash of the message signature.
+    ///
+    /// Only set for the messages of an `InkProject`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature_hash: Option<SignatureHash>,
+    /// The permissions required by callers of the message.
+    ///
+    /// Allows wallets to warn users about calls that are likely to fail due to
+    /// missing authorization without executing a dry run.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    permissions: Vec<F::String>,
 }
 
 /// Type state for builders to tell that some mandatory state has not yet been set
@@ -428,6 +599,8 @@ impl MessageSpec {
                 args: Vec::new(),
                 return_type: ReturnTypeSpec::new(None),
                 docs: Vec::new(),
+                signature_hash: None,
+                permissions: Vec::new(),
             },
             marker: PhantomData,
         }
@@ -500,6 +673,24 @@ where
     pub fn docs(&self) -> &[F::String] {
         &self.docs
     }
+
+    /// Returns the hash of the message signature if any.
+    ///
+    /// The hash covers the selector, the argument types and the return type
+    /// of the message and only changes if any of them changes.
+    pub fn signature_hash(&self) -> Option<&SignatureHash> {
+        self.signature_hash.as_ref()
+    }
+
+    /// Returns the permissions required by callers of the message.
+    pub fn permissions(&self) -> &[F::String] {
+        &self.permissions
+    }
+
+    /// Sets the hash of the message signature.
+    pub(crate) fn set_signature_hash(&mut self, hash: SignatureHash) {
+        self.signature_hash = Some(hash);
+    }
 }
 
 /// A builder for messages.
@@ -595,7 +786,18 @@ impl<S, M, P, R> MessageSpecBuilder<S, M, P, R> {
     {
         let mut this = self;
         debug_assert!(this.spec.docs.is_empty());
-        this.spec.docs = docs.into_iter().collect::<Vec<_>>();
+        this.spec.docs = docs.into_iter().map(str::trim).collect::<Vec<_>>();
+        this
+    }
+
+    /// Sets the permissions required by callers of the message.
+    pub fn permissions<P>(self, permissions: P) -> Self
+    where
+        P: IntoIterator<Item = &'static str>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.permissions.is_empty());
+        this.spec.permissions = permissions.into_iter().collect::<Vec<_>>();
         this
     }
 }
@@ -625,6 +827,8 @@ impl IntoCompact for MessageSpec {
                 .collect::<Vec<_>>(),
             return_type: self.return_type.into_compact(registry),
             docs: registry.map_into_compact(self.docs),
+            signature_hash: self.signature_hash,
+            permissions: registry.map_into_compact(self.permissions),
         }
     }
 }
@@ -642,6 +846,17 @@ pub struct EventSpec<F: Form = MetaForm> {
     args: Vec<EventParamSpec<F>>,
     /// The event documentation.
     docs: Vec<F::String>,
+    /// If the event is anonymous.
+    ///
+    /// Anonymous events do not include their signature in their topics.
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    anonymous: bool,
+    /// The signature of the event if it is not `Contract::Event`.
+    ///
+    /// This is the case for events defined via `#[ink::event]` whose signature
+    /// is their optionally namespaced name, e.g. `PSP22::Transfer`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature: Option<F::String>,
 }
 
 /// An event specification builder.
@@ -668,7 +883,21 @@ impl EventSpecBuilder {
     {
         let mut this = self;
         debug_assert!(this.spec.docs.is_empty());
-        this.spec.docs = docs.into_iter().collect::<Vec<_>>();
+        this.spec.docs = docs.into_iter().map(str::trim).collect::<Vec<_>>();
+        this
+    }
+
+    /// Sets if the event is anonymous.
+    pub fn anonymous(self, anonymous: bool) -> Self {
+        let mut this = self;
+        this.spec.anonymous = anonymous;
+        this
+    }
+
+    /// Sets the signature of the event if it is not `Contract::Event`.
+    pub fn signature(self, signature: &'static str) -> Self {
+        let mut this = self;
+        this.spec.signature = Some(signature);
         this
     }
 
@@ -690,6 +919,10 @@ impl IntoCompact for EventSpec {
                 .map(|arg| arg.into_compact(registry))
                 .collect::<Vec<_>>(),
             docs: registry.map_into_compact(self.docs),
+            anonymous: self.anonymous,
+            signature: self
+                .signature
+                .map(|signature| signature.into_compact(registry)),
         }
     }
 }
@@ -702,6 +935,8 @@ impl EventSpec {
                 name,
                 args: Vec::new(),
                 docs: Vec::new(),
+                anonymous: false,
+                signature: None,
             },
         }
     }
@@ -725,6 +960,299 @@ where
     pub fn docs(&self) -> &[F::String] {
         &self.docs
     }
+
+    /// Returns `true` if the event is anonymous.
+    pub fn is_anonymous(&self) -> bool {
+        self.anonymous
+    }
+
+    /// Returns the signature of the event if it is not `Contract::Event`.
+    pub fn signature(&self) -> Option<&F::String> {
+        self.signature.as_ref()
+    }
+}
+
+/// Describes the language level errors that can occur upon dispatch.
+///
+/// Dispatch errors happen before the user provided code of a constructor
+/// or message is executed, e.g. if the input could not be decoded.
+/// They are the same for all ink! smart contracts.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+pub struct LangErrorSpec<F: Form = MetaForm> {
+    /// The type of the language level error.
+    #[serde(rename = "type")]
+    ty: TypeSpec<F>,
+    /// The variants of the language level error.
+    variants: Vec<LangErrorVariantSpec<F>>,
+}
+
+/// A language level error specification builder.
+pub struct LangErrorSpecBuilder {
+    spec: LangErrorSpec,
+}
+
+impl LangErrorSpecBuilder {
+    /// Sets the variants of the language level error specification.
+    pub fn variants<V>(self, variants: V) -> Self
+    where
+        V: IntoIterator<Item = LangErrorVariantSpec>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.variants.is_empty());
+        this.spec.variants = variants.into_iter().collect::<Vec<_>>();
+        this
+    }
+
+    /// Finalizes building the language level error specification.
+    pub fn done(self) -> LangErrorSpec {
+        self.spec
+    }
+}
+
+impl IntoCompact for LangErrorSpec {
+    type Output = LangErrorSpec<CompactForm>;
+
+    fn into_compact(self, registry: &mut Registry) -> Self::Output {
+        LangErrorSpec {
+            ty: self.ty.into_compact(registry),
+            variants: self
+                .variants
+                .into_iter()
+                .map(|variant| variant.into_compact(registry))
+                .collect::<Vec<_>>(),
+        }
+    }
+}
+
+impl LangErrorSpec {
+    /// Creates a new language level error specification builder.
+    pub fn new(ty: TypeSpec) -> LangErrorSpecBuilder {
+        LangErrorSpecBuilder {
+            spec: Self {
+                ty,
+                variants: Vec::new(),
+            },
+        }
+    }
+}
+
+impl<F> LangErrorSpec<F>
+where
+    F: Form,
+{
+    /// Returns the type of the language level error.
+    pub fn ty(&self) -> &TypeSpec<F> {
+        &self.ty
+    }
+
+    /// Returns the variants of the language level error.
+    pub fn variants(&self) -> &[LangErrorVariantSpec<F>] {
+        &self.variants
+    }
+}
+
+/// Describes a single variant of the language level error.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+pub struct LangErrorVariantSpec<F: Form = MetaForm> {
+    /// The name of the variant.
+    name: F::String,
+    /// The code returned by the contract execution upon encountering the error.
+    code: u32,
+    /// The variant documentation.
+    docs: Vec<F::String>,
+}
+
+/// A language level error variant specification builder.
+pub struct LangErrorVariantSpecBuilder {
+    spec: LangErrorVariantSpec,
+}
+
+impl LangErrorVariantSpecBuilder {
+    /// Sets the documentation of the language level error variant.
+    pub fn docs<D>(self, docs: D) -> Self
+    where
+        D: IntoIterator<Item = &'static str>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.docs.is_empty());
+        this.spec.docs = docs.into_iter().map(str::trim).collect::<Vec<_>>();
+        this
+    }
+
+    /// Finalizes building the language level error variant specification.
+    pub fn done(self) -> LangErrorVariantSpec {
+        self.spec
+    }
+}
+
+impl IntoCompact for LangErrorVariantSpec {
+    type Output = LangErrorVariantSpec<CompactForm>;
+
+    fn into_compact(self, registry: &mut Registry) -> Self::Output {
+        LangErrorVariantSpec {
+            name: self.name.into_compact(registry),
+            code: self.code,
+            docs: registry.map_into_compact(self.docs),
+        }
+    }
+}
+
+impl LangErrorVariantSpec {
+    /// Creates a new language level error variant specification builder.
+    pub fn new(name: &'static str, code: u32) -> LangErrorVariantSpecBuilder {
+        LangErrorVariantSpecBuilder {
+            spec: Self {
+                name,
+                code,
+                docs: Vec::new(),
+            },
+        }
+    }
+}
+
+impl<F> LangErrorVariantSpec<F>
+where
+    F: Form,
+{
+    /// Returns the name of the variant.
+    pub fn name(&self) -> &F::String {
+        &self.name
+    }
+
+    /// Returns the code returned by the contract execution upon the error.
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// Returns the variant documentation.
+    pub fn docs(&self) -> &[F::String] {
+        &self.docs
+    }
+}
+
+/// Describes a trait definition implemented by the contract.
+///
+/// This allows tooling to detect the interfaces supported by a contract
+/// without resorting to matching the selectors of its messages.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+pub struct TraitSpec<F: Form = MetaForm> {
+    /// The name of the trait definition.
+    name: F::String,
+    /// The namespace of the trait implementation if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    namespace: Option<F::String>,
+    /// The version of the trait definition if it is versioned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    version: Option<u32>,
+    /// The selectors of the constructors and messages of the trait.
+    selectors: Vec<Selector>,
+}
+
+/// A trait definition specification builder.
+pub struct TraitSpecBuilder {
+    spec: TraitSpec,
+}
+
+impl TraitSpecBuilder {
+    /// Sets the namespace of the trait implementation.
+    pub fn namespace(self, namespace: &'static str) -> Self {
+        let mut this = self;
+        debug_assert!(this.spec.namespace.is_none());
+        this.spec.namespace = Some(namespace);
+        this
+    }
+
+    /// Sets the version of the trait definition.
+    ///
+    /// The default version `1` of unversioned trait definitions is omitted.
+    pub fn version(self, version: u32) -> Self {
+        let mut this = self;
+        debug_assert!(this.spec.version.is_none());
+        this.spec.version = Some(version).filter(|version| *version != 1);
+        this
+    }
+
+    /// Sets the selectors of the constructors and messages of the trait.
+    pub fn selectors<S>(self, selectors: S) -> Self
+    where
+        S: IntoIterator<Item = [u8; 4]>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.selectors.is_empty());
+        this.spec.selectors = selectors.into_iter().map(Into::into).collect::<Vec<_>>();
+        this
+    }
+
+    /// Finalizes building the trait definition specification.
+    pub fn done(self) -> TraitSpec {
+        self.spec
+    }
+}
+
+impl IntoCompact for TraitSpec {
+    type Output = TraitSpec<CompactForm>;
+
+    fn into_compact(self, registry: &mut Registry) -> Self::Output {
+        TraitSpec {
+            name: self.name.into_compact(registry),
+            namespace: self
+                .namespace
+                .map(|namespace| namespace.into_compact(registry)),
+            version: self.version,
+            selectors: self.selectors,
+        }
+    }
+}
+
+impl TraitSpec {
+    /// Creates a new trait definition specification builder.
+    pub fn new(name: &'static str) -> TraitSpecBuilder {
+        TraitSpecBuilder {
+            spec: Self {
+                name,
+                namespace: None,
+                version: None,
+                selectors: Vec::new(),
+            },
+        }
+    }
+}
+
+impl<F> TraitSpec<F>
+where
+    F: Form,
+{
+    /// Returns the name of the trait definition.
+    pub fn name(&self) -> &F::String {
+        &self.name
+    }
+
+    /// Returns the namespace of the trait implementation if any.
+    pub fn namespace(&self) -> Option<&F::String> {
+        self.namespace.as_ref()
+    }
+
+    /// Returns the version of the trait definition if it is versioned.
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+
+    /// Returns the selectors of the constructors and messages of the trait.
+    pub fn selectors(&self) -> &[Selector] {
+        &self.selectors
+    }
 }
 
 /// The 4 byte selector to identify constructors and messages
@@ -746,7 +1274,7 @@ impl<'de> serde::Deserialize<'de> for Selector {
         D: serde::Deserializer<'de>,
     {
         let mut arr = [0; 4];
-        serde_hex::deserialize_check_len(d, serde_hex::ExpectedLen::Exact(&mut arr[..]))?;
+        serde_hex::deserialize_exact(d, &mut arr)?;
         Ok(arr.into())
     }
 }
@@ -758,6 +1286,70 @@ impl Selector {
     }
 }
 
+/// The 32 bytes hash of a message signature.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, derive_more::From)]
+pub struct SignatureHash([u8; 32]);
+
+impl serde::Serialize for SignatureHash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde_hex::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SignatureHash {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut arr = [0; 32];
+        serde_hex::deserialize_exact(d, &mut arr)?;
+        Ok(arr.into())
+    }
+}
+
+impl SignatureHash {
+    /// Returns the underlying hash bytes.
+    pub fn to_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// The 32 bytes fingerprint of the dispatch table of a contract code.
+///
+/// See `ink_lang::reflect::ContractFingerprint` for how it is computed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, derive_more::From)]
+pub struct CodeFingerprint([u8; 32]);
+
+impl serde::Serialize for CodeFingerprint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde_hex::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CodeFingerprint {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut arr = [0; 32];
+        serde_hex::deserialize_exact(d, &mut arr)?;
+        Ok(arr.into())
+    }
+}
+
+impl CodeFingerprint {
+    /// Returns the underlying fingerprint bytes.
+    pub fn to_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 /// Describes the syntactical name of a type at a given type position.
 ///
 /// This is important when trying to work with type aliases.
@@ -991,7 +1583,7 @@ impl EventParamSpecBuilder {
         debug_assert!(self.spec.docs.is_empty());
         Self {
             spec: EventParamSpec {
-                docs: docs.into_iter().collect::<Vec<_>>(),
+                docs: docs.into_iter().map(str::trim).collect::<Vec<_>>(),
                 ..self.spec
             },
         }
@@ -1069,6 +1661,8 @@ pub struct MessageParamSpec<F: Form = MetaForm> {
     /// The type of the parameter.
     #[serde(rename = "type")]
     ty: TypeSpec<F>,
+    /// The documentation associated with the parameter.
+    docs: Vec<F::String>,
 }
 
 impl IntoCompact for MessageParamSpec {
@@ -1078,6 +1672,7 @@ impl IntoCompact for MessageParamSpec {
         MessageParamSpec {
             name: self.name.into_compact(registry),
             ty: self.ty.into_compact(registry),
+            docs: registry.map_into_compact(self.docs),
         }
     }
 }
@@ -1090,6 +1685,7 @@ impl MessageParamSpec {
                 name,
                 // Uses `()` type by default.
                 ty: TypeSpec::new::<()>(),
+                docs: Vec::new(),
             },
         }
     }
@@ -1108,6 +1704,11 @@ where
     pub fn ty(&self) -> &TypeSpec<F> {
         &self.ty
     }
+
+    /// Returns the documentation associated with the parameter.
+    pub fn docs(&self) -> &[F::String] {
+        &self.docs
+    }
 }
 
 /// Used to construct a message parameter specification.
@@ -1124,6 +1725,17 @@ impl MessageParamSpecBuilder {
         this
     }
 
+    /// Sets the documentation of the message parameter.
+    pub fn docs<D>(self, docs: D) -> Self
+    where
+        D: IntoIterator<Item = &'static str>,
+    {
+        debug_assert!(self.spec.docs.is_empty());
+        let mut this = self;
+        this.spec.docs = docs.into_iter().map(str::trim).collect::<Vec<_>>();
+        this
+    }
+
     /// Finishes construction of the message parameter.
     pub fn done(self) -> MessageParamSpec {
         self.spec
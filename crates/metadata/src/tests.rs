@@ -20,12 +20,63 @@ use scale_info::{
 };
 use serde_json::json;
 
+/// Returns an ink! project with a single `new` constructor and the given messages
+/// whose storage is a single cell of type `T` at the key with all bytes set to `key`.
+///
+/// The contract specification is further adjusted via `spec`, e.g. to add events.
+fn test_project<T, F>(key: u8, messages: Vec<MessageSpec>, spec: F) -> InkProject
+where
+    T: scale_info::TypeInfo + 'static,
+    F: FnOnce(ContractSpecBuilder<specs::Valid>) -> ContractSpecBuilder<specs::Valid>,
+{
+    let layout = layout::CellLayout::new::<T>(layout::LayoutKey::from(
+        ink_primitives::Key::from([key; 32]),
+    ));
+    let builder = ContractSpec::new()
+        .constructors(vec![ConstructorSpec::from_name("new")
+            .selector([94u8, 189u8, 136u8, 214u8])
+            .payable(false)
+            .done()])
+        .messages(messages);
+    InkProject::new(layout, spec(builder).done())
+}
+
+/// Returns an ink! message taking a single argument of type `T`.
+fn test_message<T>(name: &'static str, selector: [u8; 4]) -> MessageSpec
+where
+    T: scale_info::TypeInfo + 'static,
+{
+    MessageSpec::from_name(name)
+        .selector(selector)
+        .mutates(false)
+        .payable(false)
+        .args(vec![MessageParamSpec::new("value")
+            .of_type(TypeSpec::with_name_str::<T>("Value"))
+            .done()])
+        .returns(ReturnTypeSpec::new(None))
+        .done()
+}
+
+/// Returns an ink! message without arguments returning a value of type `T`.
+fn test_getter<T>(name: &'static str, selector: [u8; 4]) -> MessageSpec
+where
+    T: scale_info::TypeInfo + 'static,
+{
+    MessageSpec::from_name(name)
+        .selector(selector)
+        .mutates(false)
+        .payable(false)
+        .returns(ReturnTypeSpec::new(TypeSpec::with_name_str::<T>("Value")))
+        .done()
+}
+
 #[test]
 fn spec_constructor_selector_must_serialize_to_hex() {
     // given
     let name = "foo";
     let cs = ConstructorSpec::from_name(name)
         .selector(123_456_789u32.to_be_bytes())
+        .payable(true)
         .done();
     let mut registry = Registry::new();
     let compact_spec = cs.into_compact(&mut registry);
@@ -41,6 +92,8 @@ fn spec_constructor_selector_must_serialize_to_hex() {
         json!({
             "name": ["foo"],
             "selector": "0x075bcd15",
+            "payable": true,
+            "default": false,
             "args": [],
             "docs": []
         })
@@ -55,15 +108,19 @@ fn spec_contract_json() {
         .constructors(vec![
             ConstructorSpec::from_name("new")
                 .selector([94u8, 189u8, 136u8, 214u8])
+                .payable(true)
                 .args(vec![MessageParamSpec::new("init_value")
                     .of_type(TypeSpec::with_name_segs::<i32, _>(
                         vec!["i32"].into_iter().map(AsRef::as_ref),
                     ))
+                    .docs(vec![" The initial value."])
                     .done()])
                 .docs(Vec::new())
                 .done(),
             ConstructorSpec::from_name("default")
                 .selector([2u8, 34u8, 255u8, 24u8])
+                .payable(false)
+                .default(true)
                 .args(Vec::new())
                 .docs(Vec::new())
                 .done(),
@@ -115,18 +172,23 @@ fn spec_contract_json() {
                                     "i32"
                                 ],
                                 "type": 1
-                            }
+                            },
+                            "docs": ["The initial value."]
                         }
                     ],
                     "docs": [],
                     "name": ["new"],
-                    "selector": "0x5ebd88d6"
+                    "selector": "0x5ebd88d6",
+                    "payable": true,
+                    "default": false
                 },
                 {
                     "args": [],
                     "docs": [],
                     "name": ["default"],
-                    "selector": "0x0222ff18"
+                    "selector": "0x0222ff18",
+                    "payable": false,
+                    "default": true
                 }
             ],
             "docs": [],
@@ -141,7 +203,8 @@ fn spec_contract_json() {
                                     "i32"
                                 ],
                                 "type": 1
-                            }
+                            },
+                            "docs": []
                         }
                     ],
                     "docs": [],
@@ -176,6 +239,7 @@ fn trim_docs() {
     let name = "foo";
     let cs = ConstructorSpec::from_name(name)
         .selector(123_456_789u32.to_be_bytes())
+        .payable(false)
         .docs(vec![" foobar      "])
         .done();
     let mut registry = Registry::new();
@@ -192,9 +256,774 @@ fn trim_docs() {
         json!({
             "name": ["foo"],
             "selector": "0x075bcd15",
+            "payable": false,
+            "default": false,
             "args": [],
             "docs": ["foobar"]
         })
     );
     assert_eq!(deserialized, compact_spec);
 }
+
+#[test]
+fn validate_accepts_generated_metadata() {
+    // given
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![test_getter::<i32>("get", [37u8, 68u8, 74u8, 254u8])],
+        |spec| spec,
+    );
+    let json = serde_json::to_string(&project).unwrap();
+
+    // when
+    let project = validate(&json).unwrap();
+
+    // then
+    assert_eq!(serde_json::to_string(&project).unwrap(), json);
+}
+
+#[test]
+fn validate_reports_all_errors() {
+    // given
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![test_getter::<i32>("get", [37u8, 68u8, 74u8, 254u8])],
+        |spec| spec,
+    );
+    let mut json = serde_json::to_value(&project).unwrap();
+    json["spec"]["constructors"][0]["selector"] = json!("0x5ebd");
+    json["spec"]["messages"][0]["returnType"]["type"] = json!(42);
+    json["storage"]["cell"]["ty"] = json!(0);
+
+    // when
+    let errors = validate(&json.to_string()).unwrap_err();
+
+    // then
+    assert_eq!(
+        errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        vec![
+            "spec.constructors[0].selector: \
+             expected a 0x prefixed hex encoded 4 bytes selector",
+            "spec.messages[0].returnType.type: \
+             type 42 is missing from the type registry of 1 types",
+            "storage.cell.ty: type 0 is missing from the type registry of 1 types",
+        ]
+    );
+}
+
+#[test]
+fn validate_rejects_duplicate_selectors() {
+    // given
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![test_getter::<i32>("get", [37u8, 68u8, 74u8, 254u8])],
+        |spec| spec,
+    );
+    let mut json = serde_json::to_value(&project).unwrap();
+    let message = json["spec"]["messages"][0].clone();
+    json["spec"]["messages"]
+        .as_array_mut()
+        .unwrap()
+        .push(message);
+
+    // when
+    let errors = validate(&json.to_string()).unwrap_err();
+
+    // then
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path(), "spec.messages[1].selector");
+    assert_eq!(errors[0].message(), "duplicate selector 0x25444afe");
+}
+
+#[test]
+fn validate_agrees_with_metadata_schema() {
+    fn key() -> serde_json::Value {
+        json!(format!("0x{}", "00".repeat(32)))
+    }
+    fn cell() -> serde_json::Value {
+        json!({ "cell": { "key": key(), "ty": 1 } })
+    }
+    fn hash(hasher: &str, prefix: &str) -> serde_json::Value {
+        json!({
+            "hash": {
+                "offset": key(),
+                "strategy": { "hasher": hasher, "prefix": prefix, "postfix": "" },
+                "layout": cell(),
+            }
+        })
+    }
+
+    // given
+    let schema: serde_json::Value = serde_json::from_str(METADATA_SCHEMA).unwrap();
+    let schema = jsonschema::JSONSchema::compile(&schema, None).unwrap();
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![test_getter::<i32>("get", [37u8, 68u8, 74u8, 254u8])],
+        |spec| spec,
+    );
+    let valid = serde_json::to_value(&project).unwrap();
+    // Duplicate selectors and type identifiers beyond the type registry
+    // are not covered since the schema cannot express them.
+    let mutations: Vec<(&str, Box<dyn Fn(&mut serde_json::Value)>)> = vec![
+        ("unchanged", Box::new(|_| ())),
+        (
+            "no return type",
+            Box::new(|json| json["spec"]["messages"][0]["returnType"] = json!(null)),
+        ),
+        (
+            "hash layout",
+            Box::new(|json| json["storage"] = hash("Blake2x256", "0x0102")),
+        ),
+        (
+            "struct layout",
+            Box::new(|json| {
+                json["storage"] = json!({
+                    "struct": {
+                        "fields": [
+                            { "name": "value", "layout": cell() },
+                            { "name": null, "layout": cell() },
+                        ]
+                    }
+                })
+            }),
+        ),
+        (
+            "short selector",
+            Box::new(|json| {
+                json["spec"]["constructors"][0]["selector"] = json!("0x5ebd")
+            }),
+        ),
+        (
+            "missing messages",
+            Box::new(|json| {
+                json["spec"].as_object_mut().unwrap().remove("messages");
+            }),
+        ),
+        (
+            "no messages",
+            Box::new(|json| json["spec"]["messages"] = json!([])),
+        ),
+        (
+            "non-boolean payable",
+            Box::new(|json| json["spec"]["constructors"][0]["payable"] = json!("yes")),
+        ),
+        (
+            "empty name",
+            Box::new(|json| json["spec"]["messages"][0]["name"] = json!([])),
+        ),
+        (
+            "non-string docs",
+            Box::new(|json| json["spec"]["docs"] = json!([1])),
+        ),
+        (
+            "type identifier zero",
+            Box::new(|json| json["storage"]["cell"]["ty"] = json!(0)),
+        ),
+        (
+            "short layout key",
+            Box::new(|json| json["storage"]["cell"]["key"] = json!("0x00")),
+        ),
+        (
+            "multiple layout kinds",
+            Box::new(|json| json["storage"]["array"] = json!({})),
+        ),
+        (
+            "unknown layout kind",
+            Box::new(|json| json["storage"] = json!({ "unknown": {} })),
+        ),
+        (
+            "type without definition",
+            Box::new(|json| {
+                json["types"][0].as_object_mut().unwrap().remove("def");
+            }),
+        ),
+        (
+            "type with multiple definitions",
+            Box::new(|json| json["types"][0]["def"]["tuple"] = json!([])),
+        ),
+        (
+            "unknown hasher",
+            Box::new(|json| json["storage"] = hash("Md5", "")),
+        ),
+        (
+            "odd hash prefix",
+            Box::new(|json| json["storage"] = hash("Sha2x256", "0x123")),
+        ),
+        (
+            "numeric struct field name",
+            Box::new(|json| {
+                json["storage"] = json!({
+                    "struct": { "fields": [{ "name": 1, "layout": cell() }] }
+                })
+            }),
+        ),
+        (
+            "unversioned trait",
+            Box::new(|json| {
+                json["spec"]["traits"] =
+                    json!([{ "name": "Flip", "version": 1, "selectors": [] }])
+            }),
+        ),
+        (
+            "negative error code",
+            Box::new(|json| {
+                json["spec"]["langError"] = json!({
+                    "type": { "type": 1, "displayName": ["DispatchError"] },
+                    "variants": [{ "name": "Unknown", "code": -1, "docs": [] }],
+                })
+            }),
+        ),
+        (
+            "short code fingerprint",
+            Box::new(|json| json["spec"]["codeFingerprint"] = json!("0x01")),
+        ),
+    ];
+
+    for (description, mutate) in mutations {
+        // when
+        let mut json = valid.clone();
+        mutate(&mut json);
+
+        // then
+        assert_eq!(
+            validate(&json.to_string()).is_ok(),
+            schema.is_valid(&json),
+            "validate and METADATA_SCHEMA disagree upon the {} case",
+            description,
+        );
+    }
+}
+
+#[test]
+fn spec_lang_error_json() {
+    // given
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![test_getter::<i32>("get", [37u8, 68u8, 74u8, 254u8])],
+        |spec| {
+            spec.lang_error(
+                LangErrorSpec::new(TypeSpec::with_name_str::<u32>("DispatchError"))
+                    .variants(vec![LangErrorVariantSpec::new("UnknownSelector", 1)
+                        .docs(vec![" The selector is unknown."])
+                        .done()])
+                    .done(),
+            )
+        },
+    );
+
+    // when
+    let json = serde_json::to_value(&project).unwrap();
+
+    // then
+    assert_eq!(
+        json["spec"]["langError"],
+        json!({
+            "type": {
+                "displayName": ["DispatchError"],
+                "type": 2
+            },
+            "variants": [
+                {
+                    "name": "UnknownSelector",
+                    "code": 1,
+                    "docs": ["The selector is unknown."]
+                }
+            ]
+        })
+    );
+    assert!(validate(&json.to_string()).is_ok());
+}
+
+#[test]
+fn spec_traits_json() {
+    // given
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![MessageSpec::from_trait_and_name("Flip", "flip")
+            .selector([37u8, 68u8, 74u8, 254u8])
+            .mutates(true)
+            .payable(false)
+            .returns(ReturnTypeSpec::new(None))
+            .done()],
+        |spec| {
+            spec.traits(vec![TraitSpec::new("Flip")
+                .namespace("flipper")
+                .selectors(vec![[37u8, 68u8, 74u8, 254u8]])
+                .done()])
+        },
+    );
+
+    // when
+    let json = serde_json::to_value(&project).unwrap();
+
+    // then
+    assert_eq!(
+        json["spec"]["traits"],
+        json!([
+            {
+                "name": "Flip",
+                "namespace": "flipper",
+                "selectors": ["0x25444afe"]
+            }
+        ])
+    );
+}
+
+#[test]
+fn spec_versioned_traits_json() {
+    // given
+    let traits = vec![
+        TraitSpec::new("Flip")
+            .version(1)
+            .selectors(vec![[37u8, 68u8, 74u8, 254u8]])
+            .done(),
+        TraitSpec::new("Flip")
+            .version(2)
+            .selectors(vec![[0xDE, 0xAD, 0xBE, 0xEF]])
+            .done(),
+    ];
+    let mut registry = Registry::new();
+
+    // when
+    let json = traits
+        .into_iter()
+        .map(|spec| serde_json::to_value(&spec.into_compact(&mut registry)).unwrap())
+        .collect::<Vec<_>>();
+
+    // then
+    assert_eq!(
+        json,
+        vec![
+            json!({
+                "name": "Flip",
+                "selectors": ["0x25444afe"]
+            }),
+            json!({
+                "name": "Flip",
+                "version": 2,
+                "selectors": ["0xdeadbeef"]
+            }),
+        ]
+    );
+}
+
+#[test]
+fn spec_code_fingerprint_json() {
+    // given
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![test_message::<bool>("flip", [37u8, 68u8, 74u8, 254u8])],
+        |spec| spec.code_fingerprint([0x01; 32]),
+    );
+
+    // when
+    let json = serde_json::to_value(&project).unwrap();
+
+    // then
+    assert_eq!(
+        json["spec"]["codeFingerprint"],
+        json!("0x0101010101010101010101010101010101010101010101010101010101010101")
+    );
+    let spec: ContractSpec<scale_info::form::CompactForm> =
+        serde_json::from_value(json["spec"].clone()).unwrap();
+    assert_eq!(spec.code_fingerprint().unwrap().to_bytes(), &[0x01; 32]);
+}
+
+#[test]
+fn spec_features_json() {
+    // given
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![test_message::<bool>("flip", [37u8, 68u8, 74u8, 254u8])],
+        |spec| spec.features(vec!["testnet", "debug"]),
+    );
+
+    // when
+    let json = serde_json::to_value(&project).unwrap();
+
+    // then
+    assert_eq!(json["spec"]["features"], json!(["testnet", "debug"]));
+    let spec: ContractSpec<scale_info::form::CompactForm> =
+        serde_json::from_value(json["spec"].clone()).unwrap();
+    assert_eq!(spec.features().len(), 2);
+}
+
+#[test]
+fn signature_hash_is_independent_of_type_ids() {
+    // given
+    let a = test_project::<i32, _>(
+        0x00,
+        vec![
+            test_message::<u8>("get", [0x01; 4]),
+            test_message::<bool>("set", [0x02; 4]),
+        ],
+        |spec| spec,
+    );
+    let b = test_project::<i32, _>(
+        0x00,
+        vec![
+            test_message::<bool>("set", [0x02; 4]),
+            test_message::<u8>("get", [0x01; 4]),
+        ],
+        |spec| spec,
+    );
+
+    // then
+    assert!(a.spec().messages()[0].signature_hash().is_some());
+    assert_eq!(
+        a.spec().messages()[0].signature_hash(),
+        b.spec().messages()[1].signature_hash(),
+    );
+    assert_ne!(
+        a.spec().messages()[0].signature_hash(),
+        a.spec().messages()[1].signature_hash(),
+    );
+    assert!(a.diff(&b).is_empty());
+}
+
+#[test]
+fn diff_reports_added_removed_and_changed_messages() {
+    // given
+    let old = test_project::<i32, _>(
+        0x00,
+        vec![
+            test_message::<u8>("get", [0x01; 4]),
+            test_message::<bool>("set", [0x02; 4]),
+        ],
+        |spec| spec,
+    );
+    let new = test_project::<i32, _>(
+        0x00,
+        vec![
+            test_message::<u16>("get", [0x01; 4]),
+            test_message::<bool>("flip", [0x03; 4]),
+        ],
+        |spec| spec,
+    );
+
+    // when
+    let diff = old.diff(&new);
+
+    // then
+    assert_eq!(diff.added(), &["flip".to_string()]);
+    assert_eq!(diff.removed(), &["set".to_string()]);
+    assert_eq!(diff.changed(), &["get".to_string()]);
+    assert!(!diff.is_compatible());
+    assert!(new.diff(&new).is_compatible());
+}
+
+#[test]
+fn check_upgrade_works() {
+    // given
+    let get = |selector| vec![test_message::<u8>("get", selector)];
+    let old = test_project::<i32, _>(0x00, get([0x01; 4]), |spec| spec);
+    let same = test_project::<i32, _>(0x00, get([0x01; 4]), |spec| spec);
+    let retyped = test_project::<u64, _>(0x00, get([0x01; 4]), |spec| spec);
+    let moved = test_project::<i32, _>(0x01, get([0x01; 4]), |spec| spec);
+    let breaking = test_project::<i32, _>(0x00, get([0x02; 4]), |spec| spec);
+    let to_json = |project: &InkProject| serde_json::to_string(project).unwrap();
+
+    // when
+    let report = |new: &InkProject| check_upgrade(&to_json(&old), &to_json(new)).unwrap();
+
+    // then
+    assert_eq!(report(&same).compatibility(), UpgradeCompatibility::Safe);
+    let retyped = report(&retyped);
+    assert_eq!(
+        retyped.compatibility(),
+        UpgradeCompatibility::RequiresMigration
+    );
+    assert_eq!(retyped.changed_keys().len(), 1);
+    let moved = report(&moved);
+    assert_eq!(
+        moved.compatibility(),
+        UpgradeCompatibility::RequiresMigration
+    );
+    assert_eq!(moved.added_keys().len(), 1);
+    assert_eq!(moved.removed_keys().len(), 1);
+    assert_eq!(
+        report(&breaking).compatibility(),
+        UpgradeCompatibility::Breaking
+    );
+    assert!(check_upgrade("{}", &to_json(&old)).is_err());
+}
+
+#[cfg(feature = "typescript")]
+#[test]
+fn generate_typescript_works() {
+    // given
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![
+            test_message::<Vec<u8>>("set", [0x01; 4]),
+            MessageSpec::from_name("get")
+                .selector([0x02; 4])
+                .mutates(false)
+                .payable(false)
+                .returns(ReturnTypeSpec::new(TypeSpec::with_name_str::<Option<u32>>(
+                    "Option",
+                )))
+                .docs(vec![" Returns the value."])
+                .done(),
+        ],
+        |spec| spec,
+    );
+
+    // when
+    let ts = generate_typescript(&project, "my_flipper");
+
+    // then
+    assert!(ts.contains("export const MY_FLIPPER_SELECTORS = {"));
+    assert!(ts.contains("    \"set\": \"0x01010101\","));
+    assert!(ts.contains("export interface MyFlipperQueries {"));
+    assert!(ts.contains("  \"set\"(value: string): Promise<null>;"));
+    assert!(ts.contains("   * Returns the value."));
+    assert!(ts.contains("  \"get\"(): Promise<number | null>;"));
+    assert!(ts.contains("export function myFlipperQueries("));
+}
+
+#[cfg(feature = "rpc-client")]
+#[test]
+fn contract_client_call_works() {
+    use std::cell::RefCell;
+
+    struct MockTransport {
+        requests: RefCell<Vec<(String, serde_json::Value)>>,
+    }
+
+    impl Transport for MockTransport {
+        fn request(
+            &self,
+            method: &str,
+            params: serde_json::Value,
+        ) -> Result<serde_json::Value, RpcClientError> {
+            self.requests
+                .borrow_mut()
+                .push((method.to_string(), params));
+            Ok(json!({
+                "gasConsumed": 42,
+                "result": { "Ok": { "flags": 0, "data": "0x012a000000" } },
+            }))
+        }
+    }
+
+    // given
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![test_getter::<Option<u32>>("get", [0x02; 4])],
+        |spec| spec,
+    );
+    let metadata = serde_json::to_string(&project).unwrap();
+    let transport = MockTransport {
+        requests: RefCell::new(Vec::new()),
+    };
+    let client = ContractClient::new(transport, &metadata, "contract").unwrap();
+
+    // when
+    let outcome = client.call("alice", "get", &[0x07]).unwrap();
+
+    // then
+    assert!(!outcome.reverted());
+    assert_eq!(outcome.value(), &json!(42));
+    assert_eq!(outcome.gas_consumed(), Some(42));
+    let (method, params) = client.transport().requests.borrow()[0].clone();
+    assert_eq!(method, "contracts_call");
+    assert_eq!(params[0]["dest"], "contract");
+    assert_eq!(params[0]["inputData"], "0x0202020207");
+    assert!(matches!(
+        client.call("alice", "set", &[]),
+        Err(RpcClientError::UnknownCallable(_))
+    ));
+}
+
+#[test]
+fn read_contract_works() {
+    // given
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![test_getter::<i32>("get", [37u8, 68u8, 74u8, 254u8])],
+        |spec| spec,
+    );
+    let json = serde_json::to_string(&project).unwrap();
+
+    // when
+    let info = read_contract(&json).unwrap();
+
+    // then
+    assert_eq!(info.constructors().len(), 1);
+    assert_eq!(
+        info.constructors()[0].selector().to_bytes(),
+        &[94u8, 189u8, 136u8, 214u8]
+    );
+    let message = info.message("get").unwrap();
+    assert_eq!(message.selector().to_bytes(), &[37u8, 68u8, 74u8, 254u8]);
+    assert!(!message.mutates());
+    assert!(info.message("Get::get").is_none());
+    assert_eq!(info.layout(), project.layout());
+}
+
+#[test]
+fn serde_hex_works() {
+    assert_eq!(serde_hex::to_hex(&[]), "0x");
+    assert_eq!(serde_hex::to_hex(&[0x00, 0x0F, 0xCA, 0xFE]), "0x000fcafe");
+    assert_eq!(
+        serde_hex::from_hex("0x000fCAFE"),
+        Ok(vec![0x00, 0x0F, 0xCA, 0xFE])
+    );
+    assert_eq!(serde_hex::from_hex("cafe"), Ok(vec![0xCA, 0xFE]));
+    assert_eq!(
+        serde_hex::from_hex("0xcaf"),
+        Err(serde_hex::FromHexError::InvalidLength)
+    );
+    assert_eq!(
+        serde_hex::from_hex("0xcafg"),
+        Err(serde_hex::FromHexError::InvalidCharacter {
+            character: 'g',
+            index: 3
+        })
+    );
+}
+
+#[test]
+fn selector_must_deserialize_from_hex_of_exact_length() {
+    let selector: Selector = serde_json::from_value(json!("0xcafebabe")).unwrap();
+    assert_eq!(selector.to_bytes(), &[0xCA, 0xFE, 0xBA, 0xBE]);
+    assert!(serde_json::from_value::<Selector>(json!("0xcafe")).is_err());
+    assert!(serde_json::from_value::<Selector>(json!("0xcafebabe00")).is_err());
+}
+
+#[test]
+fn event_registry_works() {
+    // given
+    let project = test_project::<i32, _>(
+        0x00,
+        vec![test_message::<u32>("set", [0x01; 4])],
+        |spec| {
+            spec.events(vec![
+                EventSpec::new("Flipped")
+                    .args(vec![
+                        EventParamSpec::new("by")
+                            .of_type(TypeSpec::with_name_str::<[u8; 32]>("AccountId"))
+                            .indexed(true)
+                            .done(),
+                        EventParamSpec::new("value")
+                            .of_type(TypeSpec::with_name_str::<bool>("bool"))
+                            .done(),
+                    ])
+                    .docs(vec![" Emitted upon flipping."])
+                    .done(),
+                EventSpec::new("Reset").anonymous(true).done(),
+                EventSpec::new("Incremented")
+                    .args(vec![EventParamSpec::new("by")
+                        .of_type(TypeSpec::with_name_str::<u32>("u32"))
+                        .indexed(true)
+                        .done()])
+                    .signature("Counter::Incremented")
+                    .done(),
+            ])
+        },
+    );
+
+    // when
+    let json = EventRegistry::new().register("Flipper", &project).to_json();
+
+    // then
+    let mut signature_topic = [0x00; 32];
+    signature_topic[..b"Flipper::Flipped".len()].copy_from_slice(b"Flipper::Flipped");
+    assert_eq!(json["version"], json!(EVENT_REGISTRY_VERSION));
+    assert_eq!(json["contracts"][0]["name"], json!("Flipper"));
+    let flipped = &json["contracts"][0]["events"][0];
+    assert_eq!(flipped["index"], json!(0));
+    assert_eq!(flipped["name"], json!("Flipped"));
+    assert_eq!(
+        flipped["signatureTopic"],
+        json!(serde_hex::to_hex(&signature_topic))
+    );
+    assert_eq!(flipped["docs"], json!(["Emitted upon flipping."]));
+    assert_eq!(
+        flipped["fields"][0]["topicPrefix"],
+        json!(serde_hex::to_hex(b"Flipper::Flipped::by"))
+    );
+    assert_eq!(flipped["fields"][0]["displayName"], json!(["AccountId"]));
+    assert_eq!(flipped["fields"][1]["indexed"], json!(false));
+    assert_eq!(flipped["fields"][1]["topicPrefix"], json!(null));
+    assert_eq!(
+        flipped["fields"][1]["type"]["def"]["primitive"],
+        json!("bool")
+    );
+    let reset = &json["contracts"][0]["events"][1];
+    assert_eq!(reset["index"], json!(1));
+    assert_eq!(reset["signatureTopic"], json!(null));
+    assert_eq!(reset["fields"], json!([]));
+    let incremented = &json["contracts"][0]["events"][2];
+    let mut signature_topic = [0x00; 32];
+    signature_topic[..b"Counter::Incremented".len()]
+        .copy_from_slice(b"Counter::Incremented");
+    assert_eq!(incremented["index"], json!(2));
+    assert_eq!(
+        incremented["signatureTopic"],
+        json!(serde_hex::to_hex(&signature_topic))
+    );
+    assert_eq!(
+        incremented["fields"][0]["topicPrefix"],
+        json!(serde_hex::to_hex(b"Counter::Incremented::by"))
+    );
+}
+
+#[test]
+fn anonymous_event_spec_json() {
+    let mut registry = Registry::new();
+    let anonymous = EventSpec::new("Reset").anonymous(true).done();
+    let named = EventSpec::new("Reset").done();
+    let imported = EventSpec::new("Incremented")
+        .signature("Counter::Incremented")
+        .done();
+    assert_eq!(
+        serde_json::to_value(&anonymous.into_compact(&mut registry)).unwrap(),
+        json!({ "name": "Reset", "args": [], "docs": [], "anonymous": true })
+    );
+    assert_eq!(
+        serde_json::to_value(&named.into_compact(&mut registry)).unwrap(),
+        json!({ "name": "Reset", "args": [], "docs": [] })
+    );
+    assert_eq!(
+        serde_json::to_value(&imported.into_compact(&mut registry)).unwrap(),
+        json!({
+            "name": "Incremented",
+            "args": [],
+            "docs": [],
+            "signature": "Counter::Incremented"
+        })
+    );
+}
+
+#[test]
+fn message_spec_permissions_json() {
+    let mut registry = Registry::new();
+    let message = MessageSpec::from_name("mint")
+        .selector([0xCA, 0xFE, 0xBA, 0xBE])
+        .mutates(true)
+        .payable(false)
+        .returns(ReturnTypeSpec::new(None))
+        .permissions(vec!["owner", "role:MINTER"])
+        .done();
+    assert_eq!(
+        serde_json::to_value(&message.into_compact(&mut registry)).unwrap(),
+        json!({
+            "name": ["mint"],
+            "selector": "0xcafebabe",
+            "mutates": true,
+            "payable": false,
+            "args": [],
+            "returnType": null,
+            "docs": [],
+            "permissions": ["owner", "role:MINTER"],
+        })
+    );
+}
+
+#[test]
+fn metadata_schema_is_valid_json() {
+    let schema: serde_json::Value = serde_json::from_str(METADATA_SCHEMA).unwrap();
+    assert_eq!(schema["title"], json!("ink! smart contract metadata"));
+}
@@ -0,0 +1,410 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generation of TypeScript types and call helpers from ink! metadata.
+//!
+//! The generated code is meant to be used together with `@polkadot/api-contract`
+//! so that frontends do not have to mirror the types of a contract by hand.
+//!
+//! Types of the type registry are mapped to the JSON representation that
+//! `@polkadot/api` uses for them: integers of up to 32 bits become `number`s,
+//! larger integers become `number | string`, byte arrays and sequences become
+//! hex encoded `string`s, `Option<T>` becomes `T | null` and enums become
+//! unions of their variants.
+
+use crate::InkProject;
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+};
+
+/// Generates TypeScript types and call helpers for the contract.
+///
+/// The contract name is used as prefix of the generated items, e.g. the
+/// contract name `flipper` yields the `FlipperQueries` interface and the
+/// `flipperQueries` function.
+pub fn generate_typescript(project: &InkProject, contract_name: &str) -> String {
+    let registry = serde_json::to_value(project.registry())
+        .expect("the type registry is always serializable");
+    let spec = serde_json::to_value(project.spec())
+        .expect("contract specifications are always serializable");
+    let types = registry["types"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+    let generator = Generator::new(types);
+    let name = pascal_case(contract_name);
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// Generated from the ink! metadata of the `{}` contract. Do not edit.",
+        contract_name,
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "import type {{ ContractPromise }} from \"@polkadot/api-contract\";"
+    )
+    .unwrap();
+    generator.write_type_definitions(&mut out);
+    let messages = spec["messages"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+    let constructors = spec["constructors"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+    writeln!(out, "\nexport const {}_SELECTORS = {{", name.to_uppercase()).unwrap();
+    writeln!(out, "  constructors: {{").unwrap();
+    for constructor in constructors {
+        writeln!(
+            out,
+            "    \"{}\": {},",
+            label(constructor),
+            constructor["selector"]
+        )
+        .unwrap();
+    }
+    writeln!(out, "  }},\n  messages: {{").unwrap();
+    for message in messages {
+        writeln!(out, "    \"{}\": {},", label(message), message["selector"]).unwrap();
+    }
+    writeln!(out, "  }},\n}} as const;").unwrap();
+    writeln!(out, "\nexport interface {}Queries {{", name).unwrap();
+    for message in messages {
+        write_docs(&mut out, "  ", message);
+        writeln!(
+            out,
+            "  \"{}\"({}): Promise<{}>;",
+            label(message),
+            generator.params(message),
+            generator.return_type(message),
+        )
+        .unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(
+        out,
+        "\nexport function {}Queries(contract: ContractPromise, caller: string): {}Queries {{",
+        camel_case(contract_name),
+        name,
+    )
+    .unwrap();
+    writeln!(out, "  return {{").unwrap();
+    for message in messages {
+        writeln!(
+            out,
+            "    \"{label}\": ({params}) => contract.query[\"{label}\"](caller, {{}}, {args})\
+             .then(({{ output }}) => output?.toJSON() as any),",
+            label = label(message),
+            params = generator.params(message),
+            args = arg_names(message).join(", "),
+        )
+        .unwrap();
+    }
+    writeln!(out, "  }};\n}}").unwrap();
+    out
+}
+
+/// Returns the label of a message or constructor as used by `@polkadot/api-contract`.
+fn label(callable: &Value) -> String {
+    callable["name"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+        .iter()
+        .filter_map(Value::as_str)
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// Returns the names of the arguments of a message or constructor.
+fn arg_names(callable: &Value) -> Vec<String> {
+    callable["args"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+        .iter()
+        .map(|arg| arg["name"].as_str().unwrap_or("_").to_string())
+        .collect()
+}
+
+/// Writes the documentation of a message as JSDoc comment.
+fn write_docs(out: &mut String, indent: &str, callable: &Value) {
+    let docs = callable["docs"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+        .iter()
+        .filter_map(Value::as_str)
+        .collect::<Vec<_>>();
+    if docs.is_empty() {
+        return
+    }
+    writeln!(out, "{}/**", indent).unwrap();
+    for line in docs {
+        writeln!(out, "{} *{}", indent, line).unwrap();
+    }
+    writeln!(out, "{} */", indent).unwrap();
+}
+
+/// Converts a `snake_case` name into `PascalCase`.
+fn pascal_case(name: &str) -> String {
+    name.split(|c: char| c == '_' || c == '-')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            let mut chars = segment.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Converts a `snake_case` name into `camelCase`.
+fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Maps the types of the type registry to TypeScript types.
+struct Generator<'a> {
+    /// The JSON encoded types of the type registry.
+    types: &'a [Value],
+    /// The names of the types that are emitted as named type definitions.
+    names: BTreeMap<u64, String>,
+}
+
+impl<'a> Generator<'a> {
+    /// Creates a generator for the JSON encoded types of the type registry.
+    ///
+    /// Composite and variant types with a path are emitted as named type
+    /// definitions. Their names are suffixed with their type identifier if
+    /// several types share the same name, e.g. due to generic parameters.
+    fn new(types: &'a [Value]) -> Self {
+        let mut candidates = BTreeMap::<String, Vec<u64>>::new();
+        for (index, ty) in types.iter().enumerate() {
+            let is_named_def = ty["def"].get("composite").is_some()
+                || ty["def"].get("variant").is_some();
+            let name = ty["path"]
+                .as_array()
+                .and_then(|path| path.last())
+                .and_then(Value::as_str);
+            match name {
+                Some("Option") | Some("Result") | None => (),
+                Some(name) if is_named_def => {
+                    candidates
+                        .entry(name.to_string())
+                        .or_default()
+                        .push(index as u64 + 1)
+                }
+                Some(_) => (),
+            }
+        }
+        let mut names = BTreeMap::new();
+        for (name, ids) in candidates {
+            let is_unique = ids.len() == 1;
+            for id in ids {
+                let name = if is_unique {
+                    name.clone()
+                } else {
+                    format!("{}{}", name, id)
+                };
+                names.insert(id, name);
+            }
+        }
+        Self { types, names }
+    }
+
+    /// Returns the JSON encoded type with the 1-based identifier.
+    fn ty(&self, id: u64) -> Option<&Value> {
+        id.checked_sub(1)
+            .and_then(|index| self.types.get(index as usize))
+    }
+
+    /// Writes all named type definitions.
+    fn write_type_definitions(&self, out: &mut String) {
+        for (id, name) in &self.names {
+            let definition = self
+                .ty(*id)
+                .map(|ty| self.definition(ty))
+                .unwrap_or_else(|| "unknown".to_string());
+            writeln!(out, "\nexport type {} = {};", name, definition).unwrap();
+        }
+    }
+
+    /// Returns the TypeScript type referring to the type with the identifier.
+    fn reference(&self, id: &Value) -> String {
+        let id = match id.as_u64() {
+            Some(id) => id,
+            None => return "unknown".to_string(),
+        };
+        if let Some(name) = self.names.get(&id) {
+            return name.clone()
+        }
+        self.ty(id)
+            .map(|ty| self.definition(ty))
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+
+    /// Returns the TypeScript type definition of the JSON encoded type.
+    fn definition(&self, ty: &Value) -> String {
+        let def = &ty["def"];
+        let name = ty["path"]
+            .as_array()
+            .and_then(|path| path.last())
+            .and_then(Value::as_str);
+        if let Some(primitive) = def["primitive"].as_str() {
+            return match primitive {
+                "bool" => "boolean",
+                "char" | "str" => "string",
+                "u8" | "u16" | "u32" | "i8" | "i16" | "i32" => "number",
+                _ => "number | string",
+            }
+            .to_string()
+        }
+        if let Some(composite) = def.get("composite") {
+            return self.fields(&composite["fields"])
+        }
+        if let Some(variant) = def.get("variant") {
+            let variants = variant["variants"]
+                .as_array()
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            if name == Some("Option") {
+                let some = variants
+                    .iter()
+                    .find(|variant| variant["name"] == "Some")
+                    .map(|variant| self.reference(&variant["fields"][0]["type"]))
+                    .unwrap_or_else(|| "unknown".to_string());
+                return format!("{} | null", some)
+            }
+            let is_fieldless = variants.iter().all(|variant| {
+                variant["fields"]
+                    .as_array()
+                    .map(Vec::is_empty)
+                    .unwrap_or(true)
+            });
+            return variants
+                .iter()
+                .map(|variant| {
+                    let name = variant["name"].as_str().unwrap_or("_");
+                    if is_fieldless {
+                        format!("\"{}\"", name)
+                    } else {
+                        format!("{{ {}: {} }}", name, self.fields(&variant["fields"]))
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" | ")
+        }
+        if let Some(sequence) = def.get("sequence") {
+            return self.sequence(&sequence["type"])
+        }
+        if let Some(array) = def.get("array") {
+            return self.sequence(&array["type"])
+        }
+        if let Some(tuple) = def["tuple"].as_array() {
+            if tuple.is_empty() {
+                return "null".to_string()
+            }
+            let elems = tuple
+                .iter()
+                .map(|id| self.reference(id))
+                .collect::<Vec<_>>();
+            return format!("[{}]", elems.join(", "))
+        }
+        "unknown".to_string()
+    }
+
+    /// Returns the TypeScript type of a sequence or array of the element type.
+    ///
+    /// Sequences of bytes are hex encoded strings.
+    fn sequence(&self, elem: &Value) -> String {
+        let is_byte = elem
+            .as_u64()
+            .and_then(|id| self.ty(id))
+            .map(|ty| ty["def"]["primitive"] == "u8")
+            .unwrap_or(false);
+        if is_byte {
+            return "string".to_string()
+        }
+        format!("Array<{}>", self.reference(elem))
+    }
+
+    /// Returns the TypeScript type of the fields of a composite or variant.
+    ///
+    /// Unnamed fields are represented as tuple, a single unnamed field as
+    /// the type of the field itself.
+    fn fields(&self, fields: &Value) -> String {
+        let fields = fields.as_array().map(Vec::as_slice).unwrap_or(&[]);
+        if fields.is_empty() {
+            return "null".to_string()
+        }
+        if fields.iter().all(|field| field["name"].is_string()) {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    format!(
+                        "{}: {}",
+                        field["name"].as_str().unwrap_or("_"),
+                        self.reference(&field["type"])
+                    )
+                })
+                .collect::<Vec<_>>();
+            return format!("{{ {} }}", fields.join("; "))
+        }
+        if let [field] = fields {
+            return self.reference(&field["type"])
+        }
+        let fields = fields
+            .iter()
+            .map(|field| self.reference(&field["type"]))
+            .collect::<Vec<_>>();
+        format!("[{}]", fields.join(", "))
+    }
+
+    /// Returns the TypeScript parameters of a message or constructor.
+    fn params(&self, callable: &Value) -> String {
+        callable["args"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{}: {}",
+                    arg["name"].as_str().unwrap_or("_"),
+                    self.reference(&arg["type"]["type"])
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Returns the TypeScript return type of a message.
+    fn return_type(&self, message: &Value) -> String {
+        match message["returnType"].get("type") {
+            Some(id) => self.reference(id),
+            None => "null".to_string(),
+        }
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compatibility checks for upgrades of ink! smart contracts.
+//!
+//! Before replacing the code of a contract via `set_code_hash` the metadata of
+//! the old and the new contract version can be compared in order to find out
+//! whether existing callers and the existing contract storage stay usable.
+
+use crate::{
+    diff::TypeResolver,
+    InkProject,
+    MessagesDiff,
+    ReadError,
+};
+use serde_json::{
+    json,
+    Value,
+};
+use std::collections::BTreeMap;
+
+/// The compatibility of two versions of a contract.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UpgradeCompatibility {
+    /// The messages and the storage layout are compatible.
+    Safe,
+    /// The messages are compatible but the storage has to be migrated.
+    RequiresMigration,
+    /// Messages have been removed or their signatures changed.
+    ///
+    /// Existing callers of the contract are going to fail after the upgrade.
+    Breaking,
+}
+
+/// The report of an upgrade compatibility check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpgradeReport {
+    /// The differences between the messages of both versions.
+    messages: MessagesDiff,
+    /// The storage keys that are only used by the new version.
+    added_keys: Vec<String>,
+    /// The storage keys that are only used by the old version.
+    removed_keys: Vec<String>,
+    /// The storage keys that are used by both versions for different types.
+    changed_keys: Vec<String>,
+}
+
+impl UpgradeReport {
+    /// Returns the compatibility of both contract versions.
+    pub fn compatibility(&self) -> UpgradeCompatibility {
+        if !self.messages.is_compatible() {
+            return UpgradeCompatibility::Breaking
+        }
+        if !self.added_keys.is_empty()
+            || !self.removed_keys.is_empty()
+            || !self.changed_keys.is_empty()
+        {
+            return UpgradeCompatibility::RequiresMigration
+        }
+        UpgradeCompatibility::Safe
+    }
+
+    /// Returns the differences between the messages of both versions.
+    pub fn messages(&self) -> &MessagesDiff {
+        &self.messages
+    }
+
+    /// Returns the storage keys that are only used by the new version.
+    ///
+    /// The storage under these keys has to be initialized upon migration.
+    pub fn added_keys(&self) -> &[String] {
+        &self.added_keys
+    }
+
+    /// Returns the storage keys that are only used by the old version.
+    pub fn removed_keys(&self) -> &[String] {
+        &self.removed_keys
+    }
+
+    /// Returns the storage keys that are used by both versions for different types.
+    pub fn changed_keys(&self) -> &[String] {
+        &self.changed_keys
+    }
+}
+
+/// Checks whether the contract with the `old` metadata can be upgraded to
+/// the contract with the `new` metadata.
+///
+/// Messages are compared by their signature hashes and storage layouts
+/// are compared by their storage keys and the fully resolved types stored
+/// under them.
+///
+/// # Errors
+///
+/// If either input is not valid JSON encoded ink! metadata.
+pub fn check_upgrade(old: &str, new: &str) -> Result<UpgradeReport, ReadError> {
+    let old: InkProject = serde_json::from_str(old)?;
+    let new: InkProject = serde_json::from_str(new)?;
+    Ok(old.check_upgrade(&new))
+}
+
+impl InkProject {
+    /// Checks whether `self` can be upgraded to the `other` project.
+    ///
+    /// See [`check_upgrade`](`crate::check_upgrade`) for more details.
+    pub fn check_upgrade(&self, other: &InkProject) -> UpgradeReport {
+        let old = storage_entries(self);
+        let new = storage_entries(other);
+        let mut report = UpgradeReport {
+            messages: self.diff(other),
+            added_keys: Vec::new(),
+            removed_keys: Vec::new(),
+            changed_keys: Vec::new(),
+        };
+        for (key, entry) in &new {
+            match old.get(key) {
+                None => report.added_keys.push(key.clone()),
+                Some(old_entry) if old_entry != entry => {
+                    report.changed_keys.push(key.clone())
+                }
+                Some(_) => (),
+            }
+        }
+        report.removed_keys = old
+            .keys()
+            .filter(|key| !new.contains_key(*key))
+            .cloned()
+            .collect();
+        report
+    }
+}
+
+/// Returns the resolved entries of the storage layout keyed by their storage keys.
+fn storage_entries(project: &InkProject) -> BTreeMap<String, Value> {
+    let registry = serde_json::to_value(project.registry())
+        .expect("the type registry is always serializable");
+    let layout = serde_json::to_value(project.layout())
+        .expect("storage layouts are always serializable");
+    let resolver = TypeResolver::new(&registry);
+    let mut entries = BTreeMap::new();
+    collect_entries(&resolver, &layout, &mut entries);
+    entries
+}
+
+/// Collects the entries of the JSON encoded storage layout.
+///
+/// Cells are resolved to the type that is stored under their key. Hashed layouts
+/// are resolved as a whole to their offset since their content is not stored
+/// under statically known keys.
+fn collect_entries(
+    resolver: &TypeResolver,
+    layout: &Value,
+    entries: &mut BTreeMap<String, Value>,
+) {
+    match layout {
+        Value::Object(fields) => {
+            if let Some(cell) = fields.get("cell") {
+                if let Some(key) = cell["key"].as_str() {
+                    let ty = cell["ty"]
+                        .as_u64()
+                        .map(|id| resolver.resolve(id, &mut Vec::new()))
+                        .unwrap_or(Value::Null);
+                    entries.insert(key.to_string(), ty);
+                }
+                return
+            }
+            if let Some(hash) = fields.get("hash") {
+                if let Some(offset) = hash["offset"].as_str() {
+                    let mut inner = BTreeMap::new();
+                    collect_entries(resolver, &hash["layout"], &mut inner);
+                    let entry = json!({
+                        "strategy": hash["strategy"],
+                        "layout": inner,
+                    });
+                    entries.insert(offset.to_string(), entry);
+                }
+                return
+            }
+            if let Some(dispatch_key) = fields
+                .get("enum")
+                .and_then(|layout| layout["dispatchKey"].as_str())
+            {
+                entries.insert(dispatch_key.to_string(), json!("discriminant"));
+            }
+            for value in fields.values() {
+                collect_entries(resolver, value, entries)
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_entries(resolver, value, entries)
+            }
+        }
+        _ => (),
+    }
+}
@@ -13,6 +13,25 @@
 // limitations under the License.
 
 use crate::serde_hex;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::String,
+    vec::Vec,
+};
+
+/// Conducts the BLAKE2 256-bit hash of the given input.
+#[cfg(feature = "std")]
+pub fn blake2b_256(input: &[u8]) -> [u8; 32] {
+    use blake2::digest::{
+        Update as _,
+        VariableOutput as _,
+    };
+    let mut output = [0x00; 32];
+    let mut blake2 = blake2::VarBlake2b::new(32).expect("32 is a valid output size");
+    blake2.update(input);
+    blake2.finalize_variable(|result| output.copy_from_slice(result));
+    output
+}
 
 /// Serializes the given bytes as byte string.
 pub fn serialize_as_byte_str<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
@@ -36,17 +55,12 @@ where
     impl<'b> serde::de::Visitor<'b> for Visitor {
         type Value = Vec<u8>;
 
-        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
             write!(formatter, "hex string with optional 0x prefix")
         }
 
         fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
-            let result = if v.starts_with("0x") {
-                serde_hex::from_hex(v)
-            } else {
-                serde_hex::from_hex(&format!("0x{}", v))
-            };
-            result.map_err(E::custom)
+            serde_hex::from_hex(v).map_err(E::custom)
         }
 
         fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Self::Value, E> {
@@ -0,0 +1,711 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validation of ink! metadata provided in its JSON representation.
+//!
+//! This is useful for tooling that has to deal with metadata files
+//! that have not necessarily been generated by ink! itself.
+//!
+//! The structural checks mirror [`METADATA_SCHEMA`] so that they report
+//! the path of every offending value. The tests check that both agree.
+
+use crate::InkProject;
+use serde_json::{
+    Map,
+    Value,
+};
+use std::collections::BTreeSet;
+
+/// The JSON Schema of the ink! metadata format.
+///
+/// Describes the structure of the JSON generated for an [`InkProject`].
+pub const METADATA_SCHEMA: &str = include_str!("metadata.schema.json");
+
+/// An error encountered upon validating ink! metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The path to the offending JSON value, e.g. `spec.messages[0].selector`.
+    path: String,
+    /// A human readable description of the problem.
+    message: String,
+}
+
+impl ValidationError {
+    /// Creates a new validation error for the JSON value at the given path.
+    fn new<P, M>(path: P, message: M) -> Self
+    where
+        P: Into<String>,
+        M: Into<String>,
+    {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Returns the path to the offending JSON value.
+    ///
+    /// An empty path refers to the root of the metadata.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the description of the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.path.is_empty() {
+            return write!(f, "{}", self.message)
+        }
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Validates the given ink! metadata JSON and decodes it upon success.
+///
+/// # Errors
+///
+/// Returns all problems that have been found with the metadata at once
+/// so that they can be fixed in one go.
+///
+/// - If the input is not valid JSON.
+/// - If the metadata does not conform to [`METADATA_SCHEMA`].
+/// - If selectors of constructors or messages are duplicated.
+/// - If a type referenced by the specification or the storage layout
+///   is missing from the type registry.
+pub fn validate(json: &str) -> Result<InkProject, Vec<ValidationError>> {
+    let value: Value = serde_json::from_str(json).map_err(|error| {
+        vec![ValidationError::new("", format!("invalid JSON: {}", error))]
+    })?;
+    let mut validator = Validator::default();
+    validator.validate_root(&value);
+    if !validator.errors.is_empty() {
+        return Err(validator.errors)
+    }
+    serde_json::from_value(value)
+        .map_err(|error| vec![ValidationError::new("", error.to_string())])
+}
+
+/// Gathers the errors encountered during validation.
+#[derive(Default)]
+struct Validator {
+    /// The number of types in the type registry.
+    num_types: usize,
+    /// All errors encountered so far.
+    errors: Vec<ValidationError>,
+}
+
+/// Returns the path of the field with the given name.
+fn field_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        return name.to_string()
+    }
+    format!("{}.{}", path, name)
+}
+
+/// Returns the path of the element at the given index.
+fn index_path(path: &str, index: usize) -> String {
+    format!("{}[{}]", path, index)
+}
+
+/// Returns `true` if the string is a hex string with an optional `0x` prefix.
+fn is_hex_bytes(value: &str) -> bool {
+    let digits = value.strip_prefix("0x").unwrap_or(value);
+    digits.len() % 2 == 0 && digits.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Returns `true` if the string is a `0x` prefixed hex string of `len` bytes.
+fn is_hex_of_len(value: &str, len: usize) -> bool {
+    value.len() == 2 + 2 * len
+        && value.starts_with("0x")
+        && value[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+impl Validator {
+    /// Records an error for the JSON value at the given path.
+    fn error<M>(&mut self, path: &str, message: M)
+    where
+        M: Into<String>,
+    {
+        self.errors.push(ValidationError::new(path, message))
+    }
+
+    /// Returns the object at the path or records an error.
+    fn expect_object<'a>(
+        &mut self,
+        path: &str,
+        value: &'a Value,
+    ) -> Option<&'a Map<String, Value>> {
+        let object = value.as_object();
+        if object.is_none() {
+            self.error(path, "expected an object")
+        }
+        object
+    }
+
+    /// Returns the array at the path or records an error.
+    fn expect_array<'a>(&mut self, path: &str, value: &'a Value) -> Option<&'a [Value]> {
+        let array = value.as_array().map(Vec::as_slice);
+        if array.is_none() {
+            self.error(path, "expected an array")
+        }
+        array
+    }
+
+    /// Returns the field of the object or records an error if it is missing.
+    fn expect_field<'a>(
+        &mut self,
+        path: &str,
+        object: &'a Map<String, Value>,
+        name: &str,
+    ) -> Option<&'a Value> {
+        let field = object.get(name);
+        if field.is_none() {
+            self.error(path, format!("missing field `{}`", name))
+        }
+        field
+    }
+
+    /// Checks that the field of the object is a boolean.
+    fn check_bool(&mut self, path: &str, object: &Map<String, Value>, name: &str) {
+        if let Some(value) = self.expect_field(path, object, name) {
+            if !value.is_boolean() {
+                self.error(&field_path(path, name), "expected a boolean")
+            }
+        }
+    }
+
+    /// Checks that the value is an array of strings.
+    fn check_strings(&mut self, path: &str, value: &Value) {
+        if let Some(strings) = self.expect_array(path, value) {
+            for (n, string) in strings.iter().enumerate() {
+                if !string.is_string() {
+                    self.error(&index_path(path, n), "expected a string")
+                }
+            }
+        }
+    }
+
+    /// Checks that the value refers to a type of the type registry.
+    fn check_type_id(&mut self, path: &str, value: &Value) {
+        match value.as_u64() {
+            Some(id) if (1..=self.num_types as u64).contains(&id) => (),
+            Some(id) => {
+                self.error(
+                    path,
+                    format!(
+                        "type {} is missing from the type registry of {} types",
+                        id, self.num_types
+                    ),
+                )
+            }
+            None => self.error(path, "expected a type identifier"),
+        }
+    }
+
+    /// Checks that the value is a hex encoded storage key.
+    fn check_layout_key(&mut self, path: &str, value: &Value) {
+        match value.as_str() {
+            Some(key) if is_hex_of_len(key, 32) => (),
+            _ => self.error(path, "expected a 0x prefixed hex encoded 32 bytes key"),
+        }
+    }
+
+    fn validate_root(&mut self, value: &Value) {
+        let root = match self.expect_object("", value) {
+            Some(root) => root,
+            None => return,
+        };
+        if let Some(types) = self.expect_field("", root, "types") {
+            if let Some(types) = self.expect_array("types", types) {
+                self.num_types = types.len();
+                for (n, ty) in types.iter().enumerate() {
+                    let path = index_path("types", n);
+                    if let Some(ty) = self.expect_object(&path, ty) {
+                        self.validate_type(&path, ty);
+                    }
+                }
+            }
+        }
+        if let Some(spec) = self.expect_field("", root, "spec") {
+            self.validate_spec("spec", spec);
+        }
+        if let Some(storage) = self.expect_field("", root, "storage") {
+            self.validate_layout("storage", storage);
+        }
+    }
+
+    fn validate_type(&mut self, path: &str, ty: &Map<String, Value>) {
+        if let Some(type_path) = ty.get("path") {
+            self.check_strings(&field_path(path, "path"), type_path);
+        }
+        if let Some(params) = ty.get("params") {
+            let params_path = field_path(path, "params");
+            if let Some(params) = self.expect_array(&params_path, params) {
+                for (n, param) in params.iter().enumerate() {
+                    self.check_type_id(&index_path(&params_path, n), param);
+                }
+            }
+        }
+        if let Some(def) = self.expect_field(path, ty, "def") {
+            match def.as_object() {
+                Some(def) if def.len() == 1 => (),
+                _ => {
+                    self.error(
+                        &field_path(path, "def"),
+                        "expected an object with exactly one type definition kind",
+                    )
+                }
+            }
+        }
+    }
+
+    fn validate_spec(&mut self, path: &str, value: &Value) {
+        let spec = match self.expect_object(path, value) {
+            Some(spec) => spec,
+            None => return,
+        };
+        for &(name, is_message) in &[("constructors", false), ("messages", true)] {
+            let callables = match self.expect_field(path, spec, name) {
+                Some(callables) => callables,
+                None => continue,
+            };
+            let callables_path = field_path(path, name);
+            let callables = match self.expect_array(&callables_path, callables) {
+                Some(callables) => callables,
+                None => continue,
+            };
+            if callables.is_empty() {
+                self.error(&callables_path, "must have at least one entry")
+            }
+            let mut selectors = BTreeSet::new();
+            for (n, callable) in callables.iter().enumerate() {
+                let callable_path = index_path(&callables_path, n);
+                if let Some(selector) =
+                    self.validate_callable(&callable_path, callable, is_message)
+                {
+                    if !selectors.insert(selector) {
+                        self.error(
+                            &field_path(&callable_path, "selector"),
+                            format!("duplicate selector {}", selector),
+                        )
+                    }
+                }
+            }
+        }
+        if let Some(events) = self.expect_field(path, spec, "events") {
+            let events_path = field_path(path, "events");
+            if let Some(events) = self.expect_array(&events_path, events) {
+                for (n, event) in events.iter().enumerate() {
+                    self.validate_event(&index_path(&events_path, n), event);
+                }
+            }
+        }
+        if let Some(docs) = self.expect_field(path, spec, "docs") {
+            self.check_strings(&field_path(path, "docs"), docs);
+        }
+        if let Some(lang_error) = spec.get("langError") {
+            self.validate_lang_error(&field_path(path, "langError"), lang_error);
+        }
+        if let Some(traits) = spec.get("traits") {
+            let traits_path = field_path(path, "traits");
+            if let Some(traits) = self.expect_array(&traits_path, traits) {
+                for (n, trait_spec) in traits.iter().enumerate() {
+                    self.validate_trait(&index_path(&traits_path, n), trait_spec);
+                }
+            }
+        }
+        if let Some(fingerprint) = spec.get("codeFingerprint") {
+            match fingerprint.as_str() {
+                Some(fingerprint) if is_hex_of_len(fingerprint, 32) => (),
+                _ => {
+                    self.error(
+                        &field_path(path, "codeFingerprint"),
+                        "expected a 0x prefixed hex encoded 32 bytes fingerprint",
+                    )
+                }
+            }
+        }
+        if let Some(features) = spec.get("features") {
+            self.check_strings(&field_path(path, "features"), features);
+        }
+    }
+
+    fn validate_trait(&mut self, path: &str, value: &Value) {
+        let trait_spec = match self.expect_object(path, value) {
+            Some(trait_spec) => trait_spec,
+            None => return,
+        };
+        if let Some(name) = self.expect_field(path, trait_spec, "name") {
+            if !name.is_string() {
+                self.error(&field_path(path, "name"), "expected a string")
+            }
+        }
+        if let Some(namespace) = trait_spec.get("namespace") {
+            if !namespace.is_string() {
+                self.error(&field_path(path, "namespace"), "expected a string")
+            }
+        }
+        if let Some(version) = trait_spec.get("version") {
+            match version.as_u64() {
+                Some(version) if (2..=u32::MAX as u64).contains(&version) => (),
+                _ => {
+                    self.error(
+                        &field_path(path, "version"),
+                        "expected a trait definition version greater than 1",
+                    )
+                }
+            }
+        }
+        let selectors = match self.expect_field(path, trait_spec, "selectors") {
+            Some(selectors) => selectors,
+            None => return,
+        };
+        let selectors_path = field_path(path, "selectors");
+        if let Some(selectors) = self.expect_array(&selectors_path, selectors) {
+            for (n, selector) in selectors.iter().enumerate() {
+                match selector.as_str() {
+                    Some(selector) if is_hex_of_len(selector, 4) => (),
+                    _ => {
+                        self.error(
+                            &index_path(&selectors_path, n),
+                            "expected a 0x prefixed hex encoded 4 bytes selector",
+                        )
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_lang_error(&mut self, path: &str, value: &Value) {
+        let lang_error = match self.expect_object(path, value) {
+            Some(lang_error) => lang_error,
+            None => return,
+        };
+        if let Some(ty) = self.expect_field(path, lang_error, "type") {
+            self.validate_type_spec(&field_path(path, "type"), ty);
+        }
+        let variants = match self.expect_field(path, lang_error, "variants") {
+            Some(variants) => variants,
+            None => return,
+        };
+        let variants_path = field_path(path, "variants");
+        if let Some(variants) = self.expect_array(&variants_path, variants) {
+            let mut codes = BTreeSet::new();
+            for (n, variant) in variants.iter().enumerate() {
+                let variant_path = index_path(&variants_path, n);
+                let variant = match self.expect_object(&variant_path, variant) {
+                    Some(variant) => variant,
+                    None => continue,
+                };
+                if let Some(name) = self.expect_field(&variant_path, variant, "name") {
+                    if !name.is_string() {
+                        self.error(
+                            &field_path(&variant_path, "name"),
+                            "expected a string",
+                        )
+                    }
+                }
+                if let Some(docs) = self.expect_field(&variant_path, variant, "docs") {
+                    self.check_strings(&field_path(&variant_path, "docs"), docs);
+                }
+                if let Some(code) = self.expect_field(&variant_path, variant, "code") {
+                    let code_path = field_path(&variant_path, "code");
+                    match code.as_u64() {
+                        Some(code) if !codes.insert(code) => {
+                            self.error(&code_path, format!("duplicate code {}", code))
+                        }
+                        Some(_) => (),
+                        None => self.error(&code_path, "expected an unsigned integer"),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Validates a constructor or message and returns its selector if valid.
+    fn validate_callable<'a>(
+        &mut self,
+        path: &str,
+        value: &'a Value,
+        is_message: bool,
+    ) -> Option<&'a str> {
+        let callable = self.expect_object(path, value)?;
+        if let Some(name) = self.expect_field(path, callable, "name") {
+            let name_path = field_path(path, "name");
+            self.check_strings(&name_path, name);
+            if name.as_array().map(Vec::is_empty).unwrap_or(false) {
+                self.error(&name_path, "must not be empty")
+            }
+        }
+        self.check_bool(path, callable, "payable");
+        if is_message {
+            self.check_bool(path, callable, "mutates");
+            if let Some(return_type) = self.expect_field(path, callable, "returnType") {
+                if !return_type.is_null() {
+                    self.validate_type_spec(&field_path(path, "returnType"), return_type);
+                }
+            }
+            if let Some(hash) = callable.get("signatureHash") {
+                match hash.as_str() {
+                    Some(hash) if is_hex_of_len(hash, 32) => (),
+                    _ => {
+                        self.error(
+                            &field_path(path, "signatureHash"),
+                            "expected a 0x prefixed hex encoded 32 bytes hash",
+                        )
+                    }
+                }
+            }
+            if let Some(permissions) = callable.get("permissions") {
+                self.check_strings(&field_path(path, "permissions"), permissions);
+            }
+        } else {
+            self.check_bool(path, callable, "default");
+        }
+        if let Some(args) = self.expect_field(path, callable, "args") {
+            let args_path = field_path(path, "args");
+            if let Some(args) = self.expect_array(&args_path, args) {
+                for (n, arg) in args.iter().enumerate() {
+                    let arg_path = index_path(&args_path, n);
+                    if let Some(arg) = self.expect_object(&arg_path, arg) {
+                        self.validate_param(&arg_path, arg);
+                    }
+                }
+            }
+        }
+        if let Some(docs) = self.expect_field(path, callable, "docs") {
+            self.check_strings(&field_path(path, "docs"), docs);
+        }
+        let selector = self.expect_field(path, callable, "selector")?;
+        match selector.as_str() {
+            Some(selector) if is_hex_of_len(selector, 4) => Some(selector),
+            _ => {
+                self.error(
+                    &field_path(path, "selector"),
+                    "expected a 0x prefixed hex encoded 4 bytes selector",
+                );
+                None
+            }
+        }
+    }
+
+    fn validate_event(&mut self, path: &str, value: &Value) {
+        let event = match self.expect_object(path, value) {
+            Some(event) => event,
+            None => return,
+        };
+        if let Some(name) = self.expect_field(path, event, "name") {
+            if !name.is_string() {
+                self.error(&field_path(path, "name"), "expected a string")
+            }
+        }
+        if let Some(args) = self.expect_field(path, event, "args") {
+            let args_path = field_path(path, "args");
+            if let Some(args) = self.expect_array(&args_path, args) {
+                for (n, arg) in args.iter().enumerate() {
+                    let arg_path = index_path(&args_path, n);
+                    if let Some(arg) = self.expect_object(&arg_path, arg) {
+                        self.validate_param(&arg_path, arg);
+                        self.check_bool(&arg_path, arg, "indexed");
+                    }
+                }
+            }
+        }
+        if let Some(docs) = self.expect_field(path, event, "docs") {
+            self.check_strings(&field_path(path, "docs"), docs);
+        }
+        if let Some(anonymous) = event.get("anonymous") {
+            if !anonymous.is_boolean() {
+                self.error(&field_path(path, "anonymous"), "expected a boolean")
+            }
+        }
+    }
+
+    /// Validates the fields shared by message and event parameters.
+    fn validate_param(&mut self, path: &str, param: &Map<String, Value>) {
+        if let Some(name) = self.expect_field(path, param, "name") {
+            if !name.is_string() {
+                self.error(&field_path(path, "name"), "expected a string")
+            }
+        }
+        if let Some(ty) = self.expect_field(path, param, "type") {
+            self.validate_type_spec(&field_path(path, "type"), ty);
+        }
+        if let Some(docs) = self.expect_field(path, param, "docs") {
+            self.check_strings(&field_path(path, "docs"), docs);
+        }
+    }
+
+    fn validate_type_spec(&mut self, path: &str, value: &Value) {
+        let type_spec = match self.expect_object(path, value) {
+            Some(type_spec) => type_spec,
+            None => return,
+        };
+        if let Some(ty) = self.expect_field(path, type_spec, "type") {
+            self.check_type_id(&field_path(path, "type"), ty);
+        }
+        if let Some(display_name) = self.expect_field(path, type_spec, "displayName") {
+            self.check_strings(&field_path(path, "displayName"), display_name);
+        }
+    }
+
+    fn validate_layout(&mut self, path: &str, value: &Value) {
+        let layout = match self.expect_object(path, value) {
+            Some(layout) => layout,
+            None => return,
+        };
+        let mut entries = layout.iter();
+        let (kind, inner) = match (entries.next(), entries.next()) {
+            (Some(entry), None) => entry,
+            _ => return self.error(path, "expected exactly one layout kind"),
+        };
+        let inner_path = field_path(path, kind);
+        let inner_path = inner_path.as_str();
+        let inner = match self.expect_object(inner_path, inner) {
+            Some(inner) => inner,
+            None => return,
+        };
+        match kind.as_str() {
+            "cell" => {
+                if let Some(key) = self.expect_field(inner_path, inner, "key") {
+                    self.check_layout_key(&field_path(inner_path, "key"), key);
+                }
+                if let Some(ty) = self.expect_field(inner_path, inner, "ty") {
+                    self.check_type_id(&field_path(inner_path, "ty"), ty);
+                }
+                if let Some(cell_path) = inner.get("path") {
+                    self.check_strings(&field_path(inner_path, "path"), cell_path);
+                }
+            }
+            "hash" | "array" => {
+                if let Some(offset) = self.expect_field(inner_path, inner, "offset") {
+                    self.check_layout_key(&field_path(inner_path, "offset"), offset);
+                }
+                if kind == "hash" {
+                    if let Some(strategy) =
+                        self.expect_field(inner_path, inner, "strategy")
+                    {
+                        self.validate_hashing_strategy(
+                            &field_path(inner_path, "strategy"),
+                            strategy,
+                        );
+                    }
+                } else {
+                    for name in &["len", "cellsPerElem"] {
+                        if let Some(value) = self.expect_field(inner_path, inner, name) {
+                            if !value.is_u64() {
+                                self.error(
+                                    &field_path(inner_path, name),
+                                    "expected an unsigned integer",
+                                )
+                            }
+                        }
+                    }
+                }
+                if let Some(layout) = self.expect_field(inner_path, inner, "layout") {
+                    self.validate_layout(&field_path(inner_path, "layout"), layout);
+                }
+            }
+            "struct" => self.validate_struct_layout(inner_path, inner),
+            "enum" => {
+                if let Some(key) = self.expect_field(inner_path, inner, "dispatchKey") {
+                    self.check_layout_key(&field_path(inner_path, "dispatchKey"), key);
+                }
+                if let Some(variants) = self.expect_field(inner_path, inner, "variants") {
+                    let variants_path = field_path(inner_path, "variants");
+                    if let Some(variants) = self.expect_object(&variants_path, variants) {
+                        for (discriminant, variant) in variants {
+                            let variant_path = field_path(&variants_path, discriminant);
+                            if let Some(variant) =
+                                self.expect_object(&variant_path, variant)
+                            {
+                                self.validate_struct_layout(&variant_path, variant);
+                            }
+                        }
+                    }
+                }
+            }
+            unknown => self.error(path, format!("unknown layout kind `{}`", unknown)),
+        }
+    }
+
+    fn validate_hashing_strategy(&mut self, path: &str, value: &Value) {
+        let strategy = match self.expect_object(path, value) {
+            Some(strategy) => strategy,
+            None => return,
+        };
+        if let Some(hasher) = self.expect_field(path, strategy, "hasher") {
+            match hasher.as_str() {
+                Some("Blake2x256") | Some("Sha2x256") | Some("Keccak256") => (),
+                _ => {
+                    self.error(
+                        &field_path(path, "hasher"),
+                        "expected one of `Blake2x256`, `Sha2x256` or `Keccak256`",
+                    )
+                }
+            }
+        }
+        for name in &["prefix", "postfix"] {
+            if let Some(bytes) = self.expect_field(path, strategy, name) {
+                match bytes.as_str() {
+                    Some(bytes) if is_hex_bytes(bytes) => (),
+                    _ => {
+                        self.error(&field_path(path, name), "expected hex encoded bytes")
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_struct_layout(&mut self, path: &str, layout: &Map<String, Value>) {
+        let fields = match self.expect_field(path, layout, "fields") {
+            Some(fields) => fields,
+            None => return,
+        };
+        let fields_path = field_path(path, "fields");
+        if let Some(fields) = self.expect_array(&fields_path, fields) {
+            for (n, field) in fields.iter().enumerate() {
+                let field_path_n = index_path(&fields_path, n);
+                if let Some(field) = self.expect_object(&field_path_n, field) {
+                    if let Some(name) = self.expect_field(&field_path_n, field, "name") {
+                        if !name.is_null() && !name.is_string() {
+                            self.error(
+                                &field_path(&field_path_n, "name"),
+                                "expected a string or null",
+                            )
+                        }
+                    }
+                    if let Some(key) = field.get("key") {
+                        self.check_layout_key(&field_path(&field_path_n, "key"), key);
+                    }
+                    if let Some(layout) =
+                        self.expect_field(&field_path_n, field, "layout")
+                    {
+                        self.validate_layout(
+                            &field_path(&field_path_n, "layout"),
+                            layout,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -19,13 +19,29 @@
 //! crates. If needed we shall instead enhance the exposed types here.
 //!
 //! The `ink_prelude` crate guarantees a stable interface between `std` and `no_std` mode.
+//!
+//! Additionally it provides in-memory types that avoid heap allocations for small
+//! amounts of data, namely [`SmallVec`], [`ArrayVec`] and [`ArrayString`], as well
+//! as the [`tinyfmt`] formatting that does not depend on `core::fmt`. Its
+//! [`tinyfmt!`](crate::tinyfmt) macro formats into a fixed size buffer without heap
+//! allocations and its [`format_lite!`](crate::format_lite) macro into a `String`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+pub mod tinyfmt;
+
+pub use arrayvec::{
+    ArrayString,
+    ArrayVec,
+};
 use cfg_if::cfg_if;
+pub use smallvec::{
+    smallvec,
+    SmallVec,
+};
 
 cfg_if! {
     if #[cfg(feature = "std")] {
@@ -0,0 +1,378 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lightweight string formatting that does not depend on `core::fmt`.
+//!
+//! The formatting machinery of `core::fmt` adds a significant amount of code
+//! to the Wasm blob of a contract. Instead values implementing the [`TinyFmt`]
+//! trait, i.e. strings, characters, booleans, integers and byte slices wrapped
+//! in [`Hex`], are appended one after another to a [`Sink`].
+//!
+//! Error messages of contracts, e.g. the payloads of `ink::revert!`, are usually
+//! short. The [`Buffer`] sink concatenates them on the stack and the
+//! [`tinyfmt!`](crate::tinyfmt) macro builds one in a single expression without
+//! the need for an allocator. The [`format_lite!`](crate::format_lite) macro
+//! concatenates its arguments into a [`String`] instead.
+//!
+//! Integers are formatted in their decimal representation and byte slices
+//! wrapped in [`Hex`] in their `0x` prefixed hexadecimal representation.
+
+use crate::string::String;
+use arrayvec::{
+    Array,
+    ArrayString,
+};
+use core::ops::Deref;
+
+/// The destination of formatted values.
+pub trait Sink {
+    /// Appends the string to the sink.
+    fn push_str(&mut self, string: &str);
+
+    /// Appends the ASCII character to the sink.
+    fn push_ascii(&mut self, byte: u8);
+}
+
+impl Sink for String {
+    fn push_str(&mut self, string: &str) {
+        String::push_str(self, string)
+    }
+
+    fn push_ascii(&mut self, byte: u8) {
+        debug_assert!(byte.is_ascii());
+        self.push(byte as char)
+    }
+}
+
+/// A string buffer of fixed capacity.
+///
+/// The capacity in bytes is given by the byte array type `A`, e.g. `Buffer<[u8; 64]>`.
+/// Contents exceeding the capacity are cut off at a character boundary which is
+/// reported by [`Buffer::is_truncated`]. This way constructing an error message
+/// never traps the contract execution.
+#[derive(Copy, Clone)]
+pub struct Buffer<A>
+where
+    A: Array<Item = u8> + Copy,
+{
+    inner: ArrayString<A>,
+    truncated: bool,
+}
+
+impl<A> Buffer<A>
+where
+    A: Array<Item = u8> + Copy,
+{
+    /// Creates an empty buffer.
+    pub fn new() -> Self {
+        Self {
+            inner: ArrayString::new(),
+            truncated: false,
+        }
+    }
+
+    /// Appends the textual representation of the value to the buffer.
+    pub fn push<T>(&mut self, value: &T) -> &mut Self
+    where
+        T: TinyFmt + ?Sized,
+    {
+        value.tiny_fmt(self);
+        self
+    }
+
+    /// Appends the string to the buffer and truncates it if the buffer is full.
+    pub fn push_str(&mut self, string: &str) -> &mut Self {
+        <Self as Sink>::push_str(self, string);
+        self
+    }
+
+    /// Returns the contents of the buffer.
+    pub fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the UTF-8 encoded contents of the buffer.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Returns the capacity of the buffer in bytes.
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Returns `true` if contents have been cut off since the buffer was full.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl<A> Sink for Buffer<A>
+where
+    A: Array<Item = u8> + Copy,
+{
+    /// Appends the string to the buffer and truncates it if the buffer is full.
+    fn push_str(&mut self, string: &str) {
+        let remaining = self.inner.capacity() - self.inner.len();
+        if string.len() <= remaining {
+            self.inner.push_str(string);
+        } else {
+            let mut end = remaining;
+            while !string.is_char_boundary(end) {
+                end -= 1;
+            }
+            self.inner.push_str(&string[..end]);
+            self.truncated = true;
+        }
+    }
+
+    /// Appends the ASCII character to the buffer if it is not full.
+    fn push_ascii(&mut self, byte: u8) {
+        debug_assert!(byte.is_ascii());
+        if self.inner.len() < self.inner.capacity() {
+            self.inner.push(byte as char);
+        } else {
+            self.truncated = true;
+        }
+    }
+}
+
+impl<A> Default for Buffer<A>
+where
+    A: Array<Item = u8> + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> Deref for Buffer<A>
+where
+    A: Array<Item = u8> + Copy,
+{
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<A> AsRef<str> for Buffer<A>
+where
+    A: Array<Item = u8> + Copy,
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Types that can be appended to a [`Sink`] without `core::fmt`.
+pub trait TinyFmt {
+    /// Appends the textual representation of `self` to the sink.
+    fn tiny_fmt<S>(&self, sink: &mut S)
+    where
+        S: Sink + ?Sized;
+}
+
+impl<T> TinyFmt for &T
+where
+    T: TinyFmt + ?Sized,
+{
+    fn tiny_fmt<S>(&self, sink: &mut S)
+    where
+        S: Sink + ?Sized,
+    {
+        <T as TinyFmt>::tiny_fmt(*self, sink)
+    }
+}
+
+impl TinyFmt for str {
+    fn tiny_fmt<S>(&self, sink: &mut S)
+    where
+        S: Sink + ?Sized,
+    {
+        sink.push_str(self);
+    }
+}
+
+impl TinyFmt for String {
+    fn tiny_fmt<S>(&self, sink: &mut S)
+    where
+        S: Sink + ?Sized,
+    {
+        sink.push_str(self);
+    }
+}
+
+impl TinyFmt for char {
+    fn tiny_fmt<S>(&self, sink: &mut S)
+    where
+        S: Sink + ?Sized,
+    {
+        let mut encoded = [0u8; 4];
+        sink.push_str(self.encode_utf8(&mut encoded));
+    }
+}
+
+impl TinyFmt for bool {
+    fn tiny_fmt<S>(&self, sink: &mut S)
+    where
+        S: Sink + ?Sized,
+    {
+        sink.push_str(if *self { "true" } else { "false" });
+    }
+}
+
+/// Appends the decimal representation of the unsigned integer to the sink.
+fn push_decimal<S>(mut value: u128, sink: &mut S)
+where
+    S: Sink + ?Sized,
+{
+    // `u128::MAX` has 39 decimal digits.
+    let mut digits = [0u8; 39];
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break
+        }
+    }
+    for digit in &digits[start..] {
+        sink.push_ascii(*digit);
+    }
+}
+
+macro_rules! impl_tiny_fmt_for_unsigned {
+    ( $($ty:ty),* ) => {
+        $(
+            impl TinyFmt for $ty {
+                fn tiny_fmt<S>(&self, sink: &mut S)
+                where
+                    S: Sink + ?Sized,
+                {
+                    push_decimal(*self as u128, sink)
+                }
+            }
+        )*
+    };
+}
+impl_tiny_fmt_for_unsigned!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_tiny_fmt_for_signed {
+    ( $($ty:ty),* ) => {
+        $(
+            impl TinyFmt for $ty {
+                fn tiny_fmt<S>(&self, sink: &mut S)
+                where
+                    S: Sink + ?Sized,
+                {
+                    let value = *self as i128;
+                    if value < 0 {
+                        sink.push_ascii(b'-');
+                        // Negating in the unsigned domain also handles the minimum value.
+                        push_decimal((value as u128).wrapping_neg(), sink)
+                    } else {
+                        push_decimal(value as u128, sink)
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_tiny_fmt_for_signed!(i8, i16, i32, i64, i128, isize);
+
+/// Formats the wrapped bytes in their `0x` prefixed lowercase hexadecimal representation.
+///
+/// # Example
+///
+/// ```
+/// # use ink_prelude::tinyfmt::{Buffer, Hex};
+/// let mut buffer = <Buffer<[u8; 16]>>::new();
+/// buffer.push(&Hex(&[0xDE, 0xAD, 0xBE, 0xEF]));
+/// assert_eq!(buffer.as_str(), "0xdeadbeef");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Hex<'a>(pub &'a [u8]);
+
+impl<'a> TinyFmt for Hex<'a> {
+    fn tiny_fmt<S>(&self, sink: &mut S)
+    where
+        S: Sink + ?Sized,
+    {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        sink.push_str("0x");
+        for byte in self.0 {
+            sink.push_ascii(DIGITS[(byte >> 4) as usize]);
+            sink.push_ascii(DIGITS[(byte & 0x0F) as usize]);
+        }
+    }
+}
+
+/// Concatenates the textual representations of the arguments into a [`Buffer`].
+///
+/// The first argument is the byte array type defining the capacity of the buffer,
+/// followed by the values appended one after another via
+/// [`TinyFmt`](crate::tinyfmt::TinyFmt).
+///
+/// # Example
+///
+/// ```
+/// # use ink_prelude::{tinyfmt, tinyfmt::Hex};
+/// let balance = 42u128;
+/// let account = [0x01, 0xFF];
+/// let message = tinyfmt!([u8; 64]; "balance of ", Hex(&account), ": ", balance);
+/// assert_eq!(message.as_str(), "balance of 0x01ff: 42");
+/// assert!(!message.is_truncated());
+///
+/// let message = tinyfmt!([u8; 8]; "balance: ", balance);
+/// assert_eq!(message.as_str(), "balance:");
+/// assert!(message.is_truncated());
+/// ```
+#[macro_export]
+macro_rules! tinyfmt {
+    ( $capacity:ty; $($arg:expr),* $(,)? ) => {{
+        let mut buffer = $crate::tinyfmt::Buffer::<$capacity>::new();
+        $(
+            buffer.push(&$arg);
+        )*
+        buffer
+    }};
+}
+
+/// Concatenates the textual representations of the arguments into a [`String`].
+///
+/// In contrast to `format!` no format string is used: the arguments are
+/// appended one after another via [`TinyFmt`](crate::tinyfmt::TinyFmt).
+/// Use [`tinyfmt!`](crate::tinyfmt) in order to format without an allocator.
+///
+/// # Example
+///
+/// ```
+/// # use ink_prelude::format_lite;
+/// let balance = 42u128;
+/// let message = format_lite!("insufficient balance: ", balance, " < ", -1i8);
+/// assert_eq!(message, "insufficient balance: 42 < -1");
+/// ```
+#[macro_export]
+macro_rules! format_lite {
+    ( $($arg:expr),* $(,)? ) => {{
+        let mut buffer = $crate::string::String::new();
+        $(
+            $crate::tinyfmt::TinyFmt::tiny_fmt(&$arg, &mut buffer);
+        )*
+        buffer
+    }};
+}
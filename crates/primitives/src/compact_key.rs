@@ -0,0 +1,129 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Key;
+use core::fmt;
+
+/// Compact 16 bytes key into contract storage.
+///
+/// Newer versions of `pallet-contracts` accept storage keys of a fixed size
+/// that is smaller than the 32 bytes of a [`Key`]. Using compact keys halves
+/// the amount of bytes that have to be hashed upon every storage access.
+///
+/// # Note
+///
+/// A compact key is made up of the 16 least significant bytes of a [`Key`]
+/// and therefore preserves the offsets used by static storage layouts.
+#[derive(Copy, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct CompactKey([u8; 16]);
+
+impl CompactKey {
+    /// Creates a new compact key from the given bytes.
+    #[inline]
+    pub const fn new(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the underlying bytes of the compact key.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// Returns the full key with its 16 most significant bytes set to zero.
+    #[inline]
+    pub const fn to_key(&self) -> Key {
+        let mut bytes = [0x00; 32];
+        let mut i = 0;
+        while i < 16 {
+            bytes[i] = self.0[i];
+            i += 1;
+        }
+        Key::from_bytes(bytes)
+    }
+}
+
+impl From<[u8; 16]> for CompactKey {
+    #[inline]
+    fn from(bytes: [u8; 16]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl From<Key> for CompactKey {
+    #[inline]
+    fn from(key: Key) -> Self {
+        key.to_compact()
+    }
+}
+
+impl From<CompactKey> for Key {
+    #[inline]
+    fn from(key: CompactKey) -> Self {
+        key.to_key()
+    }
+}
+
+impl fmt::Debug for CompactKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CompactKey({})", self)
+    }
+}
+
+impl fmt::Display for CompactKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0x")?;
+        for byte in &self.0 {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_key_works() {
+        let compact = CompactKey::new([0x01; 16]);
+        let mut expected = [0x00; 32];
+        expected[..16].copy_from_slice(&[0x01; 16]);
+        assert_eq!(compact.to_key().to_bytes(), expected);
+        assert_eq!(compact.to_key().to_compact(), compact);
+    }
+
+    #[test]
+    fn scale_codec_works() {
+        use scale::{
+            Decode as _,
+            Encode as _,
+        };
+        let compact = Key::from([0x42; 32]).to_compact();
+        let encoded = compact.encode();
+        assert_eq!(encoded, vec![0x42; 16]);
+        assert_eq!(CompactKey::decode(&mut &encoded[..]), Ok(compact));
+        assert!(CompactKey::decode(&mut &[0x42; 15][..]).is_err());
+    }
+
+    #[test]
+    fn debug_works() {
+        assert_eq!(
+            format!("{:?}", CompactKey::new([0xAB; 16])),
+            "CompactKey(0xABABABABABABABABABABABABABABABAB)",
+        );
+    }
+}
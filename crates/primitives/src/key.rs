@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::CompactKey;
 use core::{
     fmt,
     ops::{
@@ -86,6 +87,17 @@ impl From<[u8; 32]> for Key {
 }
 
 impl Key {
+    /// Creates a new key from the given bytes.
+    ///
+    /// # Note
+    ///
+    /// In contrast to the `From<[u8; 32]>` implementation this is a `const fn`
+    /// and therefore allows to define keys at compile time.
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self::from_bytes_be_fallback(bytes)
+    }
+
     /// Creates a new key from the given bytes.
     ///
     /// # Note
@@ -93,9 +105,9 @@ impl Key {
     /// This is a fallback procedure in case the target machine does not have
     /// little endian byte ordering.
     #[inline]
-    fn from_bytes_be_fallback(bytes: [u8; 32]) -> Self {
+    const fn from_bytes_be_fallback(bytes: [u8; 32]) -> Self {
         #[inline]
-        fn carve_out_u64_bytes(bytes: &[u8; 32], offset: u8) -> [u8; 8] {
+        const fn carve_out_u64_bytes(bytes: &[u8; 32], offset: u8) -> [u8; 8] {
             let o = (offset * 8) as usize;
             [
                 bytes[o],
@@ -116,6 +128,38 @@ impl Key {
         ])
     }
 
+    /// Returns the key that is offset by the given amount.
+    ///
+    /// Wraps around on overflow the same way as the `Add<u64>` implementation
+    /// but can be used to compute storage layouts at compile time.
+    #[inline]
+    #[rustfmt::skip]
+    pub const fn add_u64(self, rhs: u64) -> Self {
+        let (res_0,  ovfl_0) = self.0[0].overflowing_add(rhs);
+        let (res_1,  ovfl_1) = self.0[1].overflowing_add(ovfl_0 as u64);
+        let (res_2,  ovfl_2) = self.0[2].overflowing_add(ovfl_1 as u64);
+        let (res_3, _ovfl_3) = self.0[3].overflowing_add(ovfl_2 as u64);
+        Self([res_0, res_1, res_2, res_3])
+    }
+
+    /// Returns the compact 16 bytes representation of the key.
+    ///
+    /// The compact key consists of the 16 least significant bytes of the key
+    /// so that keys which are offset from the same base key stay distinct.
+    #[inline]
+    pub const fn to_compact(&self) -> CompactKey {
+        let low = self.0[0].to_le_bytes();
+        let high = self.0[1].to_le_bytes();
+        let mut bytes = [0x00; 16];
+        let mut i = 0;
+        while i < 8 {
+            bytes[i] = low[i];
+            bytes[i + 8] = high[i];
+            i += 1;
+        }
+        CompactKey::new(bytes)
+    }
+
     /// Tries to return the underlying bytes as slice.
     ///
     /// This only returns `Some` if the execution environment has little-endian
@@ -246,16 +290,8 @@ const _: () = {
 
 impl AddAssign<u64> for Key {
     #[inline]
-    #[rustfmt::skip]
     fn add_assign(&mut self, rhs: u64) {
-        let (res_0,  ovfl_0) = self.0[0].overflowing_add(rhs);
-        let (res_1,  ovfl_1) = self.0[1].overflowing_add(ovfl_0 as u64);
-        let (res_2,  ovfl_2) = self.0[2].overflowing_add(ovfl_1 as u64);
-        let (res_3, _ovfl_3) = self.0[3].overflowing_add(ovfl_2 as u64);
-        self.0[0] = res_0;
-        self.0[1] = res_1;
-        self.0[2] = res_2;
-        self.0[3] = res_3;
+        *self = self.add_u64(rhs)
     }
 }
 
@@ -392,4 +428,21 @@ mod tests {
             assert_eq!(key.to_bytes(), expected);
         }
     }
+
+    #[test]
+    fn const_fns_work() {
+        const KEY: Key = Key::from_bytes([0xFF; 32]).add_u64(1);
+        assert_eq!(KEY.to_bytes(), [0x00; 32]);
+        assert_eq!(
+            Key::from_bytes(test_bytes()).add_u64(42),
+            Key::from(test_bytes()) + 42,
+        );
+    }
+
+    #[test]
+    fn to_compact_works() {
+        let key = Key::from(test_bytes());
+        assert_eq!(key.to_compact().as_bytes()[..], test_bytes()[..16]);
+        assert_ne!(key.to_compact(), (key + 1).to_compact());
+    }
 }
@@ -23,10 +23,18 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod compact_key;
 mod key;
 mod key_ptr;
+pub mod math;
+pub mod merkle;
+pub mod rand;
+#[cfg(feature = "std")]
+pub mod ss58;
 
 pub use self::{
+    compact_key::CompactKey,
     key::Key,
     key_ptr::KeyPtr,
+    math::TokenAmount,
 };
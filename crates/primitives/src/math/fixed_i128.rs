@@ -0,0 +1,365 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    FixedU128,
+    OverflowError,
+    U256,
+};
+use core::{
+    convert::TryFrom,
+    fmt,
+    ops::{
+        Add,
+        AddAssign,
+        Div,
+        DivAssign,
+        Mul,
+        MulAssign,
+        Neg,
+        Sub,
+        SubAssign,
+    },
+};
+
+/// A signed fixed-point number with 18 decimals.
+///
+/// The number is represented by its inner `i128` value divided by
+/// [`FixedI128::DIV`], i.e. an inner value of `-1_500_000_000_000_000_000`
+/// represents `-1.5`. Multiplications and divisions are computed on the
+/// magnitudes with a [`U256`] intermediate so that they only fail if the
+/// result overflows.
+#[derive(Copy, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct FixedI128(i128);
+
+impl FixedI128 {
+    /// The number of decimals of the fixed-point number.
+    pub const DECIMALS: u32 = 18;
+
+    /// The inner value representing one, i.e. `10^DECIMALS`.
+    pub const DIV: i128 = 1_000_000_000_000_000_000;
+
+    /// The number zero.
+    pub const ZERO: Self = Self(0);
+
+    /// The number one.
+    pub const ONE: Self = Self(Self::DIV);
+
+    /// The smallest value that can be represented by this type.
+    pub const MIN: Self = Self(i128::MIN);
+
+    /// The largest value that can be represented by this type.
+    pub const MAX: Self = Self(i128::MAX);
+
+    /// Creates a fixed-point number from its inner value.
+    pub const fn from_inner(inner: i128) -> Self {
+        Self(inner)
+    }
+
+    /// Returns the inner value of the fixed-point number.
+    pub const fn into_inner(self) -> i128 {
+        self.0
+    }
+
+    /// Creates a fixed-point number from an integer or returns `None` upon overflow.
+    pub fn checked_from_integer(int: i128) -> Option<Self> {
+        int.checked_mul(Self::DIV).map(Self)
+    }
+
+    /// Creates the fixed-point number `n / d`.
+    ///
+    /// Returns `None` if `d` is zero or if the result overflows.
+    pub fn checked_from_rational(n: i128, d: i128) -> Option<Self> {
+        mul_div(n, Self::DIV, d).map(Self)
+    }
+
+    /// Returns the integral part of the fixed-point number truncated towards zero.
+    pub const fn trunc(self) -> i128 {
+        self.0 / Self::DIV
+    }
+
+    /// Returns the fractional part of the fixed-point number.
+    ///
+    /// The fractional part has the same sign as the fixed-point number.
+    pub const fn frac(self) -> Self {
+        Self(self.0 % Self::DIV)
+    }
+
+    /// Returns `true` if the fixed-point number is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if the fixed-point number is strictly negative.
+    pub const fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns the absolute value or `None` if `self` is [`FixedI128::MIN`].
+    pub fn checked_abs(self) -> Option<Self> {
+        self.0.checked_abs().map(Self)
+    }
+
+    /// Returns `-self` or `None` if `self` is [`FixedI128::MIN`].
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+
+    /// Returns `self + rhs` or `None` upon overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Returns `self - rhs` or `None` upon overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Returns `self * rhs` or `None` upon overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        mul_div(self.0, rhs.0, Self::DIV).map(Self)
+    }
+
+    /// Returns `self / rhs` or `None` if `rhs` is zero or the result overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        mul_div(self.0, Self::DIV, rhs.0).map(Self)
+    }
+
+    /// Multiplies the integer with the fixed-point number and truncates the
+    /// product towards zero.
+    ///
+    /// Returns `None` if the product overflows.
+    ///
+    /// # Example
+    ///
+    /// Applies a price change of -2.5% to the given price:
+    ///
+    /// ```
+    /// # use ink_primitives::math::FixedI128;
+    /// let change = FixedI128::checked_from_rational(-25, 1000).unwrap();
+    /// assert_eq!(change.checked_mul_int(10_000), Some(-250));
+    /// ```
+    pub fn checked_mul_int(self, int: i128) -> Option<i128> {
+        mul_div(self.0, int, Self::DIV)
+    }
+
+    /// Returns `self + rhs` saturating at the numeric bounds.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Returns `self - rhs` saturating at the numeric bounds.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Returns `self * rhs` saturating at the numeric bounds.
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or_else(|| {
+            if self.is_negative() != rhs.is_negative() {
+                Self::MIN
+            } else {
+                Self::MAX
+            }
+        })
+    }
+
+    /// Multiplies the integer with the fixed-point number saturating at the
+    /// bounds of `i128`.
+    pub fn saturating_mul_int(self, int: i128) -> i128 {
+        self.checked_mul_int(int).unwrap_or_else(|| {
+            if self.is_negative() != (int < 0) {
+                i128::MIN
+            } else {
+                i128::MAX
+            }
+        })
+    }
+}
+
+/// Returns `a * b / c` computed on the magnitudes with a 256-bit intermediate.
+///
+/// The quotient is truncated towards zero.
+fn mul_div(a: i128, b: i128, c: i128) -> Option<i128> {
+    let negative = (a < 0) ^ (b < 0) ^ (c < 0);
+    let magnitude = U256::from(a.unsigned_abs())
+        .checked_mul_div(U256::from(b.unsigned_abs()), U256::from(c.unsigned_abs()))
+        .and_then(|result| u128::try_from(result).ok())?;
+    if negative {
+        if magnitude > i128::MIN.unsigned_abs() {
+            return None
+        }
+        Some((magnitude as i128).wrapping_neg())
+    } else {
+        i128::try_from(magnitude).ok()
+    }
+}
+
+macro_rules! impl_checked_ops {
+    ( $( $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident,
+         $checked:ident, $error:literal; )* ) => {
+        $(
+            impl $trait for FixedI128 {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    self.$checked(rhs).expect($error)
+                }
+            }
+
+            impl $assign_trait for FixedI128 {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = <Self as $trait>::$method(*self, rhs)
+                }
+            }
+        )*
+    };
+}
+impl_checked_ops! {
+    Add, add, AddAssign, add_assign, checked_add, "encountered overflow in FixedI128 addition";
+    Sub, sub, SubAssign, sub_assign, checked_sub, "encountered overflow in FixedI128 subtraction";
+    Mul, mul, MulAssign, mul_assign, checked_mul, "encountered overflow in FixedI128 multiplication";
+    Div, div, DivAssign, div_assign, checked_div, "encountered invalid FixedI128 division";
+}
+
+impl Neg for FixedI128 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg()
+            .expect("encountered overflow in FixedI128 negation")
+    }
+}
+
+impl TryFrom<FixedU128> for FixedI128 {
+    type Error = OverflowError;
+
+    fn try_from(value: FixedU128) -> Result<Self, Self::Error> {
+        i128::try_from(value.into_inner())
+            .map(Self)
+            .map_err(|_| OverflowError)
+    }
+}
+
+impl TryFrom<FixedI128> for FixedU128 {
+    type Error = OverflowError;
+
+    fn try_from(value: FixedI128) -> Result<Self, Self::Error> {
+        u128::try_from(value.0)
+            .map(FixedU128::from_inner)
+            .map_err(|_| OverflowError)
+    }
+}
+
+impl fmt::Display for FixedI128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let magnitude = self.0.unsigned_abs();
+        let div = Self::DIV as u128;
+        write!(f, "{}{}.{:018}", sign, magnitude / div, magnitude % div)
+    }
+}
+
+impl fmt::Debug for FixedI128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FixedI128({})", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_works() {
+        assert_eq!(FixedI128::checked_from_integer(1), Some(FixedI128::ONE));
+        assert_eq!(FixedI128::checked_from_integer(i128::MIN), None);
+        assert_eq!(
+            FixedI128::checked_from_rational(-3, 2),
+            Some(FixedI128::from_inner(-1_500_000_000_000_000_000))
+        );
+        assert_eq!(
+            FixedI128::checked_from_rational(-3, -2),
+            Some(FixedI128::from_inner(1_500_000_000_000_000_000))
+        );
+        assert_eq!(FixedI128::checked_from_rational(1, 0), None);
+        assert_eq!(
+            FixedI128::from_inner(-2_500_000_000_000_000_000).trunc(),
+            -2
+        );
+        assert_eq!(
+            FixedI128::from_inner(-2_500_000_000_000_000_000).frac(),
+            FixedI128::from_inner(-500_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn arithmetic_works() {
+        let minus_one_and_half = FixedI128::checked_from_rational(-3, 2).unwrap();
+        let two = FixedI128::checked_from_integer(2).unwrap();
+        assert_eq!(
+            minus_one_and_half * two,
+            FixedI128::checked_from_integer(-3).unwrap()
+        );
+        assert_eq!(
+            minus_one_and_half * -two,
+            FixedI128::checked_from_integer(3).unwrap()
+        );
+        assert_eq!(
+            two / minus_one_and_half * minus_one_and_half,
+            two - FixedI128::from_inner(1)
+        );
+        assert_eq!(
+            minus_one_and_half + two,
+            FixedI128::checked_from_rational(1, 2).unwrap()
+        );
+        assert_eq!(
+            FixedI128::ZERO - FixedI128::ONE,
+            FixedI128::checked_from_integer(-1).unwrap()
+        );
+        assert_eq!(FixedI128::MIN.checked_sub(FixedI128::ONE), None);
+        assert_eq!(FixedI128::MIN.checked_neg(), None);
+        assert_eq!(FixedI128::MIN.checked_abs(), None);
+        assert_eq!(FixedI128::ONE.checked_div(FixedI128::ZERO), None);
+        assert_eq!(FixedI128::MAX.checked_mul(two), None);
+        assert_eq!(FixedI128::MAX.saturating_mul(-two), FixedI128::MIN);
+        assert_eq!(FixedI128::MIN.saturating_mul(-two), FixedI128::MAX);
+        assert_eq!(minus_one_and_half.saturating_mul_int(i128::MAX), i128::MIN);
+        assert_eq!(minus_one_and_half.checked_mul_int(5), Some(-7));
+        assert_eq!(
+            FixedI128::from_inner(-1).checked_mul(FixedI128::from_inner(i128::MIN)),
+            Some(FixedI128::from_inner(170_141_183_460_469_231_731))
+        );
+    }
+
+    #[test]
+    fn conversion_works() {
+        assert_eq!(FixedI128::try_from(FixedU128::ONE), Ok(FixedI128::ONE));
+        assert_eq!(FixedI128::try_from(FixedU128::MAX), Err(OverflowError));
+        assert_eq!(FixedU128::try_from(FixedI128::ONE), Ok(FixedU128::ONE));
+        assert_eq!(FixedU128::try_from(-FixedI128::ONE), Err(OverflowError));
+    }
+
+    #[test]
+    fn display_works() {
+        let value = FixedI128::checked_from_rational(-1, 2).unwrap();
+        assert_eq!(format!("{}", value), "-0.500000000000000000");
+        assert_eq!(
+            format!("{:?}", FixedI128::ONE),
+            "FixedI128(1.000000000000000000)"
+        );
+    }
+}
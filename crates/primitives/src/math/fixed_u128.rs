@@ -0,0 +1,253 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::U256;
+use core::{
+    convert::TryFrom,
+    fmt,
+    ops::{
+        Add,
+        AddAssign,
+        Div,
+        DivAssign,
+        Mul,
+        MulAssign,
+        Sub,
+        SubAssign,
+    },
+};
+
+/// An unsigned fixed-point number with 18 decimals.
+///
+/// The number is represented by its inner `u128` value divided by
+/// [`FixedU128::DIV`], i.e. an inner value of `1_500_000_000_000_000_000`
+/// represents `1.5`. Multiplications and divisions are computed with a
+/// [`U256`] intermediate so that they only fail if the result overflows.
+#[derive(Copy, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct FixedU128(u128);
+
+impl FixedU128 {
+    /// The number of decimals of the fixed-point number.
+    pub const DECIMALS: u32 = 18;
+
+    /// The inner value representing one, i.e. `10^DECIMALS`.
+    pub const DIV: u128 = 1_000_000_000_000_000_000;
+
+    /// The number zero.
+    pub const ZERO: Self = Self(0);
+
+    /// The number one.
+    pub const ONE: Self = Self(Self::DIV);
+
+    /// The largest value that can be represented by this type.
+    pub const MAX: Self = Self(u128::MAX);
+
+    /// Creates a fixed-point number from its inner value.
+    pub const fn from_inner(inner: u128) -> Self {
+        Self(inner)
+    }
+
+    /// Returns the inner value of the fixed-point number.
+    pub const fn into_inner(self) -> u128 {
+        self.0
+    }
+
+    /// Creates a fixed-point number from an integer or returns `None` upon overflow.
+    pub fn checked_from_integer(int: u128) -> Option<Self> {
+        int.checked_mul(Self::DIV).map(Self)
+    }
+
+    /// Creates the fixed-point number `n / d`.
+    ///
+    /// Returns `None` if `d` is zero or if the result overflows.
+    pub fn checked_from_rational(n: u128, d: u128) -> Option<Self> {
+        mul_div(n, Self::DIV, d).map(Self)
+    }
+
+    /// Returns the integral part of the fixed-point number.
+    pub const fn trunc(self) -> u128 {
+        self.0 / Self::DIV
+    }
+
+    /// Returns the fractional part of the fixed-point number.
+    pub const fn frac(self) -> Self {
+        Self(self.0 % Self::DIV)
+    }
+
+    /// Returns `true` if the fixed-point number is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `self + rhs` or `None` upon overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Returns `self - rhs` or `None` upon underflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Returns `self * rhs` or `None` upon overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        mul_div(self.0, rhs.0, Self::DIV).map(Self)
+    }
+
+    /// Returns `self / rhs` or `None` if `rhs` is zero or the result overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        mul_div(self.0, Self::DIV, rhs.0).map(Self)
+    }
+
+    /// Multiplies the integer with the fixed-point number and truncates the product.
+    ///
+    /// Returns `None` if the product overflows.
+    ///
+    /// # Example
+    ///
+    /// Computes a fee of 0.3% of the given amount:
+    ///
+    /// ```
+    /// # use ink_primitives::math::FixedU128;
+    /// let fee = FixedU128::checked_from_rational(3, 1000).unwrap();
+    /// assert_eq!(fee.checked_mul_int(1_000_000), Some(3_000));
+    /// ```
+    pub fn checked_mul_int(self, int: u128) -> Option<u128> {
+        mul_div(self.0, int, Self::DIV)
+    }
+
+    /// Returns `self + rhs` saturating at [`FixedU128::MAX`].
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Returns `self - rhs` saturating at [`FixedU128::ZERO`].
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Returns `self * rhs` saturating at [`FixedU128::MAX`].
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Multiplies the integer with the fixed-point number saturating at `u128::MAX`.
+    pub fn saturating_mul_int(self, int: u128) -> u128 {
+        self.checked_mul_int(int).unwrap_or(u128::MAX)
+    }
+}
+
+/// Returns `a * b / c` computed with a 256-bit intermediate.
+fn mul_div(a: u128, b: u128, c: u128) -> Option<u128> {
+    U256::from(a)
+        .checked_mul_div(U256::from(b), U256::from(c))
+        .and_then(|result| u128::try_from(result).ok())
+}
+
+macro_rules! impl_checked_ops {
+    ( $( $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident,
+         $checked:ident, $error:literal; )* ) => {
+        $(
+            impl $trait for FixedU128 {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    self.$checked(rhs).expect($error)
+                }
+            }
+
+            impl $assign_trait for FixedU128 {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = <Self as $trait>::$method(*self, rhs)
+                }
+            }
+        )*
+    };
+}
+impl_checked_ops! {
+    Add, add, AddAssign, add_assign, checked_add, "encountered overflow in FixedU128 addition";
+    Sub, sub, SubAssign, sub_assign, checked_sub, "encountered underflow in FixedU128 subtraction";
+    Mul, mul, MulAssign, mul_assign, checked_mul, "encountered overflow in FixedU128 multiplication";
+    Div, div, DivAssign, div_assign, checked_div, "encountered invalid FixedU128 division";
+}
+
+impl fmt::Display for FixedU128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{:018}", self.trunc(), self.frac().0)
+    }
+}
+
+impl fmt::Debug for FixedU128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FixedU128({})", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_works() {
+        assert_eq!(FixedU128::checked_from_integer(1), Some(FixedU128::ONE));
+        assert_eq!(FixedU128::checked_from_integer(u128::MAX), None);
+        assert_eq!(
+            FixedU128::checked_from_rational(3, 2),
+            Some(FixedU128::from_inner(1_500_000_000_000_000_000))
+        );
+        assert_eq!(FixedU128::checked_from_rational(1, 0), None);
+        assert_eq!(FixedU128::from_inner(2_500_000_000_000_000_000).trunc(), 2);
+    }
+
+    #[test]
+    fn arithmetic_works() {
+        let one_and_half = FixedU128::checked_from_rational(3, 2).unwrap();
+        let two = FixedU128::checked_from_integer(2).unwrap();
+        assert_eq!(
+            one_and_half * two,
+            FixedU128::checked_from_integer(3).unwrap()
+        );
+        assert_eq!(
+            two / one_and_half * one_and_half,
+            two - FixedU128::from_inner(1)
+        );
+        assert_eq!(
+            one_and_half + one_and_half,
+            FixedU128::checked_from_integer(3).unwrap()
+        );
+        assert_eq!(
+            one_and_half - FixedU128::ONE,
+            FixedU128::checked_from_rational(1, 2).unwrap()
+        );
+        assert_eq!(FixedU128::ZERO.checked_sub(FixedU128::ONE), None);
+        assert_eq!(FixedU128::ONE.checked_div(FixedU128::ZERO), None);
+        assert_eq!(FixedU128::MAX.checked_mul(two), None);
+        assert_eq!(FixedU128::MAX.saturating_mul(two), FixedU128::MAX);
+        assert_eq!(one_and_half.checked_mul_int(u128::MAX), None);
+        assert_eq!(one_and_half.saturating_mul_int(u128::MAX), u128::MAX);
+        assert_eq!(one_and_half.checked_mul_int(5), Some(7));
+    }
+
+    #[test]
+    fn display_works() {
+        let value = FixedU128::checked_from_rational(3, 2).unwrap();
+        assert_eq!(format!("{}", value), "1.500000000000000000");
+        assert_eq!(
+            format!("{:?}", FixedU128::ZERO),
+            "FixedU128(0.000000000000000000)"
+        );
+    }
+}
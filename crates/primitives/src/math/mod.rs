@@ -0,0 +1,46 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Numeric types for token math in ink! smart contracts.
+//!
+//! The types are lightweight alternatives to external big integer crates that
+//! keep the Wasm blob of a contract small. All arithmetic is checked: the
+//! `checked_*` methods return `None` and the operators panic upon overflow,
+//! underflow or division by zero. Divisions truncate towards zero.
+
+mod fixed_i128;
+mod fixed_u128;
+mod token_amount;
+mod u256;
+
+pub use self::{
+    fixed_i128::FixedI128,
+    fixed_u128::FixedU128,
+    token_amount::TokenAmount,
+    u256::U256,
+};
+
+/// The error returned when a conversion between numeric types fails
+/// because the value does not fit into the target type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OverflowError;
+
+impl core::fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "encountered value out of range for the target type")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OverflowError {}
@@ -0,0 +1,361 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::FixedU128;
+use core::{
+    fmt,
+    ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+    },
+};
+
+/// A token amount denominated with `DECIMALS` decimals.
+///
+/// The amount is stored as its raw `u128` value in the smallest unit of the
+/// token, i.e. with `DECIMALS = 6` a raw value of `1_500_000` represents `1.5`
+/// tokens. Since the decimals are part of the type, amounts of tokens with
+/// different decimal bases cannot be mixed by accident and must be converted
+/// explicitly via [`TokenAmount::checked_convert`].
+///
+/// # Note
+///
+/// The SCALE encoding is the same as the one of the raw `u128` value, so
+/// contracts can switch their `Balance` arguments to this type without
+/// breaking their callers. The type information carries the decimals as the
+/// length of an array of `()` type parameter, e.g. `[(); 6]`.
+///
+/// Tokens can have at most [`TokenAmount::MAX_DECIMALS`] decimals since one
+/// whole token does not fit into a `u128` otherwise.
+#[derive(Copy, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TokenAmount<const DECIMALS: u8>(u128);
+
+impl<const DECIMALS: u8> TokenAmount<DECIMALS> {
+    /// The number of decimals of the token.
+    pub const DECIMALS: u8 = DECIMALS;
+
+    /// The maximum number of decimals of a token.
+    pub const MAX_DECIMALS: u8 = 38;
+
+    /// The raw value representing one whole token, i.e. `10^DECIMALS`.
+    ///
+    /// # Note
+    ///
+    /// Evaluating the unit of a token with more than [`TokenAmount::MAX_DECIMALS`]
+    /// decimals fails at compile time. This also applies to the methods based on it,
+    /// i.e. [`TokenAmount::checked_from_units`], [`TokenAmount::trunc`],
+    /// [`TokenAmount::frac`] and the `Display` implementation.
+    pub const UNIT: u128 = 10u128.pow(DECIMALS as u32);
+
+    /// The amount of zero tokens.
+    pub const ZERO: Self = Self(0);
+
+    /// The largest amount that can be represented by this type.
+    pub const MAX: Self = Self(u128::MAX);
+
+    /// Creates a token amount from its raw value in the smallest unit.
+    pub const fn from_raw(raw: u128) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw value of the token amount in the smallest unit.
+    pub const fn into_raw(self) -> u128 {
+        self.0
+    }
+
+    /// Creates a token amount of whole tokens or returns `None` upon overflow.
+    pub fn checked_from_units(units: u128) -> Option<Self> {
+        units.checked_mul(Self::UNIT).map(Self)
+    }
+
+    /// Returns the number of whole tokens of the amount.
+    pub const fn trunc(self) -> u128 {
+        self.0 / Self::UNIT
+    }
+
+    /// Returns the fractional part of the amount.
+    pub const fn frac(self) -> Self {
+        Self(self.0 % Self::UNIT)
+    }
+
+    /// Returns `true` if the amount is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Converts the amount into an amount denominated with `TARGET` decimals.
+    ///
+    /// Returns `None` if the converted amount overflows or if the conversion
+    /// would lose precision. Use [`TokenAmount::checked_convert_floor`] in order
+    /// to round down instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ink_primitives::math::TokenAmount;
+    /// let amount = TokenAmount::<6>::from_raw(1_500_000);
+    /// let converted = amount.checked_convert::<18>().unwrap();
+    /// assert_eq!(converted.into_raw(), 1_500_000_000_000_000_000);
+    /// assert_eq!(converted.checked_convert::<6>(), Some(amount));
+    /// assert_eq!(TokenAmount::<6>::from_raw(1).checked_convert::<2>(), None);
+    /// ```
+    pub fn checked_convert<const TARGET: u8>(self) -> Option<TokenAmount<TARGET>> {
+        if TARGET < DECIMALS {
+            let lossy = match rescale_factor(DECIMALS - TARGET) {
+                Some(factor) => self.0 % factor != 0,
+                None => self.0 != 0,
+            };
+            if lossy {
+                return None
+            }
+        }
+        self.checked_convert_floor::<TARGET>()
+    }
+
+    /// Converts the amount into an amount denominated with `TARGET` decimals
+    /// rounding down.
+    ///
+    /// Returns `None` if the converted amount overflows.
+    pub fn checked_convert_floor<const TARGET: u8>(self) -> Option<TokenAmount<TARGET>> {
+        let raw = if TARGET >= DECIMALS {
+            self.0.checked_mul(rescale_factor(TARGET - DECIMALS)?)?
+        } else {
+            rescale_factor(DECIMALS - TARGET).map_or(0, |factor| self.0 / factor)
+        };
+        Some(TokenAmount(raw))
+    }
+
+    /// Returns `self + rhs` or `None` upon overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Returns `self - rhs` or `None` upon underflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Returns `self + rhs` saturating at [`TokenAmount::MAX`].
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Returns `self - rhs` saturating at [`TokenAmount::ZERO`].
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Multiplies the amount with the integer or returns `None` upon overflow.
+    pub fn checked_mul_int(self, int: u128) -> Option<Self> {
+        self.0.checked_mul(int).map(Self)
+    }
+
+    /// Multiplies the amount with the fixed-point number and truncates the product.
+    ///
+    /// Returns `None` if the product overflows.
+    pub fn checked_mul_fixed(self, rhs: FixedU128) -> Option<Self> {
+        rhs.checked_mul_int(self.0).map(Self)
+    }
+}
+
+/// Returns `10^decimals` or `None` if it does not fit into a `u128`.
+fn rescale_factor(decimals: u8) -> Option<u128> {
+    10u128.checked_pow(decimals as u32)
+}
+
+impl<const DECIMALS: u8> Add for TokenAmount<DECIMALS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("encountered overflow in TokenAmount addition")
+    }
+}
+
+impl<const DECIMALS: u8> AddAssign for TokenAmount<DECIMALS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<const DECIMALS: u8> Sub for TokenAmount<DECIMALS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("encountered underflow in TokenAmount subtraction")
+    }
+}
+
+impl<const DECIMALS: u8> SubAssign for TokenAmount<DECIMALS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<const DECIMALS: u8> scale::Encode for TokenAmount<DECIMALS> {
+    fn size_hint(&self) -> usize {
+        <u128 as scale::Encode>::size_hint(&self.0)
+    }
+
+    fn encode_to<T: scale::Output>(&self, dest: &mut T) {
+        <u128 as scale::Encode>::encode_to(&self.0, dest)
+    }
+}
+
+impl<const DECIMALS: u8> scale::EncodeLike for TokenAmount<DECIMALS> {}
+
+impl<const DECIMALS: u8> scale::Decode for TokenAmount<DECIMALS> {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        <u128 as scale::Decode>::decode(input).map(Self)
+    }
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use scale_info::{
+        build::Fields,
+        MetaType,
+        Path,
+        Type,
+        TypeDefArray,
+        TypeInfo,
+    };
+
+    /// Carries the decimals of a token amount in its type information.
+    struct Decimals<const DECIMALS: u8>;
+
+    impl<const DECIMALS: u8> TypeInfo for Decimals<DECIMALS> {
+        fn type_info() -> Type {
+            TypeDefArray::new(DECIMALS as u32, MetaType::new::<()>()).into()
+        }
+    }
+
+    impl<const DECIMALS: u8> TypeInfo for TokenAmount<DECIMALS> {
+        fn type_info() -> Type {
+            Type::builder()
+                .path(Path::new("TokenAmount", "ink_primitives::math"))
+                .type_params(vec![MetaType::new::<Decimals<DECIMALS>>()])
+                .composite(Fields::unnamed().field_of::<u128>())
+        }
+    }
+};
+
+impl<const DECIMALS: u8> fmt::Display for TokenAmount<DECIMALS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if DECIMALS == 0 {
+            return write!(f, "{}", self.0)
+        }
+        write!(
+            f,
+            "{}.{:0width$}",
+            self.trunc(),
+            self.frac().0,
+            width = DECIMALS as usize
+        )
+    }
+}
+
+impl<const DECIMALS: u8> fmt::Debug for TokenAmount<DECIMALS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TokenAmount<{}>({})", DECIMALS, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::{
+        Decode as _,
+        Encode as _,
+    };
+
+    type Usdc = TokenAmount<6>;
+    type Dot = TokenAmount<10>;
+
+    #[test]
+    fn from_works() {
+        assert_eq!(Usdc::UNIT, 1_000_000);
+        assert_eq!(Usdc::checked_from_units(2), Some(Usdc::from_raw(2_000_000)));
+        assert_eq!(Usdc::checked_from_units(u128::MAX), None);
+        assert_eq!(Usdc::from_raw(2_500_000).trunc(), 2);
+        assert_eq!(Usdc::from_raw(2_500_000).frac(), Usdc::from_raw(500_000));
+        assert_eq!(TokenAmount::<0>::UNIT, 1);
+        assert_eq!(TokenAmount::<38>::UNIT, 10u128.pow(38));
+    }
+
+    #[test]
+    fn convert_works() {
+        let amount = Usdc::from_raw(1_500_000);
+        assert_eq!(
+            amount.checked_convert::<10>(),
+            Some(Dot::from_raw(15_000_000_000))
+        );
+        assert_eq!(amount.checked_convert::<6>(), Some(amount));
+        assert_eq!(amount.checked_convert::<0>(), None);
+        assert_eq!(
+            amount.checked_convert_floor::<0>(),
+            Some(TokenAmount::from_raw(1))
+        );
+        assert_eq!(Usdc::MAX.checked_convert::<10>(), None);
+        assert_eq!(Usdc::MAX.checked_convert_floor::<10>(), None);
+        assert_eq!(
+            TokenAmount::<40>::MAX.checked_convert_floor::<0>(),
+            Some(TokenAmount::from_raw(0))
+        );
+    }
+
+    #[test]
+    fn arithmetic_works() {
+        let one = Usdc::checked_from_units(1).unwrap();
+        let half = Usdc::from_raw(500_000);
+        assert_eq!(one + half, Usdc::from_raw(1_500_000));
+        assert_eq!(one - half, half);
+        assert_eq!(Usdc::ZERO.checked_sub(one), None);
+        assert_eq!(Usdc::ZERO.saturating_sub(one), Usdc::ZERO);
+        assert_eq!(Usdc::MAX.checked_add(one), None);
+        assert_eq!(Usdc::MAX.saturating_add(one), Usdc::MAX);
+        assert_eq!(half.checked_mul_int(3), Some(Usdc::from_raw(1_500_000)));
+        let fee = FixedU128::checked_from_rational(3, 1000).unwrap();
+        assert_eq!(one.checked_mul_fixed(fee), Some(Usdc::from_raw(3_000)));
+    }
+
+    #[test]
+    fn codec_works() {
+        let amount = Usdc::from_raw(1_500_000);
+        let encoded = amount.encode();
+        assert_eq!(encoded, 1_500_000u128.encode());
+        assert_eq!(Usdc::decode(&mut &encoded[..]).unwrap(), amount);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn type_info_works() {
+        use scale_info::TypeInfo as _;
+        assert_eq!(Usdc::type_info(), TokenAmount::<6>::type_info());
+        assert_ne!(Usdc::type_info(), Dot::type_info());
+    }
+
+    #[test]
+    fn display_works() {
+        assert_eq!(format!("{}", Usdc::from_raw(1_500_000)), "1.500000");
+        assert_eq!(format!("{}", TokenAmount::<0>::from_raw(42)), "42");
+        assert_eq!(
+            format!("{:?}", Usdc::from_raw(5)),
+            "TokenAmount<6>(0.000005)"
+        );
+    }
+}
@@ -0,0 +1,498 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::OverflowError;
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt,
+    ops::{
+        Add,
+        AddAssign,
+        Div,
+        DivAssign,
+        Mul,
+        MulAssign,
+        Rem,
+        RemAssign,
+        Sub,
+        SubAssign,
+    },
+};
+
+/// A 256-bit unsigned integer.
+///
+/// The value is stored as four 64-bit limbs with the least significant limb first
+/// and therefore SCALE encodes to its 32 bytes in little endian order.
+#[derive(Copy, Default, Clone, PartialEq, Eq, Hash)]
+#[derive(scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct U256([u64; 4]);
+
+/// The product of two [`U256`] values with the least significant limb first.
+type U512 = [u64; 8];
+
+impl U256 {
+    /// The number zero.
+    pub const ZERO: Self = Self([0; 4]);
+
+    /// The number one.
+    pub const ONE: Self = Self([1, 0, 0, 0]);
+
+    /// The largest value that can be represented by this type, i.e. `2^256 - 1`.
+    pub const MAX: Self = Self([u64::MAX; 4]);
+
+    /// Creates a value from its limbs with the least significant limb first.
+    pub const fn from_limbs(limbs: [u64; 4]) -> Self {
+        Self(limbs)
+    }
+
+    /// Returns the limbs of the value with the least significant limb first.
+    pub const fn limbs(&self) -> [u64; 4] {
+        self.0
+    }
+
+    /// Creates a value from a `u128`.
+    pub const fn from_u128(value: u128) -> Self {
+        Self([value as u64, (value >> 64) as u64, 0, 0])
+    }
+
+    /// Returns the lower 128 bits of the value.
+    pub const fn low_u128(&self) -> u128 {
+        self.0[0] as u128 | (self.0[1] as u128) << 64
+    }
+
+    /// Creates a value from its little endian bytes.
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            let mut limb_bytes = [0x00; 8];
+            limb_bytes.copy_from_slice(chunk);
+            *limb = u64::from_le_bytes(limb_bytes);
+        }
+        Self(limbs)
+    }
+
+    /// Returns the little endian bytes of the value.
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0x00; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(&self.0) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a value from its big endian bytes.
+    pub fn from_be_bytes(mut bytes: [u8; 32]) -> Self {
+        bytes.reverse();
+        Self::from_le_bytes(bytes)
+    }
+
+    /// Returns the big endian bytes of the value.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Returns `true` if the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    /// Returns the number of bits required to represent the value.
+    pub fn bits(&self) -> u32 {
+        significant_bits(&self.0)
+    }
+
+    /// Adds `rhs` and returns the wrapped sum and whether an overflow occurred.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let mut limbs = [0u64; 4];
+        let mut carry = false;
+        for (n, limb) in limbs.iter_mut().enumerate() {
+            let (sum, overflow_1) = self.0[n].overflowing_add(rhs.0[n]);
+            let (sum, overflow_2) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = overflow_1 || overflow_2;
+        }
+        (Self(limbs), carry)
+    }
+
+    /// Subtracts `rhs` and returns the wrapped difference and whether an underflow occurred.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let mut limbs = [0u64; 4];
+        let mut borrow = false;
+        for (n, limb) in limbs.iter_mut().enumerate() {
+            let (diff, underflow_1) = self.0[n].overflowing_sub(rhs.0[n]);
+            let (diff, underflow_2) = diff.overflowing_sub(borrow as u64);
+            *limb = diff;
+            borrow = underflow_1 || underflow_2;
+        }
+        (Self(limbs), borrow)
+    }
+
+    /// Returns `self + rhs` or `None` upon overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (sum, false) => Some(sum),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns `self - rhs` or `None` upon underflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (diff, false) => Some(diff),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns `self * rhs` or `None` upon overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        narrow(&self.full_mul(rhs))
+    }
+
+    /// Returns `self / rhs` or `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None
+        }
+        let (quotient, _) = div_rem(&widen(self), rhs);
+        narrow(&quotient)
+    }
+
+    /// Returns `self % rhs` or `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None
+        }
+        let (_, remainder) = div_rem(&widen(self), rhs);
+        Some(remainder)
+    }
+
+    /// Returns `self * mul / div` or `None` if `div` is zero or the result overflows.
+    ///
+    /// The intermediate product is computed with 512 bits so that it never
+    /// overflows. This is the common operation to compute shares and prices.
+    pub fn checked_mul_div(self, mul: Self, div: Self) -> Option<Self> {
+        if div.is_zero() {
+            return None
+        }
+        let (quotient, _) = div_rem(&self.full_mul(mul), div);
+        narrow(&quotient)
+    }
+
+    /// Returns `self` raised to the power of `exp` or `None` upon overflow.
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+
+    /// Returns `self + rhs` saturating at [`U256::MAX`].
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Returns `self - rhs` saturating at [`U256::ZERO`].
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or(Self::ZERO)
+    }
+
+    /// Returns `self * rhs` saturating at [`U256::MAX`].
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Returns the full 512-bit product of `self` and `rhs`.
+    fn full_mul(self, rhs: Self) -> U512 {
+        let mut product = [0u64; 8];
+        for (i, lhs_limb) in self.0.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, rhs_limb) in rhs.0.iter().enumerate() {
+                let limb = *lhs_limb as u128 * *rhs_limb as u128
+                    + product[i + j] as u128
+                    + carry;
+                product[i + j] = limb as u64;
+                carry = limb >> 64;
+            }
+            product[i + 4] = carry as u64;
+        }
+        product
+    }
+
+    /// Shifts the value to the left by one bit and returns the shifted out bit.
+    fn shl_one(&mut self) -> bool {
+        let carry = self.0[3] >> 63 == 1;
+        for n in (1..4).rev() {
+            self.0[n] = self.0[n] << 1 | self.0[n - 1] >> 63;
+        }
+        self.0[0] <<= 1;
+        carry
+    }
+}
+
+/// Returns the number of significant bits of the limbs.
+fn significant_bits(limbs: &[u64]) -> u32 {
+    limbs
+        .iter()
+        .rposition(|limb| *limb != 0)
+        .map(|n| 64 * (n as u32 + 1) - limbs[n].leading_zeros())
+        .unwrap_or(0)
+}
+
+/// Widens the value to 512 bits.
+fn widen(value: U256) -> U512 {
+    let mut wide = [0u64; 8];
+    wide[..4].copy_from_slice(&value.0);
+    wide
+}
+
+/// Narrows the 512-bit value or returns `None` if it does not fit into 256 bits.
+fn narrow(wide: &U512) -> Option<U256> {
+    if wide[4..].iter().any(|limb| *limb != 0) {
+        return None
+    }
+    let mut limbs = [0u64; 4];
+    limbs.copy_from_slice(&wide[..4]);
+    Some(U256(limbs))
+}
+
+/// Divides the 512-bit numerator by the non-zero divisor.
+///
+/// Returns the quotient and the remainder of the division.
+fn div_rem(numerator: &U512, divisor: U256) -> (U512, U256) {
+    debug_assert!(!divisor.is_zero());
+    let mut quotient = [0u64; 8];
+    let mut remainder = U256::ZERO;
+    for bit in (0..significant_bits(numerator) as usize).rev() {
+        let carry = remainder.shl_one();
+        remainder.0[0] |= numerator[bit / 64] >> (bit % 64) & 1;
+        // The remainder exceeds the divisor if a bit has been shifted out of it.
+        if carry || remainder >= divisor {
+            remainder = remainder.overflowing_sub(divisor).0;
+            quotient[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+    (quotient, remainder)
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ( $($ty:ty),* ) => {
+        $(
+            impl From<$ty> for U256 {
+                fn from(value: $ty) -> Self {
+                    Self::from_u128(value as u128)
+                }
+            }
+        )*
+    };
+}
+impl_from_unsigned!(u8, u16, u32, u64, u128);
+
+macro_rules! impl_try_from_u256 {
+    ( $($ty:ty),* ) => {
+        $(
+            impl TryFrom<U256> for $ty {
+                type Error = OverflowError;
+
+                fn try_from(value: U256) -> Result<Self, Self::Error> {
+                    if value.bits() > <$ty>::MAX.count_ones() {
+                        return Err(OverflowError)
+                    }
+                    Ok(value.low_u128() as $ty)
+                }
+            }
+        )*
+    };
+}
+impl_try_from_u256!(u8, u16, u32, u64, u128);
+
+macro_rules! impl_checked_ops {
+    ( $( $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident,
+         $checked:ident, $error:literal; )* ) => {
+        $(
+            impl $trait for U256 {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    self.$checked(rhs).expect($error)
+                }
+            }
+
+            impl $assign_trait for U256 {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = <Self as $trait>::$method(*self, rhs)
+                }
+            }
+        )*
+    };
+}
+impl_checked_ops! {
+    Add, add, AddAssign, add_assign, checked_add, "encountered overflow in U256 addition";
+    Sub, sub, SubAssign, sub_assign, checked_sub, "encountered underflow in U256 subtraction";
+    Mul, mul, MulAssign, mul_assign, checked_mul, "encountered overflow in U256 multiplication";
+    Div, div, DivAssign, div_assign, checked_div, "encountered U256 division by zero";
+    Rem, rem, RemAssign, rem_assign, checked_rem, "encountered U256 remainder by zero";
+}
+
+impl fmt::Display for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The largest power of 10 that fits into a limb.
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        if self.is_zero() {
+            return write!(f, "0")
+        }
+        // `U256::MAX` has 78 decimal digits and therefore requires 5 chunks.
+        let mut chunks = [0u64; 5];
+        let mut len = 0;
+        let mut value = *self;
+        while !value.is_zero() {
+            let (quotient, remainder) = div_rem(&widen(value), U256::from(CHUNK));
+            chunks[len] = remainder.0[0];
+            len += 1;
+            value = narrow(&quotient).expect("the quotient is smaller than the value");
+        }
+        write!(f, "{}", chunks[len - 1])?;
+        for chunk in chunks[..len - 1].iter().rev() {
+            write!(f, "{:019}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "U256({})", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_sub_works() {
+        let a = U256::from(u128::MAX);
+        assert_eq!(a + U256::ONE, U256::from_limbs([0, 0, 1, 0]));
+        assert_eq!(U256::from_limbs([0, 0, 1, 0]) - U256::ONE, a);
+        assert_eq!(U256::MAX.checked_add(U256::ONE), None);
+        assert_eq!(U256::ZERO.checked_sub(U256::ONE), None);
+        assert_eq!(U256::MAX.saturating_add(U256::ONE), U256::MAX);
+        assert_eq!(U256::ZERO.saturating_sub(U256::ONE), U256::ZERO);
+    }
+
+    #[test]
+    fn mul_div_works() {
+        let a = U256::from(u128::MAX);
+        let product = a * a;
+        assert_eq!(product / a, a);
+        assert_eq!(product % a, U256::ZERO);
+        assert_eq!((product + U256::from(7u8)) % a, U256::from(7u8));
+        assert_eq!(U256::MAX.checked_mul(U256::from(2u8)), None);
+        assert_eq!(U256::ONE.checked_div(U256::ZERO), None);
+        assert_eq!(U256::ONE.checked_rem(U256::ZERO), None);
+        assert_eq!(U256::from(100u8) / U256::from(7u8), U256::from(14u8));
+        assert_eq!(U256::from(100u8) % U256::from(7u8), U256::from(2u8));
+    }
+
+    #[test]
+    fn checked_mul_div_works() {
+        let two = U256::from(2u8);
+        assert_eq!(U256::MAX.checked_mul_div(two, two), Some(U256::MAX));
+        assert_eq!(U256::MAX.checked_mul_div(two, U256::ONE), None);
+        assert_eq!(U256::ONE.checked_mul_div(two, U256::ZERO), None);
+    }
+
+    #[test]
+    fn checked_pow_works() {
+        let ten = U256::from(10u8);
+        assert_eq!(
+            ten.checked_pow(18),
+            Some(U256::from(1_000_000_000_000_000_000u64))
+        );
+        assert_eq!(ten.checked_pow(0), Some(U256::ONE));
+        assert_eq!(
+            U256::from(2u8).checked_pow(255),
+            Some(U256::from_limbs([0, 0, 0, 1 << 63]))
+        );
+        assert_eq!(U256::from(2u8).checked_pow(256), None);
+    }
+
+    #[test]
+    fn ord_works() {
+        assert!(
+            U256::from_limbs([0, 0, 0, 1])
+                > U256::from_limbs([u64::MAX, u64::MAX, u64::MAX, 0])
+        );
+        assert!(U256::ONE < U256::from(2u8));
+        assert_eq!(U256::MAX.cmp(&U256::MAX), Ordering::Equal);
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(u128::try_from(U256::from(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(u8::try_from(U256::from(256u16)), Err(OverflowError));
+        assert_eq!(u128::try_from(U256::MAX), Err(OverflowError));
+        let value = U256::from_limbs([1, 2, 3, 4]);
+        assert_eq!(U256::from_le_bytes(value.to_le_bytes()), value);
+        assert_eq!(U256::from_be_bytes(value.to_be_bytes()), value);
+        assert_eq!(value.to_be_bytes()[31], 1);
+        assert_eq!(value.bits(), 195);
+    }
+
+    #[test]
+    fn display_works() {
+        assert_eq!(format!("{}", U256::ZERO), "0");
+        assert_eq!(format!("{}", U256::from(u128::MAX)), u128::MAX.to_string());
+        assert_eq!(
+            format!("{}", U256::MAX),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(format!("{:?}", U256::ONE), "U256(1)");
+    }
+
+    #[test]
+    fn scale_codec_works() {
+        use scale::{
+            Decode,
+            Encode,
+        };
+        let value = U256::from_limbs([1, 2, 3, 4]);
+        let encoded = value.encode();
+        assert_eq!(encoded, value.to_le_bytes().to_vec());
+        assert_eq!(U256::decode(&mut &encoded[..]), Ok(value));
+    }
+}
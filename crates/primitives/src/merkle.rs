@@ -0,0 +1,247 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verification of Merkle proofs.
+//!
+//! Proofs are verified without any heap allocations which makes them
+//! usable from within ink! smart contracts, e.g. for airdrop claims.
+//!
+//! Inner nodes are computed as the hash of the concatenation of their two
+//! children in ascending byte order which makes proofs independent of the
+//! position of the nodes within the tree. Leaves are expected to be hashed
+//! via [`MerkleHasher::hash_leaf`] by the caller before the proof is verified.
+//!
+//! Leaves and inner nodes are hashed in separate domains: leaf data is prefixed
+//! with [`LEAF_PREFIX`] and the children of inner nodes are prefixed with
+//! [`NODE_PREFIX`]. Otherwise an inner node could be presented as a leaf whose
+//! data is the concatenation of its children, a so-called second preimage.
+//! Therefore the trees are not compatible with tooling that does not separate
+//! the domains.
+//!
+//! # Example
+//!
+//! ```
+//! use ink_primitives::merkle::{
+//!     verify_proof,
+//!     Keccak256,
+//!     MerkleHasher as _,
+//! };
+//!
+//! let alice = Keccak256::hash_leaf(b"alice");
+//! let bob = Keccak256::hash_leaf(b"bob");
+//! let root = Keccak256::hash_pair(&alice, &bob);
+//! assert!(verify_proof::<Keccak256, _>(&root, &alice, &[bob]));
+//! assert!(!verify_proof::<Keccak256, _>(&root, &alice, &[alice]));
+//! ```
+
+use blake2::digest::{
+    Update as _,
+    VariableOutput as _,
+};
+use tiny_keccak::Hasher as _;
+
+/// The hash of a node of a Merkle tree.
+pub type Node = [u8; 32];
+
+/// The prefix of the leaf data before it is hashed.
+pub const LEAF_PREFIX: u8 = 0x00;
+
+/// The prefix of the children of an inner node before they are hashed.
+pub const NODE_PREFIX: u8 = 0x01;
+
+/// The hash function of a Merkle tree.
+pub trait MerkleHasher {
+    /// Hashes the given leaf data prefixed with [`LEAF_PREFIX`].
+    fn hash_leaf(data: &[u8]) -> Node;
+
+    /// Computes the parent of two nodes.
+    ///
+    /// The nodes are hashed in ascending byte order prefixed with [`NODE_PREFIX`].
+    fn hash_pair(a: &[u8], b: &[u8]) -> Node;
+}
+
+/// Merkle trees using the KECCAK 256-bit hash.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Keccak256 {}
+
+/// Merkle trees using the BLAKE2 256-bit hash.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Blake2x256 {}
+
+/// Returns the given nodes in ascending byte order.
+fn sorted<'a>(a: &'a [u8], b: &'a [u8]) -> (&'a [u8], &'a [u8]) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+impl MerkleHasher for Keccak256 {
+    fn hash_leaf(data: &[u8]) -> Node {
+        let mut output = Node::default();
+        let mut hasher = tiny_keccak::Keccak::v256();
+        hasher.update(&[LEAF_PREFIX]);
+        hasher.update(data);
+        hasher.finalize(&mut output);
+        output
+    }
+
+    fn hash_pair(a: &[u8], b: &[u8]) -> Node {
+        let (first, second) = sorted(a, b);
+        let mut output = Node::default();
+        let mut hasher = tiny_keccak::Keccak::v256();
+        hasher.update(&[NODE_PREFIX]);
+        hasher.update(first);
+        hasher.update(second);
+        hasher.finalize(&mut output);
+        output
+    }
+}
+
+impl MerkleHasher for Blake2x256 {
+    fn hash_leaf(data: &[u8]) -> Node {
+        blake2x256(&[&[LEAF_PREFIX][..], data])
+    }
+
+    fn hash_pair(a: &[u8], b: &[u8]) -> Node {
+        let (first, second) = sorted(a, b);
+        blake2x256(&[&[NODE_PREFIX][..], first, second])
+    }
+}
+
+/// Returns the BLAKE2 256-bit hash of the concatenation of the given inputs.
+fn blake2x256(inputs: &[&[u8]]) -> Node {
+    let mut output = Node::default();
+    let mut hasher = blake2::VarBlake2b::new_keyed(&[], 32);
+    for input in inputs {
+        hasher.update(input);
+    }
+    hasher.finalize_variable(|result| output.copy_from_slice(result));
+    output
+}
+
+/// Returns `true` if the proof shows that the leaf is part of the tree with the root.
+///
+/// The `proof` yields the siblings of the nodes on the path from the leaf
+/// up to the root in this order. An empty proof is valid if the leaf is
+/// the root itself.
+pub fn verify_proof<H, P>(root: &[u8], leaf: &[u8], proof: P) -> bool
+where
+    H: MerkleHasher,
+    P: IntoIterator,
+    P::Item: AsRef<[u8]>,
+{
+    let mut proof = proof.into_iter();
+    let mut node = match proof.next() {
+        Some(sibling) => H::hash_pair(leaf, sibling.as_ref()),
+        None => return root == leaf,
+    };
+    for sibling in proof {
+        node = H::hash_pair(&node, sibling.as_ref());
+    }
+    root == &node[..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds the tree of the four given leaves and returns its root and the
+    /// proof of the first leaf.
+    fn tree_of_four<H: MerkleHasher>(leaves: &[Node; 4]) -> (Node, [Node; 2]) {
+        let left = H::hash_pair(&leaves[0], &leaves[1]);
+        let right = H::hash_pair(&leaves[2], &leaves[3]);
+        (H::hash_pair(&left, &right), [leaves[1], right])
+    }
+
+    /// Asserts that an inner node cannot be presented as a leaf whose data is
+    /// the concatenation of the children of the inner node.
+    fn second_preimage_fails_for<H: MerkleHasher>() {
+        let leaves = [
+            H::hash_leaf(b"alice"),
+            H::hash_leaf(b"bob"),
+            H::hash_leaf(b"charlie"),
+            H::hash_leaf(b"dave"),
+        ];
+        let (root, proof) = tree_of_four::<H>(&leaves);
+        let (first, second) = sorted(&leaves[0], &leaves[1]);
+        let mut forged_data = [0x00; 64];
+        forged_data[..32].copy_from_slice(first);
+        forged_data[32..].copy_from_slice(second);
+        let forged_leaf = H::hash_leaf(&forged_data);
+        assert_ne!(forged_leaf, H::hash_pair(&leaves[0], &leaves[1]));
+        assert!(!verify_proof::<H, _>(&root, &forged_leaf, &proof[1..]));
+    }
+
+    fn verify_proof_works_for<H: MerkleHasher>() {
+        let leaves = [
+            H::hash_leaf(b"alice"),
+            H::hash_leaf(b"bob"),
+            H::hash_leaf(b"charlie"),
+            H::hash_leaf(b"dave"),
+        ];
+        let (root, proof) = tree_of_four::<H>(&leaves);
+        assert!(verify_proof::<H, _>(&root, &leaves[0], &proof));
+        assert!(verify_proof::<H, _>(
+            &root,
+            &leaves[0],
+            proof.iter().map(|node| &node[..])
+        ));
+        // Wrong leaf.
+        assert!(!verify_proof::<H, _>(&root, &leaves[2], &proof));
+        // Incomplete proof.
+        assert!(!verify_proof::<H, _>(&root, &leaves[0], &proof[..1]));
+        // Empty proofs only prove the root.
+        assert!(verify_proof::<H, _>(&root, &root, &[] as &[Node]));
+        assert!(!verify_proof::<H, _>(&root, &leaves[0], &[] as &[Node]));
+    }
+
+    #[test]
+    fn keccak256_works() {
+        verify_proof_works_for::<Keccak256>();
+        // The hash of the empty leaf is the hash of the leaf prefix.
+        assert_eq!(
+            Keccak256::hash_leaf(&[]),
+            [
+                0xBC, 0x36, 0x78, 0x9E, 0x7A, 0x1E, 0x28, 0x14, 0x36, 0x46, 0x42, 0x29,
+                0x82, 0x8F, 0x81, 0x7D, 0x66, 0x12, 0xF7, 0xB4, 0x77, 0xD6, 0x65, 0x91,
+                0xFF, 0x96, 0xA9, 0xE0, 0x64, 0xBC, 0xC9, 0x8A,
+            ]
+        );
+    }
+
+    #[test]
+    fn blake2x256_works() {
+        verify_proof_works_for::<Blake2x256>();
+        assert_ne!(
+            Blake2x256::hash_leaf(b"alice"),
+            Keccak256::hash_leaf(b"alice")
+        );
+    }
+
+    #[test]
+    fn second_preimage_fails() {
+        second_preimage_fails_for::<Keccak256>();
+        second_preimage_fails_for::<Blake2x256>();
+    }
+
+    #[test]
+    fn hash_pair_is_symmetric() {
+        let a = Keccak256::hash_leaf(b"a");
+        let b = Keccak256::hash_leaf(b"b");
+        assert_eq!(Keccak256::hash_pair(&a, &b), Keccak256::hash_pair(&b, &a));
+        assert_eq!(Blake2x256::hash_pair(&a, &b), Blake2x256::hash_pair(&b, &a));
+    }
+}
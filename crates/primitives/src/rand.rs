@@ -0,0 +1,231 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic pseudo-random number generation for ink! smart contracts.
+//!
+//! # Security
+//!
+//! All values produced by [`XorShiftRng`] are fully determined by its seed.
+//! Seeds derived from on-chain data such as the randomness or the hash of a
+//! block are known to or can be influenced by block authors and other
+//! contracts. Never use this generator to decide about anything of value
+//! that is worth manipulating, e.g. high-value lotteries.
+//!
+//! To make this explicit at the call site, generators can only be created
+//! from a [`PredictableSeed`].
+
+/// A seed for [`XorShiftRng`] that must be assumed to be known to everyone.
+///
+/// # Example
+///
+/// Seeding a generator from the randomness provided by the contract executor:
+///
+/// ```
+/// # use ink_primitives::rand::{PredictableSeed, XorShiftRng};
+/// // E.g. the randomness returned by `ink_env::random`.
+/// let random = [0x42; 32];
+/// let mut rng = XorShiftRng::new(PredictableSeed::from_bytes(&random));
+/// let mut participants = [1, 2, 3, 4];
+/// rng.shuffle(&mut participants);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PredictableSeed(u64);
+
+impl PredictableSeed {
+    /// Creates a seed from the given integer.
+    pub const fn from_u64(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Creates a seed that depends on all of the given bytes.
+    ///
+    /// This is useful to combine e.g. a block hash or randomness with data
+    /// specific to the contract such as a round number.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let seed = bytes.chunks(8).fold(0u64, |state, chunk| {
+            let mut word = [0x00; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            splitmix64(state ^ u64::from_le_bytes(word))
+        });
+        Self(splitmix64(seed ^ bytes.len() as u64))
+    }
+}
+
+/// Scrambles the bits of the given value using the SplitMix64 finalizer.
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// A small and fast xorshift64* pseudo-random number generator.
+///
+/// The generator is not cryptographically secure, see the [module](self)
+/// documentation. It is suitable for shuffles or samplings whose outcome
+/// is not worth manipulating.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct XorShiftRng {
+    /// The non-zero state of the generator.
+    state: u64,
+}
+
+impl XorShiftRng {
+    /// Creates a generator from the given seed.
+    pub fn new(seed: PredictableSeed) -> Self {
+        // The all-zero state is the only state that xorshift never leaves.
+        let state = match splitmix64(seed.0) {
+            0 => 0x9E37_79B9_7F4A_7C15,
+            state => state,
+        };
+        Self { state }
+    }
+
+    /// Returns the next pseudo-random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns the next pseudo-random `u32`.
+    pub fn next_u32(&mut self) -> u32 {
+        // The high bits of xorshift64* are of better quality than the low bits.
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns a uniformly distributed pseudo-random number below `bound`.
+    ///
+    /// # Panics
+    ///
+    /// If `bound` is zero.
+    pub fn gen_below(&mut self, bound: u64) -> u64 {
+        assert!(bound != 0, "encountered zero bound for random number");
+        // Rejects the lowest values that would otherwise bias the result.
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let value = self.next_u64();
+            if value >= threshold {
+                return value % bound
+            }
+        }
+    }
+
+    /// Fills the given buffer with pseudo-random bytes.
+    pub fn fill_bytes(&mut self, buffer: &mut [u8]) {
+        for chunk in buffer.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    /// Shuffles the elements of the slice in place.
+    pub fn shuffle<T>(&mut self, elements: &mut [T]) {
+        for i in (1..elements.len()).rev() {
+            let j = self.gen_below(i as u64 + 1) as usize;
+            elements.swap(i, j);
+        }
+    }
+
+    /// Returns a pseudo-randomly chosen element of the slice.
+    ///
+    /// Returns `None` if the slice is empty.
+    pub fn choose<'a, T>(&mut self, elements: &'a [T]) -> Option<&'a T> {
+        if elements.is_empty() {
+            return None
+        }
+        let index = self.gen_below(elements.len() as u64) as usize;
+        elements.get(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_values() {
+        let seed = PredictableSeed::from_bytes(&[0x42; 32]);
+        let mut a = XorShiftRng::new(seed);
+        let mut b = XorShiftRng::new(seed);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(
+            XorShiftRng::new(PredictableSeed::from_u64(1)).next_u64(),
+            XorShiftRng::new(PredictableSeed::from_u64(2)).next_u64(),
+        );
+    }
+
+    #[test]
+    fn from_bytes_depends_on_all_bytes() {
+        let mut bytes = [0x00; 32];
+        let seed = PredictableSeed::from_bytes(&bytes);
+        bytes[31] = 0x01;
+        assert_ne!(PredictableSeed::from_bytes(&bytes), seed);
+        assert_ne!(
+            PredictableSeed::from_bytes(&[]),
+            PredictableSeed::from_bytes(&[0x00]),
+        );
+    }
+
+    #[test]
+    fn zero_seed_works() {
+        let mut rng = XorShiftRng::new(PredictableSeed::from_u64(0));
+        assert_ne!(rng.next_u64(), rng.next_u64());
+    }
+
+    #[test]
+    fn gen_below_works() {
+        let mut rng = XorShiftRng::new(PredictableSeed::from_u64(7));
+        let mut seen = [false; 10];
+        for _ in 0..1_000 {
+            let value = rng.gen_below(10);
+            assert!(value < 10);
+            seen[value as usize] = true;
+        }
+        assert!(seen.iter().all(|seen| *seen));
+        assert_eq!(rng.gen_below(1), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered zero bound for random number")]
+    fn gen_below_zero_panics() {
+        XorShiftRng::new(PredictableSeed::from_u64(7)).gen_below(0);
+    }
+
+    #[test]
+    fn shuffle_works() {
+        let mut rng = XorShiftRng::new(PredictableSeed::from_u64(42));
+        let mut elements = (0..32).collect::<Vec<u32>>();
+        rng.shuffle(&mut elements);
+        assert_ne!(elements, (0..32).collect::<Vec<u32>>());
+        elements.sort_unstable();
+        assert_eq!(elements, (0..32).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn choose_and_fill_bytes_work() {
+        let mut rng = XorShiftRng::new(PredictableSeed::from_u64(42));
+        assert_eq!(rng.choose::<u8>(&[]), None);
+        assert_eq!(rng.choose(&[5]), Some(&5));
+        let mut buffer = [0x00; 13];
+        rng.fill_bytes(&mut buffer);
+        assert_ne!(buffer, [0x00; 13]);
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encoding and decoding of SS58 addresses.
+//!
+//! SS58 is the human readable address format of Substrate based chains.
+//! Contracts operate on the raw 32 bytes of their account identifiers only,
+//! this module allows off-chain tests and tooling to express the accounts as
+//! readable addresses instead.
+//!
+//! # Example
+//!
+//! ```
+//! # use ink_primitives::ss58;
+//! let alice = ss58::decode("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+//! assert_eq!(ss58::encode(&alice), "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY");
+//! ```
+
+use blake2::{
+    Blake2b,
+    Digest as _,
+};
+use core::fmt;
+
+/// The generic Substrate address format.
+pub const SUBSTRATE_FORMAT: u16 = 42;
+
+/// The largest address format that can be encoded.
+pub const MAX_FORMAT: u16 = 0b0011_1111_1111_1111;
+
+/// The prefix of the hashed input of the checksum.
+const CHECKSUM_PREFIX: &[u8] = b"SS58PRE";
+
+/// The number of checksum bytes of an address of a 32 bytes account.
+const CHECKSUM_LEN: usize = 2;
+
+/// An error encountered upon decoding an SS58 address.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The address is not valid base58.
+    InvalidBase58,
+    /// The address does not encode a 32 bytes account.
+    InvalidLength,
+    /// The address format is reserved or out of range.
+    InvalidFormat,
+    /// The checksum of the address does not match.
+    InvalidChecksum,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error = match self {
+            Self::InvalidBase58 => "encountered invalid base58 in SS58 address",
+            Self::InvalidLength => "encountered SS58 address of invalid length",
+            Self::InvalidFormat => "encountered invalid SS58 address format",
+            Self::InvalidChecksum => "encountered invalid SS58 address checksum",
+        };
+        write!(f, "{}", error)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Encodes the account into an SS58 address of the generic Substrate format.
+pub fn encode(account: &[u8; 32]) -> String {
+    encode_with_format(account, SUBSTRATE_FORMAT)
+}
+
+/// Encodes the account into an SS58 address of the given format.
+///
+/// # Panics
+///
+/// If the format is greater than [`MAX_FORMAT`].
+pub fn encode_with_format(account: &[u8; 32], format: u16) -> String {
+    assert!(
+        format <= MAX_FORMAT,
+        "encountered SS58 address format greater than {}",
+        MAX_FORMAT
+    );
+    let mut bytes = encode_format(format);
+    bytes.extend_from_slice(account);
+    let checksum = checksum(&bytes);
+    bytes.extend_from_slice(&checksum[..CHECKSUM_LEN]);
+    bs58::encode(bytes).into_string()
+}
+
+/// Decodes the account of an SS58 address of any format.
+///
+/// # Errors
+///
+/// If the address is malformed or if its checksum does not match.
+pub fn decode(address: &str) -> Result<[u8; 32], Error> {
+    decode_with_format(address).map(|(_format, account)| account)
+}
+
+/// Decodes the format and the account of an SS58 address.
+///
+/// # Errors
+///
+/// If the address is malformed or if its checksum does not match.
+pub fn decode_with_format(address: &str) -> Result<(u16, [u8; 32]), Error> {
+    let bytes = bs58::decode(address)
+        .into_vec()
+        .map_err(|_| Error::InvalidBase58)?;
+    let (format, prefix_len) = match bytes.first() {
+        Some(0..=63) => (bytes[0] as u16, 1),
+        Some(64..=127) if bytes.len() > 1 => {
+            let lower = (bytes[0] << 2) | (bytes[1] >> 6);
+            let upper = bytes[1] & 0b0011_1111;
+            (lower as u16 | (upper as u16) << 8, 2)
+        }
+        Some(_) => return Err(Error::InvalidFormat),
+        None => return Err(Error::InvalidLength),
+    };
+    if bytes.len() != prefix_len + 32 + CHECKSUM_LEN {
+        return Err(Error::InvalidLength)
+    }
+    let (payload, expected) = bytes.split_at(prefix_len + 32);
+    if checksum(payload)[..CHECKSUM_LEN] != *expected {
+        return Err(Error::InvalidChecksum)
+    }
+    let mut account = [0x00; 32];
+    account.copy_from_slice(&payload[prefix_len..]);
+    Ok((format, account))
+}
+
+/// Returns the one or two bytes encoding of the address format.
+fn encode_format(format: u16) -> Vec<u8> {
+    if format < 64 {
+        return vec![format as u8]
+    }
+    let first = ((format & 0b0000_0000_1111_1100) >> 2) as u8 | 0b0100_0000;
+    let second = ((format >> 8) | ((format & 0b0000_0000_0000_0011) << 6)) as u8;
+    vec![first, second]
+}
+
+/// Returns the checksum hash of the format and account bytes.
+fn checksum(bytes: &[u8]) -> Vec<u8> {
+    let mut hasher = Blake2b::new();
+    hasher.update(CHECKSUM_PREFIX);
+    hasher.update(bytes);
+    hasher.finalize().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The public key of the well known `//Alice` development account.
+    const ALICE: [u8; 32] = [
+        0xD4, 0x35, 0x93, 0xC7, 0x15, 0xFD, 0xD3, 0x1C, 0x61, 0x14, 0x1A, 0xBD, 0x04,
+        0xA9, 0x9F, 0xD6, 0x82, 0x2C, 0x85, 0x58, 0x85, 0x4C, 0xCD, 0xE3, 0x9A, 0x56,
+        0x84, 0xE7, 0xA5, 0x6D, 0xA2, 0x7D,
+    ];
+
+    /// The address of the `//Alice` account in the generic Substrate format.
+    const ALICE_ADDRESS: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+
+    #[test]
+    fn encode_works() {
+        assert_eq!(encode(&ALICE), ALICE_ADDRESS);
+    }
+
+    #[test]
+    fn decode_works() {
+        assert_eq!(
+            decode_with_format(ALICE_ADDRESS),
+            Ok((SUBSTRATE_FORMAT, ALICE))
+        );
+    }
+
+    #[test]
+    fn two_bytes_format_roundtrip_works() {
+        for format in &[64, 255, 1000, MAX_FORMAT] {
+            let address = encode_with_format(&ALICE, *format);
+            assert_eq!(decode_with_format(&address), Ok((*format, ALICE)));
+        }
+    }
+
+    #[test]
+    fn invalid_addresses_fail() {
+        assert_eq!(decode("0OIl"), Err(Error::InvalidBase58));
+        assert_eq!(decode(""), Err(Error::InvalidLength));
+        assert_eq!(decode(&ALICE_ADDRESS[1..]), Err(Error::InvalidLength));
+        // Increments the encoded number and thereby its last checksum byte.
+        let mut corrupted = ALICE_ADDRESS.to_string();
+        corrupted.pop();
+        corrupted.push('Z');
+        assert_eq!(decode(&corrupted), Err(Error::InvalidChecksum));
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Canonical ink! trait definitions of the PSP token standards.
+//!
+//! - [`psp22`]: fungible tokens
+//! - [`psp34`]: non-fungible tokens
+//! - [`psp37`]: multi tokens
+//! - [`receiver`]: receiver callbacks of safe transfers
+//!
+//! All messages pin their selectors to the ones defined by the standards which
+//! are the first four bytes of the BLAKE2 256-bit hash of the trait name and the
+//! message name separated by `::`, e.g. `PSP22::transfer`. Therefore contracts
+//! implementing these trait definitions stay compatible with each other and
+//! with all PSP aware tooling, independent of the selectors ink! composes.
+//!
+//! # Events
+//!
+//! ink! events have to be defined within the `#[ink::contract]` module. For this
+//! reason the events required by the standards are documented by every module and
+//! have to be copied into the contracts implementing the trait definitions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod psp22;
+pub mod psp34;
+pub mod psp37;
+pub mod receiver;
+
+/// The account identifier used by the standards.
+pub type AccountId = ink_env::AccountId;
+
+/// The balance type used by the standards.
+///
+/// This is the balance type of the default environment.
+pub type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
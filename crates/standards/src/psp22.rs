@@ -0,0 +1,145 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The PSP22 fungible token standard.
+//!
+//! # Events
+//!
+//! Contracts implementing [`PSP22`] have to emit the following events:
+//!
+//! ```ignore
+//! /// Emitted when tokens are transferred, including minting and burning.
+//! #[ink(event)]
+//! pub struct Transfer {
+//!     #[ink(topic)]
+//!     from: Option<AccountId>,
+//!     #[ink(topic)]
+//!     to: Option<AccountId>,
+//!     value: Balance,
+//! }
+//!
+//! /// Emitted when the allowance of `spender` for `owner` is set.
+//! #[ink(event)]
+//! pub struct Approval {
+//!     #[ink(topic)]
+//!     owner: AccountId,
+//!     #[ink(topic)]
+//!     spender: AccountId,
+//!     value: Balance,
+//! }
+//! ```
+
+use crate::{
+    AccountId,
+    Balance,
+};
+use ink_lang as ink;
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+
+/// The PSP22 error type.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22Error {
+    /// Custom error type for cases not covered by the standard.
+    Custom(String),
+    /// Returned if not enough balance to fulfill a request is available.
+    InsufficientBalance,
+    /// Returned if not enough allowance to fulfill a request is available.
+    InsufficientAllowance,
+    /// Returned if the recipient is the zero address.
+    ZeroRecipientAddress,
+    /// Returned if the sender is the zero address.
+    ZeroSenderAddress,
+    /// Returned if a safe transfer check failed.
+    SafeTransferCheckFailed(String),
+}
+
+/// Trait implemented by all PSP22 fungible tokens.
+#[ink::trait_definition]
+pub trait PSP22 {
+    /// Returns the total token supply.
+    #[ink(message, selector = "0x162DF8C2")]
+    fn total_supply(&self) -> Balance;
+
+    /// Returns the account balance of `owner`.
+    #[ink(message, selector = "0x6568382F")]
+    fn balance_of(&self, owner: AccountId) -> Balance;
+
+    /// Returns the amount which `spender` is still allowed to withdraw from `owner`.
+    #[ink(message, selector = "0x4D47D921")]
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance;
+
+    /// Transfers `value` tokens from the caller's account to account `to`.
+    ///
+    /// The `data` is forwarded to the recipient if it is a contract.
+    #[ink(message, selector = "0xDB20F9F5")]
+    fn transfer(
+        &mut self,
+        to: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Transfers `value` tokens on the behalf of `from` to the account `to`.
+    ///
+    /// The `data` is forwarded to the recipient if it is a contract.
+    #[ink(message, selector = "0x54B3C76E")]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Allows `spender` to withdraw from the caller's account multiple times,
+    /// up to the `value` amount.
+    #[ink(message, selector = "0xB20F1BBD")]
+    fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error>;
+
+    /// Atomically increases the allowance of `spender` by `delta_value`.
+    #[ink(message, selector = "0x96D6B57A")]
+    fn increase_allowance(
+        &mut self,
+        spender: AccountId,
+        delta_value: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Atomically decreases the allowance of `spender` by `delta_value`.
+    #[ink(message, selector = "0xFECB57D5")]
+    fn decrease_allowance(
+        &mut self,
+        spender: AccountId,
+        delta_value: Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// Trait implemented by PSP22 fungible tokens providing metadata.
+#[ink::trait_definition]
+pub trait PSP22Metadata {
+    /// Returns the name of the token if any.
+    #[ink(message, selector = "0x3D261BD4")]
+    fn token_name(&self) -> Option<String>;
+
+    /// Returns the symbol of the token if any.
+    #[ink(message, selector = "0x34205BE5")]
+    fn token_symbol(&self) -> Option<String>;
+
+    /// Returns the number of decimals of the token.
+    #[ink(message, selector = "0x7271B782")]
+    fn token_decimals(&self) -> u8;
+}
@@ -0,0 +1,131 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The PSP34 non-fungible token standard.
+//!
+//! # Events
+//!
+//! Contracts implementing [`PSP34`] have to emit the following events:
+//!
+//! ```ignore
+//! /// Emitted when a token is transferred, including minting and burning.
+//! #[ink(event)]
+//! pub struct Transfer {
+//!     #[ink(topic)]
+//!     from: Option<AccountId>,
+//!     #[ink(topic)]
+//!     to: Option<AccountId>,
+//!     #[ink(topic)]
+//!     id: Id,
+//! }
+//!
+//! /// Emitted when `operator` is approved or disapproved to transfer the token
+//! /// `id` of `owner`, or all of its tokens if `id` is `None`.
+//! #[ink(event)]
+//! pub struct Approval {
+//!     #[ink(topic)]
+//!     owner: AccountId,
+//!     #[ink(topic)]
+//!     operator: AccountId,
+//!     #[ink(topic)]
+//!     id: Option<Id>,
+//!     approved: bool,
+//! }
+//! ```
+
+use crate::{
+    AccountId,
+    Balance,
+};
+use ink_lang as ink;
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+
+/// The identifier of a non-fungible token.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Id {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Bytes(Vec<u8>),
+}
+
+/// The PSP34 error type.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP34Error {
+    /// Custom error type for cases not covered by the standard.
+    Custom(String),
+    /// Returned if the owner approves themselves.
+    SelfApprove,
+    /// Returned if the caller is not allowed to perform the operation.
+    NotApproved,
+    /// Returned if a token with the identifier already exists.
+    TokenExists,
+    /// Returned if no token with the identifier exists.
+    TokenNotExists,
+    /// Returned if a safe transfer check failed.
+    SafeTransferCheckFailed(String),
+}
+
+/// Trait implemented by all PSP34 non-fungible tokens.
+#[ink::trait_definition]
+pub trait PSP34 {
+    /// Returns the identifier of the collection.
+    #[ink(message, selector = "0xFFA27A5F")]
+    fn collection_id(&self) -> Id;
+
+    /// Returns the number of tokens owned by `owner`.
+    #[ink(message, selector = "0xCDE7E55F")]
+    fn balance_of(&self, owner: AccountId) -> u32;
+
+    /// Returns the owner of the token `id` if it exists.
+    #[ink(message, selector = "0x1168624D")]
+    fn owner_of(&self, id: Id) -> Option<AccountId>;
+
+    /// Returns `true` if `operator` is allowed to transfer the token `id` of
+    /// `owner`, or all of its tokens if `id` is `None`.
+    #[ink(message, selector = "0x4790F55A")]
+    fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool;
+
+    /// Approves or disapproves `operator` to transfer the token `id` of the
+    /// caller, or all of its tokens if `id` is `None`.
+    #[ink(message, selector = "0x1932A8B0")]
+    fn approve(
+        &mut self,
+        operator: AccountId,
+        id: Option<Id>,
+        approved: bool,
+    ) -> Result<(), PSP34Error>;
+
+    /// Transfers the token `id` to account `to`.
+    ///
+    /// The `data` is forwarded to the recipient if it is a contract.
+    #[ink(message, selector = "0x3128D61B")]
+    fn transfer(
+        &mut self,
+        to: AccountId,
+        id: Id,
+        data: Vec<u8>,
+    ) -> Result<(), PSP34Error>;
+
+    /// Returns the total number of tokens.
+    #[ink(message, selector = "0x628413FE")]
+    fn total_supply(&self) -> Balance;
+}
@@ -0,0 +1,128 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The PSP37 multi token standard.
+//!
+//! # Events
+//!
+//! Contracts implementing [`PSP37`] have to emit the following events:
+//!
+//! ```ignore
+//! /// Emitted when `value` tokens of token `id` are transferred,
+//! /// including minting and burning.
+//! #[ink(event)]
+//! pub struct TransferSingle {
+//!     #[ink(topic)]
+//!     from: Option<AccountId>,
+//!     #[ink(topic)]
+//!     to: Option<AccountId>,
+//!     id: Id,
+//!     value: Balance,
+//! }
+//!
+//! /// Emitted when the allowance of `operator` for the token `id` of `owner`,
+//! /// or all of its tokens if `id` is `None`, is set.
+//! #[ink(event)]
+//! pub struct Approval {
+//!     #[ink(topic)]
+//!     owner: AccountId,
+//!     #[ink(topic)]
+//!     operator: AccountId,
+//!     id: Option<Id>,
+//!     value: Balance,
+//! }
+//! ```
+
+use crate::{
+    AccountId,
+    Balance,
+};
+use ink_lang as ink;
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+
+pub use crate::psp34::Id;
+
+/// The PSP37 error type.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP37Error {
+    /// Custom error type for cases not covered by the standard.
+    Custom(String),
+    /// Returned if not enough balance to fulfill a request is available.
+    InsufficientBalance,
+    /// Returned if the recipient is the zero address.
+    TransferToZeroAddress,
+    /// Returned if the caller is not allowed to perform the operation.
+    NotAllowed,
+    /// Returned if a safe transfer check failed.
+    SafeTransferCheckFailed(String),
+}
+
+/// Trait implemented by all PSP37 multi tokens.
+#[ink::trait_definition]
+pub trait PSP37 {
+    /// Returns the balance of `owner` for the token `id`, or the number of
+    /// distinct tokens owned by `owner` if `id` is `None`.
+    #[ink(message, selector = "0xC42919E2")]
+    fn balance_of(&self, owner: AccountId, id: Option<Id>) -> Balance;
+
+    /// Returns the total supply of the token `id`, or the number of distinct
+    /// tokens if `id` is `None`.
+    #[ink(message, selector = "0x9A49E85A")]
+    fn total_supply(&self, id: Option<Id>) -> Balance;
+
+    /// Returns the amount of the token `id` which `operator` is still allowed
+    /// to withdraw from `owner`, or the allowance for all tokens if `id` is `None`.
+    #[ink(message, selector = "0xCB78A065")]
+    fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>)
+        -> Balance;
+
+    /// Allows `operator` to withdraw up to `value` of the token `id` from the
+    /// caller's account, or of all tokens if `id` is `None`.
+    #[ink(message, selector = "0x31A1A453")]
+    fn approve(
+        &mut self,
+        operator: AccountId,
+        id: Option<Id>,
+        value: Balance,
+    ) -> Result<(), PSP37Error>;
+
+    /// Transfers `value` of the token `id` from the caller's account to account `to`.
+    ///
+    /// The `data` is forwarded to the recipient if it is a contract.
+    #[ink(message, selector = "0x04E09961")]
+    fn transfer(
+        &mut self,
+        to: AccountId,
+        id: Id,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error>;
+
+    /// Transfers `value` of the token `id` on the behalf of `from` to the account `to`.
+    ///
+    /// The `data` is forwarded to the recipient if it is a contract.
+    #[ink(message, selector = "0x5CF8B7D4")]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        id: Id,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error>;
+}
@@ -0,0 +1,317 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Receiver callbacks of safe token transfers.
+//!
+//! Tokens performing a safe transfer notify the recipient about the received
+//! tokens if the recipient is a contract. Contracts that want to receive tokens
+//! via safe transfers implement [`PSP22Receiver`] or [`PSP34Receiver`] and may
+//! reject the transfer by returning an error.
+//!
+//! Token contracts should use [`notify_psp22_received`] and [`notify_psp34_received`]
+//! instead of calling the receivers directly so that the safe transfer semantics
+//! stay consistent across all tokens:
+//!
+//! - The transfer succeeds if the recipient is no contract.
+//! - The transfer succeeds if the recipient contract accepts it.
+//! - The transfer fails with `SafeTransferCheckFailed` if the recipient contract
+//!   rejects the transfer, does not implement the receiver trait or traps.
+//!
+//! # Example
+//!
+//! ```ignore
+//! fn transfer(
+//!     &mut self,
+//!     to: AccountId,
+//!     value: Balance,
+//!     data: Vec<u8>,
+//! ) -> Result<(), PSP22Error> {
+//!     let from = self.env().caller();
+//!     self.transfer_from_to(from, to, value)?;
+//!     receiver::notify_psp22_received::<Environment>(to, from, from, value, data)
+//! }
+//! ```
+
+use crate::{
+    psp22::PSP22Error,
+    psp34::{
+        Id,
+        PSP34Error,
+    },
+    AccountId,
+    Balance,
+};
+use ink_env::{
+    call::{
+        build_call,
+        utils::ReturnType,
+        ExecutionInput,
+        Selector,
+    },
+    Environment,
+};
+use ink_lang as ink;
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+
+/// The error returned by receivers to reject a safe transfer.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ReceiverError {
+    /// The receiver rejected the transfer for the given reason.
+    TransferRejected(String),
+}
+
+/// The selector of [`PSP22Receiver::before_received`].
+///
+/// Must be kept in sync with the selector pinned by the trait definition.
+pub const PSP22_BEFORE_RECEIVED_SELECTOR: [u8; 4] = [0xFD, 0xA6, 0xF1, 0xA9];
+
+/// The selector of [`PSP34Receiver::before_received`].
+///
+/// Must be kept in sync with the selector pinned by the trait definition.
+pub const PSP34_BEFORE_RECEIVED_SELECTOR: [u8; 4] = [0xBB, 0x7D, 0xF7, 0x80];
+
+/// Trait implemented by contracts that receive PSP22 tokens via safe transfers.
+#[ink::trait_definition]
+pub trait PSP22Receiver {
+    /// Called by the PSP22 token contract before `value` tokens of `from` are
+    /// transferred to the receiver on behalf of `operator`.
+    ///
+    /// The `data` is forwarded from the transfer message.
+    #[ink(message, selector = "0xFDA6F1A9")]
+    fn before_received(
+        &mut self,
+        operator: AccountId,
+        from: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), ReceiverError>;
+}
+
+/// Trait implemented by contracts that receive PSP34 tokens via safe transfers.
+#[ink::trait_definition]
+pub trait PSP34Receiver {
+    /// Called by the PSP34 token contract before the token `id` of `from` is
+    /// transferred to the receiver on behalf of `operator`.
+    ///
+    /// The `data` is forwarded from the transfer message.
+    #[ink(message, selector = "0xBB7DF780")]
+    fn before_received(
+        &mut self,
+        operator: AccountId,
+        from: AccountId,
+        id: Id,
+        data: Vec<u8>,
+    ) -> Result<(), ReceiverError>;
+}
+
+/// Notifies the recipient `to` of a PSP22 safe transfer if it is a contract.
+///
+/// Returns [`PSP22Error::SafeTransferCheckFailed`] if the recipient contract
+/// did not accept the transfer.
+///
+/// # Note
+///
+/// The receiver decodes the accounts and the value as [`AccountId`] and [`Balance`]
+/// so the environment `E` has to encode its types the same way.
+pub fn notify_psp22_received<E>(
+    to: E::AccountId,
+    operator: E::AccountId,
+    from: E::AccountId,
+    value: E::Balance,
+    data: Vec<u8>,
+) -> Result<(), PSP22Error>
+where
+    E: Environment,
+{
+    let input = ExecutionInput::new(Selector::new(PSP22_BEFORE_RECEIVED_SELECTOR))
+        .push_arg(operator)
+        .push_arg(from)
+        .push_arg(value)
+        .push_arg(data);
+    notify_received::<E, _>(to, input).map_err(PSP22Error::SafeTransferCheckFailed)
+}
+
+/// Notifies the recipient `to` of a PSP34 safe transfer if it is a contract.
+///
+/// Returns [`PSP34Error::SafeTransferCheckFailed`] if the recipient contract
+/// did not accept the transfer.
+///
+/// # Note
+///
+/// The receiver decodes the accounts as [`AccountId`] so the environment `E`
+/// has to encode its account IDs the same way.
+pub fn notify_psp34_received<E>(
+    to: E::AccountId,
+    operator: E::AccountId,
+    from: E::AccountId,
+    id: Id,
+    data: Vec<u8>,
+) -> Result<(), PSP34Error>
+where
+    E: Environment,
+{
+    let input = ExecutionInput::new(Selector::new(PSP34_BEFORE_RECEIVED_SELECTOR))
+        .push_arg(operator)
+        .push_arg(from)
+        .push_arg(id)
+        .push_arg(data);
+    notify_received::<E, _>(to, input).map_err(PSP34Error::SafeTransferCheckFailed)
+}
+
+/// Calls the receiver callback of `to` with the given input.
+///
+/// Returns the reason of the failed safe transfer check if any.
+fn notify_received<E, Args>(
+    to: E::AccountId,
+    input: ExecutionInput<Args>,
+) -> Result<(), String>
+where
+    E: Environment,
+    Args: scale::Encode,
+{
+    let result = build_call::<E>()
+        .callee(to)
+        .exec_input(input)
+        .returns::<ReturnType<Result<(), ReceiverError>>>()
+        .fire();
+    match result {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(ReceiverError::TransferRejected(reason))) => Err(reason),
+        // The recipient is no contract and therefore cannot reject the transfer.
+        Err(ink_env::Error::NotCallable) => Ok(()),
+        Err(_) => {
+            Err(String::from(
+                "recipient does not accept tokens via safe transfers",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink_env::{
+        hash::{
+            Blake2x256,
+            CryptoHash,
+            HashOutput,
+        },
+        DefaultEnvironment,
+    };
+
+    fn compose_selector(input: &[u8]) -> [u8; 4] {
+        let mut output = <Blake2x256 as HashOutput>::Type::default();
+        <Blake2x256 as CryptoHash>::hash(input, &mut output);
+        [output[0], output[1], output[2], output[3]]
+    }
+
+    #[test]
+    fn selectors_follow_the_standards() {
+        assert_eq!(
+            PSP22_BEFORE_RECEIVED_SELECTOR,
+            compose_selector(b"PSP22Receiver::before_received")
+        );
+        assert_eq!(
+            PSP34_BEFORE_RECEIVED_SELECTOR,
+            compose_selector(b"PSP34Receiver::before_received")
+        );
+    }
+
+    #[test]
+    fn notify_psp22_received_works() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+            let accepting = AccountId::from([0x01; 32]);
+            let rejecting = AccountId::from([0x02; 32]);
+            let ignorant = AccountId::from([0x03; 32]);
+            // The mocks answer calls with the selectors of the trait definitions.
+            MockPSP22Receiver::new()
+                .on_before_received(|_operator, _from, value, _data| {
+                    assert_eq!(value, 42);
+                    Ok(())
+                })
+                .register::<DefaultEnvironment>(accepting);
+            MockPSP22Receiver::new()
+                .on_before_received(|_operator, _from, _value, _data| {
+                    Err(ReceiverError::TransferRejected(String::from("no thanks")))
+                })
+                .register::<DefaultEnvironment>(rejecting);
+            MockPSP22Receiver::new().register::<DefaultEnvironment>(ignorant);
+
+            let notify = |to| {
+                notify_psp22_received::<DefaultEnvironment>(
+                    to,
+                    AccountId::from([0x04; 32]),
+                    AccountId::from([0x04; 32]),
+                    42,
+                    Vec::new(),
+                )
+            };
+            assert_eq!(notify(accepting), Ok(()));
+            assert_eq!(
+                notify(rejecting),
+                Err(PSP22Error::SafeTransferCheckFailed(String::from(
+                    "no thanks"
+                )))
+            );
+            assert!(matches!(
+                notify(ignorant),
+                Err(PSP22Error::SafeTransferCheckFailed(_))
+            ));
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn notify_psp34_received_works() {
+        ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+            let accepting = AccountId::from([0x01; 32]);
+            let rejecting = AccountId::from([0x02; 32]);
+            MockPSP34Receiver::new()
+                .on_before_received(|_operator, _from, id, _data| {
+                    assert_eq!(id, Id::U8(7));
+                    Ok(())
+                })
+                .register::<DefaultEnvironment>(accepting);
+            MockPSP34Receiver::new()
+                .on_before_received(|_operator, _from, _id, _data| {
+                    Err(ReceiverError::TransferRejected(String::from("no thanks")))
+                })
+                .register::<DefaultEnvironment>(rejecting);
+
+            let notify = |to| {
+                notify_psp34_received::<DefaultEnvironment>(
+                    to,
+                    AccountId::from([0x04; 32]),
+                    AccountId::from([0x04; 32]),
+                    Id::U8(7),
+                    Vec::new(),
+                )
+            };
+            assert_eq!(notify(accepting), Ok(()));
+            assert_eq!(
+                notify(rejecting),
+                Err(PSP34Error::SafeTransferCheckFailed(String::from(
+                    "no thanks"
+                )))
+            );
+            Ok(())
+        })
+        .unwrap()
+    }
+}
@@ -14,6 +14,7 @@
 
 extern crate proc_macro;
 
+mod max_encoded_len;
 mod packed_layout;
 mod spread_layout;
 mod storage_layout;
@@ -22,6 +23,7 @@ mod storage_layout;
 mod tests;
 
 use self::{
+    max_encoded_len::max_encoded_len_derive,
     packed_layout::packed_layout_derive,
     spread_layout::spread_layout_derive,
     storage_layout::storage_layout_derive,
@@ -29,3 +31,4 @@ use self::{
 synstructure::decl_derive!([SpreadLayout] => spread_layout_derive);
 synstructure::decl_derive!([PackedLayout] => packed_layout_derive);
 synstructure::decl_derive!([StorageLayout] => storage_layout_derive);
+synstructure::decl_derive!([MaxEncodedLen] => max_encoded_len_derive);
@@ -0,0 +1,52 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Returns the sum of the maximum encoded lengths of all fields of the variant.
+fn variant_max_encoded_len(variant: &synstructure::VariantInfo) -> TokenStream2 {
+    let field_lens = variant.ast().fields.iter().map(|field| {
+        let ty = &field.ty;
+        quote! { <#ty as ::ink_storage::traits::MaxEncodedLen>::MAX_ENCODED_LEN }
+    });
+    quote! { 0 #( + #field_lens )* }
+}
+
+/// Derives `ink_storage`'s `MaxEncodedLen` trait for the given `struct` or `enum`.
+///
+/// The length of an `enum` is the length of its longest variant plus one byte
+/// for the SCALE encoded discriminant.
+pub fn max_encoded_len_derive(mut s: synstructure::Structure) -> TokenStream2 {
+    s.add_bounds(synstructure::AddBounds::Generics)
+        .underscore_const(true);
+    let max_encoded_len = match s.ast().data {
+        syn::Data::Struct(_) => variant_max_encoded_len(&s.variants()[0]),
+        _ => {
+            let variant_lens = s.variants().iter().map(variant_max_encoded_len);
+            let mut max_variant_len = quote! { 0 };
+            for variant_len in variant_lens {
+                max_variant_len = quote! {
+                    ::ink_storage::traits::max_len(#max_variant_len, #variant_len)
+                };
+            }
+            quote! { 1 + #max_variant_len }
+        }
+    };
+    s.gen_impl(quote! {
+        gen impl ::ink_storage::traits::MaxEncodedLen for @Self {
+            const MAX_ENCODED_LEN: usize = #max_encoded_len;
+        }
+    })
+}
@@ -0,0 +1,89 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::max_encoded_len_derive;
+
+#[test]
+fn struct_works() {
+    synstructure::test_derive! {
+        max_encoded_len_derive {
+            struct NamedFields {
+                a: i32,
+                b: [u8; 32],
+            }
+        }
+        expands to {
+            const _: () = {
+                impl ::ink_storage::traits::MaxEncodedLen for NamedFields {
+                    const MAX_ENCODED_LEN: usize = 0
+                        + <i32 as ::ink_storage::traits::MaxEncodedLen>::MAX_ENCODED_LEN
+                        + <[u8; 32] as ::ink_storage::traits::MaxEncodedLen>::MAX_ENCODED_LEN;
+                }
+            };
+        }
+        no_build
+    }
+}
+
+#[test]
+fn enum_works() {
+    synstructure::test_derive! {
+        max_encoded_len_derive {
+            enum MixedEnum {
+                A,
+                B(i32),
+                C { a: i64, b: bool },
+            }
+        }
+        expands to {
+            const _: () = {
+                impl ::ink_storage::traits::MaxEncodedLen for MixedEnum {
+                    const MAX_ENCODED_LEN: usize = 1
+                        + ::ink_storage::traits::max_len(
+                            ::ink_storage::traits::max_len(
+                                ::ink_storage::traits::max_len(0, 0),
+                                0 + <i32 as ::ink_storage::traits::MaxEncodedLen>::MAX_ENCODED_LEN
+                            ),
+                            0 + <i64 as ::ink_storage::traits::MaxEncodedLen>::MAX_ENCODED_LEN
+                                + <bool as ::ink_storage::traits::MaxEncodedLen>::MAX_ENCODED_LEN
+                        );
+                }
+            };
+        }
+        no_build
+    }
+}
+
+#[test]
+fn generic_struct_works() {
+    synstructure::test_derive! {
+        max_encoded_len_derive {
+            struct GenericStruct<T> {
+                a: T,
+            }
+        }
+        expands to {
+            const _: () = {
+                impl<T> ::ink_storage::traits::MaxEncodedLen for GenericStruct<T>
+                where
+                    T: ::ink_storage::traits::MaxEncodedLen
+                {
+                    const MAX_ENCODED_LEN: usize = 0
+                        + <T as ::ink_storage::traits::MaxEncodedLen>::MAX_ENCODED_LEN;
+                }
+            };
+        }
+        no_build
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod max_encoded_len;
 mod packed_layout;
 mod spread_layout;
 mod storage_layout;
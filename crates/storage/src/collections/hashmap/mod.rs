@@ -338,6 +338,24 @@ where
         self.values.get_mut(key).map(|entry| &mut entry.value)
     }
 
+    /// Loads the values corresponding to the given keys upfront.
+    ///
+    /// Subsequent accesses to these keys are served without loading from
+    /// the contract storage.
+    ///
+    /// # Note
+    ///
+    /// Every value is loaded with its own contract storage read, so this
+    /// saves no host function calls compared to loading the values on access.
+    pub fn prefetch<'b, Q, I>(&mut self, keys: I)
+    where
+        K: Borrow<Q>,
+        Q: 'b + Ord + scale::Encode + ToOwned<Owned = K>,
+        I: IntoIterator<Item = &'b Q>,
+    {
+        self.values.prefetch(keys)
+    }
+
     /// Returns `true` if there is an entry corresponding to the key in the map.
     pub fn contains_key<Q>(&self, key: &Q) -> bool
     where
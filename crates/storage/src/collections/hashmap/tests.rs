@@ -301,6 +301,30 @@ fn spread_layout_push_pull_works() -> ink_env::Result<()> {
     })
 }
 
+#[test]
+fn prefetch_works() -> ink_env::Result<()> {
+    use ink_env::{
+        test,
+        DefaultEnvironment,
+    };
+    ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+        push_hmap(&filled_hmap());
+        let mut hmap = pull_hmap();
+        hmap.prefetch(&[b'A', b'C', b'X']);
+        // Prefetched entries must not be loaded again from the contract storage.
+        let contract = test::get_current_contract_account_id::<DefaultEnvironment>()?;
+        let (reads, _) = test::get_contract_storage_rw::<DefaultEnvironment>(&contract)?;
+        assert_eq!(hmap.get(&b'A'), Some(&1));
+        assert_eq!(hmap.get(&b'C'), Some(&3));
+        assert_eq!(hmap.get(&b'X'), None);
+        let (reads_after, _) =
+            test::get_contract_storage_rw::<DefaultEnvironment>(&contract)?;
+        assert_eq!(reads_after, reads);
+        assert_eq!(hmap.get(&b'B'), Some(&2));
+        Ok(())
+    })
+}
+
 #[test]
 #[should_panic(expected = "storage entry was empty")]
 fn spread_layout_clear_works() {
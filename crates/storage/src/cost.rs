@@ -0,0 +1,204 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Measures the storage costs of operations on storage entities.
+//!
+//! The costs are measured using the off-chain environment and allow to pick
+//! storage data structures based on data, e.g. whether a storage
+//! [`HashMap`](`crate::collections::HashMap`) or a [`Pack`](`crate::Pack`)ed
+//! `Vec` is cheaper for the operations and sizes a contract expects.
+//!
+//! # Example
+//!
+//! ```
+//! # use ink_primitives::Key;
+//! # use ink_storage::{cost, traits::push_spread_root, Vec as StorageVec};
+//! # ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+//! let key = Key::from([0x42; 32]);
+//! push_spread_root(&StorageVec::<u32>::new(), &key);
+//! let ((), cost) = cost::measure::<ink_env::DefaultEnvironment, _, _, _>(
+//!     &key,
+//!     |vec: &mut StorageVec<u32>| vec.push(42),
+//! );
+//! assert_eq!(cost.cells_written, 2);
+//! # Ok(())
+//! # }).unwrap();
+//! ```
+
+use crate::traits::{
+    pull_spread_root,
+    push_spread_root,
+    SpreadLayout,
+};
+use core::mem::ManuallyDrop;
+use ink_env::{
+    test::{
+        self,
+        Counts,
+        GasModel,
+    },
+    Environment,
+};
+use ink_primitives::Key;
+
+/// The storage cost of an operation on a storage entity.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct OperationCost {
+    /// The number of storage cells read.
+    pub cells_read: u64,
+    /// The number of storage cells written or removed.
+    pub cells_written: u64,
+    /// The number of encoded bytes read from storage cells.
+    pub bytes_read: u64,
+    /// The number of encoded bytes written to storage cells.
+    pub bytes_written: u64,
+}
+
+impl OperationCost {
+    /// Returns the number of storage cells read, written or removed.
+    pub fn cells_touched(&self) -> u64 {
+        self.cells_read + self.cells_written
+    }
+
+    /// Returns the number of encoded bytes read and written.
+    pub fn encoded_size_hint(&self) -> u64 {
+        self.bytes_read + self.bytes_written
+    }
+
+    /// Returns the weight estimate of the operation under the given gas model.
+    ///
+    /// # Note
+    ///
+    /// The base weight of the gas model is not included since it is paid
+    /// once per contract execution.
+    pub fn weight(&self, gas_model: &GasModel) -> u64 {
+        gas_model
+            .weight(&Counts {
+                storage_reads: self.cells_read,
+                storage_writes: self.cells_written,
+                bytes_read: self.bytes_read,
+                bytes_written: self.bytes_written,
+                events: 0,
+            })
+            .saturating_sub(gas_model.base)
+    }
+
+    /// Returns the current storage counters of the executing contract.
+    fn current<E>() -> Self
+    where
+        E: Environment,
+    {
+        let account_id = test::get_current_contract_account_id::<E>()
+            .expect("encountered missing executing contract");
+        let (cells_read, cells_written) = test::get_contract_storage_rw::<E>(&account_id)
+            .expect("encountered missing contract storage");
+        let (bytes_read, bytes_written) =
+            test::get_contract_storage_bytes_rw::<E>(&account_id)
+                .expect("encountered missing contract storage");
+        Self {
+            cells_read: cells_read as u64,
+            cells_written: cells_written as u64,
+            bytes_read: bytes_read as u64,
+            bytes_written: bytes_written as u64,
+        }
+    }
+
+    /// Returns the costs that have been added since `earlier`.
+    fn since(&self, earlier: &Self) -> Self {
+        Self {
+            cells_read: self.cells_read - earlier.cells_read,
+            cells_written: self.cells_written - earlier.cells_written,
+            bytes_read: self.bytes_read - earlier.bytes_read,
+            bytes_written: self.bytes_written - earlier.bytes_written,
+        }
+    }
+}
+
+/// Measures the storage cost of the operation `f` on the entity at `root_key`.
+///
+/// The entity is pulled from the contract storage, operated on by `f` and pushed
+/// back afterwards the same way an ink! message operates on its storage. The
+/// returned cost includes all three steps.
+///
+/// `E` must be the environment the off-chain environment has been initialized
+/// with, e.g. via [`run_test`](`ink_env::test::run_test`).
+///
+/// # Panics
+///
+/// If there is no executing contract in the off-chain environment.
+pub fn measure<E, T, F, R>(root_key: &Key, f: F) -> (R, OperationCost)
+where
+    E: Environment,
+    T: SpreadLayout,
+    F: FnOnce(&mut T) -> R,
+{
+    let before = OperationCost::current::<E>();
+    // The entity must not be dropped since some storage entities clean up
+    // their storage cells upon dropping.
+    let mut entity = ManuallyDrop::new(pull_spread_root::<T>(root_key));
+    let result = f(&mut entity);
+    push_spread_root::<T>(&entity, root_key);
+    let cost = OperationCost::current::<E>().since(&before);
+    (result, cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Pack,
+        Vec as StorageVec,
+    };
+    use ink_env::DefaultEnvironment;
+    use ink_prelude::vec::Vec;
+
+    #[test]
+    fn measure_works() {
+        crate::test_utils::run_test(|| {
+            let vec_key = Key::from([0x01; 32]);
+            let pack_key = Key::from([0x02; 32]);
+            push_spread_root(&(0..10).collect::<StorageVec<u32>>(), &vec_key);
+            push_spread_root(&Pack::new((0..10).collect::<Vec<u32>>()), &pack_key);
+            let ((), vec_cost) = measure::<DefaultEnvironment, _, _, _>(
+                &vec_key,
+                |vec: &mut StorageVec<u32>| vec.push(10),
+            );
+            let ((), pack_cost) = measure::<DefaultEnvironment, _, _, _>(
+                &pack_key,
+                |vec: &mut Pack<Vec<u32>>| vec.push(10),
+            );
+            assert_eq!(vec_cost.cells_written, 2);
+            assert_eq!(
+                pack_cost,
+                OperationCost {
+                    cells_read: 1,
+                    cells_written: 1,
+                    bytes_read: 41,
+                    bytes_written: 45,
+                }
+            );
+            assert_eq!(pack_cost.cells_touched(), 2);
+            assert_eq!(pack_cost.encoded_size_hint(), 86);
+            assert!(vec_cost.bytes_written < pack_cost.bytes_written);
+            let gas_model = GasModel::default();
+            assert_eq!(
+                pack_cost.weight(&gas_model),
+                gas_model.storage_read
+                    + gas_model.storage_write
+                    + 41 * gas_model.storage_read_byte
+                    + 45 * gas_model.storage_write_byte
+            );
+        })
+    }
+}
@@ -51,6 +51,7 @@ use ink_prelude::{
         Entry as BTreeMapEntry,
         OccupiedEntry as BTreeMapOccupiedEntry,
     },
+    vec::Vec,
 };
 use ink_primitives::Key;
 
@@ -676,6 +677,56 @@ where
         self.lazily_load_mut(index).value_mut().into()
     }
 
+    /// Loads the values associated with the given keys via
+    /// [`ink_env::get_contract_storage_batch`].
+    ///
+    /// # Note
+    ///
+    /// Every value is loaded with its own contract storage read, so prefetching
+    /// costs as many host function calls as loading the values on access.
+    /// Keys that have already been loaded are skipped. Does nothing if the lazy
+    /// hash map is not associated with a storage key.
+    ///
+    /// # Panics
+    ///
+    /// - If loading one of the elements from the contract storage failed.
+    /// - If the decoding of one of the loaded elements failed.
+    pub fn prefetch<'b, Q, I>(&mut self, keys: I)
+    where
+        K: Borrow<Q>,
+        Q: 'b + Ord + scale::Encode + ToOwned<Owned = K>,
+        I: IntoIterator<Item = &'b Q>,
+    {
+        let storage_key = match self.key {
+            Some(storage_key) => storage_key,
+            None => return,
+        };
+        let pending = keys
+            .into_iter()
+            .filter(|key| !self.entries().contains_key(*key))
+            .map(|key| (key.to_owned(), self.to_offset_key(&storage_key, key)))
+            .collect::<BTreeMap<K, Key>>();
+        if pending.is_empty() {
+            return
+        }
+        let root_keys = pending.values().copied().collect::<Vec<_>>();
+        let loaded = ink_env::get_contract_storage_batch(&root_keys)
+            .expect("could not load the prefetched elements");
+        let entries = self.entries_mut();
+        for ((key, root_key), encoded) in pending.into_iter().zip(loaded) {
+            let value = encoded.map(|encoded| {
+                let mut value = <V as scale::Decode>::decode(&mut &encoded[..])
+                    .expect("decoding does not match expected type");
+                <V as PackedLayout>::pull_packed(&mut value, &root_key);
+                value
+            });
+            entries.insert(
+                key,
+                Box::new(StorageEntry::new(value, EntryState::Preserved)),
+            );
+        }
+    }
+
     /// Puts the new value under the given key and returns the old value if any.
     ///
     /// # Note
@@ -1270,6 +1321,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prefetch_works() -> ink_env::Result<()> {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut hmap = new_hmap();
+            hmap.put(1, Some(b'A'));
+            hmap.put(2, Some(b'B'));
+            let root_key = Key::from([0x42; 32]);
+            SpreadLayout::push_spread(&hmap, &mut KeyPtr::from(root_key));
+            let mut hmap2 =
+                <LazyHashMap<i32, u8, Blake2x256> as SpreadLayout>::pull_spread(
+                    &mut KeyPtr::from(root_key),
+                );
+            assert_eq!(hmap2.get_mut(&2), Some(&mut b'B'));
+            // Already loaded entries are not overwritten by a prefetch.
+            *hmap2.get_mut(&2).unwrap() = b'C';
+            hmap2.prefetch(&[1, 2, 3, 1]);
+            assert_cached_entries(
+                &hmap2,
+                &[
+                    (1, StorageEntry::new(Some(b'A'), EntryState::Preserved)),
+                    (2, StorageEntry::new(Some(b'C'), EntryState::Mutated)),
+                    (3, StorageEntry::new(None, EntryState::Preserved)),
+                ],
+            );
+            // Prefetching a lazy hash map without a storage key does nothing.
+            let mut hmap3 = new_hmap();
+            hmap3.prefetch(&[1, 2]);
+            assert_cached_entries(&hmap3, &[]);
+            Ok(())
+        })
+    }
+
     #[test]
     fn spread_layout_works() -> ink_env::Result<()> {
         ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
@@ -49,6 +49,8 @@ extern crate quickcheck_macros;
 
 pub mod alloc;
 pub mod collections;
+#[cfg(feature = "std")]
+pub mod cost;
 pub mod lazy;
 mod memory;
 mod pack;
@@ -12,21 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::max;
+use super::{
+    forward_clear_packed,
+    forward_pull_packed,
+    forward_push_packed,
+    max,
+};
 use crate::traits::{
     KeyPtr,
     PackedLayout,
     SpreadLayout,
 };
 use ink_env::{
+    call::DeferredCall,
     AccountId,
+    Environment,
     Hash,
 };
 use ink_prelude::{
     boxed::Box,
     string::String,
 };
-use ink_primitives::Key;
+use ink_primitives::{
+    math::{
+        FixedI128,
+        FixedU128,
+        TokenAmount,
+        U256,
+    },
+    Key,
+};
 
 macro_rules! impl_layout_for_primitive {
     ( $($ty:ty),* $(,)? ) => {
@@ -49,6 +64,7 @@ impl_layout_for_primitive!(
     // do not support them since they are non deterministic. We might add them
     // to this list once we add deterministic support for those primitives.
     Key, Hash, AccountId, (),
+    U256, FixedI128, FixedU128,
     String,
     bool,
     u8, u16, u32, u64, u128,
@@ -238,11 +254,85 @@ where
     }
 }
 
+impl<E> SpreadLayout for DeferredCall<E>
+where
+    E: Environment,
+{
+    const FOOTPRINT: u64 = 1;
+    const REQUIRES_DEEP_CLEAN_UP: bool = false;
+
+    #[inline]
+    fn pull_spread(ptr: &mut KeyPtr) -> Self {
+        forward_pull_packed::<Self>(ptr)
+    }
+
+    #[inline]
+    fn push_spread(&self, ptr: &mut KeyPtr) {
+        forward_push_packed::<Self>(self, ptr)
+    }
+
+    #[inline]
+    fn clear_spread(&self, ptr: &mut KeyPtr) {
+        forward_clear_packed::<Self>(self, ptr)
+    }
+}
+
+impl<E> PackedLayout for DeferredCall<E>
+where
+    E: Environment,
+{
+    #[inline(always)]
+    fn pull_packed(&mut self, _at: &Key) {}
+    #[inline(always)]
+    fn push_packed(&self, _at: &Key) {}
+    #[inline(always)]
+    fn clear_packed(&self, _at: &Key) {}
+}
+
+impl<const DECIMALS: u8> SpreadLayout for TokenAmount<DECIMALS> {
+    const FOOTPRINT: u64 = 1;
+    const REQUIRES_DEEP_CLEAN_UP: bool = false;
+
+    #[inline]
+    fn pull_spread(ptr: &mut KeyPtr) -> Self {
+        forward_pull_packed::<Self>(ptr)
+    }
+
+    #[inline]
+    fn push_spread(&self, ptr: &mut KeyPtr) {
+        forward_push_packed::<Self>(self, ptr)
+    }
+
+    #[inline]
+    fn clear_spread(&self, ptr: &mut KeyPtr) {
+        forward_clear_packed::<Self>(self, ptr)
+    }
+}
+
+impl<const DECIMALS: u8> PackedLayout for TokenAmount<DECIMALS> {
+    #[inline(always)]
+    fn pull_packed(&mut self, _at: &Key) {}
+    #[inline(always)]
+    fn push_packed(&self, _at: &Key) {}
+    #[inline(always)]
+    fn clear_packed(&self, _at: &Key) {}
+}
+
 #[cfg(test)]
 mod tests {
     use crate::push_pull_works_for_primitive;
     use ink_env::AccountId;
-    use ink_primitives::Key;
+    use ink_primitives::{
+        math::{
+            FixedI128,
+            FixedU128,
+            TokenAmount,
+            U256,
+        },
+        Key,
+    };
+
+    type Usdc = TokenAmount<6>;
 
     push_pull_works_for_primitive!(bool, [false, true]);
     push_pull_works_for_primitive!(
@@ -257,6 +347,22 @@ mod tests {
             Key::from([0xFF; 32])
         ]
     );
+    push_pull_works_for_primitive!(
+        U256,
+        [U256::ZERO, U256::from(42u8), U256::from(u128::MAX), U256::MAX]
+    );
+    push_pull_works_for_primitive!(
+        FixedI128,
+        [FixedI128::MIN, -FixedI128::ONE, FixedI128::ZERO, FixedI128::MAX]
+    );
+    push_pull_works_for_primitive!(
+        FixedU128,
+        [FixedU128::ZERO, FixedU128::ONE, FixedU128::MAX]
+    );
+    push_pull_works_for_primitive!(
+        Usdc,
+        [Usdc::ZERO, Usdc::from_raw(1_500_000), Usdc::MAX]
+    );
     push_pull_works_for_primitive!(
         AccountId,
         [
@@ -293,4 +399,22 @@ mod tests {
 
     type BoxOptionU8 = Box<Option<u8>>;
     push_pull_works_for_primitive!(BoxOptionU8, [Box::new(Some(27)), Box::new(None)]);
+
+    type DeferredCall = ink_env::call::DeferredCall<ink_env::DefaultEnvironment>;
+    push_pull_works_for_primitive!(
+        DeferredCall,
+        [DeferredCall::from_params(
+            &ink_env::call::build_call::<ink_env::DefaultEnvironment>()
+                .callee(AccountId::from([0x42; 32]))
+                .gas_limit(5000)
+                .exec_input(
+                    ink_env::call::ExecutionInput::new(ink_env::call::Selector::new([
+                        0xDE, 0xAD, 0xBE, 0xEF
+                    ]))
+                    .push_arg(42i32)
+                )
+                .returns::<()>()
+                .params()
+        )]
+    );
 }
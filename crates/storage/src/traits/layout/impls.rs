@@ -19,7 +19,9 @@ use crate::traits::{
     SpreadLayout,
 };
 use ink_env::{
+    call::DeferredCall,
     AccountId,
+    Environment,
     Hash,
 };
 use ink_metadata::layout::{
@@ -38,7 +40,15 @@ use ink_prelude::{
     string::String,
     vec::Vec,
 };
-use ink_primitives::Key;
+use ink_primitives::{
+    math::{
+        FixedI128,
+        FixedU128,
+        TokenAmount,
+        U256,
+    },
+    Key,
+};
 use scale_info::TypeInfo;
 
 macro_rules! impl_storage_layout_for_primitives {
@@ -55,6 +65,7 @@ macro_rules! impl_storage_layout_for_primitives {
 #[rustfmt::skip]
 impl_storage_layout_for_primitives!(
     Key, Hash, AccountId, String,
+    U256, FixedI128, FixedU128,
     bool, char, (),
     u8, u16, u32, u64, u128,
     i8, i16, i32, i64, i128,
@@ -120,6 +131,26 @@ impl_layout_for_tuple!(A, B, C, D, E, F, G, H);
 impl_layout_for_tuple!(A, B, C, D, E, F, G, H, I);
 impl_layout_for_tuple!(A, B, C, D, E, F, G, H, I, J);
 
+impl<E> StorageLayout for DeferredCall<E>
+where
+    E: Environment,
+    Self: TypeInfo + 'static,
+{
+    fn layout(key_ptr: &mut KeyPtr) -> Layout {
+        Layout::Cell(CellLayout::new::<Self>(LayoutKey::from(
+            key_ptr.advance_by(1),
+        )))
+    }
+}
+
+impl<const DECIMALS: u8> StorageLayout for TokenAmount<DECIMALS> {
+    fn layout(key_ptr: &mut KeyPtr) -> Layout {
+        Layout::Cell(CellLayout::new::<Self>(LayoutKey::from(
+            key_ptr.advance_by(1),
+        )))
+    }
+}
+
 impl<T> StorageLayout for Box<T>
 where
     T: StorageLayout,
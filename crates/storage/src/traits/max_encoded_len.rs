@@ -0,0 +1,181 @@
+// Copyright 2018-2020 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Upper bounds for the SCALE encoded length of types.
+//!
+//! Types with a bounded encoded length can be decoded with a worst-case gas
+//! cost that is known in advance. Types that are unbounded such as `Vec<u8>`
+//! or `String` intentionally do not implement the trait.
+
+use core::marker::PhantomData;
+use ink_env::{
+    AccountId,
+    Hash,
+};
+use ink_prelude::boxed::Box;
+use ink_primitives::{
+    math::{
+        FixedI128,
+        FixedU128,
+        TokenAmount,
+        U256,
+    },
+    CompactKey,
+    Key,
+};
+
+/// Types with an upper bound for the length of their SCALE encoding.
+///
+/// # Note
+///
+/// The trait can be derived for `struct` and `enum` types with the
+/// `MaxEncodedLen` derive macro if all of their fields implement it.
+pub trait MaxEncodedLen {
+    /// The maximum number of bytes of the SCALE encoding of any instance.
+    const MAX_ENCODED_LEN: usize;
+}
+
+/// Returns the greater of both lengths.
+///
+/// Used by the `MaxEncodedLen` derive macro for `enum` types.
+#[doc(hidden)]
+pub const fn max_len(a: usize, b: usize) -> usize {
+    [a, b][(a > b) as usize]
+}
+
+macro_rules! impl_max_encoded_len_for_primitive {
+    ( $($ty:ty),* $(,)? ) => {
+        $(
+            impl MaxEncodedLen for $ty {
+                const MAX_ENCODED_LEN: usize = core::mem::size_of::<$ty>();
+            }
+        )*
+    };
+}
+#[rustfmt::skip]
+impl_max_encoded_len_for_primitive!(
+    (), bool,
+    u8, u16, u32, u64, u128,
+    i8, i16, i32, i64, i128,
+    Key, CompactKey,
+    U256, FixedI128, FixedU128,
+);
+
+impl MaxEncodedLen for AccountId {
+    const MAX_ENCODED_LEN: usize = 32;
+}
+
+impl MaxEncodedLen for Hash {
+    const MAX_ENCODED_LEN: usize = 32;
+}
+
+impl<const DECIMALS: u8> MaxEncodedLen for TokenAmount<DECIMALS> {
+    const MAX_ENCODED_LEN: usize = <u128 as MaxEncodedLen>::MAX_ENCODED_LEN;
+}
+
+impl<T> MaxEncodedLen for PhantomData<T> {
+    const MAX_ENCODED_LEN: usize = 0;
+}
+
+impl<T> MaxEncodedLen for Box<T>
+where
+    T: MaxEncodedLen,
+{
+    const MAX_ENCODED_LEN: usize = <T as MaxEncodedLen>::MAX_ENCODED_LEN;
+}
+
+impl<T> MaxEncodedLen for Option<T>
+where
+    T: MaxEncodedLen,
+{
+    const MAX_ENCODED_LEN: usize = 1 + <T as MaxEncodedLen>::MAX_ENCODED_LEN;
+}
+
+impl<T, E> MaxEncodedLen for Result<T, E>
+where
+    T: MaxEncodedLen,
+    E: MaxEncodedLen,
+{
+    const MAX_ENCODED_LEN: usize = 1 + max_len(
+        <T as MaxEncodedLen>::MAX_ENCODED_LEN,
+        <E as MaxEncodedLen>::MAX_ENCODED_LEN,
+    );
+}
+
+macro_rules! impl_max_encoded_len_for_tuple {
+    ( $($frag:ident),* $(,)? ) => {
+        impl<$($frag),*> MaxEncodedLen for ($($frag),* ,)
+        where
+            $(
+                $frag: MaxEncodedLen,
+            )*
+        {
+            const MAX_ENCODED_LEN: usize = 0 $(+ <$frag as MaxEncodedLen>::MAX_ENCODED_LEN)*;
+        }
+    }
+}
+impl_max_encoded_len_for_tuple!(A);
+impl_max_encoded_len_for_tuple!(A, B);
+impl_max_encoded_len_for_tuple!(A, B, C);
+impl_max_encoded_len_for_tuple!(A, B, C, D);
+impl_max_encoded_len_for_tuple!(A, B, C, D, E);
+impl_max_encoded_len_for_tuple!(A, B, C, D, E, F);
+impl_max_encoded_len_for_tuple!(A, B, C, D, E, F, G);
+impl_max_encoded_len_for_tuple!(A, B, C, D, E, F, G, H);
+impl_max_encoded_len_for_tuple!(A, B, C, D, E, F, G, H, I);
+impl_max_encoded_len_for_tuple!(A, B, C, D, E, F, G, H, I, J);
+
+macro_rules! impl_max_encoded_len_for_array {
+    ( $($len:literal),* $(,)? ) => {
+        $(
+            impl<T> MaxEncodedLen for [T; $len]
+            where
+                T: MaxEncodedLen,
+            {
+                const MAX_ENCODED_LEN: usize = $len * <T as MaxEncodedLen>::MAX_ENCODED_LEN;
+            }
+        )*
+    }
+}
+#[rustfmt::skip]
+impl_max_encoded_len_for_array!(
+         1,  2,  3,  4,  5,  6,  7,  8,  9,
+    10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+    20, 21, 22, 23, 24, 25, 26, 27, 28, 29,
+    30, 31, 32, 64, 128, 256,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::Encode;
+
+    fn assert_max_encoded_len<T>(value: T)
+    where
+        T: MaxEncodedLen + Encode,
+    {
+        assert!(value.encode().len() <= <T as MaxEncodedLen>::MAX_ENCODED_LEN);
+    }
+
+    #[test]
+    fn max_encoded_len_works() {
+        assert_eq!(<u32 as MaxEncodedLen>::MAX_ENCODED_LEN, 4);
+        assert_eq!(<Option<u64> as MaxEncodedLen>::MAX_ENCODED_LEN, 9);
+        assert_eq!(<Result<u8, [u16; 4]> as MaxEncodedLen>::MAX_ENCODED_LEN, 9);
+        assert_eq!(<(bool, AccountId) as MaxEncodedLen>::MAX_ENCODED_LEN, 33);
+        assert_max_encoded_len(Some(Key::from([0x01; 32])));
+        assert_max_encoded_len::<Result<u8, u128>>(Err(u128::MAX));
+        assert_max_encoded_len((true, [42_i32; 8]));
+    }
+}
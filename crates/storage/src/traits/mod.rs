@@ -25,6 +25,7 @@
 
 mod impls;
 mod keyptr;
+mod max_encoded_len;
 mod optspec;
 mod packed;
 mod spread;
@@ -54,13 +55,17 @@ pub use self::{
         ExtKeyPtr,
         KeyPtr,
     },
+    max_encoded_len::MaxEncodedLen,
     packed::PackedLayout,
     spread::{
         SpreadLayout,
         FOOTPRINT_CLEANUP_THRESHOLD,
     },
 };
+#[doc(hidden)]
+pub use self::max_encoded_len::max_len;
 pub use ::ink_storage_derive::{
+    MaxEncodedLen,
     PackedLayout,
     SpreadLayout,
     StorageLayout,